@@ -11,14 +11,38 @@ pub fn build(b: *std.Build) void {
         .optimize = optimize,
     });
 
+    // Official `Rent` vectors (`src/rent/`), shared with the package
+    // tests that check their account sizes against them.
+    const rent_vectors_mod = b.addModule("rent_vectors", .{
+        .root_source_file = b.path("src/rent/vectors.zig"),
+        .target = target,
+        .optimize = optimize,
+    });
+
+    // SDK-version and tag filtering for the package fixture loaders.
+    // Tested on its own: tests in an imported module don't run with the
+    // SDK's.
+    const vector_filter_mod = b.addModule("vector_filter", .{
+        .root_source_file = b.path("src/vector_filter.zig"),
+        .target = target,
+        .optimize = optimize,
+    });
+
     const lib_unit_tests = b.addTest(.{
         .root_module = solana_mod,
     });
+    lib_unit_tests.root_module.addImport("rent_vectors", rent_vectors_mod);
 
     const run_lib_unit_tests = b.addRunArtifact(lib_unit_tests);
 
+    const vector_filter_tests = b.addTest(.{
+        .root_module = vector_filter_mod,
+    });
+    const run_vector_filter_tests = b.addRunArtifact(vector_filter_tests);
+
     const test_step = b.step("test", "Run unit tests");
     test_step.dependOn(&run_lib_unit_tests.step);
+    test_step.dependOn(&run_vector_filter_tests.step);
 
     // -------------------------------------------------------------
     // `zig build examples` — build every `.so` under `examples/`.
@@ -43,6 +67,9 @@ pub fn build(b: *std.Build) void {
             "token_dispatch",
             "cpi",
             "pubkey",
+            "program_id_check",
+            "duplicate_aliasing",
+            "slot_hashes",
         };
         inline for (examples) |name| {
             const ex = buildProgramLocal(b, .{
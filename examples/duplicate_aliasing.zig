@@ -0,0 +1,48 @@
+//! Duplicate aliasing — one writable account passed twice.
+//!
+//! When an instruction lists the same account at two positions, the
+//! runtime serializes the second slot as a duplicate marker, and both
+//! `AccountInfo`s point at the same record in the input region. This
+//! program moves `amount` lamports from a program-owned `source` into
+//! `target` through position 0, then requires position 2 (the duplicate
+//! of `target`) to observe the credit → `Custom(6000)` otherwise.
+//!
+//! Accounts: target (w), source (w, program-owned), target again (w)
+//! Data:     [amount:u64]
+//!
+//! Expected semantics live in `src/entrypoint/duplicate_aliasing_vectors.json`,
+//! shared with the entrypoint unit tests and `program-test`.
+//!
+//! SDK features showcased:
+//!   - `parseAccounts` — resolves the duplicate slot back to the
+//!     original `AccountInfo`
+//!   - `subLamports` / `addLamports` — direct lamport mutation
+
+const sol = @import("solana_program_sdk");
+
+pub const panic = sol.panic.Panic;
+
+const AliasErr = sol.ErrorCode(
+    enum(u32) {
+        AliasNotObserved = 6000,
+    },
+    error{AliasNotObserved},
+);
+
+fn process(ctx: *sol.entrypoint.InstructionContext) AliasErr.Error!void {
+    const accs = try ctx.parseAccounts(.{ "target", "source", "target_again" });
+    try ctx.requireIxDataLen(@sizeOf(u64));
+    const amount = ctx.readIx(u64, 0);
+
+    const before = accs.target_again.lamports();
+    accs.source.subLamports(amount);
+    accs.target.addLamports(amount);
+
+    if (accs.target_again.lamports() != before + amount) {
+        return AliasErr.toError(.AliasNotObserved);
+    }
+}
+
+export fn entrypoint(input: [*]u8) u64 {
+    return sol.entrypoint.lazyEntrypointTyped(AliasErr, process)(input);
+}
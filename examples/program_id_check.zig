@@ -0,0 +1,59 @@
+//! Program-id self-check — assert the runtime-provided program id.
+//!
+//! The program id is the last field of the serialized input region,
+//! right after the instruction data, so its offset moves with the
+//! account count and every account's data length. This example walks
+//! all accounts (dup-aware), then:
+//!   - compares the runtime program id against the baked-in
+//!     `PROGRAM_ID` → `Custom(6000)` when deployed under another key
+//!   - if the instruction data carries an expected `program_id_offset`
+//!     (u64 LE), compares it with where the id actually sits in the
+//!     input buffer → `Custom(6001)` on mismatch
+//!
+//! SDK features showcased:
+//!   - `InstructionContext.skipAccounts` + `programId` — reach the
+//!     trailing program id without parsing account views
+//!   - `pubkeyEqComptime` — compare against a compiled-in id with no
+//!     rodata load
+//!   - `ErrorCode` — stable custom codes on the wire
+
+const sol = @import("solana_program_sdk");
+
+pub const panic = sol.panic.Panic;
+
+// Must match `program-test/tests/program_id_check.rs`.
+const PROGRAM_ID = sol.pubkey.comptimeFromBase58(
+    "Zigc1Hc97L8Pebma74jDzYiyoUvdxxcj7Gxppg9VRxK",
+);
+
+const ProgramIdErr = sol.ErrorCode(
+    enum(u32) {
+        WrongProgramId = 6000,
+        ProgramIdOffsetMismatch = 6001,
+    },
+    error{ WrongProgramId, ProgramIdOffsetMismatch },
+);
+
+fn process(input: [*]u8, ctx: *sol.entrypoint.InstructionContext) ProgramIdErr.Error!void {
+    ctx.skipAccounts(ctx.remainingAccounts());
+    const program_id = try ctx.programId();
+
+    if (!sol.pubkey.pubkeyEqComptime(program_id, PROGRAM_ID)) {
+        return ProgramIdErr.toError(.WrongProgramId);
+    }
+
+    if (ctx.instructionDataUnchecked().len >= @sizeOf(u64)) {
+        const expected_offset = ctx.readIx(u64, 0);
+        if (@intFromPtr(program_id) - @intFromPtr(input) != expected_offset) {
+            return ProgramIdErr.toError(.ProgramIdOffsetMismatch);
+        }
+    }
+}
+
+export fn entrypoint(input: [*]u8) u64 {
+    // Hand-rolled instead of `lazyEntrypointTyped` so `process` can
+    // measure the program id's offset from the start of the input.
+    var ctx = sol.entrypoint.InstructionContext.init(input);
+    process(input, &ctx) catch |err| return ProgramIdErr.catchToU64(err);
+    return sol.SUCCESS;
+}
@@ -0,0 +1,63 @@
+//! Slot hashes — read the SlotHashes and RecentBlockhashes sysvars.
+//!
+//! Both sysvars are too large for a fixed-size syscall read, so the
+//! program takes them as accounts, checks their keys, and views the
+//! account data in place. It returns what it saw as return data:
+//!
+//!   [slot_hashes_len:u64][newest_slot:u64][newest_slot_hash:32]
+//!   [recent_blockhashes_len:u64][newest_blockhash:32]
+//!   [lamports_per_signature:u64]
+//!
+//! Newest fields are zero when a sysvar holds no entries.
+//!
+//! Accounts: slot_hashes sysvar, recent_blockhashes sysvar
+//!
+//! SDK features showcased:
+//!   - `slot_hashes.SlotHash.from` / `recent_blockhashes.Entry.from` —
+//!     zero-copy views over sysvar account data
+//!   - `keyEqComptime` — reject a substituted sysvar account
+//!   - `cpi.setReturnData`
+
+const std = @import("std");
+const sol = @import("solana_program_sdk");
+
+pub const panic = sol.panic.Panic;
+
+const SlotHash = sol.slot_hashes.SlotHash;
+const RecentBlockhash = sol.recent_blockhashes.Entry;
+
+const OUTPUT_LEN = 8 + 8 + 32 + 8 + 32 + 8;
+
+fn process(ctx: *sol.entrypoint.InstructionContext) sol.ProgramResult {
+    const accs = try ctx.parseAccounts(.{ "slot_hashes", "recent_blockhashes" });
+    if (!accs.slot_hashes.keyEqComptime(sol.slot_hashes_id) or
+        !accs.recent_blockhashes.keyEqComptime(sol.recent_blockhashes_id))
+    {
+        return error.InvalidArgument;
+    }
+    if (accs.slot_hashes.dataLen() < @sizeOf(u64) or
+        accs.recent_blockhashes.dataLen() < @sizeOf(u64))
+    {
+        return error.InvalidAccountData;
+    }
+
+    const slot_hashes = SlotHash.from(accs.slot_hashes.data());
+    const recent_blockhashes = RecentBlockhash.from(accs.recent_blockhashes.data());
+
+    var out: [OUTPUT_LEN]u8 = .{0} ** OUTPUT_LEN;
+    std.mem.writeInt(u64, out[0..8], slot_hashes.len, .little);
+    if (slot_hashes.len > 0) {
+        std.mem.writeInt(u64, out[8..16], slot_hashes[0].slot, .little);
+        out[16..48].* = slot_hashes[0].hash;
+    }
+    std.mem.writeInt(u64, out[48..56], recent_blockhashes.len, .little);
+    if (recent_blockhashes.len > 0) {
+        out[56..88].* = recent_blockhashes[0].blockhash;
+        std.mem.writeInt(u64, out[88..96], recent_blockhashes[0].lamports_per_signature, .little);
+    }
+    sol.cpi.setReturnData(&out);
+}
+
+export fn entrypoint(input: [*]u8) u64 {
+    return sol.entrypoint.lazyEntrypoint(process)(input);
+}
@@ -0,0 +1,106 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_address_lookup_table_interface::instruction::create_lookup_table;
+use solana_pubkey::Pubkey;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_boundary_vectors.json");
+
+const U64_BOUNDARIES: [u64; 11] = [
+    0,
+    1,
+    (1 << 31) - 1,
+    1 << 31,
+    u32::MAX as u64,
+    1 << 32,
+    (1 << 53) - 1,
+    1 << 53,
+    (1 << 53) + 1,
+    u64::MAX - 1,
+    u64::MAX,
+];
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct BoundaryVector {
+    name: String,
+    family: String,
+    field: String,
+    value: u64,
+    offset: usize,
+    width: usize,
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<BoundaryVector>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+fn sweep_u64(
+    family: &str,
+    field: &str,
+    offset: usize,
+    build: impl Fn(u64) -> Vec<u8>,
+) -> Vec<BoundaryVector> {
+    U64_BOUNDARIES
+        .into_iter()
+        .map(|value| BoundaryVector {
+            name: format!("{family}_{field}_boundary_{value}"),
+            family: family.to_string(),
+            field: field.to_string(),
+            value,
+            offset,
+            width: 8,
+            data: build(value),
+        })
+        .collect()
+}
+
+fn official_fixture() -> Fixture {
+    let authority = key(1);
+    let payer = key(2);
+
+    let vectors = sweep_u64("alt_create", "recent_slot", 4, |recent_slot| {
+        create_lookup_table(authority, payer, recent_slot).0.data
+    });
+    Fixture { vectors }
+}
+
+#[test]
+fn fixture_matches_official_boundary_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_boundary_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn boundary_values_are_encoded_little_endian_at_their_offset() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+
+    for vector in &fixture.vectors {
+        let end = vector.offset + vector.width;
+        assert_eq!(
+            vector.data[vector.offset..end],
+            vector.value.to_le_bytes()[..vector.width],
+            "{}",
+            vector.name
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_boundary_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
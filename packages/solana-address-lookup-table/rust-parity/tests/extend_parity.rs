@@ -0,0 +1,143 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_address_lookup_table_interface::{
+    instruction::{extend_lookup_table, ProgramInstruction},
+    state::LOOKUP_TABLE_MAX_ADDRESSES,
+};
+use solana_instruction::Instruction;
+use solana_message::Message;
+use solana_packet::PACKET_DATA_SIZE;
+use solana_pubkey::Pubkey;
+use solana_transaction::Transaction;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_extend_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ExtendVector {
+    name: String,
+    /// Addresses already stored in the table before the extend.
+    existing_addresses: usize,
+    new_addresses: usize,
+    data: Vec<u8>,
+    expected_addresses_after: usize,
+    /// The builder accepts every case; the program rejects an empty
+    /// extend and one that would grow the table past
+    /// `LOOKUP_TABLE_MAX_ADDRESSES` with `InvalidInstructionData`.
+    expected_to_fail: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    max_addresses: usize,
+    vectors: Vec<ExtendVector>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+/// Table address `index`: `index` as a little-endian `u16`, then `0xa1`
+/// padding. New addresses continue the table's index sequence.
+fn address(index: usize) -> Pubkey {
+    let mut bytes = [0xa1; 32];
+    bytes[..2].copy_from_slice(&(index as u16).to_le_bytes());
+    Pubkey::from(bytes)
+}
+
+/// The official builder's instruction for `addresses`, the `extend_vector`
+/// keys, with `key(5)` paying.
+fn extend_instruction(addresses: Vec<Pubkey>) -> Instruction {
+    extend_lookup_table(key(3), key(4), Some(key(5)), addresses)
+}
+
+fn extend_vector(name: &str, existing_addresses: usize, new_addresses: usize) -> ExtendVector {
+    let addresses = (existing_addresses..existing_addresses + new_addresses)
+        .map(address)
+        .collect();
+    let ix = extend_instruction(addresses);
+    let expected_addresses_after = existing_addresses + new_addresses;
+    ExtendVector {
+        name: name.to_string(),
+        existing_addresses,
+        new_addresses,
+        data: ix.data,
+        expected_addresses_after,
+        expected_to_fail: new_addresses == 0
+            || expected_addresses_after > LOOKUP_TABLE_MAX_ADDRESSES,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        max_addresses: LOOKUP_TABLE_MAX_ADDRESSES,
+        vectors: vec![
+            extend_vector("extend_lookup_table_empty", 0, 0),
+            extend_vector("extend_lookup_table_1", 0, 1),
+            extend_vector("extend_lookup_table_38", 10, 38),
+            extend_vector("extend_lookup_table_256", 0, 256),
+            extend_vector("extend_lookup_table_past_max", 250, 7),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_extend_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_extend_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn extend_data_decodes_to_the_recorded_addresses() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        // Discriminant, u64 count, then the pubkeys back to back.
+        assert_eq!(vector.data.len(), 4 + 8 + vector.new_addresses * 32);
+        let ProgramInstruction::ExtendLookupTable { new_addresses } =
+            bincode::deserialize(&vector.data).unwrap()
+        else {
+            panic!("{} is not an extend", vector.name);
+        };
+        assert_eq!(new_addresses.len(), vector.new_addresses, "{}", vector.name);
+        for (i, decoded) in new_addresses.iter().enumerate() {
+            assert_eq!(*decoded, address(vector.existing_addresses + i));
+        }
+    }
+}
+
+#[test]
+fn extend_transactions_fit_in_a_packet_below_the_chunk_limit() {
+    // With these four keys one extend carries at most 30 addresses before its
+    // transaction outgrows a packet; larger extends are split across
+    // transactions.
+    const OVERSIZED: [&str; 2] = ["extend_lookup_table_38", "extend_lookup_table_256"];
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let ix = Instruction {
+            data: vector.data.clone(),
+            ..extend_instruction(vec![])
+        };
+        let transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&key(5))));
+        let len = bincode::serialized_size(&transaction).unwrap() as usize;
+        assert_eq!(
+            len > PACKET_DATA_SIZE,
+            OVERSIZED.contains(&vector.name.as_str()),
+            "{}: {len} bytes",
+            vector.name
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_extend_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
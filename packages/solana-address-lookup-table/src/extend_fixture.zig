@@ -0,0 +1,24 @@
+const std = @import("std");
+
+pub const ExtendVector = struct {
+    name: []const u8,
+    existing_addresses: usize,
+    new_addresses: usize,
+    data: []const u8,
+    expected_addresses_after: usize,
+    expected_to_fail: bool,
+};
+
+pub const Fixture = struct {
+    max_addresses: usize,
+    vectors: []const ExtendVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_extend_vectors.json"),
+        .{},
+    );
+}
@@ -452,6 +452,59 @@ test "lookup table management builders encode official discriminants" {
     try std.testing.expectEqualSlices(u8, &recipient, close.accounts[2].pubkey);
 }
 
+test "integer boundary sweep matches official Rust encodings" {
+    const boundary_fixture = @import("boundary_fixture.zig");
+    var parsed = try boundary_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    try std.testing.expectEqual(@as(usize, 11), parsed.value.vectors.len);
+
+    const authority: Pubkey = .{1} ** sol.PUBKEY_BYTES;
+    const payer: Pubkey = .{2} ** sol.PUBKEY_BYTES;
+
+    for (parsed.value.vectors) |vector| {
+        try std.testing.expectEqualStrings("alt_create", vector.family);
+
+        var lookup_table: Pubkey = undefined;
+        var metas: [4]AccountMeta = undefined;
+        var data: CreateLookupTableData = undefined;
+        const ix = createLookupTable(&authority, &payer, vector.value, false, &lookup_table, &metas, &data);
+
+        try std.testing.expectEqualSlices(u8, vector.data, ix.data);
+        try std.testing.expectEqual(vector.value, std.mem.readInt(u64, ix.data[vector.offset..][0..8], .little));
+    }
+}
+
+test "extend payloads match official Rust vectors" {
+    const extend_fixture = @import("extend_fixture.zig");
+    var parsed = try extend_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    try std.testing.expectEqual(LOOKUP_TABLE_MAX_ADDRESSES, parsed.value.max_addresses);
+    try std.testing.expectEqual(@as(usize, 5), parsed.value.vectors.len);
+
+    const table: Pubkey = .{3} ** sol.PUBKEY_BYTES;
+    const authority: Pubkey = .{4} ** sol.PUBKEY_BYTES;
+    const payer: Pubkey = .{5} ** sol.PUBKEY_BYTES;
+
+    for (parsed.value.vectors) |vector| {
+        // Same address rule as the Rust generator: the table index as a
+        // little-endian u16, then 0xa1 padding.
+        var addresses: [LOOKUP_TABLE_MAX_ADDRESSES]Pubkey = undefined;
+        for (addresses[0..vector.new_addresses], vector.existing_addresses..) |*address, index| {
+            address.* = .{0xa1} ** sol.PUBKEY_BYTES;
+            std.mem.writeInt(u16, address[0..2], @intCast(index), .little);
+        }
+
+        var metas: [4]AccountMeta = undefined;
+        var data: ExtendLookupTableData = undefined;
+        const ix = try extendLookupTableFunded(&table, &authority, &payer, addresses[0..vector.new_addresses], &metas, &data);
+        try std.testing.expectEqualSlices(u8, vector.data, ix.data);
+
+        const after = vector.existing_addresses + vector.new_addresses;
+        try std.testing.expectEqual(vector.expected_addresses_after, after);
+        try std.testing.expectEqual(vector.expected_to_fail, vector.new_addresses == 0 or after > LOOKUP_TABLE_MAX_ADDRESSES);
+    }
+}
+
 test "public surface guards" {
     try std.testing.expect(@hasDecl(@This(), "parse"));
     try std.testing.expect(@hasDecl(@This(), "resolveAddresses"));
@@ -461,3 +514,9 @@ test "public surface guards" {
     try std.testing.expect(!@hasDecl(@This(), "rpc"));
     try std.testing.expect(!@hasDecl(@This(), "wallet"));
 }
+
+test "LOOKUP_TABLE_META_SIZE matches the official lookup-table meta size" {
+    var parsed = try @import("rent_vectors").loadStandardAccountMinimums(std.testing.allocator);
+    defer parsed.deinit();
+    try std.testing.expectEqual(LOOKUP_TABLE_META_SIZE, parsed.value.get("address_lookup_table_meta").?.data_len);
+}
@@ -0,0 +1,96 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use base64::{
+    engine::general_purpose::{STANDARD, STANDARD_NO_PAD},
+    Engine,
+};
+use serde::{Deserialize, Serialize};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_base64_vectors.json");
+
+/// Seed for the pseudorandom buffer; RPC account data is arbitrary bytes.
+const SEED: u64 = 0x5eed_0001;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Base64TestVector {
+    name: String,
+    bytes: Vec<u8>,
+    /// `base64::engine::general_purpose::STANDARD`, as RPC account data and
+    /// `Program return:` logs use.
+    standard: String,
+    /// `STANDARD_NO_PAD`.
+    standard_no_pad: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    seed: u64,
+    vectors: Vec<Base64TestVector>,
+}
+
+fn vector(name: &str, bytes: Vec<u8>) -> Base64TestVector {
+    Base64TestVector {
+        name: name.to_string(),
+        standard: STANDARD.encode(&bytes),
+        standard_no_pad: STANDARD_NO_PAD.encode(&bytes),
+        bytes,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    // Lengths 0..=5 hit every padding case twice over: none, `==`, `=`.
+    let mut vectors: Vec<_> = (0..=5u8)
+        .map(|len| vector(&format!("len_{len}"), (0..len).map(|i| 0xf0 | i).collect()))
+        .collect();
+    vectors.push(vector(
+        "return_data_u64",
+        0x0102_0304_0506_0708u64.to_le_bytes().to_vec(),
+    ));
+    vectors.push(vector(
+        "pseudorandom_1024",
+        vectors::pseudorandom_bytes(SEED, 1024),
+    ));
+    Fixture {
+        seed: SEED,
+        vectors,
+    }
+}
+
+#[test]
+fn fixture_matches_official_base64_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_base64_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn both_alphabets_decode_back_and_differ_only_in_padding() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        assert_eq!(STANDARD.decode(&vector.standard).unwrap(), vector.bytes);
+        assert_eq!(
+            STANDARD_NO_PAD.decode(&vector.standard_no_pad).unwrap(),
+            vector.bytes
+        );
+        assert_eq!(
+            vector.standard.trim_end_matches('='),
+            vector.standard_no_pad,
+            "{}",
+            vector.name
+        );
+        assert_eq!(vector.standard.len() % 4, 0);
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_base64_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
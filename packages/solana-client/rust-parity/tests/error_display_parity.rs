@@ -0,0 +1,238 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_instruction_error::InstructionError;
+use solana_program_error::ProgramError;
+use solana_transaction_error::TransactionError;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_error_display_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ErrorDisplayTestVector {
+    /// `InstructionError::<Debug>` or `TransactionError::<Debug>`.
+    error_kind: String,
+    bincode_encoded: Vec<u8>,
+    /// The serde JSON form RPC returns as a failed transaction's `err`.
+    rpc_json: serde_json::Value,
+    display_string: String,
+    /// `u64::from(ProgramError::try_from(err))` for instruction errors a
+    /// program can return, linking them to the builtin error codes.
+    program_error_code: Option<u64>,
+    /// First solana-sdk release with this variant; older RPC nodes and SDKs
+    /// cannot produce or decode it. Left out for long-standing variants.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    since_sdk: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    instruction_errors: Vec<ErrorDisplayTestVector>,
+    transaction_errors: Vec<ErrorDisplayTestVector>,
+}
+
+/// Every fieldless variant, found by decoding each bincode `u32` tag on its
+/// own. Variants with a payload fail to decode and are added by hand.
+fn unit_variants<T: serde::de::DeserializeOwned>() -> Vec<T> {
+    (0u32..128)
+        .filter_map(|tag| bincode::deserialize(&tag.to_le_bytes()).ok())
+        .collect()
+}
+
+fn instruction_error_vector(err: InstructionError) -> ErrorDisplayTestVector {
+    ErrorDisplayTestVector {
+        error_kind: format!("InstructionError::{err:?}"),
+        bincode_encoded: bincode::serialize(&err).unwrap(),
+        rpc_json: serde_json::to_value(&err).unwrap(),
+        display_string: format!("{err}"),
+        program_error_code: ProgramError::try_from(err).ok().map(u64::from),
+        since_sdk: None,
+    }
+}
+
+/// Release that introduced each of the newest `TransactionError` variants.
+fn transaction_error_since_sdk(err: &TransactionError) -> Option<&'static str> {
+    match err {
+        TransactionError::ProgramCacheHitMaxLimit => Some("1.18.0"),
+        TransactionError::CommitCancelled => Some("2.2.0"),
+        _ => None,
+    }
+}
+
+/// Parse a `major.minor.patch` SDK version for ordering.
+fn sdk_version(version: &str) -> (u64, u64, u64) {
+    let mut parts = version.split('.').map(|part| part.parse::<u64>().unwrap());
+    let version = (
+        parts.next().unwrap(),
+        parts.next().unwrap(),
+        parts.next().unwrap(),
+    );
+    assert!(parts.next().is_none(), "{version:?}");
+    version
+}
+
+/// Vectors a consumer pinned to `sdk` can decode.
+fn vectors_up_to<'a>(
+    vectors: &'a [ErrorDisplayTestVector],
+    sdk: &str,
+) -> Vec<&'a ErrorDisplayTestVector> {
+    vectors
+        .iter()
+        .filter(|vector| {
+            vector
+                .since_sdk
+                .as_deref()
+                .is_none_or(|since| sdk_version(since) <= sdk_version(sdk))
+        })
+        .collect()
+}
+
+fn transaction_error_vector(err: TransactionError) -> ErrorDisplayTestVector {
+    ErrorDisplayTestVector {
+        since_sdk: transaction_error_since_sdk(&err).map(str::to_string),
+        error_kind: format!("TransactionError::{err:?}"),
+        bincode_encoded: bincode::serialize(&err).unwrap(),
+        rpc_json: serde_json::to_value(&err).unwrap(),
+        display_string: format!("{err}"),
+        program_error_code: None,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let mut instruction_errors = unit_variants::<InstructionError>();
+    // Custom codes print as `{:#x}`: the form users grep logs for.
+    instruction_errors.extend([0, 42, 6_000, u32::MAX].map(InstructionError::Custom));
+
+    let mut transaction_errors = unit_variants::<TransactionError>();
+    transaction_errors.extend([
+        TransactionError::InstructionError(0, InstructionError::Custom(42)),
+        TransactionError::InstructionError(1, InstructionError::InsufficientFunds),
+        TransactionError::InstructionError(2, InstructionError::Custom(6_000)),
+        TransactionError::InstructionError(255, InstructionError::ComputationalBudgetExceeded),
+        TransactionError::DuplicateInstruction(3),
+        TransactionError::InsufficientFundsForRent { account_index: 4 },
+        TransactionError::ProgramExecutionTemporarilyRestricted { account_index: 5 },
+    ]);
+
+    Fixture {
+        instruction_errors: instruction_errors
+            .into_iter()
+            .map(instruction_error_vector)
+            .collect(),
+        transaction_errors: transaction_errors
+            .into_iter()
+            .map(transaction_error_vector)
+            .collect(),
+    }
+}
+
+#[test]
+fn fixture_matches_official_error_display_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_error_display_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn encodings_round_trip_and_custom_codes_are_hex() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.instruction_errors {
+        let err: InstructionError = bincode::deserialize(&vector.bincode_encoded).unwrap();
+        assert_eq!(
+            err,
+            serde_json::from_value(vector.rpc_json.clone()).unwrap()
+        );
+        assert_eq!(format!("{err}"), vector.display_string);
+        if let InstructionError::Custom(code) = err {
+            assert_eq!(
+                vector.display_string,
+                format!("custom program error: 0x{code:x}")
+            );
+            let expected = if code == 0 {
+                solana_program_error::CUSTOM_ZERO
+            } else {
+                u64::from(code)
+            };
+            assert_eq!(vector.program_error_code, Some(expected));
+        }
+    }
+    for vector in &fixture.transaction_errors {
+        let err: TransactionError = bincode::deserialize(&vector.bincode_encoded).unwrap();
+        assert_eq!(
+            err,
+            serde_json::from_value(vector.rpc_json.clone()).unwrap()
+        );
+        assert_eq!(format!("{err}"), vector.display_string);
+        if let TransactionError::InstructionError(index, inner) = err {
+            let inner = fixture
+                .instruction_errors
+                .iter()
+                .find(|v| v.error_kind == format!("InstructionError::{inner:?}"))
+                .unwrap();
+            assert_eq!(
+                vector.display_string,
+                format!(
+                    "Error processing Instruction {index}: {}",
+                    inner.display_string
+                )
+            );
+        }
+    }
+}
+
+#[test]
+fn filtering_by_sdk_version_drops_only_tagged_variants() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    assert!(fixture
+        .instruction_errors
+        .iter()
+        .all(|v| v.since_sdk.is_none()));
+    let tagged: Vec<&str> = fixture
+        .transaction_errors
+        .iter()
+        .filter(|v| v.since_sdk.is_some())
+        .map(|v| v.error_kind.as_str())
+        .collect();
+    assert_eq!(
+        tagged,
+        [
+            "TransactionError::ProgramCacheHitMaxLimit",
+            "TransactionError::CommitCancelled"
+        ]
+    );
+
+    let kinds = |vectors: Vec<&ErrorDisplayTestVector>| -> Vec<String> {
+        vectors.into_iter().map(|v| v.error_kind.clone()).collect()
+    };
+    let all = kinds(fixture.transaction_errors.iter().collect());
+    let without = |dropped: &[&str]| -> Vec<String> {
+        all.iter()
+            .filter(|kind| !dropped.contains(&kind.as_str()))
+            .cloned()
+            .collect()
+    };
+    for (sdk, dropped) in [
+        ("1.17.34", &tagged[..]),
+        ("2.1.21", &tagged[1..]),
+        ("2.2.0", &[][..]),
+        ("3.0.0", &[][..]),
+    ] {
+        assert_eq!(
+            kinds(vectors_up_to(&fixture.transaction_errors, sdk)),
+            without(dropped),
+            "{sdk}"
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_error_display_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
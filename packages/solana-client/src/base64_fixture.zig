@@ -0,0 +1,22 @@
+const std = @import("std");
+
+pub const Base64Vector = struct {
+    name: []const u8,
+    bytes: []const u8,
+    standard: []const u8,
+    standard_no_pad: []const u8,
+};
+
+pub const Fixture = struct {
+    seed: u64,
+    vectors: []const Base64Vector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_base64_vectors.json"),
+        .{},
+    );
+}
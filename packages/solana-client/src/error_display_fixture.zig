@@ -0,0 +1,37 @@
+const std = @import("std");
+const vector_filter = @import("vector_filter");
+
+pub const ErrorDisplayVector = struct {
+    error_kind: []const u8,
+    bincode_encoded: []const u8,
+    rpc_json: std.json.Value,
+    display_string: []const u8,
+    program_error_code: ?u64,
+    /// First solana-sdk release with this variant; null for long-standing
+    /// variants every supported SDK knows.
+    since_sdk: ?[]const u8 = null,
+};
+
+pub const Fixture = struct {
+    instruction_errors: []const ErrorDisplayVector,
+    transaction_errors: []const ErrorDisplayVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_error_display_vectors.json"),
+        .{},
+    );
+}
+
+/// The vectors a consumer pinned to `sdk` can decode, in order. The caller
+/// owns the returned slice; the vectors still borrow from the parsed fixture.
+pub fn vectorsUpTo(
+    allocator: std.mem.Allocator,
+    vectors: []const ErrorDisplayVector,
+    sdk: std.SemanticVersion,
+) ![]ErrorDisplayVector {
+    return vector_filter.upTo(ErrorDisplayVector, allocator, vectors, sdk);
+}
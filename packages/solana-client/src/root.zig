@@ -22,6 +22,7 @@ pub const Error = error{
     InvalidEndpointUrl,
     NoEndpoints,
     ResponseTooLarge,
+    UnsupportedTransactionError,
 };
 
 pub const TransportError = error{
@@ -882,6 +883,151 @@ pub fn normalizeRpcError(err: RpcError) NormalizedRpcError {
     };
 }
 
+/// Render a failed transaction's RPC `err` value (the serde JSON form of
+/// `TransactionError`) the way Rust's `Display` does, e.g.
+/// `{"InstructionError":[0,{"Custom":42}]}` becomes
+/// `Error processing Instruction 0: custom program error: 0x2a`.
+pub fn formatTransactionError(err: std.json.Value, out: []u8) Error![]const u8 {
+    const name, const payload = try rpcErrorVariant(err);
+    const value = payload orelse return copyErrorMessage(&transaction_error_messages, name, out);
+
+    if (std.mem.eql(u8, name, "InstructionError")) {
+        const pair = switch (value) {
+            .array => |array| array.items,
+            else => return error.UnsupportedTransactionError,
+        };
+        if (pair.len != 2) return error.UnsupportedTransactionError;
+        const index = try rpcErrorInteger(u8, pair[0]);
+        const prefix = try bufPrint(out, "Error processing Instruction {d}: ", .{index});
+        const message = try formatInstructionError(pair[1], out[prefix.len..]);
+        return out[0 .. prefix.len + message.len];
+    }
+    if (std.mem.eql(u8, name, "DuplicateInstruction")) {
+        const index = try rpcErrorInteger(u8, value);
+        return bufPrint(out, "Transaction contains a duplicate instruction ({d}) that is not allowed", .{index});
+    }
+    if (std.mem.eql(u8, name, "InsufficientFundsForRent")) {
+        const index = try rpcErrorAccountIndex(value);
+        return bufPrint(out, "Transaction results in an account ({d}) with insufficient funds for rent", .{index});
+    }
+    if (std.mem.eql(u8, name, "ProgramExecutionTemporarilyRestricted")) {
+        const index = try rpcErrorAccountIndex(value);
+        return bufPrint(out, "Execution of the program referenced by account at index {d} is temporarily restricted.", .{index});
+    }
+    return error.UnsupportedTransactionError;
+}
+
+/// Render an `InstructionError` RPC value like Rust's `Display`. Custom
+/// codes print as `custom program error: 0x<hex>`, the form logs carry.
+pub fn formatInstructionError(err: std.json.Value, out: []u8) Error![]const u8 {
+    const name, const payload = try rpcErrorVariant(err);
+    const value = payload orelse return copyErrorMessage(&instruction_error_messages, name, out);
+    if (!std.mem.eql(u8, name, "Custom")) return error.UnsupportedTransactionError;
+    const code = try rpcErrorInteger(u32, value);
+    return bufPrint(out, "custom program error: 0x{x}", .{code});
+}
+
+const ErrorMessage = struct {
+    name: []const u8,
+    message: []const u8,
+};
+
+// `Display` strings of the fieldless variants, from
+// `src/official_error_display_vectors.json`.
+const instruction_error_messages = [_]ErrorMessage{
+    .{ .name = "GenericError", .message = "generic instruction error" },
+    .{ .name = "InvalidArgument", .message = "invalid program argument" },
+    .{ .name = "InvalidInstructionData", .message = "invalid instruction data" },
+    .{ .name = "InvalidAccountData", .message = "invalid account data for instruction" },
+    .{ .name = "AccountDataTooSmall", .message = "account data too small for instruction" },
+    .{ .name = "InsufficientFunds", .message = "insufficient funds for instruction" },
+    .{ .name = "IncorrectProgramId", .message = "incorrect program id for instruction" },
+    .{ .name = "MissingRequiredSignature", .message = "missing required signature for instruction" },
+    .{ .name = "AccountAlreadyInitialized", .message = "instruction requires an uninitialized account" },
+    .{ .name = "UninitializedAccount", .message = "instruction requires an initialized account" },
+    .{ .name = "UnbalancedInstruction", .message = "sum of account balances before and after instruction do not match" },
+    .{ .name = "ModifiedProgramId", .message = "instruction illegally modified the program id of an account" },
+    .{ .name = "ExternalAccountLamportSpend", .message = "instruction spent from the balance of an account it does not own" },
+    .{ .name = "ExternalAccountDataModified", .message = "instruction modified data of an account it does not own" },
+    .{ .name = "ReadonlyLamportChange", .message = "instruction changed the balance of a read-only account" },
+    .{ .name = "ReadonlyDataModified", .message = "instruction modified data of a read-only account" },
+    .{ .name = "DuplicateAccountIndex", .message = "instruction contains duplicate accounts" },
+    .{ .name = "ExecutableModified", .message = "instruction changed executable bit of an account" },
+    .{ .name = "RentEpochModified", .message = "instruction modified rent epoch of an account" },
+    .{ .name = "NotEnoughAccountKeys", .message = "insufficient account keys for instruction" },
+    .{ .name = "AccountDataSizeChanged", .message = "program other than the account's owner changed the size of the account data" },
+    .{ .name = "AccountNotExecutable", .message = "instruction expected an executable account" },
+    .{ .name = "AccountBorrowFailed", .message = "instruction tries to borrow reference for an account which is already borrowed" },
+    .{ .name = "AccountBorrowOutstanding", .message = "instruction left account with an outstanding borrowed reference" },
+    .{ .name = "DuplicateAccountOutOfSync", .message = "instruction modifications of multiply-passed account differ" },
+    .{ .name = "InvalidError", .message = "program returned invalid error code" },
+    .{ .name = "ExecutableDataModified", .message = "instruction changed executable accounts data" },
+    .{ .name = "ExecutableLamportChange", .message = "instruction changed the balance of an executable account" },
+    .{ .name = "ExecutableAccountNotRentExempt", .message = "executable accounts must be rent exempt" },
+    .{ .name = "UnsupportedProgramId", .message = "Unsupported program id" },
+    .{ .name = "CallDepth", .message = "Cross-program invocation call depth too deep" },
+    .{ .name = "MissingAccount", .message = "An account required by the instruction is missing" },
+    .{ .name = "ReentrancyNotAllowed", .message = "Cross-program invocation reentrancy not allowed for this instruction" },
+    .{ .name = "MaxSeedLengthExceeded", .message = "Length of the seed is too long for address generation" },
+    .{ .name = "InvalidSeeds", .message = "Provided seeds do not result in a valid address" },
+    .{ .name = "InvalidRealloc", .message = "Failed to reallocate account data" },
+    .{ .name = "ComputationalBudgetExceeded", .message = "Computational budget exceeded" },
+    .{ .name = "PrivilegeEscalation", .message = "Cross-program invocation with unauthorized signer or writable account" },
+    .{ .name = "ProgramEnvironmentSetupFailure", .message = "Failed to create program execution environment" },
+    .{ .name = "ProgramFailedToComplete", .message = "Program failed to complete" },
+    .{ .name = "ProgramFailedToCompile", .message = "Program failed to compile" },
+    .{ .name = "Immutable", .message = "Account is immutable" },
+    .{ .name = "IncorrectAuthority", .message = "Incorrect authority provided" },
+    .{ .name = "BorshIoError", .message = "Failed to serialize or deserialize account data" },
+    .{ .name = "AccountNotRentExempt", .message = "An account does not have enough lamports to be rent-exempt" },
+    .{ .name = "InvalidAccountOwner", .message = "Invalid account owner" },
+    .{ .name = "ArithmeticOverflow", .message = "Program arithmetic overflowed" },
+    .{ .name = "UnsupportedSysvar", .message = "Unsupported sysvar" },
+    .{ .name = "IllegalOwner", .message = "Provided owner is not allowed" },
+    .{ .name = "MaxAccountsDataAllocationsExceeded", .message = "Accounts data allocations exceeded the maximum allowed per transaction" },
+    .{ .name = "MaxAccountsExceeded", .message = "Max accounts exceeded" },
+    .{ .name = "MaxInstructionTraceLengthExceeded", .message = "Max instruction trace length exceeded" },
+    .{ .name = "BuiltinProgramsMustConsumeComputeUnits", .message = "Builtin programs must consume compute units" },
+};
+
+const transaction_error_messages = [_]ErrorMessage{
+    .{ .name = "AccountInUse", .message = "Account in use" },
+    .{ .name = "AccountLoadedTwice", .message = "Account loaded twice" },
+    .{ .name = "AccountNotFound", .message = "Attempt to debit an account but found no record of a prior credit." },
+    .{ .name = "ProgramAccountNotFound", .message = "Attempt to load a program that does not exist" },
+    .{ .name = "InsufficientFundsForFee", .message = "Insufficient funds for fee" },
+    .{ .name = "InvalidAccountForFee", .message = "This account may not be used to pay transaction fees" },
+    .{ .name = "AlreadyProcessed", .message = "This transaction has already been processed" },
+    .{ .name = "BlockhashNotFound", .message = "Blockhash not found" },
+    .{ .name = "CallChainTooDeep", .message = "Loader call chain is too deep" },
+    .{ .name = "MissingSignatureForFee", .message = "Transaction requires a fee but has no signature present" },
+    .{ .name = "InvalidAccountIndex", .message = "Transaction contains an invalid account reference" },
+    .{ .name = "SignatureFailure", .message = "Transaction did not pass signature verification" },
+    .{ .name = "InvalidProgramForExecution", .message = "This program may not be used for executing instructions" },
+    .{ .name = "SanitizeFailure", .message = "Transaction failed to sanitize accounts offsets correctly" },
+    .{ .name = "ClusterMaintenance", .message = "Transactions are currently disabled due to cluster maintenance" },
+    .{ .name = "AccountBorrowOutstanding", .message = "Transaction processing left an account with an outstanding borrowed reference" },
+    .{ .name = "WouldExceedMaxBlockCostLimit", .message = "Transaction would exceed max Block Cost Limit" },
+    .{ .name = "UnsupportedVersion", .message = "Transaction version is unsupported" },
+    .{ .name = "InvalidWritableAccount", .message = "Transaction loads a writable account that cannot be written" },
+    .{ .name = "WouldExceedMaxAccountCostLimit", .message = "Transaction would exceed max account limit within the block" },
+    .{ .name = "WouldExceedAccountDataBlockLimit", .message = "Transaction would exceed account data limit within the block" },
+    .{ .name = "TooManyAccountLocks", .message = "Transaction locked too many accounts" },
+    .{ .name = "AddressLookupTableNotFound", .message = "Transaction loads an address table account that doesn't exist" },
+    .{ .name = "InvalidAddressLookupTableOwner", .message = "Transaction loads an address table account with an invalid owner" },
+    .{ .name = "InvalidAddressLookupTableData", .message = "Transaction loads an address table account with invalid data" },
+    .{ .name = "InvalidAddressLookupTableIndex", .message = "Transaction address table lookup uses an invalid index" },
+    .{ .name = "InvalidRentPayingAccount", .message = "Transaction leaves an account with a lower balance than rent-exempt minimum" },
+    .{ .name = "WouldExceedMaxVoteCostLimit", .message = "Transaction would exceed max Vote Cost Limit" },
+    .{ .name = "WouldExceedAccountDataTotalLimit", .message = "Transaction would exceed total account data limit" },
+    .{ .name = "MaxLoadedAccountsDataSizeExceeded", .message = "Transaction exceeded max loaded accounts data size cap" },
+    .{ .name = "InvalidLoadedAccountsDataSizeLimit", .message = "LoadedAccountsDataSizeLimit set for transaction must be greater than 0." },
+    .{ .name = "ResanitizationNeeded", .message = "ResanitizationNeeded" },
+    .{ .name = "UnbalancedTransaction", .message = "Sum of account balances before and after transaction do not match" },
+    .{ .name = "ProgramCacheHitMaxLimit", .message = "Program cache hit max limit" },
+    .{ .name = "CommitCancelled", .message = "CommitCancelled" },
+};
+
 pub fn websocketUrlLen(endpoint: Endpoint) Error!usize {
     if (endpoint.websocket_url) |ws_url| {
         if (!isWebsocketUrl(ws_url)) return error.InvalidEndpointUrl;
@@ -1195,6 +1341,45 @@ fn bufPrint(out: []u8, comptime fmt: []const u8, args: anytype) Error![]u8 {
     };
 }
 
+/// Split a serde enum value into its variant name and, for variants with
+/// data, the payload: `"Name"` or `{"Name": payload}`.
+fn rpcErrorVariant(value: std.json.Value) Error!struct { []const u8, ?std.json.Value } {
+    switch (value) {
+        .string => |name| return .{ name, null },
+        .object => |object| {
+            if (object.count() != 1) return error.UnsupportedTransactionError;
+            return .{ object.keys()[0], object.values()[0] };
+        },
+        else => return error.UnsupportedTransactionError,
+    }
+}
+
+fn rpcErrorInteger(comptime T: type, value: std.json.Value) Error!T {
+    const integer = switch (value) {
+        .integer => |integer| integer,
+        else => return error.UnsupportedTransactionError,
+    };
+    return std.math.cast(T, integer) orelse error.UnsupportedTransactionError;
+}
+
+fn rpcErrorAccountIndex(value: std.json.Value) Error!u8 {
+    const object = switch (value) {
+        .object => |object| object,
+        else => return error.UnsupportedTransactionError,
+    };
+    return rpcErrorInteger(u8, object.get("account_index") orelse return error.UnsupportedTransactionError);
+}
+
+fn copyErrorMessage(table: []const ErrorMessage, name: []const u8, out: []u8) Error![]const u8 {
+    for (table) |entry| {
+        if (!std.mem.eql(u8, entry.name, name)) continue;
+        if (out.len < entry.message.len) return error.OutputTooSmall;
+        @memcpy(out[0..entry.message.len], entry.message);
+        return out[0..entry.message.len];
+    }
+    return error.UnsupportedTransactionError;
+}
+
 test "builds getLatestBlockhash request with commitment" {
     var buf: [128]u8 = undefined;
     const request = try buildGetLatestBlockhashRequest(1, .finalized, &buf);
@@ -1897,6 +2082,108 @@ test "http status maps to transport errors" {
     try std.testing.expectEqual(error.InvalidResponse, transportErrorForHttpStatus(.bad_request).?);
 }
 
+test "transaction and instruction errors format like official Rust Display" {
+    const error_display_fixture = @import("error_display_fixture.zig");
+    var parsed = try error_display_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    var buf: [160]u8 = undefined;
+
+    for (fixture.instruction_errors) |vector| {
+        const message = try formatInstructionError(vector.rpc_json, &buf);
+        try std.testing.expectEqualStrings(vector.display_string, message);
+
+        // Builtins a program can return line up with the SDK's
+        // `ProgramError` codes; only `Immutable` is renamed on the Zig side.
+        const code = vector.program_error_code orelse continue;
+        const program_error = sol.program_error.u64ToError(code);
+        const name = vector.error_kind["InstructionError::".len..];
+        if (std.mem.startsWith(u8, name, "Custom(")) {
+            try std.testing.expectEqual(error.Custom, program_error);
+            continue;
+        }
+        try std.testing.expectEqual(code, sol.program_error.errorToU64(program_error));
+        const zig_name = if (std.mem.eql(u8, name, "Immutable")) "ImmutableAccount" else name;
+        try std.testing.expectEqualStrings(zig_name, @errorName(program_error));
+    }
+
+    for (fixture.transaction_errors) |vector| {
+        const message = try formatTransactionError(vector.rpc_json, &buf);
+        try std.testing.expectEqualStrings(vector.display_string, message);
+    }
+
+    try std.testing.expectError(error.OutputTooSmall, formatTransactionError(fixture.transaction_errors[0].rpc_json, buf[0..1]));
+    try std.testing.expectError(error.UnsupportedTransactionError, formatTransactionError(.{ .string = "NotAnError" }, &buf));
+}
+
+test "sdk version filter drops only the newest transaction errors" {
+    const error_display_fixture = @import("error_display_fixture.zig");
+    var parsed = try error_display_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+
+    for (fixture.instruction_errors) |vector| {
+        try std.testing.expect(vector.since_sdk == null);
+    }
+
+    const Case = struct { sdk: std.SemanticVersion, dropped: []const []const u8 };
+    const cases = [_]Case{
+        .{ .sdk = .{ .major = 1, .minor = 17, .patch = 34 }, .dropped = &.{ "TransactionError::ProgramCacheHitMaxLimit", "TransactionError::CommitCancelled" } },
+        .{ .sdk = .{ .major = 2, .minor = 1, .patch = 21 }, .dropped = &.{"TransactionError::CommitCancelled"} },
+        .{ .sdk = .{ .major = 2, .minor = 2, .patch = 0 }, .dropped = &.{} },
+    };
+    for (cases) |case| {
+        const kept = try error_display_fixture.vectorsUpTo(std.testing.allocator, fixture.transaction_errors, case.sdk);
+        defer std.testing.allocator.free(kept);
+        try std.testing.expectEqual(fixture.transaction_errors.len - case.dropped.len, kept.len);
+
+        var i: usize = 0;
+        for (fixture.transaction_errors) |vector| {
+            const dropped = for (case.dropped) |kind| {
+                if (std.mem.eql(u8, kind, vector.error_kind)) break true;
+            } else false;
+            if (dropped) {
+                try std.testing.expect(vector.since_sdk != null);
+                continue;
+            }
+            try std.testing.expectEqualStrings(vector.error_kind, kept[i].error_kind);
+            i += 1;
+        }
+        try std.testing.expectEqual(kept.len, i);
+    }
+}
+
+test "base64 account data matches the official base64 crate" {
+    const allocator = std.testing.allocator;
+    const base64_fixture = @import("base64_fixture.zig");
+    var parsed = try base64_fixture.load(allocator);
+    defer parsed.deinit();
+
+    for (parsed.value.vectors) |vector| {
+        var encoded: [2048]u8 = undefined;
+        try std.testing.expectEqualStrings(vector.standard, std.base64.standard.Encoder.encode(&encoded, vector.bytes));
+        try std.testing.expectEqualStrings(vector.standard_no_pad, std.base64.standard_no_pad.Encoder.encode(&encoded, vector.bytes));
+
+        var decoded: [1024]u8 = undefined;
+        const no_pad_len = try std.base64.standard_no_pad.Decoder.calcSizeForSlice(vector.standard_no_pad);
+        try std.base64.standard_no_pad.Decoder.decode(decoded[0..no_pad_len], vector.standard_no_pad);
+        try std.testing.expectEqualSlices(u8, vector.bytes, decoded[0..no_pad_len]);
+
+        const data_json = try std.fmt.allocPrint(allocator, "[\"{s}\",\"base64\"]", .{vector.standard});
+        defer allocator.free(data_json);
+        const data = try std.json.parseFromSlice(std.json.Value, allocator, data_json, .{});
+        defer data.deinit();
+        const account: AccountInfo = .{
+            .lamports = 1,
+            .owner = "11111111111111111111111111111111",
+            .executable = false,
+            .rentEpoch = 0,
+            .data = data.value,
+        };
+        try std.testing.expectEqualSlices(u8, vector.bytes, try decodeAccountInfoBase64Data(account, &decoded));
+    }
+}
+
 test "public surface guards" {
     try std.testing.expectEqualStrings("processed", Commitment.processed.jsonName());
     try std.testing.expectEqualStrings("POST", HttpMethod.post.jsonName());
@@ -1916,4 +2203,6 @@ test "public surface guards" {
     try std.testing.expect(@hasDecl(@This(), "parseSubscriptionResponse"));
     try std.testing.expect(@hasDecl(@This(), "parseAccountNotification"));
     try std.testing.expect(@hasDecl(Client, "accountSubscribe"));
+    try std.testing.expect(@hasDecl(@This(), "formatTransactionError"));
+    try std.testing.expect(@hasDecl(@This(), "formatInstructionError"));
 }
@@ -0,0 +1,112 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_base58_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Base58TestVector {
+    name: String,
+    /// Decoded payload; empty for strings that do not decode.
+    bytes: Vec<u8>,
+    base58: String,
+    decodable: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<Base58TestVector>,
+}
+
+fn encoded(name: &str, bytes: Vec<u8>) -> Base58TestVector {
+    Base58TestVector {
+        name: name.to_string(),
+        base58: bs58::encode(&bytes).into_string(),
+        bytes,
+        decodable: true,
+    }
+}
+
+fn invalid(name: &str, base58: &str) -> Base58TestVector {
+    Base58TestVector {
+        name: name.to_string(),
+        bytes: Vec::new(),
+        base58: base58.to_string(),
+        decodable: bs58::decode(base58).into_vec().is_ok(),
+    }
+}
+
+fn counting(len: usize) -> Vec<u8> {
+    (0..len)
+        .map(|i| (i as u8).wrapping_mul(37).wrapping_add(1))
+        .collect()
+}
+
+fn official_fixture() -> Fixture {
+    let mut leading_zeros = vec![0; 3];
+    leading_zeros.extend([1, 2, 3]);
+    let mut zero_prefixed_pubkey = vec![0; 4];
+    zero_prefixed_pubkey.extend(counting(28));
+
+    Fixture {
+        vectors: vec![
+            encoded("empty", Vec::new()),
+            encoded("single_zero", vec![0]),
+            encoded("three_zeros", vec![0; 3]),
+            encoded("single_byte", vec![0x39]),
+            encoded("leading_zero_run", leading_zeros),
+            encoded("bytes_31", counting(31)),
+            encoded("bytes_32", counting(32)),
+            encoded("bytes_32_all_zero", vec![0; 32]),
+            encoded("bytes_32_all_ff", vec![0xff; 32]),
+            encoded("bytes_32_zero_prefixed", zero_prefixed_pubkey),
+            encoded("bytes_33", counting(33)),
+            encoded("signature_64", counting(64)),
+            encoded("blob_128", counting(128)),
+            invalid("contains_zero", "1230"),
+            invalid("contains_upper_o", "O1"),
+            invalid("contains_upper_i", "1I1"),
+            invalid("contains_lower_l", "abcl"),
+            invalid("contains_space", "12 34"),
+            invalid("trailing_newline", "2g\n"),
+            invalid("non_ascii", "1é"),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_base58_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_base58_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn decodable_strings_round_trip_and_the_rest_are_rejected() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let decoded = bs58::decode(&vector.base58).into_vec();
+        if vector.decodable {
+            assert_eq!(decoded.unwrap(), vector.bytes, "{}", vector.name);
+            // Each leading zero byte is one leading '1'.
+            let zeros = vector.bytes.iter().take_while(|&&b| b == 0).count();
+            let ones = vector.base58.chars().take_while(|&c| c == '1').count();
+            assert_eq!(zeros, ones, "{}", vector.name);
+        } else {
+            assert!(decoded.is_err(), "{}", vector.name);
+        }
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_base58_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,107 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_pubkey::Pubkey;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_bincode_collection_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct CollectionVector {
+    name: String,
+    /// The Rust type as written in the schema.
+    type_name: String,
+    encoded: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<CollectionVector>,
+}
+
+/// `bincode::serialize`: the legacy fixed-int, little-endian config the
+/// SDK uses for system and sysvar data.
+fn vector(name: &str, type_name: &str, value: &impl Serialize) -> CollectionVector {
+    CollectionVector {
+        name: name.to_string(),
+        type_name: type_name.to_string(),
+        encoded: bincode::serialize(value).unwrap(),
+    }
+}
+
+fn bytes(len: usize) -> Vec<u8> {
+    (0..len).map(|i| i as u8).collect()
+}
+
+fn official_fixture() -> Fixture {
+    let pubkey = Pubkey::new_from_array([0x44; 32]);
+    Fixture {
+        vectors: vec![
+            vector("string_empty", "String", &String::new()),
+            vector("string_seed", "String", &"seed".to_string()),
+            vector("string_utf8", "String", &"索拉纳".to_string()),
+            vector("vec_u8_empty", "Vec<u8>", &bytes(0)),
+            vector("vec_u8_3", "Vec<u8>", &bytes(3)),
+            vector("vec_u8_300", "Vec<u8>", &bytes(300)),
+            vector("vec_u64_empty", "Vec<u64>", &Vec::<u64>::new()),
+            vector("vec_u64_1", "Vec<u64>", &vec![u64::MAX]),
+            vector(
+                "vec_u64_3",
+                "Vec<u64>",
+                &vec![1_u64, 0x0102_0304_0506_0708, 0],
+            ),
+            vector(
+                "array_u8_32",
+                "[u8; 32]",
+                &<[u8; 32]>::try_from(bytes(32)).unwrap(),
+            ),
+            vector(
+                "tuple_u8_u64_pubkey",
+                "(u8, u64, Pubkey)",
+                &(7_u8, 0x0102_0304_0506_0708_u64, pubkey),
+            ),
+            vector("option_pubkey_none", "Option<Pubkey>", &None::<Pubkey>),
+            vector("option_pubkey_some", "Option<Pubkey>", &Some(pubkey)),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_bincode_collection_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_bincode_collection_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn strings_and_vecs_carry_a_u64_length_prefix() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let encoded = &vector.encoded;
+        match vector.type_name.as_str() {
+            "String" | "Vec<u8>" | "Vec<u64>" => {
+                let len = u64::from_le_bytes(encoded[..8].try_into().unwrap()) as usize;
+                let element = if vector.type_name == "Vec<u64>" { 8 } else { 1 };
+                assert_eq!(encoded.len(), 8 + len * element, "{}", vector.name);
+            }
+            "[u8; 32]" => assert_eq!(encoded.len(), 32),
+            "(u8, u64, Pubkey)" => assert_eq!(encoded.len(), 1 + 8 + 32),
+            "Option<Pubkey>" => {
+                assert_eq!(encoded.len(), if encoded[0] == 1 { 33 } else { 1 })
+            }
+            other => panic!("unexpected type {other}"),
+        }
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_bincode_collection_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
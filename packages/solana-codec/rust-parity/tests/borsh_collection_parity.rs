@@ -0,0 +1,151 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use std::collections::{BTreeMap, HashMap};
+
+use borsh::BorshSerialize;
+use serde::{Deserialize, Serialize};
+use solana_pubkey::Pubkey;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_borsh_collection_vectors.json");
+
+/// Map entries in insertion order; none of them is already sorted.
+const MAP_ENTRIES: [(&str, u64); 4] = [("cc", 3), ("a", 1), ("b", 2), ("ab", 12)];
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct CollectionVector {
+    name: String,
+    /// The Rust type as written in the schema.
+    type_name: String,
+    encoded: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<CollectionVector>,
+}
+
+fn vector(name: &str, type_name: &str, value: &impl BorshSerialize) -> CollectionVector {
+    CollectionVector {
+        name: name.to_string(),
+        type_name: type_name.to_string(),
+        encoded: borsh::to_vec(value).unwrap(),
+    }
+}
+
+/// Element `i` of the 1000-entry `Vec<u64>` is `i * 0x0101_0101`.
+fn long_vec() -> Vec<u64> {
+    (0..1000).map(|i| i * 0x0101_0101).collect()
+}
+
+fn bytes_32() -> [u8; 32] {
+    std::array::from_fn(|i| i as u8)
+}
+
+fn pubkeys() -> Vec<Pubkey> {
+    vec![
+        Pubkey::new_from_array([0x11; 32]),
+        Pubkey::new_from_array([0x22; 32]),
+    ]
+}
+
+fn official_fixture() -> Fixture {
+    let map: HashMap<String, u64> = MAP_ENTRIES
+        .iter()
+        .map(|(key, value)| (key.to_string(), *value))
+        .collect();
+    Fixture {
+        vectors: vec![
+            vector("vec_u64_empty", "Vec<u64>", &Vec::<u64>::new()),
+            vector("vec_u64_one", "Vec<u64>", &vec![u64::MAX]),
+            vector("vec_u64_1000", "Vec<u64>", &long_vec()),
+            vector("array_u8_32", "[u8; 32]", &bytes_32()),
+            vector("vec_u8_32", "Vec<u8>", &bytes_32().to_vec()),
+            vector("hash_map_string_u64", "HashMap<String, u64>", &map),
+            vector(
+                "option_vec_pubkey_none",
+                "Option<Vec<Pubkey>>",
+                &None::<Vec<Pubkey>>,
+            ),
+            vector(
+                "option_vec_pubkey_some",
+                "Option<Vec<Pubkey>>",
+                &Some(pubkeys()),
+            ),
+            vector(
+                "tuple_u8_u64_pubkey",
+                "(u8, u64, Pubkey)",
+                &(
+                    7_u8,
+                    0x0102_0304_0506_0708_u64,
+                    Pubkey::new_from_array([0x33; 32]),
+                ),
+            ),
+        ],
+    }
+}
+
+fn encoded<'a>(fixture: &'a Fixture, name: &str) -> &'a [u8] {
+    &fixture
+        .vectors
+        .iter()
+        .find(|vector| vector.name == name)
+        .unwrap()
+        .encoded
+}
+
+#[test]
+fn fixture_matches_official_borsh_collection_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_borsh_collection_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn fixed_arrays_have_no_prefix_and_maps_are_key_sorted() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+
+    assert_eq!(encoded(&fixture, "vec_u64_empty"), [0, 0, 0, 0]);
+    assert_eq!(encoded(&fixture, "vec_u64_1000").len(), 4 + 1000 * 8);
+    assert_eq!(
+        encoded(&fixture, "vec_u64_1000")[..4],
+        1000_u32.to_le_bytes()
+    );
+
+    // `[u8; 32]` is the raw bytes; `Vec<u8>` adds a `u32` length.
+    let array = encoded(&fixture, "array_u8_32");
+    let vec = encoded(&fixture, "vec_u8_32");
+    assert_eq!(array, bytes_32());
+    assert_eq!(vec[..4], 32_u32.to_le_bytes());
+    assert_eq!(&vec[4..], array);
+
+    // Borsh sorts `HashMap` entries by key, so it encodes like a `BTreeMap`.
+    let sorted: BTreeMap<String, u64> = MAP_ENTRIES
+        .iter()
+        .map(|(key, value)| (key.to_string(), *value))
+        .collect();
+    assert_eq!(
+        encoded(&fixture, "hash_map_string_u64"),
+        borsh::to_vec(&sorted).unwrap()
+    );
+
+    let some = encoded(&fixture, "option_vec_pubkey_some");
+    assert_eq!(some[..5], [1, 2, 0, 0, 0]);
+    assert_eq!(some.len(), 1 + 4 + 2 * 32);
+    assert_eq!(encoded(&fixture, "option_vec_pubkey_none"), [0]);
+
+    // Tuples are their elements back to back.
+    assert_eq!(encoded(&fixture, "tuple_u8_u64_pubkey").len(), 1 + 8 + 32);
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_borsh_collection_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
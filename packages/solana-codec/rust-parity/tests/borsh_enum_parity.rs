@@ -0,0 +1,176 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+use solana_pubkey::Pubkey;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_borsh_enum_vectors.json");
+
+/// An instruction-style enum: a `u8` variant index, then the payload.
+#[derive(Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+enum Sample {
+    Ping,
+    Transfer(u64, Pubkey),
+    Configure {
+        authority: Pubkey,
+        threshold: u16,
+        memo: Option<u32>,
+    },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct TransferFields {
+    amount: u64,
+    recipient: Pubkey,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ConfigureFields {
+    authority: Pubkey,
+    threshold: u16,
+    memo: Option<u32>,
+}
+
+/// One variant's fields sit under the key for that variant; the others are
+/// `null`.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct EnumVector {
+    name: String,
+    variant: String,
+    discriminant: u8,
+    transfer: Option<TransferFields>,
+    configure: Option<ConfigureFields>,
+    encoded: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<EnumVector>,
+}
+
+impl Sample {
+    fn to_fields(
+        &self,
+    ) -> (
+        &'static str,
+        Option<TransferFields>,
+        Option<ConfigureFields>,
+    ) {
+        match *self {
+            Sample::Ping => ("Ping", None, None),
+            Sample::Transfer(amount, recipient) => {
+                ("Transfer", Some(TransferFields { amount, recipient }), None)
+            }
+            Sample::Configure {
+                authority,
+                threshold,
+                memo,
+            } => (
+                "Configure",
+                None,
+                Some(ConfigureFields {
+                    authority,
+                    threshold,
+                    memo,
+                }),
+            ),
+        }
+    }
+
+    fn from_fields(vector: &EnumVector) -> Self {
+        match (vector.variant.as_str(), &vector.transfer, &vector.configure) {
+            ("Ping", None, None) => Sample::Ping,
+            ("Transfer", Some(fields), None) => Sample::Transfer(fields.amount, fields.recipient),
+            ("Configure", None, Some(fields)) => Sample::Configure {
+                authority: fields.authority,
+                threshold: fields.threshold,
+                memo: fields.memo,
+            },
+            _ => panic!("{}: fields do not match the variant", vector.name),
+        }
+    }
+}
+
+fn vector(name: &str, value: Sample) -> EnumVector {
+    let encoded = borsh::to_vec(&value).unwrap();
+    let (variant, transfer, configure) = value.to_fields();
+    EnumVector {
+        name: name.to_string(),
+        variant: variant.to_string(),
+        discriminant: encoded[0],
+        transfer,
+        configure,
+        encoded,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let recipient = Pubkey::new_from_array([0x22; 32]);
+    let authority = Pubkey::new_from_array(std::array::from_fn(|i| 0xff - i as u8));
+    Fixture {
+        vectors: vec![
+            vector("ping", Sample::Ping),
+            vector("transfer", Sample::Transfer(1_000_000_000, recipient)),
+            vector(
+                "transfer_max",
+                Sample::Transfer(u64::MAX, Pubkey::default()),
+            ),
+            vector(
+                "configure_with_memo",
+                Sample::Configure {
+                    authority,
+                    threshold: 0x0203,
+                    memo: Some(0xcafe_f00d),
+                },
+            ),
+            vector(
+                "configure_without_memo",
+                Sample::Configure {
+                    authority,
+                    threshold: 0,
+                    memo: None,
+                },
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_borsh_enum_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_borsh_enum_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn variant_index_byte_precedes_the_payload() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let value = Sample::from_fields(vector);
+        assert_eq!(borsh::from_slice::<Sample>(&vector.encoded).unwrap(), value);
+        let (discriminant, payload_len) = match value {
+            Sample::Ping => (0, 0),
+            Sample::Transfer(..) => (1, 8 + 32),
+            Sample::Configure { memo, .. } => (2, 32 + 2 + if memo.is_some() { 5 } else { 1 }),
+        };
+        assert_eq!(vector.discriminant, discriminant, "{}", vector.name);
+        assert_eq!(vector.encoded[0], discriminant, "{}", vector.name);
+        assert_eq!(vector.encoded.len(), 1 + payload_len, "{}", vector.name);
+    }
+
+    // An index past the last variant does not decode.
+    assert!(borsh::from_slice::<Sample>(&[3]).is_err());
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_borsh_enum_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
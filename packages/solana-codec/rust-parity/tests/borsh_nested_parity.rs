@@ -0,0 +1,170 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+use solana_pubkey::Pubkey;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_borsh_nested_vectors.json");
+
+#[derive(Debug, Clone, PartialEq, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+struct Inner {
+    a: u32,
+    b: Pubkey,
+}
+
+#[derive(Debug, Clone, PartialEq, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+struct Outer {
+    flag: bool,
+    inner: Inner,
+    tail: Vec<u8>,
+}
+
+/// `Record -> Authority -> Option<Pubkey>` puts the option two structs
+/// deep, and `Record -> Authority -> Inner` a pubkey three deep.
+#[derive(Debug, Clone, PartialEq, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+struct Authority {
+    delegate: Option<Pubkey>,
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+struct Record {
+    id: u64,
+    authority: Authority,
+}
+
+/// One value and its Borsh bytes. Pubkeys serialize as 32-byte arrays.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct NestedVector<T> {
+    name: String,
+    value: T,
+    encoded: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    inner: Vec<NestedVector<Inner>>,
+    outer: Vec<NestedVector<Outer>>,
+    record: Vec<NestedVector<Record>>,
+}
+
+fn vector<T: BorshSerialize>(name: &str, value: T) -> NestedVector<T> {
+    NestedVector {
+        name: name.to_string(),
+        encoded: borsh::to_vec(&value).unwrap(),
+        value,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let inner = Inner {
+        a: 0xdead_beef,
+        b: Pubkey::new_from_array([0x11; 32]),
+    };
+    let delegate = Pubkey::new_from_array(std::array::from_fn(|i| i as u8));
+    Fixture {
+        inner: vec![
+            vector("inner", inner.clone()),
+            vector(
+                "inner_zero",
+                Inner {
+                    a: 0,
+                    b: Pubkey::default(),
+                },
+            ),
+        ],
+        outer: vec![
+            vector(
+                "outer_empty_tail",
+                Outer {
+                    flag: false,
+                    inner: inner.clone(),
+                    tail: Vec::new(),
+                },
+            ),
+            vector(
+                "outer_with_tail",
+                Outer {
+                    flag: true,
+                    inner: inner.clone(),
+                    tail: vec![1, 2, 3, 0xff],
+                },
+            ),
+        ],
+        record: vec![
+            vector(
+                "record_delegate_some",
+                Record {
+                    id: 0x0102_0304_0506_0708,
+                    authority: Authority {
+                        delegate: Some(delegate),
+                        inner: inner.clone(),
+                    },
+                },
+            ),
+            vector(
+                "record_delegate_none",
+                Record {
+                    id: u64::MAX,
+                    authority: Authority {
+                        delegate: None,
+                        inner,
+                    },
+                },
+            ),
+        ],
+    }
+}
+
+fn assert_round_trips<T: BorshDeserialize + PartialEq + std::fmt::Debug>(
+    vectors: &[NestedVector<T>],
+) {
+    for vector in vectors {
+        let decoded: T = borsh::from_slice(&vector.encoded).unwrap();
+        assert_eq!(decoded, vector.value, "{}", vector.name);
+    }
+}
+
+#[test]
+fn fixture_matches_official_borsh_nested_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_borsh_nested_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn nested_structs_concatenate_their_fields_without_framing() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    assert_round_trips(&fixture.inner);
+    assert_round_trips(&fixture.outer);
+    assert_round_trips(&fixture.record);
+
+    // A nested struct is its own encoding inlined: no length or tag.
+    let inner = &fixture.inner[0];
+    assert_eq!(inner.encoded.len(), 4 + 32);
+    for outer in &fixture.outer {
+        assert_eq!(outer.encoded[1..37], inner.encoded[..]);
+        assert_eq!(outer.encoded.len(), 1 + 36 + 4 + outer.value.tail.len());
+    }
+    for record in &fixture.record {
+        let option_len = match record.value.authority.delegate {
+            Some(_) => 1 + 32,
+            None => 1,
+        };
+        assert_eq!(record.encoded.len(), 8 + option_len + 36);
+        assert_eq!(record.encoded[8 + option_len..], inner.encoded[..]);
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_borsh_nested_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,123 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_borsh_string_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct StringVector {
+    name: String,
+    value: String,
+    /// Unicode scalar values, `value.chars().count()`.
+    char_count: usize,
+    /// UTF-8 bytes, which is what the `u32` prefix counts.
+    byte_len: usize,
+    encoded: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct StringVecVector {
+    name: String,
+    values: Vec<String>,
+    encoded: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    strings: Vec<StringVector>,
+    string_vecs: Vec<StringVecVector>,
+}
+
+fn string_vector(name: &str, value: String) -> StringVector {
+    StringVector {
+        name: name.to_string(),
+        char_count: value.chars().count(),
+        byte_len: value.len(),
+        encoded: borsh::to_vec(&value).unwrap(),
+        value,
+    }
+}
+
+fn string_vec_vector(name: &str, values: &[&str]) -> StringVecVector {
+    let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+    StringVecVector {
+        name: name.to_string(),
+        encoded: borsh::to_vec(&values).unwrap(),
+        values,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        strings: vec![
+            string_vector("empty", String::new()),
+            string_vector("ascii", "hello, solana".to_string()),
+            string_vector("cjk", "索拉纳".to_string()),
+            string_vector("emoji", "🚀🌕".to_string()),
+            string_vector("mixed", "gm 世界 👋".to_string()),
+            // 300 bytes: the length prefix needs its second byte.
+            string_vector("ascii_300_bytes", "abcdefghij".repeat(30)),
+        ],
+        string_vecs: vec![
+            string_vec_vector("empty_vec", &[]),
+            string_vec_vector("mixed_vec", &["", "seed", "索拉纳", "🚀"]),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_borsh_string_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_borsh_string_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn length_prefix_counts_utf8_bytes() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let mut multibyte = 0;
+    for vector in &fixture.strings {
+        let prefix = u32::from_le_bytes(vector.encoded[..4].try_into().unwrap());
+        assert_eq!(prefix as usize, vector.byte_len, "{}", vector.name);
+        assert_eq!(
+            &vector.encoded[4..],
+            vector.value.as_bytes(),
+            "{}",
+            vector.name
+        );
+        if vector.char_count != vector.byte_len {
+            multibyte += 1;
+        }
+    }
+    assert!(multibyte >= 3);
+
+    let long = fixture
+        .strings
+        .iter()
+        .find(|vector| vector.name == "ascii_300_bytes")
+        .unwrap();
+    assert_eq!(long.encoded[..4], [0x2c, 0x01, 0, 0]);
+
+    // `Vec<String>`: a `u32` element count, then each string with its own
+    // prefix.
+    for vector in &fixture.string_vecs {
+        let mut expected = (vector.values.len() as u32).to_le_bytes().to_vec();
+        for value in &vector.values {
+            expected.extend(borsh::to_vec(value).unwrap());
+        }
+        assert_eq!(vector.encoded, expected, "{}", vector.name);
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_borsh_string_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,203 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_bn254::prelude::{alt_bn128_g1_addition_be, alt_bn128_g1_addition_le};
+use solana_clock::Clock;
+use solana_epoch_rewards::EpochRewards;
+use solana_secp256k1_program::new_secp256k1_instruction_with_signature;
+use solana_system_interface::instruction::SystemInstruction;
+use spl_token_interface::instruction::TokenInstruction;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_endianness_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct EndiannessTestVector {
+    name: String,
+    /// Decimal, so u128 and 256-bit field elements survive JSON readers.
+    value: String,
+    le_bytes: Vec<u8>,
+    be_bytes: Vec<u8>,
+    on_chain_uses: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<EndiannessTestVector>,
+}
+
+/// Which of `le` / `be` the real serializer wrote.
+fn byte_order(name: &str, wire: &[u8], le: &[u8], be: &[u8]) -> String {
+    assert_ne!(le, be, "{name}: value must not be a byte palindrome");
+    if wire == le {
+        "le".to_string()
+    } else if wire == be {
+        "be".to_string()
+    } else {
+        panic!("{name}: serializer wrote {wire:?}, neither {le:?} nor {be:?}");
+    }
+}
+
+fn int_vector(name: &str, value: u128, width: usize, wire: &[u8]) -> EndiannessTestVector {
+    let le_bytes = value.to_le_bytes()[..width].to_vec();
+    assert!(value.to_le_bytes()[width..].iter().all(|&b| b == 0));
+    let be_bytes: Vec<u8> = le_bytes.iter().rev().copied().collect();
+    EndiannessTestVector {
+        name: name.to_string(),
+        value: value.to_string(),
+        on_chain_uses: byte_order(name, wire, &le_bytes, &be_bytes),
+        le_bytes,
+        be_bytes,
+    }
+}
+
+/// Big-endian bytes to decimal by repeated long division.
+fn be_to_decimal(be: &[u8]) -> String {
+    let mut digits = Vec::new();
+    let mut n = be.to_vec();
+    while n.iter().any(|&b| b != 0) {
+        let mut rem = 0u32;
+        for byte in n.iter_mut() {
+            let acc = (rem << 8) | u32::from(*byte);
+            *byte = (acc / 10) as u8;
+            rem = acc % 10;
+        }
+        digits.push(b'0' + rem as u8);
+    }
+    if digits.is_empty() {
+        digits.push(b'0');
+    }
+    digits.reverse();
+    String::from_utf8(digits).unwrap()
+}
+
+fn field_be(value: u8) -> [u8; 32] {
+    let mut out = [0; 32];
+    out[31] = value;
+    out
+}
+
+/// `2G` on bn254 G1 through the EIP-197 (`*_be`) addition syscall, whose
+/// generator input is `(1, 2)` in big-endian field elements. The `*_le`
+/// variant on per-element reversed input must return the reversed result.
+fn bn254_vector() -> EndiannessTestVector {
+    let generator = [field_be(1), field_be(2)].concat();
+    let be_input = [generator.clone(), generator].concat();
+    let be_output = alt_bn128_g1_addition_be(&be_input).unwrap();
+
+    let mut le_input = [0u8; 128];
+    for (dst, src) in le_input.chunks_mut(32).zip(be_input.chunks(32)) {
+        dst.copy_from_slice(src);
+        dst.reverse();
+    }
+    let le_output = alt_bn128_g1_addition_le(&le_input).unwrap();
+
+    let be_bytes = be_output[..32].to_vec();
+    let le_bytes: Vec<u8> = be_bytes.iter().rev().copied().collect();
+    assert_eq!(le_output[..32], le_bytes[..]);
+    EndiannessTestVector {
+        name: "bn254_field_element".to_string(),
+        value: be_to_decimal(&be_bytes),
+        on_chain_uses: byte_order(
+            "bn254_field_element",
+            &be_output[..32],
+            &le_bytes,
+            &be_bytes,
+        ),
+        le_bytes,
+        be_bytes,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let lamports = 1_234_567_890_123u64;
+    let transfer = bincode::serialize(&SystemInstruction::Transfer { lamports }).unwrap();
+
+    let clock = Clock {
+        slot: 0x0102_0304_0506_0708,
+        epoch: 0x0a0b_0c0d,
+        ..Clock::default()
+    };
+    let clock_data = bincode::serialize(&clock).unwrap();
+
+    let amount = 0xdead_beef_0001u64;
+    let token_transfer = TokenInstruction::Transfer { amount }.pack();
+
+    let epoch_rewards = EpochRewards {
+        total_points: 0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10,
+        ..EpochRewards::default()
+    };
+    let epoch_rewards_data = bincode::serialize(&epoch_rewards).unwrap();
+
+    let secp = new_secp256k1_instruction_with_signature(b"endianness", &[0x22; 64], 0, &[0x33; 20]);
+    let signature_offset = u16::from_le_bytes([secp.data[1], secp.data[2]]);
+
+    Fixture {
+        vectors: vec![
+            // SystemInstruction::Transfer { lamports }: u32 tag, then u64.
+            int_vector("lamports_u64", lamports.into(), 8, &transfer[4..12]),
+            // Clock: slot, epoch_start_timestamp, epoch, ...
+            int_vector("slot_u64", clock.slot.into(), 8, &clock_data[0..8]),
+            int_vector("epoch_u64", clock.epoch.into(), 8, &clock_data[16..24]),
+            // TokenInstruction::Transfer: u8 tag, then u64 amount.
+            int_vector("token_amount_u64", amount.into(), 8, &token_transfer[1..9]),
+            // EpochRewards: two u64s and the parent blockhash precede it.
+            int_vector(
+                "total_points_u128",
+                epoch_rewards.total_points,
+                16,
+                &epoch_rewards_data[48..64],
+            ),
+            bn254_vector(),
+            // SecpSignatureOffsets.signature_offset follows the count byte.
+            int_vector(
+                "secp_offsets_u16",
+                signature_offset.into(),
+                2,
+                &secp.data[1..3],
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_endianness_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_endianness_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn only_bn254_is_big_endian() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let mut reversed = vector.le_bytes.clone();
+        reversed.reverse();
+        assert_eq!(reversed, vector.be_bytes, "{}", vector.name);
+        assert_eq!(
+            be_to_decimal(&vector.be_bytes),
+            vector.value,
+            "{}",
+            vector.name
+        );
+
+        let expected = if vector.name.starts_with("bn254") {
+            "be"
+        } else {
+            "le"
+        };
+        assert_eq!(vector.on_chain_uses, expected, "{}", vector.name);
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_endianness_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,154 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use std::{
+    mem::{align_of, size_of, size_of_val, MaybeUninit},
+    ptr::addr_of_mut,
+};
+
+use borsh::BorshSerialize;
+use memoffset::offset_of;
+use serde::{Deserialize, Serialize};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_layout_vs_borsh.json");
+
+/// Mirrors the `ExampleState` extern struct in the Zig tests: mixed widths so
+/// `repr(C)` inserts padding that the Borsh wire format never carries.
+#[repr(C)]
+#[derive(BorshSerialize)]
+struct ExampleState {
+    is_initialized: bool,
+    authority: [u8; 32],
+    count: u64,
+    bump: u8,
+    tag: u16,
+    timestamp: i64,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct FieldLayout {
+    name: String,
+    size: usize,
+    borsh_offset: usize,
+    repr_c_offset: usize,
+    offsets_agree: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    borsh_len: usize,
+    borsh: Vec<u8>,
+    repr_c_size: usize,
+    repr_c_align: usize,
+    repr_c: Vec<u8>,
+    fields: Vec<FieldLayout>,
+}
+
+fn example_state() -> ExampleState {
+    ExampleState {
+        is_initialized: true,
+        authority: [0x11; 32],
+        count: 0x0102_0304_0506_0708,
+        bump: 0xfe,
+        tag: 0xabcd,
+        timestamp: -2,
+    }
+}
+
+/// Writes each field into zeroed storage so padding bytes stay deterministic.
+fn repr_c_image(state: &ExampleState) -> Vec<u8> {
+    let mut storage = MaybeUninit::<ExampleState>::zeroed();
+    let ptr = storage.as_mut_ptr();
+    unsafe {
+        addr_of_mut!((*ptr).is_initialized).write(state.is_initialized);
+        addr_of_mut!((*ptr).authority).write(state.authority);
+        addr_of_mut!((*ptr).count).write(state.count);
+        addr_of_mut!((*ptr).bump).write(state.bump);
+        addr_of_mut!((*ptr).tag).write(state.tag);
+        addr_of_mut!((*ptr).timestamp).write(state.timestamp);
+        std::slice::from_raw_parts(ptr.cast::<u8>(), size_of::<ExampleState>()).to_vec()
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let state = example_state();
+    let borsh = borsh::to_vec(&state).unwrap();
+
+    let sizes = [
+        ("is_initialized", size_of_val(&state.is_initialized)),
+        ("authority", size_of_val(&state.authority)),
+        ("count", size_of_val(&state.count)),
+        ("bump", size_of_val(&state.bump)),
+        ("tag", size_of_val(&state.tag)),
+        ("timestamp", size_of_val(&state.timestamp)),
+    ];
+    let repr_c_offsets = [
+        offset_of!(ExampleState, is_initialized),
+        offset_of!(ExampleState, authority),
+        offset_of!(ExampleState, count),
+        offset_of!(ExampleState, bump),
+        offset_of!(ExampleState, tag),
+        offset_of!(ExampleState, timestamp),
+    ];
+
+    let mut borsh_offset = 0;
+    let fields = sizes
+        .iter()
+        .zip(repr_c_offsets)
+        .map(|(&(name, size), repr_c_offset)| {
+            let field = FieldLayout {
+                name: name.to_string(),
+                size,
+                borsh_offset,
+                repr_c_offset,
+                offsets_agree: borsh_offset == repr_c_offset,
+            };
+            borsh_offset += size;
+            field
+        })
+        .collect();
+
+    Fixture {
+        borsh_len: borsh.len(),
+        borsh,
+        repr_c_size: size_of::<ExampleState>(),
+        repr_c_align: align_of::<ExampleState>(),
+        repr_c: repr_c_image(&state),
+        fields,
+    }
+}
+
+#[test]
+fn fixture_matches_official_layout_vs_borsh() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_layout_vs_borsh.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn both_views_carry_the_same_field_bytes() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+
+    for field in &fixture.fields {
+        assert_eq!(
+            fixture.borsh[field.borsh_offset..][..field.size],
+            fixture.repr_c[field.repr_c_offset..][..field.size],
+            "{}",
+            field.name
+        );
+    }
+    assert!(fixture.fields.iter().any(|field| !field.offsets_agree));
+    assert!(fixture.borsh_len < fixture.repr_c_size);
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_layout_vs_borsh() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
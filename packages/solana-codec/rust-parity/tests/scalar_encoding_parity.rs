@@ -0,0 +1,138 @@
+use borsh::BorshSerialize;
+use serde::{Deserialize, Serialize};
+
+const BORSH_JSON: &str = include_str!("../../src/official_borsh_scalar_vectors.json");
+const BINCODE_JSON: &str = include_str!("../../src/official_bincode_scalar_vectors.json");
+
+/// Names whose Borsh and bincode encodings are expected to differ. Every
+/// other shared name must be byte-identical.
+///   - `string_seed`, `bytes_abc`: Borsh prefixes a `u32` length, bincode
+///     a `u64` length.
+///   - `enum_variant_1`: Borsh writes a `u8` variant index, bincode a `u32`.
+///
+/// `Option` is *not* listed: both formats use a one-byte `0`/`1` tag.
+const EXPECTED_DIFFERENT: [&str; 3] = ["string_seed", "bytes_abc", "enum_variant_1"];
+
+#[derive(BorshSerialize, Serialize)]
+enum Sample {
+    #[allow(dead_code)]
+    Zero,
+    One,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ScalarVector {
+    name: String,
+    encoded: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<ScalarVector>,
+}
+
+macro_rules! cases {
+    ($encode:expr) => {
+        vec![
+            ("u8", $encode(&0xfe_u8)),
+            ("u16", $encode(&0xabcd_u16)),
+            ("u32", $encode(&0xdead_beef_u32)),
+            ("u64", $encode(&0x0102_0304_0506_0708_u64)),
+            ("i32_negative", $encode(&-2_i32)),
+            ("i64_min", $encode(&i64::MIN)),
+            ("bool_true", $encode(&true)),
+            ("bool_false", $encode(&false)),
+            ("option_u32_some", $encode(&Some(7_u32))),
+            ("option_u32_none", $encode(&None::<u32>)),
+            ("option_u64_some", $encode(&Some(90_u64))),
+            ("string_seed", $encode(&"seed".to_string())),
+            ("bytes_abc", $encode(&b"abc".to_vec())),
+            ("enum_variant_1", $encode(&Sample::One)),
+        ]
+    };
+}
+
+fn fixture(cases: Vec<(&str, Vec<u8>)>) -> Fixture {
+    Fixture {
+        vectors: cases
+            .into_iter()
+            .map(|(name, encoded)| ScalarVector {
+                name: name.to_string(),
+                encoded,
+            })
+            .collect(),
+    }
+}
+
+fn official_borsh_fixture() -> Fixture {
+    fixture(cases!(|value| borsh::to_vec(value).unwrap()))
+}
+
+fn official_bincode_fixture() -> Fixture {
+    fixture(cases!(|value| bincode::serialize(value).unwrap()))
+}
+
+#[test]
+fn fixtures_match_official_scalar_vectors() {
+    let borsh: Fixture = serde_json::from_str(BORSH_JSON).unwrap();
+    assert_eq!(borsh, official_borsh_fixture());
+    let bincode: Fixture = serde_json::from_str(BINCODE_JSON).unwrap();
+    assert_eq!(bincode, official_bincode_fixture());
+}
+
+#[test]
+fn shared_scalars_encode_identically_in_borsh_and_bincode() {
+    let borsh: Fixture = serde_json::from_str(BORSH_JSON).unwrap();
+    let bincode: Fixture = serde_json::from_str(BINCODE_JSON).unwrap();
+
+    let mut paired = 0;
+    for left in &borsh.vectors {
+        let Some(right) = bincode.vectors.iter().find(|v| v.name == left.name) else {
+            continue;
+        };
+        paired += 1;
+        let name = left.name.as_str();
+        if !EXPECTED_DIFFERENT.contains(&name) {
+            assert_eq!(left.encoded, right.encoded, "{name}");
+            continue;
+        }
+
+        assert_ne!(left.encoded, right.encoded, "{name}");
+        match name {
+            "string_seed" | "bytes_abc" => {
+                // u32 vs u64 length, then the same payload.
+                let len = u32::from_le_bytes(left.encoded[..4].try_into().unwrap());
+                assert_eq!(
+                    u64::from_le_bytes(right.encoded[..8].try_into().unwrap()),
+                    u64::from(len)
+                );
+                assert_eq!(left.encoded[4..], right.encoded[8..], "{name}");
+            }
+            "enum_variant_1" => {
+                assert_eq!(left.encoded, [1]);
+                assert_eq!(right.encoded, [1, 0, 0, 0]);
+            }
+            _ => unreachable!(),
+        }
+    }
+    assert_eq!(paired, borsh.vectors.len());
+    assert_eq!(paired, bincode.vectors.len());
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_borsh_scalar_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_borsh_fixture()).unwrap()
+    );
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_bincode_scalar_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_bincode_fixture()).unwrap()
+    );
+}
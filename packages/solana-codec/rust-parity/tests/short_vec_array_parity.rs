@@ -0,0 +1,112 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_pubkey::Pubkey;
+use solana_short_vec as short_vec;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_short_vec_array_vectors.json");
+
+/// Lengths around the one- and two-byte prefix boundaries.
+const LENGTHS: [usize; 5] = [0, 1, 127, 128, 300];
+
+/// A `#[serde(with = "short_vec")]` field, as in `Message` and
+/// `CompiledInstruction`.
+#[derive(Serialize)]
+struct ShortVecField<T: Serialize>(#[serde(with = "short_vec")] Vec<T>);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ShortVecArrayTestVector {
+    name: String,
+    /// `"u8"` or `"Pubkey"`.
+    element_type: String,
+    len: usize,
+    /// The compact-u16 length prefix alone.
+    prefix: Vec<u8>,
+    /// The elements back to back, without the prefix.
+    elements: Vec<u8>,
+    /// `bincode::serialize` of the whole field.
+    encoded: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<ShortVecArrayTestVector>,
+}
+
+fn vector<T: Serialize>(element_type: &str, values: Vec<T>) -> ShortVecArrayTestVector {
+    let len = values.len();
+    let elements = values
+        .iter()
+        .flat_map(|value| bincode::serialize(value).unwrap())
+        .collect();
+    ShortVecArrayTestVector {
+        name: format!("vec_{}_{len}", element_type.to_lowercase()),
+        element_type: element_type.to_string(),
+        len,
+        prefix: bincode::serialize(&short_vec::ShortU16(len as u16)).unwrap(),
+        elements,
+        encoded: bincode::serialize(&ShortVecField(values)).unwrap(),
+    }
+}
+
+/// Element `i` is `i as u8`; pubkey `i` is bytes `32 * i ..` truncated to `u8`.
+fn official_fixture() -> Fixture {
+    let bytes = LENGTHS
+        .iter()
+        .map(|&len| vector("u8", (0..len).map(|i| i as u8).collect()));
+    let pubkeys = LENGTHS.iter().map(|&len| {
+        vector(
+            "Pubkey",
+            (0..len)
+                .map(|i| Pubkey::new_from_array(std::array::from_fn(|j| (32 * i + j) as u8)))
+                .collect(),
+        )
+    });
+    Fixture {
+        vectors: bytes.chain(pubkeys).collect(),
+    }
+}
+
+#[test]
+fn fixture_matches_official_short_vec_array_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_short_vec_array_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn encoding_is_compact_prefix_then_packed_elements() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let element_size = match vector.element_type.as_str() {
+            "u8" => 1,
+            "Pubkey" => 32,
+            other => panic!("unexpected element type {other}"),
+        };
+        assert_eq!(vector.elements.len(), vector.len * element_size);
+        assert_eq!(
+            short_vec::decode_shortu16_len(&vector.encoded),
+            Ok((vector.len, vector.prefix.len())),
+            "{}",
+            vector.name
+        );
+        assert_eq!(vector.prefix.len(), if vector.len < 128 { 1 } else { 2 });
+        assert_eq!(
+            vector.encoded,
+            [vector.prefix.as_slice(), &vector.elements].concat()
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_short_vec_array_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,21 @@
+const std = @import("std");
+
+pub const Base58TestVector = struct {
+    name: []const u8,
+    bytes: []const u8,
+    base58: []const u8,
+    decodable: bool,
+};
+
+pub const Fixture = struct {
+    vectors: []const Base58TestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_base58_vectors.json"),
+        .{},
+    );
+}
@@ -0,0 +1,20 @@
+const std = @import("std");
+
+pub const CollectionVector = struct {
+    name: []const u8,
+    type_name: []const u8,
+    encoded: []const u8,
+};
+
+pub const Fixture = struct {
+    vectors: []const CollectionVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_bincode_collection_vectors.json"),
+        .{},
+    );
+}
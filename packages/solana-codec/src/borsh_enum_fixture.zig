@@ -0,0 +1,34 @@
+const std = @import("std");
+
+pub const TransferFields = struct {
+    amount: u64,
+    recipient: [32]u8,
+};
+
+pub const ConfigureFields = struct {
+    authority: [32]u8,
+    threshold: u16,
+    memo: ?u32,
+};
+
+pub const EnumVector = struct {
+    name: []const u8,
+    variant: []const u8,
+    discriminant: u8,
+    transfer: ?TransferFields,
+    configure: ?ConfigureFields,
+    encoded: []const u8,
+};
+
+pub const Fixture = struct {
+    vectors: []const EnumVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_borsh_enum_vectors.json"),
+        .{},
+    );
+}
@@ -0,0 +1,45 @@
+const std = @import("std");
+
+pub const Inner = struct {
+    a: u32,
+    b: [32]u8,
+};
+
+pub const Outer = struct {
+    flag: bool,
+    inner: Inner,
+    tail: []const u8,
+};
+
+pub const Authority = struct {
+    delegate: ?[32]u8,
+    inner: Inner,
+};
+
+pub const Record = struct {
+    id: u64,
+    authority: Authority,
+};
+
+pub fn NestedVector(comptime T: type) type {
+    return struct {
+        name: []const u8,
+        value: T,
+        encoded: []const u8,
+    };
+}
+
+pub const Fixture = struct {
+    inner: []const NestedVector(Inner),
+    outer: []const NestedVector(Outer),
+    record: []const NestedVector(Record),
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_borsh_nested_vectors.json"),
+        .{},
+    );
+}
@@ -0,0 +1,29 @@
+const std = @import("std");
+
+pub const StringVector = struct {
+    name: []const u8,
+    value: []const u8,
+    char_count: usize,
+    byte_len: usize,
+    encoded: []const u8,
+};
+
+pub const StringVecVector = struct {
+    name: []const u8,
+    values: []const []const u8,
+    encoded: []const u8,
+};
+
+pub const Fixture = struct {
+    strings: []const StringVector,
+    string_vecs: []const StringVecVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_borsh_string_vectors.json"),
+        .{},
+    );
+}
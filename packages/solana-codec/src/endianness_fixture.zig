@@ -0,0 +1,22 @@
+const std = @import("std");
+
+pub const EndiannessVector = struct {
+    name: []const u8,
+    value: []const u8,
+    le_bytes: []const u8,
+    be_bytes: []const u8,
+    on_chain_uses: []const u8,
+};
+
+pub const Fixture = struct {
+    vectors: []const EndiannessVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_endianness_vectors.json"),
+        .{},
+    );
+}
@@ -0,0 +1,27 @@
+const std = @import("std");
+
+pub const FieldLayout = struct {
+    name: []const u8,
+    size: usize,
+    borsh_offset: usize,
+    repr_c_offset: usize,
+    offsets_agree: bool,
+};
+
+pub const Fixture = struct {
+    borsh_len: usize,
+    borsh: []const u8,
+    repr_c_size: usize,
+    repr_c_align: usize,
+    repr_c: []const u8,
+    fields: []const FieldLayout,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_layout_vs_borsh.json"),
+        .{},
+    );
+}
@@ -10,6 +10,13 @@ const sol = @import("solana_program_sdk");
 pub const Pubkey = sol.Pubkey;
 pub const PUBKEY_BYTES = sol.PUBKEY_BYTES;
 pub const MAX_SHORTVEC_VALUE: usize = std.math.maxInt(u16);
+pub const BASE58_ALPHABET = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+const BASE58_DECODE_TABLE = blk: {
+    var table: [256]u8 = @splat(0xff);
+    for (BASE58_ALPHABET, 0..) |c, i| table[c] = @intCast(i);
+    break :blk table;
+};
 
 pub const Error = error{
     BufferTooSmall,
@@ -17,6 +24,7 @@ pub const Error = error{
     LengthOverflow,
     NonCanonicalShortVec,
     InvalidCOptionTag,
+    InvalidBase58Character,
 };
 
 pub const ReadUsize = struct {
@@ -419,6 +427,71 @@ pub fn readCOptionU64Parts(tag: u32, payload: u64) Error!COptionU64 {
     };
 }
 
+pub fn base58EncodedLenMax(len: usize) usize {
+    // log(256) / log(58) < 1.38.
+    return len * 138 / 100 + 1;
+}
+
+pub fn encodeBase58(input: []const u8, out: []u8) Error![]u8 {
+    // Each leading zero byte is written as a leading '1'.
+    var zeros: usize = 0;
+    while (zeros < input.len and input[zeros] == 0) : (zeros += 1) {}
+
+    // Base58 digits, least significant first, accumulated in `out`.
+    var digits: usize = 0;
+    for (input[zeros..]) |byte| {
+        var carry: u32 = byte;
+        for (out[0..digits]) |*digit| {
+            carry += @as(u32, digit.*) << 8;
+            digit.* = @intCast(carry % 58);
+            carry /= 58;
+        }
+        while (carry > 0) : (carry /= 58) {
+            if (digits == out.len) return error.BufferTooSmall;
+            out[digits] = @intCast(carry % 58);
+            digits += 1;
+        }
+    }
+
+    const len = zeros + digits;
+    if (out.len < len) return error.BufferTooSmall;
+    std.mem.reverse(u8, out[0..digits]);
+    std.mem.copyBackwards(u8, out[zeros..len], out[0..digits]);
+    @memset(out[0..zeros], '1');
+    for (out[zeros..len]) |*c| c.* = BASE58_ALPHABET[c.*];
+    return out[0..len];
+}
+
+pub fn decodeBase58(encoded: []const u8, out: []u8) Error![]u8 {
+    var zeros: usize = 0;
+    while (zeros < encoded.len and encoded[zeros] == '1') : (zeros += 1) {}
+
+    // Bytes, least significant first, accumulated in `out`.
+    var bytes: usize = 0;
+    for (encoded[zeros..]) |c| {
+        const value = BASE58_DECODE_TABLE[c];
+        if (value == 0xff) return error.InvalidBase58Character;
+        var carry: u32 = value;
+        for (out[0..bytes]) |*byte| {
+            carry += @as(u32, byte.*) * 58;
+            byte.* = @truncate(carry);
+            carry >>= 8;
+        }
+        while (carry > 0) : (carry >>= 8) {
+            if (bytes == out.len) return error.BufferTooSmall;
+            out[bytes] = @truncate(carry);
+            bytes += 1;
+        }
+    }
+
+    const len = zeros + bytes;
+    if (out.len < len) return error.BufferTooSmall;
+    std.mem.reverse(u8, out[0..bytes]);
+    std.mem.copyBackwards(u8, out[zeros..len], out[0..bytes]);
+    @memset(out[0..zeros], 0);
+    return out[0..len];
+}
+
 fn writeInt(comptime T: type, out: []u8, value: T) Error!usize {
     if (out.len < @sizeOf(T)) return error.BufferTooSmall;
     std.mem.writeInt(T, out[0..@sizeOf(T)], value, .little);
@@ -456,6 +529,29 @@ test "shortvec canonical encodes and decodes Solana compact lengths" {
     try std.testing.expectError(error.LengthOverflow, writeShortVec(65536, &buf));
 }
 
+test "shortvec arrays are the compact length then the packed elements" {
+    const short_vec_array_fixture = @import("short_vec_array_fixture.zig");
+    var parsed = try short_vec_array_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+
+    var buf: [3 + 300 * PUBKEY_BYTES]u8 = undefined;
+    for (parsed.value.vectors) |vector| {
+        const element_size: usize = if (std.mem.eql(u8, vector.element_type, "Pubkey")) PUBKEY_BYTES else 1;
+        try std.testing.expectEqual(vector.len * element_size, vector.elements.len);
+
+        // Element `i` is `i as u8`; pubkey `i` is bytes `32 * i ..`.
+        var len = try writeShortVec(vector.len, &buf);
+        try std.testing.expectEqualSlices(u8, vector.prefix, buf[0..len]);
+        for (0..vector.len * element_size) |i| buf[len + i] = @truncate(i);
+        len += vector.len * element_size;
+        try std.testing.expectEqualSlices(u8, vector.encoded, buf[0..len]);
+
+        const decoded = try readShortVec(vector.encoded);
+        try std.testing.expectEqual(vector.len, decoded.value);
+        try std.testing.expectEqualSlices(u8, vector.elements, vector.encoded[decoded.len..]);
+    }
+}
+
 test "Borsh primitive and byte helpers are little-endian and bounded" {
     var buf: [32]u8 = undefined;
 
@@ -599,6 +695,454 @@ test "COption u64 matches bincode little-endian layout" {
     try std.testing.expectError(error.InvalidCOptionTag, readCOptionU64Parts(2, 0));
 }
 
+test "extern struct memory image differs from Borsh wire format" {
+    const layout_fixture = @import("layout_vs_borsh_fixture.zig");
+    var parsed = try layout_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+
+    const ExampleState = extern struct {
+        is_initialized: bool,
+        authority: [32]u8,
+        count: u64,
+        bump: u8,
+        tag: u16,
+        timestamp: i64,
+    };
+
+    try std.testing.expectEqual(fixture.repr_c_size, @sizeOf(ExampleState));
+    try std.testing.expectEqual(fixture.repr_c_align, @alignOf(ExampleState));
+    inline for (std.meta.fields(ExampleState), 0..) |field, i| {
+        const layout = fixture.fields[i];
+        try std.testing.expectEqualStrings(layout.name, field.name);
+        try std.testing.expectEqual(layout.size, @sizeOf(field.type));
+        try std.testing.expectEqual(layout.repr_c_offset, @offsetOf(ExampleState, field.name));
+    }
+
+    var state = std.mem.zeroes(ExampleState);
+    state.is_initialized = true;
+    state.authority = .{0x11} ** 32;
+    state.count = 0x0102_0304_0506_0708;
+    state.bump = 0xfe;
+    state.tag = 0xabcd;
+    state.timestamp = -2;
+    try std.testing.expectEqualSlices(u8, fixture.repr_c, std.mem.asBytes(&state));
+
+    var borsh: [64]u8 = undefined;
+    var len: usize = 0;
+    len += try writeBorshBool(borsh[len..], state.is_initialized);
+    @memcpy(borsh[len..][0..32], &state.authority);
+    len += 32;
+    len += try writeBorshU64(borsh[len..], state.count);
+    len += try writeBorshU8(borsh[len..], state.bump);
+    len += try writeBorshU16(borsh[len..], state.tag);
+    len += try writeBorshU64(borsh[len..], @bitCast(state.timestamp));
+    try std.testing.expectEqual(fixture.borsh_len, len);
+    try std.testing.expectEqualSlices(u8, fixture.borsh, borsh[0..len]);
+
+    // Reading Borsh bytes through the extern layout picks up the wrong
+    // bytes for every field that follows the first padding gap.
+    for (fixture.fields) |layout| {
+        if (layout.offsets_agree) continue;
+        try std.testing.expect(layout.borsh_offset < layout.repr_c_offset);
+    }
+    const misread = std.mem.readInt(u64, fixture.borsh[fixture.fields[2].repr_c_offset..][0..8], .little);
+    try std.testing.expect(misread != state.count);
+}
+
+test "multi-byte integers use the official on-chain byte order" {
+    const endianness_fixture = @import("endianness_fixture.zig");
+    var parsed = try endianness_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const vectors = parsed.value.vectors;
+    try std.testing.expectEqual(@as(usize, 7), vectors.len);
+
+    for (vectors) |vector| {
+        const value = try std.fmt.parseInt(u256, vector.value, 10);
+        const width = vector.le_bytes.len;
+        try std.testing.expectEqual(width, vector.be_bytes.len);
+        var le: [32]u8 = undefined;
+        std.mem.writeInt(u256, &le, value, .little);
+        try std.testing.expectEqualSlices(u8, le[0..width], vector.le_bytes);
+        try std.testing.expect(std.mem.allEqual(u8, le[width..], 0));
+        for (vector.le_bytes, 0..) |byte, i| {
+            try std.testing.expectEqual(byte, vector.be_bytes[width - 1 - i]);
+        }
+
+        // Bytes the Zig serializer for this field writes.
+        var buf: [128]u8 = undefined;
+        var clock = std.mem.zeroes(sol.clock.Clock);
+        var rewards = std.mem.zeroes(sol.sysvar.EpochRewards);
+        const wire: []const u8 = if (std.mem.eql(u8, vector.name, "lamports_u64") or
+            std.mem.eql(u8, vector.name, "token_amount_u64"))
+        wire: {
+            const len = try writeBincodeU64(&buf, @intCast(value));
+            break :wire buf[0..len];
+        } else if (std.mem.eql(u8, vector.name, "slot_u64")) wire: {
+            clock.slot = @intCast(value);
+            break :wire std.mem.asBytes(&clock)[@offsetOf(sol.clock.Clock, "slot")..][0..8];
+        } else if (std.mem.eql(u8, vector.name, "epoch_u64")) wire: {
+            clock.epoch = @intCast(value);
+            break :wire std.mem.asBytes(&clock)[@offsetOf(sol.clock.Clock, "epoch")..][0..8];
+        } else if (std.mem.eql(u8, vector.name, "total_points_u128")) wire: {
+            rewards.total_points = @intCast(value);
+            break :wire std.mem.asBytes(&rewards)[@offsetOf(sol.sysvar.EpochRewards, "total_points")..][0..16];
+        } else if (std.mem.eql(u8, vector.name, "bn254_field_element")) wire: {
+            // EIP-197 field elements for the `*BE` alt_bn128 syscalls.
+            std.mem.writeInt(u256, buf[0..32], value, .big);
+            break :wire buf[0..32];
+        } else if (std.mem.eql(u8, vector.name, "secp_offsets_u16")) wire: {
+            const secp256k1 = sol.secp256k1_instruction;
+            const eth_address = [_]u8{0x33} ** secp256k1.HASHED_PUBKEY_SERIALIZED_SIZE;
+            const signature = [_]u8{0x22} ** secp256k1.SIGNATURE_SERIALIZED_SIZE;
+            const ix = try secp256k1.verifyFirst("endianness", &eth_address, &signature, 0, &buf);
+            try std.testing.expectEqual(@as(u16, @intCast(value)), std.mem.readInt(u16, ix.data[1..3], .little));
+            break :wire ix.data[1..3];
+        } else return error.UnknownContext;
+
+        const expected = if (std.mem.eql(u8, vector.on_chain_uses, "le"))
+            vector.le_bytes
+        else if (std.mem.eql(u8, vector.on_chain_uses, "be"))
+            vector.be_bytes
+        else
+            return error.UnknownByteOrder;
+        try std.testing.expectEqualSlices(u8, expected, wire);
+    }
+}
+
+test "shared scalars encode identically in Borsh and bincode" {
+    const scalar_encoding_fixture = @import("scalar_encoding_fixture.zig");
+    var borsh = try scalar_encoding_fixture.loadBorsh(std.testing.allocator);
+    defer borsh.deinit();
+    var bincode = try scalar_encoding_fixture.loadBincode(std.testing.allocator);
+    defer bincode.deinit();
+    try std.testing.expectEqual(borsh.value.vectors.len, bincode.value.vectors.len);
+
+    // Only length prefixes (u32 vs u64) and enum tags (u8 vs u32) differ;
+    // `Option` uses a one-byte tag in both formats.
+    const expected_different = [_][]const u8{ "string_seed", "bytes_abc", "enum_variant_1" };
+    for (borsh.value.vectors, bincode.value.vectors) |left, right| {
+        try std.testing.expectEqualStrings(left.name, right.name);
+        var differs = false;
+        for (expected_different) |name| differs = differs or std.mem.eql(u8, name, left.name);
+        if (!differs) {
+            try std.testing.expectEqualSlices(u8, left.encoded, right.encoded);
+            continue;
+        }
+        try std.testing.expect(!std.mem.eql(u8, left.encoded, right.encoded));
+    }
+
+    // The helpers here reproduce both sides of shared and divergent cases.
+    const find = struct {
+        fn encoded(vectors: []const scalar_encoding_fixture.ScalarVector, name: []const u8) ![]const u8 {
+            for (vectors) |vector| {
+                if (std.mem.eql(u8, vector.name, name)) return vector.encoded;
+            }
+            return error.MissingVector;
+        }
+    }.encoded;
+    const b = borsh.value.vectors;
+    const c = bincode.value.vectors;
+    var buf: [16]u8 = undefined;
+    var len = try writeBorshU32(&buf, 0xdeadbeef);
+    try std.testing.expectEqualSlices(u8, try find(b, "u32"), buf[0..len]);
+    len = try writeBincodeU32(&buf, 0xdeadbeef);
+    try std.testing.expectEqualSlices(u8, try find(c, "u32"), buf[0..len]);
+    len = try writeBorshOptionU64(&buf, 90);
+    try std.testing.expectEqualSlices(u8, try find(b, "option_u64_some"), buf[0..len]);
+    len = try writeBincodeOptionU64(&buf, 90);
+    try std.testing.expectEqualSlices(u8, try find(c, "option_u64_some"), buf[0..len]);
+    len = try writeBorshString(&buf, "seed");
+    try std.testing.expectEqualSlices(u8, try find(b, "string_seed"), buf[0..len]);
+    len = try writeBincodeString(&buf, "seed");
+    try std.testing.expectEqualSlices(u8, try find(c, "string_seed"), buf[0..len]);
+    len = try writeBorshBytes(&buf, "abc");
+    try std.testing.expectEqualSlices(u8, try find(b, "bytes_abc"), buf[0..len]);
+    // bincode writes `Vec<u8>` exactly like a string.
+    len = try writeBincodeString(&buf, "abc");
+    try std.testing.expectEqualSlices(u8, try find(c, "bytes_abc"), buf[0..len]);
+}
+
+test "nested Borsh structs inline their fields with no framing" {
+    const borsh_nested_fixture = @import("borsh_nested_fixture.zig");
+    var parsed = try borsh_nested_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+
+    const encode = struct {
+        fn inner(out: []u8, value: borsh_nested_fixture.Inner) Error!usize {
+            var len = try writeBorshU32(out, value.a);
+            if (out.len < len + PUBKEY_BYTES) return error.BufferTooSmall;
+            @memcpy(out[len..][0..PUBKEY_BYTES], &value.b);
+            len += PUBKEY_BYTES;
+            return len;
+        }
+
+        fn outer(out: []u8, value: borsh_nested_fixture.Outer) Error!usize {
+            var len = try writeBorshBool(out, value.flag);
+            len += try inner(out[len..], value.inner);
+            len += try writeBorshBytes(out[len..], value.tail);
+            return len;
+        }
+
+        fn record(out: []u8, value: borsh_nested_fixture.Record) Error!usize {
+            var len = try writeBorshU64(out, value.id);
+            // Borsh `Option<Pubkey>` has the same one-byte tag as bincode.
+            len += try writeBincodeOptionPubkey(out[len..], if (value.authority.delegate) |*key| key else null);
+            len += try inner(out[len..], value.authority.inner);
+            return len;
+        }
+    };
+
+    var buf: [128]u8 = undefined;
+    for (parsed.value.inner) |vector| {
+        const len = try encode.inner(&buf, vector.value);
+        try std.testing.expectEqualSlices(u8, vector.encoded, buf[0..len]);
+    }
+    for (parsed.value.outer) |vector| {
+        const len = try encode.outer(&buf, vector.value);
+        try std.testing.expectEqualSlices(u8, vector.encoded, buf[0..len]);
+        try std.testing.expectError(error.BufferTooSmall, encode.outer(buf[0 .. len - 1], vector.value));
+
+        const flag = try readBorshBool(vector.encoded);
+        const a = try readBorshU32(vector.encoded[flag.len..]);
+        const tail = try readBorshBytes(vector.encoded[flag.len + a.len + PUBKEY_BYTES ..]);
+        try std.testing.expectEqual(vector.value.flag, flag.value);
+        try std.testing.expectEqual(vector.value.inner.a, a.value);
+        try std.testing.expectEqualSlices(u8, vector.value.tail, tail.value);
+    }
+    for (parsed.value.record) |vector| {
+        const len = try encode.record(&buf, vector.value);
+        try std.testing.expectEqualSlices(u8, vector.encoded, buf[0..len]);
+
+        const id = try readBorshU64(vector.encoded);
+        const delegate = try readBincodeOptionPubkey(vector.encoded[id.len..]);
+        try std.testing.expectEqual(vector.value.id, id.value);
+        try std.testing.expectEqual(vector.value.authority.delegate, delegate.value);
+        try std.testing.expectEqual(vector.encoded.len, id.len + delegate.len + 4 + PUBKEY_BYTES);
+    }
+}
+
+test "Borsh enums write a u8 variant index before the payload" {
+    const borsh_enum_fixture = @import("borsh_enum_fixture.zig");
+    var parsed = try borsh_enum_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+
+    var saw_index_two = false;
+    var buf: [64]u8 = undefined;
+    for (parsed.value.vectors) |vector| {
+        var len = try writeBorshU8(&buf, vector.discriminant);
+        if (vector.transfer) |fields| {
+            try std.testing.expectEqualStrings("Transfer", vector.variant);
+            len += try writeBorshU64(buf[len..], fields.amount);
+            @memcpy(buf[len..][0..PUBKEY_BYTES], &fields.recipient);
+            len += PUBKEY_BYTES;
+        } else if (vector.configure) |fields| {
+            try std.testing.expectEqualStrings("Configure", vector.variant);
+            @memcpy(buf[len..][0..PUBKEY_BYTES], &fields.authority);
+            len += PUBKEY_BYTES;
+            len += try writeBorshU16(buf[len..], fields.threshold);
+            len += try writeBorshU8(buf[len..], if (fields.memo != null) 1 else 0);
+            if (fields.memo) |memo| len += try writeBorshU32(buf[len..], memo);
+        } else {
+            try std.testing.expectEqualStrings("Ping", vector.variant);
+        }
+        try std.testing.expectEqualSlices(u8, vector.encoded, buf[0..len]);
+
+        const index = try readBorshU8(vector.encoded);
+        try std.testing.expectEqual(vector.discriminant, index.value);
+        saw_index_two = saw_index_two or index.value >= 2;
+    }
+    try std.testing.expect(saw_index_two);
+}
+
+test "Borsh string prefixes count UTF-8 bytes, not characters" {
+    const borsh_string_fixture = @import("borsh_string_fixture.zig");
+    var parsed = try borsh_string_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+
+    var buf: [512]u8 = undefined;
+    for (parsed.value.strings) |vector| {
+        try std.testing.expectEqual(vector.byte_len, vector.value.len);
+        try std.testing.expectEqual(vector.char_count, try std.unicode.utf8CountCodepoints(vector.value));
+        try std.testing.expectEqual(vector.encoded.len, try borshStringLen(vector.value));
+
+        const len = try writeBorshString(&buf, vector.value);
+        try std.testing.expectEqualSlices(u8, vector.encoded, buf[0..len]);
+        try std.testing.expectError(error.BufferTooSmall, writeBorshString(buf[0 .. len - 1], vector.value));
+
+        const decoded = try readBorshString(vector.encoded);
+        try std.testing.expectEqualStrings(vector.value, decoded.value);
+        try std.testing.expectEqual(vector.encoded.len, decoded.len);
+    }
+
+    // `Vec<String>` is a u32 element count, then each prefixed string.
+    for (parsed.value.string_vecs) |vector| {
+        var len = try writeBorshU32(&buf, @intCast(vector.values.len));
+        for (vector.values) |value| len += try writeBorshString(buf[len..], value);
+        try std.testing.expectEqualSlices(u8, vector.encoded, buf[0..len]);
+
+        const count = try readBorshU32(vector.encoded);
+        var cursor = count.len;
+        for (0..count.value) |i| {
+            const decoded = try readBorshString(vector.encoded[cursor..]);
+            try std.testing.expectEqualStrings(vector.values[i], decoded.value);
+            cursor += decoded.len;
+        }
+        try std.testing.expectEqual(vector.encoded.len, cursor);
+    }
+}
+
+test "Borsh collections: fixed arrays are unprefixed and maps are key-sorted" {
+    const borsh_collection_fixture = @import("borsh_collection_fixture.zig");
+    var parsed = try borsh_collection_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+
+    const find = struct {
+        fn encoded(vectors: []const borsh_collection_fixture.CollectionVector, name: []const u8, type_name: []const u8) ![]const u8 {
+            for (vectors) |vector| {
+                if (!std.mem.eql(u8, vector.name, name)) continue;
+                try std.testing.expectEqualStrings(type_name, vector.type_name);
+                return vector.encoded;
+            }
+            return error.MissingVector;
+        }
+    }.encoded;
+    const v = parsed.value.vectors;
+    var buf: [8192]u8 = undefined;
+
+    var len = try writeBorshU32(&buf, 0);
+    try std.testing.expectEqualSlices(u8, try find(v, "vec_u64_empty", "Vec<u64>"), buf[0..len]);
+    len = try writeBorshU32(&buf, 1);
+    len += try writeBorshU64(buf[len..], std.math.maxInt(u64));
+    try std.testing.expectEqualSlices(u8, try find(v, "vec_u64_one", "Vec<u64>"), buf[0..len]);
+    len = try writeBorshU32(&buf, 1000);
+    for (0..1000) |i| len += try writeBorshU64(buf[len..], @as(u64, i) * 0x0101_0101);
+    try std.testing.expectEqualSlices(u8, try find(v, "vec_u64_1000", "Vec<u64>"), buf[0..len]);
+
+    var bytes: [32]u8 = undefined;
+    for (&bytes, 0..) |*byte, i| byte.* = @intCast(i);
+    try std.testing.expectEqualSlices(u8, try find(v, "array_u8_32", "[u8; 32]"), &bytes);
+    len = try writeBorshBytes(&buf, &bytes);
+    try std.testing.expectEqualSlices(u8, try find(v, "vec_u8_32", "Vec<u8>"), buf[0..len]);
+
+    // Inserted as cc, a, b, ab; written in byte-wise key order.
+    const Entry = struct { key: []const u8, value: u64 };
+    var entries = [_]Entry{
+        .{ .key = "cc", .value = 3 },
+        .{ .key = "a", .value = 1 },
+        .{ .key = "b", .value = 2 },
+        .{ .key = "ab", .value = 12 },
+    };
+    std.mem.sort(Entry, &entries, {}, struct {
+        fn lessThan(_: void, a: Entry, b: Entry) bool {
+            return std.mem.lessThan(u8, a.key, b.key);
+        }
+    }.lessThan);
+    len = try writeBorshU32(&buf, entries.len);
+    for (entries) |entry| {
+        len += try writeBorshString(buf[len..], entry.key);
+        len += try writeBorshU64(buf[len..], entry.value);
+    }
+    try std.testing.expectEqualSlices(u8, try find(v, "hash_map_string_u64", "HashMap<String, u64>"), buf[0..len]);
+
+    len = try writeBorshU8(&buf, 0);
+    try std.testing.expectEqualSlices(u8, try find(v, "option_vec_pubkey_none", "Option<Vec<Pubkey>>"), buf[0..len]);
+    len = try writeBorshU8(&buf, 1);
+    len += try writeBorshU32(buf[len..], 2);
+    for ([_]u8{ 0x11, 0x22 }) |fill| {
+        @memset(buf[len..][0..PUBKEY_BYTES], fill);
+        len += PUBKEY_BYTES;
+    }
+    try std.testing.expectEqualSlices(u8, try find(v, "option_vec_pubkey_some", "Option<Vec<Pubkey>>"), buf[0..len]);
+
+    len = try writeBorshU8(&buf, 7);
+    len += try writeBorshU64(buf[len..], 0x0102_0304_0506_0708);
+    @memset(buf[len..][0..PUBKEY_BYTES], 0x33);
+    len += PUBKEY_BYTES;
+    try std.testing.expectEqualSlices(u8, try find(v, "tuple_u8_u64_pubkey", "(u8, u64, Pubkey)"), buf[0..len]);
+}
+
+test "bincode strings and vecs use a u64 length prefix" {
+    const bincode_collection_fixture = @import("bincode_collection_fixture.zig");
+    var parsed = try bincode_collection_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+
+    const find = struct {
+        fn encoded(vectors: []const bincode_collection_fixture.CollectionVector, name: []const u8, type_name: []const u8) ![]const u8 {
+            for (vectors) |vector| {
+                if (!std.mem.eql(u8, vector.name, name)) continue;
+                try std.testing.expectEqualStrings(type_name, vector.type_name);
+                return vector.encoded;
+            }
+            return error.MissingVector;
+        }
+    }.encoded;
+    const v = parsed.value.vectors;
+    var buf: [512]u8 = undefined;
+    var bytes: [300]u8 = undefined;
+    for (&bytes, 0..) |*byte, i| byte.* = @truncate(i);
+
+    inline for (.{ .{ "string_empty", "" }, .{ "string_seed", "seed" }, .{ "string_utf8", "索拉纳" } }) |case| {
+        const len = try writeBincodeString(&buf, case[1]);
+        try std.testing.expectEqualSlices(u8, try find(v, case[0], "String"), buf[0..len]);
+        const decoded = try readBincodeString(buf[0..len]);
+        try std.testing.expectEqualStrings(case[1], decoded.value);
+    }
+    // bincode writes `Vec<u8>` exactly like a string.
+    inline for (.{ .{ "vec_u8_empty", 0 }, .{ "vec_u8_3", 3 }, .{ "vec_u8_300", 300 } }) |case| {
+        const len = try writeBincodeString(&buf, bytes[0..case[1]]);
+        try std.testing.expectEqualSlices(u8, try find(v, case[0], "Vec<u8>"), buf[0..len]);
+    }
+
+    const long_vec = [_]u64{ 1, 0x0102_0304_0506_0708, 0 };
+    inline for (.{
+        .{ "vec_u64_empty", &[_]u64{} },
+        .{ "vec_u64_1", &[_]u64{std.math.maxInt(u64)} },
+        .{ "vec_u64_3", &long_vec },
+    }) |case| {
+        var len = try writeBincodeLen(&buf, case[1].len);
+        for (case[1]) |value| len += try writeBincodeU64(buf[len..], value);
+        try std.testing.expectEqualSlices(u8, try find(v, case[0], "Vec<u64>"), buf[0..len]);
+    }
+
+    // A fixed array has no prefix at all.
+    try std.testing.expectEqualSlices(u8, try find(v, "array_u8_32", "[u8; 32]"), bytes[0..32]);
+
+    const pubkey: Pubkey = .{0x44} ** PUBKEY_BYTES;
+    var len = try writeBorshU8(&buf, 7);
+    len += try writeBincodeU64(buf[len..], 0x0102_0304_0506_0708);
+    @memcpy(buf[len..][0..PUBKEY_BYTES], &pubkey);
+    len += PUBKEY_BYTES;
+    try std.testing.expectEqualSlices(u8, try find(v, "tuple_u8_u64_pubkey", "(u8, u64, Pubkey)"), buf[0..len]);
+
+    len = try writeBincodeOptionPubkey(&buf, null);
+    try std.testing.expectEqualSlices(u8, try find(v, "option_pubkey_none", "Option<Pubkey>"), buf[0..len]);
+    len = try writeBincodeOptionPubkey(&buf, &pubkey);
+    try std.testing.expectEqualSlices(u8, try find(v, "option_pubkey_some", "Option<Pubkey>"), buf[0..len]);
+}
+
+test "base58 encodes and decodes arbitrary lengths like the bs58 crate" {
+    const base58_fixture = @import("base58_fixture.zig");
+    var parsed = try base58_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+
+    var buf: [256]u8 = undefined;
+    for (parsed.value.vectors) |vector| {
+        if (!vector.decodable) {
+            try std.testing.expectError(error.InvalidBase58Character, decodeBase58(vector.base58, &buf));
+            continue;
+        }
+        try std.testing.expect(vector.base58.len <= base58EncodedLenMax(vector.bytes.len));
+        try std.testing.expectEqualStrings(vector.base58, try encodeBase58(vector.bytes, &buf));
+        try std.testing.expectEqualSlices(u8, vector.bytes, try decodeBase58(vector.base58, &buf));
+
+        if (vector.bytes.len > 0) {
+            try std.testing.expectError(error.BufferTooSmall, encodeBase58(vector.bytes, buf[0 .. vector.base58.len - 1]));
+            try std.testing.expectError(error.BufferTooSmall, decodeBase58(vector.base58, buf[0 .. vector.bytes.len - 1]));
+        }
+    }
+}
+
 test "public surface guards" {
     try std.testing.expectEqual(@as(usize, 32), PUBKEY_BYTES);
     try std.testing.expect(@hasDecl(@This(), "writeShortVec"));
@@ -609,6 +1153,8 @@ test "public surface guards" {
     try std.testing.expect(@hasDecl(@This(), "writeBincodeOptionI64"));
     try std.testing.expect(@hasDecl(@This(), "writeBincodeOptionPubkey"));
     try std.testing.expect(@hasDecl(@This(), "writeVarintU64"));
+    try std.testing.expect(@hasDecl(@This(), "encodeBase58"));
+    try std.testing.expect(@hasDecl(@This(), "decodeBase58"));
     try std.testing.expect(@hasDecl(@This(), "writeCOptionPubkey"));
     try std.testing.expect(@hasDecl(@This(), "readCOptionPubkeyParts"));
     try std.testing.expect(@hasDecl(@This(), "readCOptionU64Parts"));
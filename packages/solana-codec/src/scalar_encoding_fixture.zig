@@ -0,0 +1,28 @@
+const std = @import("std");
+
+pub const ScalarVector = struct {
+    name: []const u8,
+    encoded: []const u8,
+};
+
+pub const Fixture = struct {
+    vectors: []const ScalarVector,
+};
+
+pub fn loadBorsh(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_borsh_scalar_vectors.json"),
+        .{},
+    );
+}
+
+pub fn loadBincode(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_bincode_scalar_vectors.json"),
+        .{},
+    );
+}
@@ -0,0 +1,23 @@
+const std = @import("std");
+
+pub const ShortVecArrayTestVector = struct {
+    name: []const u8,
+    element_type: []const u8,
+    len: usize,
+    prefix: []const u8,
+    elements: []const u8,
+    encoded: []const u8,
+};
+
+pub const Fixture = struct {
+    vectors: []const ShortVecArrayTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_short_vec_array_vectors.json"),
+        .{},
+    );
+}
@@ -0,0 +1,112 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_compute_budget::compute_budget_limits::ComputeBudgetLimits;
+use solana_fee_structure::{FeeDetails, FeeStructure};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_fee_vectors.json");
+
+const SIGNATURE_COUNTS: [u64; 3] = [0, 1, 3];
+/// `(compute_unit_limit, compute_unit_price)` pairs. The price is in
+/// micro-lamports per CU and the product rounds up to whole lamports.
+const BUDGETS: [(u32, u64); 7] = [
+    (200_000, 0),
+    // 0.2 lamports rounds up to 1.
+    (200_000, 1),
+    // 0.9999 lamports rounds up to 1.
+    (300, 3_333),
+    // Exactly 1 lamport.
+    (1_000_000, 1),
+    // 1.000001 lamports rounds up to 2.
+    (1_000_001, 1),
+    (1_400_000, 10_000),
+    // Saturates at `u64::MAX` lamports.
+    (u32::MAX, u64::MAX),
+];
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct FeeTestVector {
+    name: String,
+    num_signatures: u64,
+    lamports_per_signature: u64,
+    compute_unit_limit: u32,
+    compute_unit_price: u64,
+    signature_fee: u64,
+    prioritization_fee: u64,
+    total_fee: u64,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    lamports_per_signature: u64,
+    vectors: Vec<FeeTestVector>,
+}
+
+fn vector(
+    num_signatures: u64,
+    (compute_unit_limit, compute_unit_price): (u32, u64),
+) -> FeeTestVector {
+    let lamports_per_signature = FeeStructure::default().lamports_per_signature;
+    let limits = ComputeBudgetLimits {
+        compute_unit_limit,
+        compute_unit_price,
+        ..ComputeBudgetLimits::default()
+    };
+    let details = FeeDetails::new(
+        num_signatures.saturating_mul(lamports_per_signature),
+        limits.get_prioritization_fee(),
+    );
+    FeeTestVector {
+        name: format!("{num_signatures}_sig_{compute_unit_limit}_cu_{compute_unit_price}_price"),
+        num_signatures,
+        lamports_per_signature,
+        compute_unit_limit,
+        compute_unit_price,
+        signature_fee: details.transaction_fee(),
+        prioritization_fee: details.prioritization_fee(),
+        total_fee: details.total_fee(),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        lamports_per_signature: FeeStructure::default().lamports_per_signature,
+        vectors: SIGNATURE_COUNTS
+            .into_iter()
+            .flat_map(|count| BUDGETS.into_iter().map(move |budget| vector(count, budget)))
+            .collect(),
+    }
+}
+
+#[test]
+fn fixture_matches_official_fee_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match("official_fee_vectors.json", &fixture, &official_fixture());
+}
+
+#[test]
+fn prioritization_fee_is_the_ceiling_of_micro_lamports() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let micro_lamports =
+            u128::from(vector.compute_unit_limit) * u128::from(vector.compute_unit_price);
+        let expected = u64::try_from(micro_lamports.div_ceil(1_000_000)).unwrap_or(u64::MAX);
+        assert_eq!(vector.prioritization_fee, expected, "{}", vector.name);
+        assert_eq!(
+            vector.total_fee,
+            vector
+                .signature_fee
+                .saturating_add(vector.prioritization_fee)
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_fee_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
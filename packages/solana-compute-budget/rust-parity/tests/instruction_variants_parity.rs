@@ -0,0 +1,131 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_compute_budget_interface::ComputeBudgetInstruction;
+use solana_sdk::{
+    compute_budget::{self as legacy, ComputeBudgetInstruction as LegacyInstruction},
+    instruction::Instruction as LegacyIx,
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_instruction_variant_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct InstructionVariantVector {
+    name: String,
+    /// Borsh enum index, the first data byte.
+    discriminant: u8,
+    /// Set only for `RequestUnitsDeprecated`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    units: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    additional_fee: Option<u32>,
+    /// The single integer argument of a current variant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    value: Option<u64>,
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<InstructionVariantVector>,
+}
+
+/// `RequestUnitsDeprecated` was removed from the SDK after 1.14, so the
+/// legacy encoding comes from `solana-sdk` 1.14.
+fn request_units(name: &str, units: u32, additional_fee: u32) -> InstructionVariantVector {
+    let ix = LegacyIx::new_with_borsh(
+        legacy::id(),
+        &LegacyInstruction::RequestUnitsDeprecated {
+            units,
+            additional_fee,
+        },
+        vec![],
+    );
+    InstructionVariantVector {
+        name: name.to_string(),
+        discriminant: ix.data[0],
+        units: Some(units),
+        additional_fee: Some(additional_fee),
+        value: None,
+        data: ix.data,
+    }
+}
+
+fn current(name: &str, value: u64, data: Vec<u8>) -> InstructionVariantVector {
+    InstructionVariantVector {
+        name: name.to_string(),
+        discriminant: data[0],
+        units: None,
+        additional_fee: None,
+        value: Some(value),
+        data,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        vectors: vec![
+            request_units("request_units_deprecated_zero", 0, 0),
+            request_units("request_units_deprecated_typical", 1_400_000, 10_000),
+            request_units("request_units_deprecated_max", u32::MAX, u32::MAX),
+            current(
+                "request_heap_frame",
+                256 * 1024,
+                ComputeBudgetInstruction::request_heap_frame(256 * 1024).data,
+            ),
+            current(
+                "set_compute_unit_limit",
+                1_400_000,
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000).data,
+            ),
+            current(
+                "set_compute_unit_price",
+                u64::MAX,
+                ComputeBudgetInstruction::set_compute_unit_price(u64::MAX).data,
+            ),
+            current(
+                "set_loaded_accounts_data_size_limit",
+                64 * 1024 * 1024,
+                ComputeBudgetInstruction::set_loaded_accounts_data_size_limit(64 * 1024 * 1024)
+                    .data,
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_instruction_variant_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_instruction_variant_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn current_variants_kept_their_legacy_encoding() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let legacy = match vector.discriminant {
+            0 => continue,
+            1 => LegacyInstruction::request_heap_frame(vector.value.unwrap() as u32),
+            2 => LegacyInstruction::set_compute_unit_limit(vector.value.unwrap() as u32),
+            3 => LegacyInstruction::set_compute_unit_price(vector.value.unwrap()),
+            // 1.14 predates SetLoadedAccountsDataSizeLimit.
+            4 => continue,
+            other => panic!("{}: unexpected discriminant {other}", vector.name),
+        };
+        assert_eq!(legacy.data, vector.data, "{}", vector.name);
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_instruction_variant_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,26 @@
+const std = @import("std");
+
+pub const FeeVector = struct {
+    name: []const u8,
+    num_signatures: u64,
+    lamports_per_signature: u64,
+    compute_unit_limit: u32,
+    compute_unit_price: u64,
+    signature_fee: u64,
+    prioritization_fee: u64,
+    total_fee: u64,
+};
+
+pub const Fixture = struct {
+    lamports_per_signature: u64,
+    vectors: []const FeeVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_fee_vectors.json"),
+        .{},
+    );
+}
@@ -0,0 +1,23 @@
+const std = @import("std");
+
+pub const InstructionVariantVector = struct {
+    name: []const u8,
+    discriminant: u8,
+    units: ?u32 = null,
+    additional_fee: ?u32 = null,
+    value: ?u64 = null,
+    data: []const u8,
+};
+
+pub const Fixture = struct {
+    vectors: []const InstructionVariantVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_instruction_variant_vectors.json"),
+        .{},
+    );
+}
@@ -8,6 +8,8 @@ pub const Instruction = sol.cpi.Instruction;
 pub const PROGRAM_ID: Pubkey = sol.pubkey.comptimeFromBase58("ComputeBudget111111111111111111111111111111");
 
 pub const InstructionTag = enum(u8) {
+    /// Formerly `RequestUnitsDeprecated`; no longer built, but still present
+    /// in historical transactions and accepted by `decodeInstruction`.
     unused = 0,
     request_heap_frame = 1,
     set_compute_unit_limit = 2,
@@ -15,6 +17,7 @@ pub const InstructionTag = enum(u8) {
     set_loaded_accounts_data_size_limit = 4,
 };
 
+pub const REQUEST_UNITS_DEPRECATED_DATA_LEN: usize = 1 + @sizeOf(u32) + @sizeOf(u32);
 pub const REQUEST_HEAP_FRAME_DATA_LEN: usize = 1 + @sizeOf(u32);
 pub const SET_COMPUTE_UNIT_LIMIT_DATA_LEN: usize = 1 + @sizeOf(u32);
 pub const SET_COMPUTE_UNIT_PRICE_DATA_LEN: usize = 1 + @sizeOf(u64);
@@ -25,6 +28,24 @@ pub const SetComputeUnitLimitData = [SET_COMPUTE_UNIT_LIMIT_DATA_LEN]u8;
 pub const SetComputeUnitPriceData = [SET_COMPUTE_UNIT_PRICE_DATA_LEN]u8;
 pub const SetLoadedAccountsDataSizeLimitData = [SET_LOADED_ACCOUNTS_DATA_SIZE_LIMIT_LEN]u8;
 
+pub const Error = error{
+    InvalidInstructionData,
+    UnknownInstructionTag,
+};
+
+pub const RequestUnitsDeprecated = struct {
+    units: u32,
+    additional_fee: u32,
+};
+
+pub const DecodedInstruction = union(enum) {
+    request_units_deprecated: RequestUnitsDeprecated,
+    request_heap_frame: u32,
+    set_compute_unit_limit: u32,
+    set_compute_unit_price: u64,
+    set_loaded_accounts_data_size_limit: u32,
+};
+
 pub fn requestHeapFrame(bytes: u32, data: *RequestHeapFrameData) Instruction {
     writeU32(.request_heap_frame, bytes, data);
     return noAccounts(data);
@@ -46,6 +67,57 @@ pub fn setLoadedAccountsDataSizeLimit(bytes: u32, data: *SetLoadedAccountsDataSi
     return noAccounts(data);
 }
 
+/// `setComputeUnitPrice` is denominated in micro-lamports per compute unit.
+pub const MICRO_LAMPORTS_PER_LAMPORT: u64 = 1_000_000;
+
+/// Lamports charged for `compute_unit_limit` units at `compute_unit_price`
+/// micro-lamports each, rounded up and saturating at `u64::MAX`.
+pub fn prioritizationFee(compute_unit_limit: u32, compute_unit_price: u64) u64 {
+    const micro_lamports = @as(u128, compute_unit_limit) * compute_unit_price;
+    const lamports = std.math.divCeil(u128, micro_lamports, MICRO_LAMPORTS_PER_LAMPORT) catch unreachable;
+    return std.math.cast(u64, lamports) orelse std.math.maxInt(u64);
+}
+
+/// Signature fee plus prioritization fee, saturating like the runtime's
+/// `FeeDetails::total_fee`.
+pub fn transactionFee(
+    num_signatures: u64,
+    lamports_per_signature: u64,
+    compute_unit_limit: u32,
+    compute_unit_price: u64,
+) u64 {
+    const signature_fee = num_signatures *| lamports_per_signature;
+    return signature_fee +| prioritizationFee(compute_unit_limit, compute_unit_price);
+}
+
+/// Decode Compute Budget instruction data, including the legacy
+/// `RequestUnitsDeprecated` variant. Like borsh, trailing bytes are rejected.
+pub fn decodeInstruction(data: []const u8) Error!DecodedInstruction {
+    if (data.len == 0) return error.InvalidInstructionData;
+    const tag = std.meta.intToEnum(InstructionTag, data[0]) catch return error.UnknownInstructionTag;
+    return switch (tag) {
+        .unused => blk: {
+            if (data.len != REQUEST_UNITS_DEPRECATED_DATA_LEN) return error.InvalidInstructionData;
+            break :blk .{ .request_units_deprecated = .{
+                .units = std.mem.readInt(u32, data[1..5], .little),
+                .additional_fee = std.mem.readInt(u32, data[5..9], .little),
+            } };
+        },
+        .request_heap_frame => .{ .request_heap_frame = try readU32(data) },
+        .set_compute_unit_limit => .{ .set_compute_unit_limit = try readU32(data) },
+        .set_compute_unit_price => blk: {
+            if (data.len != SET_COMPUTE_UNIT_PRICE_DATA_LEN) return error.InvalidInstructionData;
+            break :blk .{ .set_compute_unit_price = std.mem.readInt(u64, data[1..9], .little) };
+        },
+        .set_loaded_accounts_data_size_limit => .{ .set_loaded_accounts_data_size_limit = try readU32(data) },
+    };
+}
+
+fn readU32(data: []const u8) Error!u32 {
+    if (data.len != 1 + @sizeOf(u32)) return error.InvalidInstructionData;
+    return std.mem.readInt(u32, data[1..5], .little);
+}
+
 fn writeU32(tag: InstructionTag, value: u32, data: []u8) void {
     std.debug.assert(data.len == 1 + @sizeOf(u32));
     data[0] = @intFromEnum(tag);
@@ -87,10 +159,92 @@ test "requestHeapFrame and setLoadedAccountsDataSizeLimit encode u32 payloads" {
     try std.testing.expectEqualSlices(u8, &.{ 4, 0, 0, 1, 0 }, loaded_ix.data);
 }
 
+test "integer boundary sweep matches official Rust encodings" {
+    const boundary_fixture = @import("boundary_fixture.zig");
+    var parsed = try boundary_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    try std.testing.expectEqual(@as(usize, 29), parsed.value.vectors.len);
+
+    for (parsed.value.vectors) |vector| {
+        var heap_data: RequestHeapFrameData = undefined;
+        var limit_data: SetComputeUnitLimitData = undefined;
+        var price_data: SetComputeUnitPriceData = undefined;
+        var loaded_data: SetLoadedAccountsDataSizeLimitData = undefined;
+
+        const ix = if (std.mem.eql(u8, vector.family, "compute_budget_request_heap_frame"))
+            requestHeapFrame(@intCast(vector.value), &heap_data)
+        else if (std.mem.eql(u8, vector.family, "compute_budget_set_compute_unit_limit"))
+            setComputeUnitLimit(@intCast(vector.value), &limit_data)
+        else if (std.mem.eql(u8, vector.family, "compute_budget_set_compute_unit_price"))
+            setComputeUnitPrice(vector.value, &price_data)
+        else if (std.mem.eql(u8, vector.family, "compute_budget_set_loaded_accounts_data_size_limit"))
+            setLoadedAccountsDataSizeLimit(@intCast(vector.value), &loaded_data)
+        else
+            return error.UnknownBoundaryFamily;
+
+        try std.testing.expectEqualSlices(u8, vector.data, ix.data);
+        const encoded = std.mem.readVarInt(u64, ix.data[vector.offset..][0..vector.width], .little);
+        try std.testing.expectEqual(vector.value, encoded);
+    }
+}
+
+test "fee arithmetic matches official fee structure" {
+    const fee_fixture = @import("fee_fixture.zig");
+    var parsed = try fee_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    try std.testing.expectEqual(@as(u64, 5_000), parsed.value.lamports_per_signature);
+    try std.testing.expectEqual(@as(usize, 21), parsed.value.vectors.len);
+
+    for (parsed.value.vectors) |vector| {
+        try std.testing.expectEqual(
+            vector.prioritization_fee,
+            prioritizationFee(vector.compute_unit_limit, vector.compute_unit_price),
+        );
+        try std.testing.expectEqual(vector.signature_fee, vector.num_signatures * vector.lamports_per_signature);
+        try std.testing.expectEqual(vector.total_fee, transactionFee(
+            vector.num_signatures,
+            vector.lamports_per_signature,
+            vector.compute_unit_limit,
+            vector.compute_unit_price,
+        ));
+    }
+}
+
+test "decodeInstruction covers legacy and current official variants" {
+    const instruction_variant_fixture = @import("instruction_variant_fixture.zig");
+    var parsed = try instruction_variant_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    try std.testing.expectEqual(@as(usize, 7), parsed.value.vectors.len);
+
+    for (parsed.value.vectors) |vector| {
+        const decoded = try decodeInstruction(vector.data);
+        try std.testing.expectEqual(vector.discriminant, vector.data[0]);
+        switch (decoded) {
+            .request_units_deprecated => |legacy| {
+                try std.testing.expectEqual(vector.units.?, legacy.units);
+                try std.testing.expectEqual(vector.additional_fee.?, legacy.additional_fee);
+            },
+            .request_heap_frame, .set_compute_unit_limit, .set_loaded_accounts_data_size_limit => |value| {
+                try std.testing.expectEqual(vector.value.?, value);
+            },
+            .set_compute_unit_price => |value| try std.testing.expectEqual(vector.value.?, value),
+        }
+        try std.testing.expectEqual(vector.discriminant, @intFromEnum(std.meta.activeTag(decoded)));
+    }
+
+    try std.testing.expectError(error.InvalidInstructionData, decodeInstruction(&.{}));
+    try std.testing.expectError(error.InvalidInstructionData, decodeInstruction(&.{ 0, 1, 2, 3, 4 }));
+    try std.testing.expectError(error.InvalidInstructionData, decodeInstruction(&.{ 2, 0, 0, 0, 0, 0 }));
+    try std.testing.expectError(error.UnknownInstructionTag, decodeInstruction(&.{5}));
+}
+
 test "public surface guards" {
     try std.testing.expect(@hasDecl(@This(), "PROGRAM_ID"));
     try std.testing.expect(@hasDecl(@This(), "setComputeUnitLimit"));
     try std.testing.expect(@hasDecl(@This(), "setComputeUnitPrice"));
+    try std.testing.expect(@hasDecl(@This(), "decodeInstruction"));
+    try std.testing.expect(@hasDecl(@This(), "prioritizationFee"));
+    try std.testing.expect(@hasDecl(@This(), "transactionFee"));
     try std.testing.expect(!@hasDecl(@This(), "rpc"));
     try std.testing.expect(!@hasDecl(@This(), "wallet"));
 }
@@ -0,0 +1,149 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_config_program::{create_config_account, get_config_data, ConfigState};
+#[allow(deprecated)]
+use solana_sdk::stake::config::Config as StakeConfig;
+use solana_sdk::{account::ReadableAccount, pubkey::Pubkey};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_config_state_vectors.json");
+
+#[derive(Default, Serialize)]
+struct TestState {
+    value: u32,
+    enabled: bool,
+}
+
+impl ConfigState for TestState {
+    fn max_space() -> u64 {
+        5
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ConfigKeyVector {
+    pubkey: [u8; 32],
+    is_signer: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct StakeConfigFields {
+    warmup_cooldown_rate: f64,
+    slash_penalty: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ConfigStateTestVector {
+    name: String,
+    keys: Vec<ConfigKeyVector>,
+    /// Offset of the payload: the bincode `ConfigKeys` short-vec length.
+    payload_offset: usize,
+    payload: Vec<u8>,
+    /// Account data as `create_config_account` lays it out.
+    data: Vec<u8>,
+    /// Decoded payload for the stake config vector.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stake_config: Option<StakeConfigFields>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<ConfigStateTestVector>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+fn vector<T: ConfigState>(
+    name: &str,
+    keys: Vec<(Pubkey, bool)>,
+    config_data: &T,
+) -> ConfigStateTestVector {
+    let account = create_config_account(keys.clone(), config_data, 1);
+    let data = account.data().to_vec();
+    let payload = get_config_data(&data).unwrap().to_vec();
+    ConfigStateTestVector {
+        name: name.to_string(),
+        keys: keys
+            .into_iter()
+            .map(|(pubkey, is_signer)| ConfigKeyVector {
+                pubkey: pubkey.to_bytes(),
+                is_signer,
+            })
+            .collect(),
+        payload_offset: data.len() - payload.len(),
+        payload,
+        data,
+        stake_config: None,
+    }
+}
+
+#[allow(deprecated)]
+fn stake_config_vector() -> ConfigStateTestVector {
+    // Genesis creates the stake config account with no keys.
+    let config = StakeConfig::default();
+    ConfigStateTestVector {
+        stake_config: Some(StakeConfigFields {
+            warmup_cooldown_rate: config.warmup_cooldown_rate,
+            slash_penalty: config.slash_penalty,
+        }),
+        ..vector("stake_config", Vec::new(), &config)
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let state = TestState {
+        value: 0x5566_7788,
+        enabled: true,
+    };
+    Fixture {
+        vectors: vec![
+            vector("zero_keys", Vec::new(), &state),
+            vector("one_signer", vec![(key(1), true)], &state),
+            vector(
+                "mixed_signers",
+                vec![(key(1), true), (key(2), false), (key(3), true)],
+                &state,
+            ),
+            stake_config_vector(),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_config_state_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_config_state_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn data_is_short_vec_keys_followed_by_payload() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let data = &vector.data;
+        // Fewer than 128 keys: the short-vec length is one byte.
+        assert_eq!(usize::from(data[0]), vector.keys.len(), "{}", vector.name);
+        assert_eq!(vector.payload_offset, 1 + vector.keys.len() * 33);
+        for (i, key) in vector.keys.iter().enumerate() {
+            let entry = &data[1 + i * 33..][..33];
+            assert_eq!(entry[..32], key.pubkey);
+            assert_eq!(entry[32], u8::from(key.is_signer));
+        }
+        assert_eq!(data[vector.payload_offset..], vector.payload);
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_config_state_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,33 @@
+const std = @import("std");
+
+pub const ConfigKeyVector = struct {
+    pubkey: [32]u8,
+    is_signer: bool,
+};
+
+pub const StakeConfigFields = struct {
+    warmup_cooldown_rate: f64,
+    slash_penalty: u8,
+};
+
+pub const ConfigStateVector = struct {
+    name: []const u8,
+    keys: []const ConfigKeyVector,
+    payload_offset: usize,
+    payload: []const u8,
+    data: []const u8,
+    stake_config: ?StakeConfigFields = null,
+};
+
+pub const Fixture = struct {
+    vectors: []const ConfigStateVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_config_state_vectors.json"),
+        .{},
+    );
+}
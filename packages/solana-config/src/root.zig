@@ -211,6 +211,38 @@ test "initializeRaw writes empty keys and config signer" {
     try std.testing.expectEqualSlices(u8, &.{ 0, 0, 0, 0, 0 }, ix.data);
 }
 
+test "parseConfigState and writeStoreData match official config account data" {
+    const config_state_fixture = @import("config_state_fixture.zig");
+    var parsed = try config_state_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqual(@as(usize, 4), fixture.vectors.len);
+
+    for (fixture.vectors) |vector| {
+        var keys_out: [3]Key = undefined;
+        const view = try parseConfigState(vector.data, &keys_out);
+        try std.testing.expectEqual(vector.keys.len, view.keys.len);
+        for (vector.keys, view.keys) |expected, actual| {
+            try std.testing.expectEqualSlices(u8, &expected.pubkey, &actual.pubkey);
+            try std.testing.expectEqual(expected.is_signer, actual.is_signer);
+        }
+        try std.testing.expectEqualSlices(u8, vector.payload, view.state_data);
+        try std.testing.expectEqual(vector.payload_offset, try serializedKeysLen(view.keys));
+
+        var data: [128]u8 = undefined;
+        const written = try writeStoreData(view.keys, vector.payload, &data);
+        try std.testing.expectEqualSlices(u8, vector.data, written);
+
+        if (vector.stake_config) |stake_config| {
+            const payload = view.state_data;
+            try std.testing.expectEqual(@as(usize, 9), payload.len);
+            const rate: f64 = @bitCast(std.mem.readInt(u64, payload[0..8], .little));
+            try std.testing.expectEqual(stake_config.warmup_cooldown_rate, rate);
+            try std.testing.expectEqual(stake_config.slash_penalty, payload[8]);
+        }
+    }
+}
+
 test "public surface guards" {
     try std.testing.expect(@hasDecl(@This(), "storeRaw"));
     try std.testing.expect(@hasDecl(@This(), "initializeRaw"));
@@ -0,0 +1,142 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use solana_keypair::Keypair;
+use solana_signature::{ParseSignatureError, Signature};
+use solana_signer::Signer;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_signature_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct SignatureCase {
+    label: String,
+    seed: [u8; 32],
+    public_key: [u8; 32],
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    hex: String,
+    r: [u8; 32],
+    s: [u8; 32],
+    base58: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct MalformedBase58 {
+    label: String,
+    base58: String,
+    error: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    signatures: Vec<SignatureCase>,
+    malformed_base58: Vec<MalformedBase58>,
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn signed(label: &str, seed: [u8; 32], message: &[u8]) -> SignatureCase {
+    let keypair = Keypair::new_from_array(seed);
+    let signature = keypair.sign_message(message);
+    let bytes = signature.as_ref();
+
+    SignatureCase {
+        label: label.to_string(),
+        seed,
+        public_key: keypair.pubkey().to_bytes(),
+        message: message.to_vec(),
+        signature: bytes.to_vec(),
+        hex: hex(bytes),
+        r: bytes[..32].try_into().unwrap(),
+        s: bytes[32..].try_into().unwrap(),
+        base58: signature.to_string(),
+    }
+}
+
+fn malformed(label: &str, base58: &str, error: ParseSignatureError) -> MalformedBase58 {
+    MalformedBase58 {
+        label: label.to_string(),
+        base58: base58.to_string(),
+        error: format!("{error:?}"),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        signatures: vec![
+            signed("seed-1-hello", [1; 32], b"hello solana"),
+            signed("seed-2-empty-message", [2; 32], b""),
+            signed("seed-7-all-bytes", [7; 32], &(0..=255).collect::<Vec<u8>>()),
+        ],
+        malformed_base58: vec![
+            // A 32-byte pubkey string decodes to too few bytes.
+            malformed(
+                "pubkey-length",
+                "11111111111111111111111111111111",
+                ParseSignatureError::WrongSize,
+            ),
+            malformed("empty", "", ParseSignatureError::WrongSize),
+            malformed("too-long", &"2".repeat(89), ParseSignatureError::WrongSize),
+            malformed(
+                "invalid-alphabet",
+                &"0".repeat(88),
+                ParseSignatureError::Invalid,
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_signatures() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_signature_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn signature_base58_round_trips_through_from_str() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+
+    for case in &fixture.signatures {
+        let parsed = Signature::from_str(&case.base58).unwrap();
+        assert_eq!(parsed.as_ref(), case.signature.as_slice(), "{}", case.label);
+        assert_eq!(parsed.to_string(), case.base58, "{}", case.label);
+        assert!(
+            parsed.verify(&case.public_key, &case.message),
+            "{}",
+            case.label
+        );
+    }
+
+    for edge in [[0u8; 64], [0xff; 64]] {
+        let signature = Signature::from(edge);
+        assert_eq!(Signature::from_str(&signature.to_string()), Ok(signature));
+    }
+}
+
+#[test]
+fn malformed_base58_is_rejected() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+
+    for case in &fixture.malformed_base58 {
+        let error = Signature::from_str(&case.base58).unwrap_err();
+        assert_eq!(format!("{error:?}"), case.error, "{}", case.label);
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_signature_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -101,6 +101,28 @@ test "Keypair round-trips through Solana-style 64-byte secret key" {
     try std.testing.expectEqualSlices(u8, &from_seed.seed(), &recovered.seed());
 }
 
+test "Keypair signatures match official Rust signatures and R || S split" {
+    const signature_fixture = @import("signature_fixture.zig");
+    var parsed = try signature_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    try std.testing.expectEqual(@as(usize, 3), parsed.value.signatures.len);
+
+    for (parsed.value.signatures) |case| {
+        const kp = try Keypair.fromSeed(case.seed);
+        const pubkey = kp.publicKey();
+        try std.testing.expectEqualSlices(u8, &case.public_key, &pubkey);
+
+        const sig = try kp.sign(case.message);
+        try std.testing.expectEqualSlices(u8, case.signature, &sig);
+        try std.testing.expectEqualSlices(u8, &case.r, sig[0..32]);
+        try std.testing.expectEqualSlices(u8, &case.s, sig[32..]);
+
+        const hex = std.fmt.bytesToHex(sig, .lower);
+        try std.testing.expectEqualStrings(case.hex, &hex);
+        try verify(sig, case.message, &pubkey);
+    }
+}
+
 test "@import(\"solana_keypair\") exposes signing only" {
     try std.testing.expect(@hasDecl(@This(), "Keypair"));
     try std.testing.expect(@hasDecl(@This(), "verify"));
@@ -0,0 +1,33 @@
+const std = @import("std");
+
+pub const SignatureCase = struct {
+    label: []const u8,
+    seed: [32]u8,
+    public_key: [32]u8,
+    message: []const u8,
+    signature: []const u8,
+    hex: []const u8,
+    r: [32]u8,
+    s: [32]u8,
+    base58: []const u8,
+};
+
+pub const MalformedBase58 = struct {
+    label: []const u8,
+    base58: []const u8,
+    @"error": []const u8,
+};
+
+pub const Fixture = struct {
+    signatures: []const SignatureCase,
+    malformed_base58: []const MalformedBase58,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_signature_vectors.json"),
+        .{},
+    );
+}
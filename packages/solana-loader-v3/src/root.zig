@@ -704,3 +704,24 @@ test "composite helpers pair system and loader instructions" {
     try std.testing.expectEqual(@as(u64, PROGRAM_STATE_SIZE), std.mem.readInt(u64, deploy.createProgram().data[12..20], .little));
     try std.testing.expectEqualSlices(u8, &.{ 2, 0, 0, 0 }, deploy.deploy().data[0..4]);
 }
+
+test "integer boundary sweep matches official Rust encodings" {
+    const boundary_fixture = @import("boundary_fixture.zig");
+    var parsed = try boundary_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    try std.testing.expectEqual(@as(usize, 6), parsed.value.vectors.len);
+
+    const buffer: Pubkey = .{1} ** 32;
+    const authority: Pubkey = .{2} ** 32;
+
+    for (parsed.value.vectors) |vector| {
+        try std.testing.expectEqualStrings("loader_v3_write", vector.family);
+
+        var metas: [2]AccountMeta = undefined;
+        var data: [WRITE_DATA_OVERHEAD + 2]u8 = undefined;
+        const ix = try write(&buffer, &authority, @intCast(vector.value), &.{ 0xaa, 0xbb }, &metas, &data);
+
+        try std.testing.expectEqualSlices(u8, vector.data, ix.data);
+        try std.testing.expectEqual(vector.value, std.mem.readInt(u32, ix.data[vector.offset..][0..4], .little));
+    }
+}
@@ -0,0 +1,114 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_instruction::Instruction;
+use solana_loader_v4_interface::instruction;
+use solana_message::Message;
+use solana_packet::PACKET_DATA_SIZE;
+use solana_pubkey::Pubkey;
+use solana_transaction::Transaction;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_boundary_vectors.json");
+
+const U32_BOUNDARIES: [u32; 6] = [0, 1, (1 << 31) - 1, 1 << 31, u32::MAX - 1, u32::MAX];
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct BoundaryVector {
+    name: String,
+    family: String,
+    field: String,
+    value: u64,
+    offset: usize,
+    width: usize,
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<BoundaryVector>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+fn sweep_u32(
+    family: &str,
+    field: &str,
+    offset: usize,
+    build: impl Fn(u32) -> Vec<u8>,
+) -> Vec<BoundaryVector> {
+    U32_BOUNDARIES
+        .into_iter()
+        .map(|value| BoundaryVector {
+            name: format!("{family}_{field}_boundary_{value}"),
+            family: family.to_string(),
+            field: field.to_string(),
+            value: u64::from(value),
+            offset,
+            width: 4,
+            data: build(value),
+        })
+        .collect()
+}
+
+/// The official `write` of two bytes at `offset`, signed by `key(2)`.
+fn write_instruction(offset: u32) -> Instruction {
+    instruction::write(&key(1), &key(2), offset, vec![0xaa, 0xbb])
+}
+
+fn official_fixture() -> Fixture {
+    let vectors = sweep_u32("loader_v4_write", "offset", 4, |offset| {
+        write_instruction(offset).data
+    });
+    Fixture { vectors }
+}
+
+#[test]
+fn fixture_matches_official_boundary_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_boundary_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn boundary_values_are_encoded_little_endian_at_their_offset() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+
+    for vector in &fixture.vectors {
+        let end = vector.offset + vector.width;
+        assert_eq!(
+            vector.data[vector.offset..end],
+            vector.value.to_le_bytes()[..vector.width],
+            "{}",
+            vector.name
+        );
+    }
+}
+
+#[test]
+fn write_transactions_fit_in_a_packet() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let ix = Instruction {
+            data: vector.data.clone(),
+            ..write_instruction(0)
+        };
+        let transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&key(2))));
+        let len = bincode::serialized_size(&transaction).unwrap() as usize;
+        assert!(len <= PACKET_DATA_SIZE, "{}: {len} bytes", vector.name);
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_boundary_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,24 @@
+const std = @import("std");
+
+pub const BoundaryVector = struct {
+    name: []const u8,
+    family: []const u8,
+    field: []const u8,
+    value: u64,
+    offset: usize,
+    width: usize,
+    data: []const u8,
+};
+
+pub const Fixture = struct {
+    vectors: []const BoundaryVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_boundary_vectors.json"),
+        .{},
+    );
+}
@@ -371,3 +371,24 @@ test "createBuffer pairs system create account with initial length instruction"
     try std.testing.expect(isSetProgramLengthInstruction(instructions.setProgramLength().data));
     try std.testing.expectEqual(@as(u32, 123), std.mem.readInt(u32, instructions.setProgramLength().data[4..8], .little));
 }
+
+test "integer boundary sweep matches official Rust encodings" {
+    const boundary_fixture = @import("boundary_fixture.zig");
+    var parsed = try boundary_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    try std.testing.expectEqual(@as(usize, 6), parsed.value.vectors.len);
+
+    const program: Pubkey = .{1} ** 32;
+    const authority: Pubkey = .{2} ** 32;
+
+    for (parsed.value.vectors) |vector| {
+        try std.testing.expectEqualStrings("loader_v4_write", vector.family);
+
+        var metas: [2]AccountMeta = undefined;
+        var data: [WRITE_DATA_OVERHEAD + 2]u8 = undefined;
+        const ix = try write(&program, &authority, @intCast(vector.value), &.{ 0xaa, 0xbb }, &metas, &data);
+
+        try std.testing.expectEqualSlices(u8, vector.data, ix.data);
+        try std.testing.expectEqual(vector.value, std.mem.readInt(u32, ix.data[vector.offset..][0..4], .little));
+    }
+}
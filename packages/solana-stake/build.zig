@@ -29,6 +29,8 @@ pub fn build(b: *std.Build) void {
     });
 
     const tests = b.addTest(.{ .root_module = stake_mod });
+    tests.root_module.addImport("rent_vectors", sol_dep.module("rent_vectors"));
+    tests.root_module.addImport("vector_filter", sol_dep.module("vector_filter"));
     const run_tests = b.addRunArtifact(tests);
     const test_step = b.step("test", "Run host-side unit tests");
     test_step.dependOn(&run_tests.step);
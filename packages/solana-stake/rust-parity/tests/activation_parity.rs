@@ -0,0 +1,229 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_stake_interface::{
+    stake_history::{StakeHistory, StakeHistoryEntry},
+    state::Delegation,
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_stake_activation_vectors.json");
+
+const BOOTSTRAP_STAKE: u64 = 100_000;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct DelegationFields {
+    stake: u64,
+    activation_epoch: u64,
+    deactivation_epoch: u64,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct StakeHistoryEntryFields {
+    epoch: u64,
+    effective: u64,
+    activating: u64,
+    deactivating: u64,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct StakeActivationStatusFields {
+    effective: u64,
+    activating: u64,
+    deactivating: u64,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct StakeActivationTestVector {
+    name: String,
+    delegation: DelegationFields,
+    target_epoch: u64,
+    new_rate_activation_epoch: Option<u64>,
+    /// Cluster totals for every epoch before `target_epoch`, newest first
+    /// like the `StakeHistory` sysvar.
+    stake_history: Vec<StakeHistoryEntryFields>,
+    /// `Delegation::stake_activating_and_deactivating`.
+    status: StakeActivationStatusFields,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    /// The always-effective stake every cluster history starts from.
+    bootstrap_stake: u64,
+    vectors: Vec<StakeActivationTestVector>,
+}
+
+fn delegation(stake: u64, activation_epoch: u64, deactivation_epoch: u64) -> Delegation {
+    Delegation {
+        deactivation_epoch,
+        ..Delegation::new(&Default::default(), stake, activation_epoch)
+    }
+}
+
+/// The history a cluster of a bootstrap stake plus `subject` builds up to,
+/// but not including, `target_epoch`.
+fn cluster_history(
+    subject: &Delegation,
+    target_epoch: u64,
+    new_rate_activation_epoch: Option<u64>,
+) -> StakeHistory {
+    let bootstrap = delegation(BOOTSTRAP_STAKE, u64::MAX, u64::MAX);
+    let mut history = StakeHistory::default();
+    for epoch in 0..target_epoch {
+        let entry = [&bootstrap, subject]
+            .iter()
+            .map(|delegation| {
+                delegation.stake_activating_and_deactivating(
+                    epoch,
+                    &history,
+                    new_rate_activation_epoch,
+                )
+            })
+            .fold(StakeHistoryEntry::default(), |sum, entry| sum + entry);
+        history.add(epoch, entry);
+    }
+    history
+}
+
+fn vector(
+    name: &str,
+    subject: Delegation,
+    target_epoch: u64,
+    new_rate_activation_epoch: Option<u64>,
+) -> StakeActivationTestVector {
+    let history = cluster_history(&subject, target_epoch, new_rate_activation_epoch);
+    let status = subject.stake_activating_and_deactivating(
+        target_epoch,
+        &history,
+        new_rate_activation_epoch,
+    );
+    StakeActivationTestVector {
+        name: name.to_string(),
+        delegation: DelegationFields {
+            stake: subject.stake,
+            activation_epoch: subject.activation_epoch,
+            deactivation_epoch: subject.deactivation_epoch,
+        },
+        target_epoch,
+        new_rate_activation_epoch,
+        stake_history: history
+            .iter()
+            .map(|(epoch, entry)| StakeHistoryEntryFields {
+                epoch: *epoch,
+                effective: entry.effective,
+                activating: entry.activating,
+                deactivating: entry.deactivating,
+            })
+            .collect(),
+        status: StakeActivationStatusFields {
+            effective: status.effective,
+            activating: status.activating,
+            deactivating: status.deactivating,
+        },
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let small = delegation(BOOTSTRAP_STAKE / 10, 1, u64::MAX);
+    let large = delegation(BOOTSTRAP_STAKE * 10, 1, u64::MAX);
+    let large_deactivated = delegation(BOOTSTRAP_STAKE * 10, 1, 15);
+    let same_epoch = delegation(BOOTSTRAP_STAKE, 3, 3);
+    Fixture {
+        bootstrap_stake: BOOTSTRAP_STAKE,
+        vectors: vec![
+            vector("before_activation", large, 0, None),
+            vector("all_activating_at_activation_epoch", large, 1, None),
+            vector("fully_active", small, 3, None),
+            vector("mid_warmup", large, 4, None),
+            vector("mid_warmup_new_rate", large, 4, Some(0)),
+            vector(
+                "all_deactivating_at_deactivation_epoch",
+                large_deactivated,
+                15,
+                None,
+            ),
+            vector("mid_cooldown", large_deactivated, 17, None),
+            vector("mid_cooldown_new_rate", large_deactivated, 17, Some(16)),
+            vector("fully_inactive", large_deactivated, 40, None),
+            vector("activated_and_deactivated_same_epoch", same_epoch, 3, None),
+            vector(
+                "activated_and_deactivated_same_epoch_later",
+                same_epoch,
+                6,
+                None,
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_stake_activation_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_stake_activation_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn vectors_cover_every_activation_phase() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let status = |name: &str| {
+        let vector = fixture
+            .vectors
+            .iter()
+            .find(|vector| vector.name == name)
+            .unwrap();
+        let status = &vector.status;
+        (
+            vector.delegation.stake,
+            status.effective,
+            status.activating,
+            status.deactivating,
+        )
+    };
+
+    let (stake, effective, activating, deactivating) = status("all_activating_at_activation_epoch");
+    assert_eq!((effective, activating, deactivating), (0, stake, 0));
+    let (stake, effective, activating, deactivating) = status("fully_active");
+    assert_eq!((effective, activating, deactivating), (stake, 0, 0));
+    for name in ["mid_warmup", "mid_warmup_new_rate"] {
+        let (stake, effective, activating, deactivating) = status(name);
+        assert!(0 < effective && effective < stake, "{name}");
+        assert_eq!((effective + activating, deactivating), (stake, 0), "{name}");
+    }
+    let (_, old_rate, _, _) = status("mid_warmup");
+    let (_, new_rate, _, _) = status("mid_warmup_new_rate");
+    assert!(new_rate < old_rate);
+
+    let (stake, effective, activating, deactivating) =
+        status("all_deactivating_at_deactivation_epoch");
+    assert_eq!((effective, activating, deactivating), (stake, 0, stake));
+    for name in ["mid_cooldown", "mid_cooldown_new_rate"] {
+        let (stake, effective, activating, deactivating) = status(name);
+        assert!(0 < effective && effective < stake, "{name}");
+        assert_eq!((activating, deactivating), (0, effective), "{name}");
+    }
+    let (_, old_rate, _, _) = status("mid_cooldown");
+    let (_, new_rate, _, _) = status("mid_cooldown_new_rate");
+    assert!(new_rate > old_rate);
+    for name in [
+        "before_activation",
+        "fully_inactive",
+        "activated_and_deactivated_same_epoch",
+        "activated_and_deactivated_same_epoch_later",
+    ] {
+        let (_, effective, activating, deactivating) = status(name);
+        assert_eq!((effective, activating, deactivating), (0, 0, 0), "{name}");
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_stake_activation_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,285 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+use solana_stake_interface::{
+    instruction::{self as stake_instruction, StakeInstruction},
+    state::StakeAuthorize,
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_stake_authorize_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AccountFixture {
+    pubkey: [u8; 32],
+    is_signer: u8,
+    is_writable: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct StakeAuthorizeTestVector {
+    name: String,
+    /// snake_case `StakeInstruction` variant.
+    variant: String,
+    /// `u32` bincode variant index of `StakeInstruction`.
+    discriminant: u32,
+    /// `u32` bincode variant index of `StakeAuthorize`.
+    stake_authorize: u32,
+    stake: [u8; 32],
+    /// The current authority, or the seed base for the seeded variants.
+    authority: [u8; 32],
+    /// Carried in the data for the unchecked variants and as a signer
+    /// account for the checked ones.
+    new_authorized: [u8; 32],
+    authority_seed: Option<String>,
+    authority_owner: Option<[u8; 32]>,
+    custodian: Option<[u8; 32]>,
+    accounts: Vec<AccountFixture>,
+    /// bincode `StakeInstruction`.
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<StakeAuthorizeTestVector>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+struct Case<'a> {
+    name: &'a str,
+    stake_authorize: StakeAuthorize,
+    /// `Some` for the seeded variants.
+    seed: Option<&'a str>,
+    custodian: Option<Pubkey>,
+}
+
+const STAKE: u8 = 1;
+const AUTHORITY: u8 = 2;
+const OWNER: u8 = 3;
+const NEW_AUTHORIZED: u8 = 4;
+const CUSTODIAN: u8 = 5;
+
+fn vector(variant: &str, case: &Case, ix: Instruction) -> StakeAuthorizeTestVector {
+    assert_eq!(ix.program_id, solana_stake_interface::program::ID);
+    StakeAuthorizeTestVector {
+        name: format!("{variant}_{}", case.name),
+        variant: variant.to_string(),
+        discriminant: u32::from_le_bytes(ix.data[..4].try_into().unwrap()),
+        stake_authorize: case.stake_authorize as u32,
+        stake: key(STAKE).to_bytes(),
+        authority: key(AUTHORITY).to_bytes(),
+        new_authorized: key(NEW_AUTHORIZED).to_bytes(),
+        authority_seed: case.seed.map(str::to_string),
+        authority_owner: case.seed.map(|_| key(OWNER).to_bytes()),
+        custodian: case.custodian.map(|custodian| custodian.to_bytes()),
+        accounts: ix
+            .accounts
+            .iter()
+            .map(|meta| AccountFixture {
+                pubkey: meta.pubkey.to_bytes(),
+                is_signer: u8::from(meta.is_signer),
+                is_writable: u8::from(meta.is_writable),
+            })
+            .collect(),
+        data: ix.data,
+    }
+}
+
+fn unseeded(case: &Case) -> [StakeAuthorizeTestVector; 2] {
+    let custodian = case.custodian.as_ref();
+    [
+        vector(
+            "authorize",
+            case,
+            stake_instruction::authorize(
+                &key(STAKE),
+                &key(AUTHORITY),
+                &key(NEW_AUTHORIZED),
+                case.stake_authorize,
+                custodian,
+            ),
+        ),
+        vector(
+            "authorize_checked",
+            case,
+            stake_instruction::authorize_checked(
+                &key(STAKE),
+                &key(AUTHORITY),
+                &key(NEW_AUTHORIZED),
+                case.stake_authorize,
+                custodian,
+            ),
+        ),
+    ]
+}
+
+fn seeded(case: &Case) -> [StakeAuthorizeTestVector; 2] {
+    let seed = case.seed.unwrap();
+    let custodian = case.custodian.as_ref();
+    [
+        vector(
+            "authorize_with_seed",
+            case,
+            stake_instruction::authorize_with_seed(
+                &key(STAKE),
+                &key(AUTHORITY),
+                seed.to_string(),
+                &key(OWNER),
+                &key(NEW_AUTHORIZED),
+                case.stake_authorize,
+                custodian,
+            ),
+        ),
+        vector(
+            "authorize_checked_with_seed",
+            case,
+            stake_instruction::authorize_checked_with_seed(
+                &key(STAKE),
+                &key(AUTHORITY),
+                seed.to_string(),
+                &key(OWNER),
+                &key(NEW_AUTHORIZED),
+                case.stake_authorize,
+                custodian,
+            ),
+        ),
+    ]
+}
+
+fn official_fixture() -> Fixture {
+    let custodian = Some(key(CUSTODIAN));
+    let unseeded_cases = [
+        Case {
+            name: "staker",
+            stake_authorize: StakeAuthorize::Staker,
+            seed: None,
+            custodian: None,
+        },
+        Case {
+            name: "withdrawer_with_custodian",
+            stake_authorize: StakeAuthorize::Withdrawer,
+            seed: None,
+            custodian,
+        },
+    ];
+    let seeded_cases = [
+        Case {
+            name: "staker_empty_seed",
+            stake_authorize: StakeAuthorize::Staker,
+            seed: Some(""),
+            custodian: None,
+        },
+        Case {
+            name: "withdrawer_with_custodian",
+            stake_authorize: StakeAuthorize::Withdrawer,
+            seed: Some("stake:0"),
+            custodian,
+        },
+        Case {
+            name: "staker_max_seed",
+            stake_authorize: StakeAuthorize::Staker,
+            seed: Some("abcdefghijklmnopqrstuvwxyz012345"),
+            custodian: None,
+        },
+        Case {
+            name: "withdrawer_utf8_seed",
+            stake_authorize: StakeAuthorize::Withdrawer,
+            seed: Some("stake-\u{e9}t\u{e9}-\u{2713}"),
+            custodian: None,
+        },
+    ];
+    Fixture {
+        vectors: unseeded_cases
+            .iter()
+            .flat_map(unseeded)
+            .chain(seeded_cases.iter().flat_map(seeded))
+            .collect(),
+    }
+}
+
+#[test]
+fn fixture_matches_official_stake_authorize_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_stake_authorize_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn data_decodes_to_the_recorded_fields() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let stake_authorize = |value: u32| match value {
+        0 => StakeAuthorize::Staker,
+        1 => StakeAuthorize::Withdrawer,
+        _ => panic!("unknown StakeAuthorize {value}"),
+    };
+    for vector in &fixture.vectors {
+        let instruction: StakeInstruction = bincode::deserialize(&vector.data).unwrap();
+        let authorize = stake_authorize(vector.stake_authorize);
+        let new_authorized = Pubkey::from(vector.new_authorized);
+        let seed = vector.authority_seed.clone();
+        let owner = vector.authority_owner.map(Pubkey::from);
+        match instruction {
+            StakeInstruction::Authorize(pubkey, kind) => {
+                assert_eq!(
+                    (vector.discriminant, vector.variant.as_str()),
+                    (1, "authorize")
+                );
+                assert_eq!((pubkey, kind), (new_authorized, authorize));
+            }
+            StakeInstruction::AuthorizeWithSeed(args) => {
+                assert_eq!(
+                    (vector.discriminant, vector.variant.as_str()),
+                    (8, "authorize_with_seed")
+                );
+                assert_eq!(args.new_authorized_pubkey, new_authorized);
+                assert_eq!(args.stake_authorize, authorize);
+                assert_eq!(Some(args.authority_seed), seed);
+                assert_eq!(Some(args.authority_owner), owner);
+            }
+            StakeInstruction::AuthorizeChecked(kind) => {
+                assert_eq!(
+                    (vector.discriminant, vector.variant.as_str()),
+                    (10, "authorize_checked")
+                );
+                assert_eq!(kind, authorize);
+                assert_eq!(vector.accounts[3].pubkey, vector.new_authorized);
+            }
+            StakeInstruction::AuthorizeCheckedWithSeed(args) => {
+                assert_eq!(
+                    (vector.discriminant, vector.variant.as_str()),
+                    (11, "authorize_checked_with_seed")
+                );
+                assert_eq!(args.stake_authorize, authorize);
+                assert_eq!(Some(args.authority_seed), seed);
+                assert_eq!(Some(args.authority_owner), owner);
+                assert_eq!(vector.accounts[3].pubkey, vector.new_authorized);
+            }
+            instruction => panic!("{}: unexpected {instruction:?}", vector.name),
+        }
+        if let Some(seed) = &vector.authority_seed {
+            assert!(seed.len() <= 32, "{}", vector.name);
+        }
+        let custodian = vector.accounts.last().unwrap();
+        assert_eq!(
+            vector.custodian,
+            (custodian.pubkey == [CUSTODIAN; 32]).then_some(custodian.pubkey)
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_stake_authorize_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,108 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_instruction_error::InstructionError;
+use solana_program_error::ProgramError;
+use solana_stake_interface::error::StakeError;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_stake_error_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct StakeErrorTestVector {
+    /// `StakeError::<Debug>`.
+    name: String,
+    /// `StakeError::X as u32`, the `InstructionError::Custom` payload.
+    code: u32,
+    /// `Display`.
+    message: String,
+    /// bincode `InstructionError::Custom(code)`.
+    instruction_error: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    /// `u32` bincode variant index of `InstructionError::Custom`.
+    custom_variant_index: u32,
+    vectors: Vec<StakeErrorTestVector>,
+}
+
+/// Every variant, found by decoding each bincode `u32` tag until one fails.
+fn variants() -> Vec<StakeError> {
+    (0u32..)
+        .map_while(|tag| bincode::deserialize(&tag.to_le_bytes()).ok())
+        .collect()
+}
+
+fn vector(err: StakeError) -> StakeErrorTestVector {
+    let code = err.clone() as u32;
+    StakeErrorTestVector {
+        name: format!("{err:?}"),
+        code,
+        message: err.to_string(),
+        instruction_error: bincode::serialize(&InstructionError::Custom(code)).unwrap(),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let custom = bincode::serialize(&InstructionError::Custom(0)).unwrap();
+    Fixture {
+        custom_variant_index: u32::from_le_bytes(custom[..4].try_into().unwrap()),
+        vectors: variants().into_iter().map(vector).collect(),
+    }
+}
+
+#[test]
+fn fixture_matches_official_stake_error_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_stake_error_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn codes_round_trip_through_program_and_instruction_errors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    assert_eq!(fixture.vectors.len(), 17);
+    for (vector, err) in fixture.vectors.iter().zip(variants()) {
+        assert_eq!(
+            ProgramError::from(err),
+            ProgramError::Custom(vector.code),
+            "{}",
+            vector.name
+        );
+        let instruction_error: InstructionError =
+            bincode::deserialize(&vector.instruction_error).unwrap();
+        assert_eq!(instruction_error, InstructionError::Custom(vector.code));
+        assert_eq!(
+            vector.instruction_error[..4],
+            fixture.custom_variant_index.to_le_bytes()
+        );
+    }
+
+    // The newest variants, whose codes are easy to miscount.
+    let code = |name: &str| {
+        fixture
+            .vectors
+            .iter()
+            .find(|vector| vector.name == name)
+            .unwrap()
+            .code
+    };
+    assert_eq!(
+        code("RedelegatedStakeMustFullyActivateBeforeDeactivationIsPermitted"),
+        15
+    );
+    assert_eq!(code("EpochRewardsActive"), 16);
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_stake_error_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,292 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+use solana_stake_interface::{
+    instruction::{self as stake_instruction, LockupArgs, StakeInstruction},
+    state::{Authorized, Lockup, StakeAuthorize},
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_instruction_accounts.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AccountFixture {
+    pubkey: [u8; 32],
+    is_signer: u8,
+    is_writable: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct InstructionCase {
+    label: String,
+    accounts: Vec<AccountFixture>,
+    data: Vec<u8>,
+    /// First solana-sdk release whose `StakeInstruction` has this variant.
+    /// Left out for variants every supported SDK understands.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    since_sdk: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    cases: Vec<InstructionCase>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+/// `MoveStake` and `MoveLamports` shipped with the 2.1 stake program.
+const MOVE_INSTRUCTIONS_SINCE_SDK: &str = "2.1.0";
+
+/// Parse a `major.minor.patch` SDK version for ordering.
+fn sdk_version(version: &str) -> (u64, u64, u64) {
+    let mut parts = version.split('.').map(|part| part.parse::<u64>().unwrap());
+    let version = (
+        parts.next().unwrap(),
+        parts.next().unwrap(),
+        parts.next().unwrap(),
+    );
+    assert!(parts.next().is_none(), "{version:?}");
+    version
+}
+
+/// Cases a consumer pinned to `sdk` can decode.
+fn cases_up_to<'a>(fixture: &'a Fixture, sdk: &str) -> Vec<&'a InstructionCase> {
+    fixture
+        .cases
+        .iter()
+        .filter(|case| {
+            case.since_sdk
+                .as_deref()
+                .is_none_or(|since| sdk_version(since) <= sdk_version(sdk))
+        })
+        .collect()
+}
+
+/// `StakeInstruction` has no name accessor, so derive the snake_case variant
+/// name from its `Debug` output.
+fn variant_name(data: &[u8]) -> String {
+    let instruction: StakeInstruction = bincode::deserialize(data).unwrap();
+    let debug = format!("{instruction:?}");
+    let mut name = String::new();
+    for ch in debug.chars().take_while(char::is_ascii_alphanumeric) {
+        if ch.is_ascii_uppercase() && !name.is_empty() {
+            name.push('_');
+        }
+        name.push(ch.to_ascii_lowercase());
+    }
+    name
+}
+
+fn case(label: &str, ix: Instruction) -> InstructionCase {
+    assert_eq!(ix.program_id, solana_stake_interface::program::ID);
+    InstructionCase {
+        label: label.to_string(),
+        accounts: ix
+            .accounts
+            .iter()
+            .map(|meta| AccountFixture {
+                pubkey: meta.pubkey.to_bytes(),
+                is_signer: u8::from(meta.is_signer),
+                is_writable: u8::from(meta.is_writable),
+            })
+            .collect(),
+        data: ix.data,
+        since_sdk: None,
+    }
+}
+
+fn case_since(label: &str, ix: Instruction, since_sdk: &str) -> InstructionCase {
+    InstructionCase {
+        since_sdk: Some(since_sdk.to_string()),
+        ..case(label, ix)
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let stake = key(1);
+    let other_stake = key(2);
+    let authority = key(3);
+    let vote = key(4);
+    let to = key(5);
+    let custodian = key(6);
+    let new_authority = key(7);
+    let base = key(8);
+    let owner = key(9);
+    let reference_vote = key(0x0c);
+
+    let authorized = Authorized {
+        staker: key(0x0a),
+        withdrawer: key(0x0b),
+    };
+    let lockup = Lockup {
+        unix_timestamp: -5,
+        epoch: 9,
+        custodian,
+    };
+    let lockup_args = LockupArgs {
+        unix_timestamp: Some(-5),
+        epoch: Some(9),
+        custodian: Some(new_authority),
+    };
+
+    let split = stake_instruction::split(&stake, &authority, 500, &other_stake);
+    let merge = stake_instruction::merge(&stake, &other_stake, &authority);
+
+    Fixture {
+        cases: vec![
+            case(
+                "initialize",
+                stake_instruction::initialize(&stake, &authorized, &lockup),
+            ),
+            case(
+                "authorize",
+                stake_instruction::authorize(
+                    &stake,
+                    &authority,
+                    &new_authority,
+                    StakeAuthorize::Staker,
+                    None,
+                ),
+            ),
+            case(
+                "delegate_stake",
+                stake_instruction::delegate_stake(&stake, &authority, &vote),
+            ),
+            case("split", split.last().unwrap().clone()),
+            case(
+                "withdraw",
+                stake_instruction::withdraw(&stake, &authority, &to, 600, Some(&custodian)),
+            ),
+            case(
+                "deactivate",
+                stake_instruction::deactivate_stake(&stake, &authority),
+            ),
+            case(
+                "set_lockup",
+                stake_instruction::set_lockup(&stake, &lockup_args, &custodian),
+            ),
+            case("merge", merge[0].clone()),
+            case(
+                "authorize_with_seed",
+                stake_instruction::authorize_with_seed(
+                    &stake,
+                    &base,
+                    "stake-seed".to_string(),
+                    &owner,
+                    &new_authority,
+                    StakeAuthorize::Withdrawer,
+                    Some(&custodian),
+                ),
+            ),
+            case(
+                "initialize_checked",
+                stake_instruction::initialize_checked(&stake, &authorized),
+            ),
+            case(
+                "authorize_checked",
+                stake_instruction::authorize_checked(
+                    &stake,
+                    &authority,
+                    &new_authority,
+                    StakeAuthorize::Withdrawer,
+                    None,
+                ),
+            ),
+            case(
+                "authorize_checked_with_seed",
+                stake_instruction::authorize_checked_with_seed(
+                    &stake,
+                    &base,
+                    "stake-seed".to_string(),
+                    &owner,
+                    &new_authority,
+                    StakeAuthorize::Staker,
+                    None,
+                ),
+            ),
+            case(
+                "set_lockup_checked",
+                stake_instruction::set_lockup_checked(&stake, &lockup_args, &custodian),
+            ),
+            case(
+                "get_minimum_delegation",
+                stake_instruction::get_minimum_delegation(),
+            ),
+            case(
+                "deactivate_delinquent",
+                stake_instruction::deactivate_delinquent_stake(&stake, &vote, &reference_vote),
+            ),
+            case_since(
+                "move_stake",
+                stake_instruction::move_stake(&stake, &other_stake, &authority, 700),
+                MOVE_INSTRUCTIONS_SINCE_SDK,
+            ),
+            case_since(
+                "move_lamports",
+                stake_instruction::move_lamports(&stake, &other_stake, &authority, 800),
+                MOVE_INSTRUCTIONS_SINCE_SDK,
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_instruction_accounts() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_instruction_accounts.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn data_decodes_to_the_labelled_variant() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+
+    for case in &fixture.cases {
+        assert_eq!(variant_name(&case.data), case.label, "{}", case.label);
+    }
+}
+
+#[test]
+fn filtering_by_sdk_version_drops_only_tagged_cases() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let tagged: Vec<&str> = fixture
+        .cases
+        .iter()
+        .filter(|case| case.since_sdk.is_some())
+        .map(|case| case.label.as_str())
+        .collect();
+    assert_eq!(tagged, ["move_stake", "move_lamports"]);
+
+    let labels = |cases: Vec<&InstructionCase>| -> Vec<String> {
+        cases.into_iter().map(|case| case.label.clone()).collect()
+    };
+    let before = labels(cases_up_to(&fixture, "2.0.14"));
+    let untagged: Vec<String> = fixture
+        .cases
+        .iter()
+        .filter(|case| case.since_sdk.is_none())
+        .map(|case| case.label.clone())
+        .collect();
+    assert_eq!(before, untagged);
+
+    for sdk in [MOVE_INSTRUCTIONS_SINCE_SDK, "3.0.0"] {
+        assert_eq!(
+            cases_up_to(&fixture, sdk).len(),
+            fixture.cases.len(),
+            "{sdk}"
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_instruction_accounts() {
+    println!("{}", serde_json::to_string_pretty(&official_fixture()).unwrap());
+}
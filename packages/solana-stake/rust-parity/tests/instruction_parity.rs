@@ -1,10 +1,14 @@
 use solana_instruction::AccountMeta;
 use solana_pubkey::Pubkey;
+use solana_rent::Rent;
 use solana_stake_interface::{
     instruction as stake_instruction,
-    state::{Authorized, Lockup, StakeAuthorize},
+    state::{Authorized, Lockup, StakeAuthorize, StakeStateV2},
 };
 
+const STANDARD_ACCOUNT_MINIMUMS_JSON: &str =
+    include_str!("../../../../src/rent/standard_account_minimums_vectors.json");
+
 fn key(byte: u8) -> Pubkey {
     Pubkey::from([byte; 32])
 }
@@ -238,3 +242,23 @@ fn official_recent_builders_match_zig_layout() {
     );
     assert_meta(&move_lamports.accounts[1], other, false, true);
 }
+
+#[test]
+fn official_stake_state_size_matches_the_standard_minimums_fixture() {
+    // The root SDK's src/rent/standard_account_minimums_vectors.json is
+    // generated from Rent::default(); the "stake" entry is what
+    // root.zig's size constant is checked against.
+    let fixture: serde_json::Value =
+        serde_json::from_str(STANDARD_ACCOUNT_MINIMUMS_JSON).expect("standard minimums fixture");
+    let entry = fixture["vectors"]
+        .as_array()
+        .expect("vectors")
+        .iter()
+        .find(|vector| vector["name"] == "stake")
+        .expect("stake entry");
+    assert_eq!(entry["data_len"], StakeStateV2::size_of() as u64);
+    assert_eq!(
+        entry["minimum_balance"],
+        Rent::default().minimum_balance(StakeStateV2::size_of())
+    );
+}
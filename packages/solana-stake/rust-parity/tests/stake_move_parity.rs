@@ -0,0 +1,493 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use mollusk_svm::Mollusk;
+use serde::{Deserialize, Serialize};
+use solana_account::Account;
+use solana_instruction::Instruction;
+use solana_native_token::LAMPORTS_PER_SOL;
+use solana_pubkey::Pubkey;
+use solana_rent::Rent;
+use solana_stake_interface::{
+    instruction as stake_instruction,
+    stake_flags::StakeFlags,
+    state::{Authorized, Delegation, Meta, Stake, StakeStateV2},
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_stake_move_vectors.json");
+const INSTRUCTION_ACCOUNTS_JSON: &str =
+    include_str!("../../src/official_instruction_accounts.json");
+
+/// `MoveStake` and `MoveLamports` shipped with the 2.1 stake program, as in
+/// `instruction_accounts_parity.rs`.
+const MOVE_INSTRUCTIONS_SINCE_SDK: &str = "2.1.0";
+
+const CURRENT_EPOCH: u64 = 10;
+const DELEGATED: u64 = 10 * LAMPORTS_PER_SOL;
+/// Undelegated lamports above the rent-exempt reserve in every account.
+const FREE: u64 = LAMPORTS_PER_SOL;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AccountFixture {
+    pubkey: [u8; 32],
+    is_signer: u8,
+    is_writable: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct InstructionCase {
+    label: String,
+    accounts: Vec<AccountFixture>,
+    data: Vec<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    since_sdk: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct StakeMoveEligibilityTestVector {
+    name: String,
+    instruction: String,
+    source_state: String,
+    source_state_data: Vec<u8>,
+    source_lamports: u64,
+    destination_state: String,
+    destination_state_data: Vec<u8>,
+    destination_lamports: u64,
+    amount: u64,
+    data: Vec<u8>,
+    expected_ok: bool,
+    /// `InstructionError` debug string; `Custom(n)` is `StakeError` `n`.
+    expected_error: Option<String>,
+    /// First solana-sdk release whose `StakeInstruction` has `instruction`.
+    since_sdk: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    current_epoch: u64,
+    minimum_delegation: u64,
+    rent_exempt_reserve: u64,
+    instructions: Vec<InstructionCase>,
+    vectors: Vec<StakeMoveEligibilityTestVector>,
+}
+
+#[derive(Deserialize)]
+struct InstructionAccountsFixture {
+    cases: Vec<InstructionCase>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+// Same keys as the move cases in `instruction_accounts_parity.rs`.
+fn source() -> Pubkey {
+    key(1)
+}
+
+fn destination() -> Pubkey {
+    key(2)
+}
+
+fn authority() -> Pubkey {
+    key(3)
+}
+
+fn vote() -> Pubkey {
+    key(4)
+}
+
+fn meta(staker: Pubkey) -> Meta {
+    Meta {
+        rent_exempt_reserve: Rent::default().minimum_balance(StakeStateV2::size_of()),
+        authorized: Authorized {
+            staker,
+            withdrawer: key(0x0b),
+        },
+        lockup: Default::default(),
+    }
+}
+
+/// A labelled stake account at `CURRENT_EPOCH` with an empty stake
+/// history. Bootstrap delegations (`activation_epoch == u64::MAX`) are
+/// fully effective without any history.
+fn stake_account(label: &str, staker: Pubkey) -> (StakeStateV2, u64) {
+    let meta = meta(staker);
+    let reserve = meta.rent_exempt_reserve;
+    let delegated = |voter: Pubkey, activation_epoch: u64, deactivation_epoch: u64| {
+        let mut delegation = Delegation::new(&voter, DELEGATED, activation_epoch);
+        delegation.deactivation_epoch = deactivation_epoch;
+        let stake = Stake {
+            delegation,
+            credits_observed: 0,
+        };
+        (
+            StakeStateV2::Stake(meta, stake, StakeFlags::empty()),
+            reserve + DELEGATED + FREE,
+        )
+    };
+    match label {
+        "initialized" => (StakeStateV2::Initialized(meta), reserve + FREE),
+        "fully_active" => delegated(vote(), u64::MAX, u64::MAX),
+        "fully_active_other_vote" => delegated(key(0x44), u64::MAX, u64::MAX),
+        "activating" => delegated(vote(), CURRENT_EPOCH, u64::MAX),
+        "deactivating" => delegated(vote(), u64::MAX, CURRENT_EPOCH),
+        "deactivated" => delegated(vote(), u64::MAX, CURRENT_EPOCH - 5),
+        _ => unreachable!("{label}"),
+    }
+}
+
+fn mollusk() -> Mollusk {
+    let mut mollusk = Mollusk::default();
+    mollusk.sysvars.clock.epoch = CURRENT_EPOCH;
+    mollusk
+}
+
+fn minimum_delegation(mollusk: &Mollusk) -> u64 {
+    let result = mollusk.process_instruction(&stake_instruction::get_minimum_delegation(), &[]);
+    u64::from_le_bytes(result.return_data.try_into().unwrap())
+}
+
+fn instruction_case(label: &str, ix: Instruction) -> InstructionCase {
+    InstructionCase {
+        label: label.to_string(),
+        accounts: ix
+            .accounts
+            .iter()
+            .map(|meta| AccountFixture {
+                pubkey: meta.pubkey.to_bytes(),
+                is_signer: u8::from(meta.is_signer),
+                is_writable: u8::from(meta.is_writable),
+            })
+            .collect(),
+        data: ix.data,
+        since_sdk: Some(MOVE_INSTRUCTIONS_SINCE_SDK.to_string()),
+    }
+}
+
+struct Case<'a> {
+    name: &'a str,
+    instruction: &'a str,
+    source: &'a str,
+    destination: &'a str,
+    amount: u64,
+    /// Destination staker, to break authority equality.
+    destination_staker: Pubkey,
+}
+
+fn run(mollusk: &Mollusk, case: &Case) -> StakeMoveEligibilityTestVector {
+    let (source_state, source_lamports) = stake_account(case.source, authority());
+    let (destination_state, destination_lamports) =
+        stake_account(case.destination, case.destination_staker);
+    let ix = match case.instruction {
+        "move_stake" => {
+            stake_instruction::move_stake(&source(), &destination(), &authority(), case.amount)
+        }
+        "move_lamports" => {
+            stake_instruction::move_lamports(&source(), &destination(), &authority(), case.amount)
+        }
+        _ => unreachable!(),
+    };
+
+    let account = |state: &StakeStateV2, lamports: u64| {
+        let mut account = Account::new(
+            lamports,
+            StakeStateV2::size_of(),
+            &solana_stake_interface::program::ID,
+        );
+        bincode::serialize_into(&mut account.data[..], state).unwrap();
+        account
+    };
+    let source_account = account(&source_state, source_lamports);
+    let destination_account = account(&destination_state, destination_lamports);
+    let result = mollusk.process_instruction(
+        &ix,
+        &[
+            (source(), source_account.clone()),
+            (destination(), destination_account.clone()),
+            (authority(), Account::default()),
+        ],
+    );
+
+    StakeMoveEligibilityTestVector {
+        name: case.name.to_string(),
+        instruction: case.instruction.to_string(),
+        source_state: case.source.to_string(),
+        source_state_data: source_account.data,
+        source_lamports,
+        destination_state: case.destination.to_string(),
+        destination_state_data: destination_account.data,
+        destination_lamports,
+        amount: case.amount,
+        data: ix.data,
+        expected_ok: result.raw_result.is_ok(),
+        expected_error: result.raw_result.err().map(|err| format!("{err:?}")),
+        since_sdk: Some(MOVE_INSTRUCTIONS_SINCE_SDK.to_string()),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let mollusk = mollusk();
+    let minimum_delegation = minimum_delegation(&mollusk);
+    let amount = 2 * LAMPORTS_PER_SOL;
+    let same = authority();
+    let cases = [
+        // MoveStake: source fully active; destination fully active on the
+        // same vote account or fully inactive; authorities equal.
+        (
+            "move_stake_active_to_active",
+            "move_stake",
+            "fully_active",
+            "fully_active",
+            amount,
+            same,
+        ),
+        (
+            "move_stake_active_to_initialized",
+            "move_stake",
+            "fully_active",
+            "initialized",
+            amount,
+            same,
+        ),
+        (
+            "move_stake_active_to_deactivated",
+            "move_stake",
+            "fully_active",
+            "deactivated",
+            amount,
+            same,
+        ),
+        (
+            "move_stake_all_stake",
+            "move_stake",
+            "fully_active",
+            "initialized",
+            DELEGATED,
+            same,
+        ),
+        (
+            "move_stake_other_vote",
+            "move_stake",
+            "fully_active",
+            "fully_active_other_vote",
+            amount,
+            same,
+        ),
+        (
+            "move_stake_inactive_source",
+            "move_stake",
+            "initialized",
+            "fully_active",
+            amount,
+            same,
+        ),
+        (
+            "move_stake_activating_source",
+            "move_stake",
+            "activating",
+            "initialized",
+            amount,
+            same,
+        ),
+        (
+            "move_stake_deactivating_source",
+            "move_stake",
+            "deactivating",
+            "initialized",
+            amount,
+            same,
+        ),
+        (
+            "move_stake_activating_destination",
+            "move_stake",
+            "fully_active",
+            "activating",
+            amount,
+            same,
+        ),
+        (
+            "move_stake_authority_mismatch",
+            "move_stake",
+            "fully_active",
+            "initialized",
+            amount,
+            key(0x0d),
+        ),
+        (
+            "move_stake_zero",
+            "move_stake",
+            "fully_active",
+            "initialized",
+            0,
+            same,
+        ),
+        (
+            "move_stake_more_than_delegated",
+            "move_stake",
+            "fully_active",
+            "initialized",
+            DELEGATED + 1,
+            same,
+        ),
+        (
+            "move_stake_leaves_source_below_minimum",
+            "move_stake",
+            "fully_active",
+            "fully_active",
+            DELEGATED - minimum_delegation + 1,
+            same,
+        ),
+        (
+            "move_stake_below_minimum_to_inactive",
+            "move_stake",
+            "fully_active",
+            "initialized",
+            minimum_delegation - 1,
+            same,
+        ),
+        // MoveLamports: only free lamports move out of a fully active or
+        // fully inactive source; the destination only has to be
+        // non-transient (activating is fine) with equal authorities.
+        (
+            "move_lamports_active_to_active",
+            "move_lamports",
+            "fully_active",
+            "fully_active",
+            FREE,
+            same,
+        ),
+        (
+            "move_lamports_initialized_to_initialized",
+            "move_lamports",
+            "initialized",
+            "initialized",
+            FREE,
+            same,
+        ),
+        (
+            "move_lamports_active_to_activating",
+            "move_lamports",
+            "fully_active",
+            "activating",
+            FREE,
+            same,
+        ),
+        (
+            "move_lamports_more_than_free",
+            "move_lamports",
+            "fully_active",
+            "initialized",
+            FREE + 1,
+            same,
+        ),
+        (
+            "move_lamports_activating_source",
+            "move_lamports",
+            "activating",
+            "initialized",
+            FREE,
+            same,
+        ),
+        (
+            "move_lamports_deactivating_source",
+            "move_lamports",
+            "deactivating",
+            "initialized",
+            FREE,
+            same,
+        ),
+        (
+            "move_lamports_authority_mismatch",
+            "move_lamports",
+            "initialized",
+            "initialized",
+            FREE,
+            key(0x0d),
+        ),
+    ];
+
+    Fixture {
+        current_epoch: CURRENT_EPOCH,
+        minimum_delegation,
+        rent_exempt_reserve: meta(authority()).rent_exempt_reserve,
+        instructions: vec![
+            instruction_case(
+                "move_stake",
+                stake_instruction::move_stake(&source(), &destination(), &authority(), 700),
+            ),
+            instruction_case(
+                "move_lamports",
+                stake_instruction::move_lamports(&source(), &destination(), &authority(), 800),
+            ),
+        ],
+        vectors: cases
+            .iter()
+            .map(
+                |&(name, instruction, source, destination, amount, destination_staker)| {
+                    run(
+                        &mollusk,
+                        &Case {
+                            name,
+                            instruction,
+                            source,
+                            destination,
+                            amount,
+                            destination_staker,
+                        },
+                    )
+                },
+            )
+            .collect(),
+    }
+}
+
+#[test]
+fn fixture_matches_official_stake_move_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_stake_move_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn move_instructions_match_the_instruction_account_entries() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let accounts: InstructionAccountsFixture =
+        serde_json::from_str(INSTRUCTION_ACCOUNTS_JSON).unwrap();
+    for case in &fixture.instructions {
+        let existing = accounts
+            .cases
+            .iter()
+            .find(|existing| existing.label == case.label)
+            .unwrap();
+        assert_eq!(case, existing);
+    }
+
+    for vector in &fixture.vectors {
+        let template = fixture
+            .instructions
+            .iter()
+            .find(|case| case.label == vector.instruction)
+            .unwrap();
+        // Same discriminant, only the u64 amount differs.
+        assert_eq!(vector.data[..4], template.data[..4], "{}", vector.name);
+        assert_eq!(vector.data[4..], vector.amount.to_le_bytes());
+        assert_eq!(vector.expected_ok, vector.expected_error.is_none());
+        for data in [&vector.source_state_data, &vector.destination_state_data] {
+            assert_eq!(data.len(), StakeStateV2::size_of());
+            let _: StakeStateV2 = bincode::deserialize(data).unwrap();
+        }
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_stake_move_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
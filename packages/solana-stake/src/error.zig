@@ -0,0 +1,113 @@
+//! Stake Program custom error-code parity.
+//!
+//! Mirrors `solana_stake_interface::error::StakeError` so callers can map
+//! the `InstructionError::Custom(code)` a failed stake CPI or transaction
+//! reports back to the variant that produced it.
+//!
+//! The module provides three layers:
+//!
+//! - `StakeError` — the raw `enum(u32)` discriminator space
+//! - `Error` — an `ErrorCode(...)` wrapper for typed entrypoints
+//! - `tryFrom`, `toStr`, `toU64` — decode / render / re-emit helpers
+
+const std = @import("std");
+const sol = @import("solana_program_sdk");
+
+pub const StakeError = enum(u32) {
+    NoCreditsToRedeem = 0,
+    LockupInForce = 1,
+    AlreadyDeactivated = 2,
+    TooSoonToRedelegate = 3,
+    InsufficientStake = 4,
+    MergeTransientStake = 5,
+    MergeMismatch = 6,
+    CustodianMissing = 7,
+    CustodianSignatureMissing = 8,
+    InsufficientReferenceVotes = 9,
+    VoteAddressMismatch = 10,
+    MinimumDelinquentEpochsForDeactivationNotMet = 11,
+    InsufficientDelegation = 12,
+    RedelegateTransientOrInactiveStake = 13,
+    RedelegateToSameVoteAccount = 14,
+    RedelegatedStakeMustFullyActivateBeforeDeactivationIsPermitted = 15,
+    EpochRewardsActive = 16,
+};
+
+pub const Error = sol.ErrorCode(StakeError, error{
+    NoCreditsToRedeem,
+    LockupInForce,
+    AlreadyDeactivated,
+    TooSoonToRedelegate,
+    InsufficientStake,
+    MergeTransientStake,
+    MergeMismatch,
+    CustodianMissing,
+    CustodianSignatureMissing,
+    InsufficientReferenceVotes,
+    VoteAddressMismatch,
+    MinimumDelinquentEpochsForDeactivationNotMet,
+    InsufficientDelegation,
+    RedelegateTransientOrInactiveStake,
+    RedelegateToSameVoteAccount,
+    RedelegatedStakeMustFullyActivateBeforeDeactivationIsPermitted,
+    EpochRewardsActive,
+});
+
+pub fn tryFrom(code: u32) sol.ProgramError!StakeError {
+    return std.meta.intToEnum(StakeError, code) catch error.InvalidArgument;
+}
+
+pub fn toStr(err: StakeError) []const u8 {
+    return switch (err) {
+        .NoCreditsToRedeem => "not enough credits to redeem",
+        .LockupInForce => "lockup has not yet expired",
+        .AlreadyDeactivated => "stake already deactivated",
+        .TooSoonToRedelegate => "one re-delegation permitted per epoch",
+        .InsufficientStake => "split amount is more than is staked",
+        .MergeTransientStake => "stake account with transient stake cannot be merged",
+        .MergeMismatch => "stake account merge failed due to different authority, lockups or state",
+        .CustodianMissing => "custodian address not present",
+        .CustodianSignatureMissing => "custodian signature not present",
+        .InsufficientReferenceVotes => "insufficient voting activity in the reference vote account",
+        .VoteAddressMismatch => "stake account is not delegated to the provided vote account",
+        .MinimumDelinquentEpochsForDeactivationNotMet => "stake account has not been delinquent for the minimum epochs required for deactivation",
+        .InsufficientDelegation => "delegation amount is less than the minimum",
+        .RedelegateTransientOrInactiveStake => "stake account with transient or inactive stake cannot be redelegated",
+        .RedelegateToSameVoteAccount => "stake redelegation to the same vote account is not permitted",
+        .RedelegatedStakeMustFullyActivateBeforeDeactivationIsPermitted => "redelegated stake must be fully activated before deactivation",
+        .EpochRewardsActive => "stake action is not permitted while the epoch rewards period is active",
+    };
+}
+
+pub inline fn toU64(err: StakeError) u64 {
+    return sol.customError(@intFromEnum(err));
+}
+
+test "stake error: official vectors cover every variant" {
+    const stake_error_fixture = @import("stake_error_fixture.zig");
+    var parsed = try stake_error_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqual(@typeInfo(StakeError).@"enum".fields.len, fixture.vectors.len);
+
+    for (fixture.vectors) |vector| {
+        const err = try tryFrom(vector.code);
+        try std.testing.expectEqualStrings(vector.name, @tagName(err));
+        try std.testing.expectEqualStrings(vector.message, toStr(err));
+        try std.testing.expectEqual(sol.customError(vector.code), toU64(err));
+
+        // bincode `InstructionError::Custom(code)`: the variant index, then the code.
+        try std.testing.expectEqual(@as(usize, 8), vector.instruction_error.len);
+        try std.testing.expectEqual(fixture.custom_variant_index, std.mem.readInt(u32, vector.instruction_error[0..4], .little));
+        try std.testing.expectEqual(vector.code, std.mem.readInt(u32, vector.instruction_error[4..8], .little));
+    }
+    try std.testing.expectError(error.InvalidArgument, tryFrom(@intCast(fixture.vectors.len)));
+}
+
+test "stake error: ErrorCode wrapper preserves custom u32 values" {
+    try std.testing.expectEqual(@as(u64, 16), Error.catchToU64(error.EpochRewardsActive));
+    try std.testing.expectEqual(
+        sol.program_error.errorToU64(error.InvalidArgument),
+        Error.catchToU64(error.InvalidArgument),
+    );
+}
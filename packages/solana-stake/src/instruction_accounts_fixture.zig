@@ -0,0 +1,40 @@
+const std = @import("std");
+const vector_filter = @import("vector_filter");
+
+pub const AccountFixture = struct {
+    pubkey: [32]u8,
+    is_signer: u8,
+    is_writable: u8,
+};
+
+pub const InstructionCase = struct {
+    label: []const u8,
+    accounts: []const AccountFixture,
+    data: []const u8,
+    /// First solana-sdk release that knows this variant; null when every
+    /// supported SDK does.
+    since_sdk: ?[]const u8 = null,
+};
+
+pub const Fixture = struct {
+    cases: []const InstructionCase,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_instruction_accounts.json"),
+        .{},
+    );
+}
+
+/// The cases of `fixture` a consumer pinned to `sdk` can decode, in order.
+/// The caller owns the returned slice; the cases still borrow from `fixture`.
+pub fn casesUpTo(
+    allocator: std.mem.Allocator,
+    fixture: Fixture,
+    sdk: std.SemanticVersion,
+) ![]InstructionCase {
+    return vector_filter.upTo(InstructionCase, allocator, fixture.cases, sdk);
+}
@@ -21,6 +21,11 @@ pub const Error = codec.Error || error{
     SeedTooLong,
 };
 
+/// Stake Program custom-error helpers.
+pub const stake_error = @import("error.zig");
+pub const StakeError = stake_error.StakeError;
+pub const StakeErrorSet = stake_error.Error;
+
 pub const StakeAuthorize = enum(u32) {
     staker = 0,
     withdrawer = 1,
@@ -90,6 +95,13 @@ pub const AuthorizeCheckedWithSeedData = [AUTHORIZE_CHECKED_WITH_SEED_DATA_CAPAC
 pub const SetLockupData = [SET_LOCKUP_DATA_CAPACITY]u8;
 pub const SetLockupCheckedData = [SET_LOCKUP_CHECKED_DATA_CAPACITY]u8;
 
+/// Smallest delegation the stake program accepts, as
+/// `solana_stake_program::get_minimum_delegation`: one lamport, or one SOL
+/// once `stake_raise_minimum_delegation_to_1_sol` is active.
+pub fn minimumDelegation(raise_minimum_delegation_to_1_sol: bool) u64 {
+    return if (raise_minimum_delegation_to_1_sol) sol.lamports_per_sol else 1;
+}
+
 pub fn initialize(
     stake: *const Pubkey,
     authorized: Authorized,
@@ -105,7 +117,7 @@ pub fn initialize(
 
 pub fn initializeChecked(
     stake: *const Pubkey,
-    authorized: Authorized,
+    authorized: *const Authorized,
     metas: *[4]AccountMeta,
     data: *DiscriminantOnlyData,
 ) Instruction {
@@ -513,7 +525,7 @@ test "checked initialize encodes signer authorities" {
     var metas: [4]AccountMeta = undefined;
     var data: DiscriminantOnlyData = undefined;
 
-    const ix = initializeChecked(&stake, authorized, &metas, &data);
+    const ix = initializeChecked(&stake, &authorized, &metas, &data);
     try std.testing.expectEqualSlices(u8, &.{ 9, 0, 0, 0 }, ix.data);
     try std.testing.expectEqual(@as(u8, 0), ix.accounts[2].is_signer);
     try std.testing.expectEqual(@as(u8, 1), ix.accounts[3].is_signer);
@@ -683,6 +695,306 @@ test "remaining discriminant-only builders encode expected variants" {
     try std.testing.expectEqualSlices(u8, &.{ 13, 0, 0, 0 }, minimum_ix.data);
 }
 
+test "integer boundary sweep matches official Rust encodings" {
+    const boundary_fixture = @import("boundary_fixture.zig");
+    var parsed = try boundary_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    try std.testing.expectEqual(@as(usize, 44), parsed.value.vectors.len);
+
+    const stake: Pubkey = .{1} ** 32;
+    const other_stake: Pubkey = .{2} ** 32;
+    const authority: Pubkey = .{3} ** 32;
+    const to: Pubkey = .{5} ** 32;
+
+    for (parsed.value.vectors) |vector| {
+        var metas: [6]AccountMeta = undefined;
+        var data: U64Data = undefined;
+
+        const ix = if (std.mem.eql(u8, vector.family, "stake_split"))
+            split(&stake, &other_stake, &authority, vector.value, metas[0..3], &data)
+        else if (std.mem.eql(u8, vector.family, "stake_withdraw"))
+            withdraw(&stake, &to, &authority, vector.value, null, &metas, &data)
+        else if (std.mem.eql(u8, vector.family, "stake_move_stake"))
+            moveStake(&stake, &other_stake, &authority, vector.value, metas[0..3], &data)
+        else if (std.mem.eql(u8, vector.family, "stake_move_lamports"))
+            moveLamports(&stake, &other_stake, &authority, vector.value, metas[0..3], &data)
+        else
+            return error.UnknownBoundaryFamily;
+
+        try std.testing.expectEqualSlices(u8, vector.data, ix.data);
+        try std.testing.expectEqual(vector.value, std.mem.readInt(u64, ix.data[vector.offset..][0..8], .little));
+    }
+}
+
+test "builders match official account metas for every instruction variant" {
+    const accounts_fixture = @import("instruction_accounts_fixture.zig");
+    var parsed = try accounts_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const cases = parsed.value.cases;
+    try std.testing.expectEqual(@as(usize, 17), cases.len);
+
+    const stake: Pubkey = .{1} ** 32;
+    const other_stake: Pubkey = .{2} ** 32;
+    const authority: Pubkey = .{3} ** 32;
+    const vote: Pubkey = .{4} ** 32;
+    const to: Pubkey = .{5} ** 32;
+    const custodian: Pubkey = .{6} ** 32;
+    const new_authority: Pubkey = .{7} ** 32;
+    const base: Pubkey = .{8} ** 32;
+    const owner: Pubkey = .{9} ** 32;
+    const reference_vote: Pubkey = .{0x0c} ** 32;
+
+    const authorized: Authorized = .{ .staker = .{0x0a} ** 32, .withdrawer = .{0x0b} ** 32 };
+    const lockup: Lockup = .{ .unix_timestamp = -5, .epoch = 9, .custodian = custodian };
+
+    var metas: [17][6]AccountMeta = undefined;
+    var initialize_data: InitializeData = undefined;
+    var authorize_data: AuthorizeData = undefined;
+    var authorize_checked_data: AuthorizeCheckedData = undefined;
+    var with_seed_data: AuthorizeWithSeedData = undefined;
+    var checked_with_seed_data: AuthorizeCheckedWithSeedData = undefined;
+    var set_lockup_data: SetLockupData = undefined;
+    var set_lockup_checked_data: SetLockupCheckedData = undefined;
+    var u64_data: [4]U64Data = undefined;
+    var discriminant_data: [6]DiscriminantOnlyData = undefined;
+
+    const built = [_]Instruction{
+        initialize(&stake, authorized, lockup, metas[0][0..2], &initialize_data),
+        authorize(&stake, &authority, &new_authority, .staker, null, metas[1][0..4], &authorize_data),
+        delegateStake(&stake, &authority, &vote, metas[2][0..6], &discriminant_data[0]),
+        split(&stake, &other_stake, &authority, 500, metas[3][0..3], &u64_data[0]),
+        withdraw(&stake, &to, &authority, 600, &custodian, metas[4][0..6], &u64_data[1]),
+        deactivateStake(&stake, &authority, metas[5][0..3], &discriminant_data[1]),
+        try setLockup(&stake, .{ .unix_timestamp = -5, .epoch = 9, .custodian = new_authority }, &custodian, metas[6][0..2], &set_lockup_data),
+        merge(&stake, &other_stake, &authority, metas[7][0..5], &discriminant_data[2]),
+        try authorizeWithSeed(&stake, &base, "stake-seed", &owner, &new_authority, .withdrawer, &custodian, metas[8][0..4], &with_seed_data),
+        initializeChecked(&stake, &authorized, metas[9][0..4], &discriminant_data[3]),
+        authorizeChecked(&stake, &authority, &new_authority, .withdrawer, null, metas[10][0..5], &authorize_checked_data),
+        try authorizeCheckedWithSeed(&stake, &base, "stake-seed", &owner, &new_authority, .staker, null, metas[11][0..5], &checked_with_seed_data),
+        try setLockupChecked(&stake, .{ .unix_timestamp = -5, .epoch = 9, .new_custodian = &new_authority }, &custodian, metas[12][0..3], &set_lockup_checked_data),
+        getMinimumDelegation(&discriminant_data[4]),
+        deactivateDelinquentStake(&stake, &vote, &reference_vote, metas[14][0..3], &discriminant_data[5]),
+        moveStake(&stake, &other_stake, &authority, 700, metas[15][0..3], &u64_data[2]),
+        moveLamports(&stake, &other_stake, &authority, 800, metas[16][0..3], &u64_data[3]),
+    };
+
+    for (cases, built) |case, ix| {
+        const tag: StakeInstruction = @enumFromInt(std.mem.readInt(u32, ix.data[0..4], .little));
+        try std.testing.expectEqualStrings(case.label, @tagName(tag));
+        try std.testing.expectEqualSlices(u8, case.data, ix.data);
+        try std.testing.expectEqual(case.accounts.len, ix.accounts.len);
+        for (case.accounts, ix.accounts) |expected, actual| {
+            try std.testing.expectEqualSlices(u8, &expected.pubkey, actual.pubkey);
+            try std.testing.expectEqual(expected.is_signer, actual.is_signer);
+            try std.testing.expectEqual(expected.is_writable, actual.is_writable);
+        }
+    }
+}
+
+test "move builders match official eligibility vectors" {
+    const stake_move_fixture = @import("stake_move_fixture.zig");
+    var parsed = try stake_move_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqual(@as(usize, 2), fixture.instructions.len);
+    try std.testing.expectEqual(@as(usize, 21), fixture.vectors.len);
+
+    const source: Pubkey = .{1} ** 32;
+    const destination: Pubkey = .{2} ** 32;
+    const authority: Pubkey = .{3} ** 32;
+
+    for (fixture.vectors) |vector| {
+        var metas: [3]AccountMeta = undefined;
+        var data: U64Data = undefined;
+        const ix = if (std.mem.eql(u8, vector.instruction, "move_stake"))
+            moveStake(&source, &destination, &authority, vector.amount, &metas, &data)
+        else if (std.mem.eql(u8, vector.instruction, "move_lamports"))
+            moveLamports(&source, &destination, &authority, vector.amount, &metas, &data)
+        else
+            return error.UnknownInstruction;
+        try std.testing.expectEqualSlices(u8, vector.data, ix.data);
+
+        const template = for (fixture.instructions) |case| {
+            if (std.mem.eql(u8, case.label, vector.instruction)) break case;
+        } else return error.MissingInstruction;
+        try std.testing.expectEqualStrings("2.1.0", template.since_sdk.?);
+        try std.testing.expectEqualStrings(template.since_sdk.?, vector.since_sdk.?);
+        try std.testing.expectEqual(template.accounts.len, ix.accounts.len);
+        for (template.accounts, ix.accounts) |expected, actual| {
+            try std.testing.expectEqualSlices(u8, &expected.pubkey, actual.pubkey);
+            try std.testing.expectEqual(expected.is_signer, actual.is_signer);
+            try std.testing.expectEqual(expected.is_writable, actual.is_writable);
+        }
+
+        try std.testing.expectEqual(vector.expected_ok, vector.expected_error == null);
+        // StakeStateV2 tag: 1 = Initialized, 2 = Stake.
+        for ([_][]const u8{ vector.source_state, vector.destination_state }, [_][]const u8{ vector.source_state_data, vector.destination_state_data }) |state, state_data| {
+            try std.testing.expectEqual(STAKE_STATE_SIZE, state_data.len);
+            const tag: u32 = if (std.mem.eql(u8, state, "initialized")) 1 else 2;
+            try std.testing.expectEqual(tag, std.mem.readInt(u32, state_data[0..4], .little));
+            try std.testing.expectEqual(fixture.rent_exempt_reserve, std.mem.readInt(u64, state_data[4..12], .little));
+        }
+    }
+}
+
+test "sdk version filter drops only the move instructions before 2.1" {
+    const accounts_fixture = @import("instruction_accounts_fixture.zig");
+    var parsed = try accounts_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+
+    const before = try accounts_fixture.casesUpTo(std.testing.allocator, fixture, .{ .major = 2, .minor = 0, .patch = 14 });
+    defer std.testing.allocator.free(before);
+    try std.testing.expectEqual(fixture.cases.len - 2, before.len);
+    var kept: usize = 0;
+    for (fixture.cases) |case| {
+        if (case.since_sdk) |since| {
+            try std.testing.expectEqualStrings("2.1.0", since);
+            const tag: StakeInstruction = @enumFromInt(std.mem.readInt(u32, case.data[0..4], .little));
+            try std.testing.expect(tag == .move_stake or tag == .move_lamports);
+            continue;
+        }
+        try std.testing.expectEqualStrings(case.label, before[kept].label);
+        kept += 1;
+    }
+    try std.testing.expectEqual(before.len, kept);
+
+    for ([_]std.SemanticVersion{ .{ .major = 2, .minor = 1, .patch = 0 }, .{ .major = 3, .minor = 0, .patch = 0 } }) |sdk| {
+        const all = try accounts_fixture.casesUpTo(std.testing.allocator, fixture, sdk);
+        defer std.testing.allocator.free(all);
+        try std.testing.expectEqual(fixture.cases.len, all.len);
+    }
+}
+
+test "stake Meta vectors carry the initialize authorized and lockup encoding" {
+    const stake_meta_fixture = @import("stake_meta_fixture.zig");
+    var parsed = try stake_meta_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqual(@as(usize, 3), fixture.vectors.len);
+
+    const stake: Pubkey = .{1} ** 32;
+    for (fixture.vectors) |vector| {
+        const bytes = vector.bytes;
+        try std.testing.expectEqual(@as(usize, 120), bytes.len);
+        try std.testing.expectEqual(vector.rent_exempt_reserve, std.mem.readInt(u64, bytes[0..8], .little));
+
+        // Meta stores Authorized and Lockup exactly as Initialize encodes them.
+        var metas: [2]AccountMeta = undefined;
+        var data: InitializeData = undefined;
+        const ix = initialize(
+            &stake,
+            .{ .staker = vector.authorized.staker, .withdrawer = vector.authorized.withdrawer },
+            .{
+                .unix_timestamp = vector.lockup.unix_timestamp,
+                .epoch = vector.lockup.epoch,
+                .custodian = vector.lockup.custodian,
+            },
+            &metas,
+            &data,
+        );
+        try std.testing.expectEqualSlices(u8, ix.data[4..], bytes[8..]);
+    }
+}
+
+test "authorize builders match official checked and seeded vectors" {
+    const stake_authorize_fixture = @import("stake_authorize_fixture.zig");
+    var parsed = try stake_authorize_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const vectors = parsed.value.vectors;
+    try std.testing.expectEqual(@as(usize, 12), vectors.len);
+
+    for (vectors) |*vector| {
+        const tag: StakeInstruction = @enumFromInt(vector.discriminant);
+        try std.testing.expectEqualStrings(vector.variant, @tagName(tag));
+        const stake_authorize: StakeAuthorize = @enumFromInt(vector.stake_authorize);
+        const custodian: ?*const Pubkey = if (vector.custodian) |*key| key else null;
+
+        var metas: [5]AccountMeta = undefined;
+        var authorize_data: AuthorizeData = undefined;
+        var authorize_checked_data: AuthorizeCheckedData = undefined;
+        var with_seed_data: AuthorizeWithSeedData = undefined;
+        var checked_with_seed_data: AuthorizeCheckedWithSeedData = undefined;
+        const ix = switch (tag) {
+            .authorize => authorize(&vector.stake, &vector.authority, &vector.new_authorized, stake_authorize, custodian, metas[0..4], &authorize_data),
+            .authorize_checked => authorizeChecked(&vector.stake, &vector.authority, &vector.new_authorized, stake_authorize, custodian, &metas, &authorize_checked_data),
+            .authorize_with_seed => try authorizeWithSeed(&vector.stake, &vector.authority, vector.authority_seed.?, &vector.authority_owner.?, &vector.new_authorized, stake_authorize, custodian, metas[0..4], &with_seed_data),
+            .authorize_checked_with_seed => try authorizeCheckedWithSeed(&vector.stake, &vector.authority, vector.authority_seed.?, &vector.authority_owner.?, &vector.new_authorized, stake_authorize, custodian, &metas, &checked_with_seed_data),
+            else => return error.UnexpectedVariant,
+        };
+
+        try std.testing.expectEqualSlices(u8, vector.data, ix.data);
+        try std.testing.expectEqual(vector.accounts.len, ix.accounts.len);
+        for (vector.accounts, ix.accounts) |expected, actual| {
+            try std.testing.expectEqualSlices(u8, &expected.pubkey, actual.pubkey);
+            try std.testing.expectEqual(expected.is_signer, actual.is_signer);
+            try std.testing.expectEqual(expected.is_writable, actual.is_writable);
+        }
+    }
+}
+
+test "stake activation vectors carry sysvar-ordered cluster history" {
+    const stake_activation_fixture = @import("stake_activation_fixture.zig");
+    var parsed = try stake_activation_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqual(@as(usize, 11), fixture.vectors.len);
+
+    for (fixture.vectors) |vector| {
+        const delegation = vector.delegation;
+        const status = vector.status;
+
+        // The history covers every epoch before the target, newest first.
+        var entries: [64]sol.stake_history.Entry = undefined;
+        try std.testing.expectEqual(vector.target_epoch, vector.stake_history.len);
+        for (vector.stake_history, 0..) |entry, i| {
+            try std.testing.expectEqual(vector.target_epoch - 1 - @as(u64, i), entry.epoch);
+            entries[i] = .{
+                .epoch = entry.epoch,
+                .effective = entry.effective,
+                .activating = entry.activating,
+                .deactivating = entry.deactivating,
+            };
+        }
+        const history: sol.stake_history.StakeHistory = .{ .entries = entries[0..vector.stake_history.len] };
+        if (vector.target_epoch > 0) {
+            try std.testing.expectEqual(vector.target_epoch - 1, history.latest().?.epoch);
+            // The bootstrap stake is always effective.
+            try std.testing.expect(history.get(0).?.effective >= fixture.bootstrap_stake);
+        }
+
+        try std.testing.expect(status.effective + status.activating <= delegation.stake);
+        try std.testing.expect(status.deactivating <= status.effective);
+        if (delegation.activation_epoch == delegation.deactivation_epoch or
+            vector.target_epoch < delegation.activation_epoch)
+        {
+            try std.testing.expect(status.effective == 0 and status.activating == 0 and status.deactivating == 0);
+        } else if (vector.target_epoch == delegation.activation_epoch) {
+            try std.testing.expectEqual(delegation.stake, status.activating);
+        } else if (vector.target_epoch >= delegation.deactivation_epoch) {
+            try std.testing.expectEqual(@as(u64, 0), status.activating);
+            try std.testing.expectEqual(status.effective, status.deactivating);
+        } else {
+            try std.testing.expectEqual(@as(u64, 0), status.deactivating);
+        }
+    }
+}
+
+test "stake economics match official size, reserve, and minimum delegation" {
+    const stake_economics_fixture = @import("stake_economics_fixture.zig");
+    var parsed = try stake_economics_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+
+    const rent: sol.rent.Rent.Data = .{};
+    try std.testing.expectEqual(STAKE_STATE_SIZE, fixture.stake_state_size);
+    try std.testing.expectEqual(fixture.rent_exempt_reserve, rent.getMinimumBalance(STAKE_STATE_SIZE));
+    try std.testing.expectEqual(fixture.minimum_delegation, minimumDelegation(false));
+    try std.testing.expectEqual(fixture.raised_minimum_delegation, minimumDelegation(true));
+    try std.testing.expectEqual(fixture.minimum_delegated_balance, fixture.rent_exempt_reserve + minimumDelegation(false));
+    try std.testing.expectEqual(fixture.raised_minimum_delegated_balance, fixture.rent_exempt_reserve + minimumDelegation(true));
+}
+
 test "public surface guards" {
     try std.testing.expect(@hasDecl(@This(), "initialize"));
     try std.testing.expect(@hasDecl(@This(), "authorizeWithSeed"));
@@ -690,6 +1002,18 @@ test "public surface guards" {
     try std.testing.expect(@hasDecl(@This(), "delegateStake"));
     try std.testing.expect(@hasDecl(@This(), "withdraw"));
     try std.testing.expect(@hasDecl(@This(), "getMinimumDelegation"));
+    try std.testing.expect(@hasDecl(@This(), "minimumDelegation"));
+    try std.testing.expect(@hasDecl(@This(), "StakeError"));
     try std.testing.expect(!@hasDecl(@This(), "rpc"));
     try std.testing.expect(!@hasDecl(@This(), "wallet"));
 }
+
+test {
+    std.testing.refAllDecls(@This());
+}
+
+test "STAKE_STATE_SIZE matches the official StakeStateV2 size" {
+    var parsed = try @import("rent_vectors").loadStandardAccountMinimums(std.testing.allocator);
+    defer parsed.deinit();
+    try std.testing.expectEqual(STAKE_STATE_SIZE, parsed.value.get("stake").?.data_len);
+}
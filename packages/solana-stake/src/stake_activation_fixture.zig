@@ -0,0 +1,44 @@
+const std = @import("std");
+
+pub const DelegationFields = struct {
+    stake: u64,
+    activation_epoch: u64,
+    deactivation_epoch: u64,
+};
+
+pub const StakeHistoryEntryFields = struct {
+    epoch: u64,
+    effective: u64,
+    activating: u64,
+    deactivating: u64,
+};
+
+pub const StakeActivationStatusFields = struct {
+    effective: u64,
+    activating: u64,
+    deactivating: u64,
+};
+
+pub const StakeActivationTestVector = struct {
+    name: []const u8,
+    delegation: DelegationFields,
+    target_epoch: u64,
+    new_rate_activation_epoch: ?u64,
+    /// Newest first, like the `StakeHistory` sysvar.
+    stake_history: []const StakeHistoryEntryFields,
+    status: StakeActivationStatusFields,
+};
+
+pub const Fixture = struct {
+    bootstrap_stake: u64,
+    vectors: []const StakeActivationTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_stake_activation_vectors.json"),
+        .{},
+    );
+}
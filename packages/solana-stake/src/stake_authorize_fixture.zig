@@ -0,0 +1,35 @@
+const std = @import("std");
+
+pub const AccountFixture = struct {
+    pubkey: [32]u8,
+    is_signer: u8,
+    is_writable: u8,
+};
+
+pub const StakeAuthorizeTestVector = struct {
+    name: []const u8,
+    variant: []const u8,
+    discriminant: u32,
+    stake_authorize: u32,
+    stake: [32]u8,
+    authority: [32]u8,
+    new_authorized: [32]u8,
+    authority_seed: ?[]const u8 = null,
+    authority_owner: ?[32]u8 = null,
+    custodian: ?[32]u8 = null,
+    accounts: []const AccountFixture,
+    data: []const u8,
+};
+
+pub const Fixture = struct {
+    vectors: []const StakeAuthorizeTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_stake_authorize_vectors.json"),
+        .{},
+    );
+}
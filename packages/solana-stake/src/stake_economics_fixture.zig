@@ -0,0 +1,19 @@
+const std = @import("std");
+
+pub const Fixture = struct {
+    stake_state_size: u64,
+    rent_exempt_reserve: u64,
+    minimum_delegation: u64,
+    raised_minimum_delegation: u64,
+    minimum_delegated_balance: u64,
+    raised_minimum_delegated_balance: u64,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_stake_economics_vectors.json"),
+        .{},
+    );
+}
@@ -0,0 +1,22 @@
+const std = @import("std");
+
+pub const StakeErrorTestVector = struct {
+    name: []const u8,
+    code: u32,
+    message: []const u8,
+    instruction_error: []const u8,
+};
+
+pub const Fixture = struct {
+    custom_variant_index: u32,
+    vectors: []const StakeErrorTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_stake_error_vectors.json"),
+        .{},
+    );
+}
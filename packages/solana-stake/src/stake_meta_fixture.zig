@@ -0,0 +1,33 @@
+const std = @import("std");
+
+pub const AuthorizedFields = struct {
+    staker: [32]u8,
+    withdrawer: [32]u8,
+};
+
+pub const LockupFields = struct {
+    unix_timestamp: i64,
+    epoch: u64,
+    custodian: [32]u8,
+};
+
+pub const StakeMetaTestVector = struct {
+    name: []const u8,
+    rent_exempt_reserve: u64,
+    authorized: AuthorizedFields,
+    lockup: LockupFields,
+    bytes: []const u8,
+};
+
+pub const Fixture = struct {
+    vectors: []const StakeMetaTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_stake_meta_vectors.json"),
+        .{},
+    );
+}
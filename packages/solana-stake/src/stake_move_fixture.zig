@@ -0,0 +1,38 @@
+const std = @import("std");
+const instruction_accounts_fixture = @import("instruction_accounts_fixture.zig");
+
+pub const InstructionCase = instruction_accounts_fixture.InstructionCase;
+
+pub const StakeMoveEligibilityTestVector = struct {
+    name: []const u8,
+    instruction: []const u8,
+    source_state: []const u8,
+    source_state_data: []const u8,
+    source_lamports: u64,
+    destination_state: []const u8,
+    destination_state_data: []const u8,
+    destination_lamports: u64,
+    amount: u64,
+    data: []const u8,
+    expected_ok: bool,
+    expected_error: ?[]const u8,
+    /// First solana-sdk release that knows `instruction`.
+    since_sdk: ?[]const u8 = null,
+};
+
+pub const Fixture = struct {
+    current_epoch: u64,
+    minimum_delegation: u64,
+    rent_exempt_reserve: u64,
+    instructions: []const InstructionCase,
+    vectors: []const StakeMoveEligibilityTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_stake_move_vectors.json"),
+        .{},
+    );
+}
@@ -22,6 +22,7 @@ pub fn build(b: *std.Build) void {
     });
 
     const tests = b.addTest(.{ .root_module = system_mod });
+    tests.root_module.addImport("rent_vectors", sol_dep.module("rent_vectors"));
     const run_tests = b.addRunArtifact(tests);
     const test_step = b.step("test", "Run host-side unit tests");
     test_step.dependOn(&run_tests.step);
@@ -0,0 +1,89 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_pubkey::Pubkey;
+use solana_rent::Rent;
+use solana_system_interface::instruction as system_instruction;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_create_account_funding_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct CreateAccountFundingTestVector {
+    name: String,
+    space: u64,
+    /// `Rent::default().minimum_balance(space)`.
+    minimum_balance: u64,
+    /// `create_account` data funding exactly `minimum_balance`.
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<CreateAccountFundingTestVector>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+fn vector(name: &str, space: u64) -> CreateAccountFundingTestVector {
+    let minimum_balance = Rent::default().minimum_balance(space as usize);
+    let ix = system_instruction::create_account(&key(1), &key(2), minimum_balance, space, &key(3));
+    CreateAccountFundingTestVector {
+        name: name.to_string(),
+        space,
+        minimum_balance,
+        data: ix.data,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        vectors: vec![
+            vector("empty", 0),
+            vector("token_account", 165),
+            vector("vote_account", 3_762),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_create_account_funding_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_create_account_funding_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn minimum_balance_is_the_exemption_boundary() {
+    let rent = Rent::default();
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let space = vector.space as usize;
+        assert!(
+            rent.is_exempt(vector.minimum_balance, space),
+            "{}",
+            vector.name
+        );
+        // One lamport short is accepted at creation time but leaves the
+        // account rent-paying.
+        assert!(
+            !rent.is_exempt(vector.minimum_balance - 1, space),
+            "{}",
+            vector.name
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_create_account_funding_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
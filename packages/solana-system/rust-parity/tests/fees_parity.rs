@@ -0,0 +1,76 @@
+#![allow(deprecated)]
+
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_fee_calculator::FeeCalculator;
+use solana_fee_structure::FeeStructure;
+use solana_sysvar::{fees::Fees, SysvarSerialize};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_fees_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct FeesSysvarTestVector {
+    name: String,
+    lamports_per_signature: u64,
+    /// bincode `Fees`: the `FeeCalculator`'s `lamports_per_signature`.
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    sysvar_id: [u8; 32],
+    account_data_len: usize,
+    vectors: Vec<FeesSysvarTestVector>,
+}
+
+fn vector(name: &str, lamports_per_signature: u64) -> FeesSysvarTestVector {
+    let fees = Fees::new(&FeeCalculator::new(lamports_per_signature));
+    FeesSysvarTestVector {
+        name: name.to_string(),
+        lamports_per_signature: fees.fee_calculator.lamports_per_signature,
+        data: bincode::serialize(&fees).unwrap(),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        sysvar_id: solana_sysvar::fees::ID.to_bytes(),
+        account_data_len: Fees::size_of(),
+        vectors: vec![
+            vector("zero", 0),
+            vector("default", FeeStructure::default().lamports_per_signature),
+            vector("max", u64::MAX),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_fees_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match("official_fees_vectors.json", &fixture, &official_fixture());
+}
+
+#[test]
+fn data_is_little_endian_lamports_per_signature() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        assert_eq!(vector.data.len(), fixture.account_data_len);
+        assert_eq!(
+            vector.data,
+            vector.lamports_per_signature.to_le_bytes(),
+            "{}",
+            vector.name
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_fees_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
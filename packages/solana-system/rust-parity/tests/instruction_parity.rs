@@ -0,0 +1,32 @@
+use solana_instruction::AccountMeta;
+use solana_pubkey::Pubkey;
+use solana_system_interface::{instruction as system_instruction, program as system_program};
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+fn assert_meta(meta: &AccountMeta, pubkey: Pubkey, is_signer: bool, is_writable: bool) {
+    assert_eq!(meta.pubkey, pubkey);
+    assert_eq!(meta.is_signer, is_signer);
+    assert_eq!(meta.is_writable, is_writable);
+}
+
+#[test]
+fn official_create_account_matches_zig_layout() {
+    let from = key(1);
+    let to = key(2);
+    let owner = key(3);
+    let ix = system_instruction::create_account(&from, &to, 1_000, 128, &owner);
+
+    assert_eq!(ix.program_id, system_program::ID);
+    assert_eq!(ix.accounts.len(), 2);
+    assert_meta(&ix.accounts[0], from, true, true);
+    assert_meta(&ix.accounts[1], to, true, true);
+    assert_eq!(ix.data.len(), 52);
+    assert_eq!(ix.data[0..4], [0, 0, 0, 0]);
+    assert_eq!(u64::from_le_bytes(ix.data[4..12].try_into().unwrap()), 1_000);
+    assert_eq!(u64::from_le_bytes(ix.data[12..20].try_into().unwrap()), 128);
+    assert_eq!(ix.data[20..52], owner.to_bytes());
+}
+
@@ -0,0 +1,136 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+use solana_system_interface::{
+    instruction::{self as system_instruction, SystemInstruction},
+    program as system_program,
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_nonce_instruction_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AccountFixture {
+    pubkey: [u8; 32],
+    is_signer: u8,
+    is_writable: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct NonceInstructionTestVector {
+    label: String,
+    /// `u32` bincode variant index of `SystemInstruction`.
+    discriminant: u32,
+    nonce_account: [u8; 32],
+    /// The `InitializeNonceAccount` authority; `None` for
+    /// `UpgradeNonceAccount`.
+    authority: Option<[u8; 32]>,
+    accounts: Vec<AccountFixture>,
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<NonceInstructionTestVector>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+fn vector(
+    label: &str,
+    nonce: Pubkey,
+    authority: Option<Pubkey>,
+    ix: Instruction,
+) -> NonceInstructionTestVector {
+    assert_eq!(ix.program_id, system_program::ID);
+    NonceInstructionTestVector {
+        label: label.to_string(),
+        discriminant: u32::from_le_bytes(ix.data[..4].try_into().unwrap()),
+        nonce_account: nonce.to_bytes(),
+        authority: authority.map(|authority| authority.to_bytes()),
+        accounts: ix
+            .accounts
+            .iter()
+            .map(|meta| AccountFixture {
+                pubkey: meta.pubkey.to_bytes(),
+                is_signer: u8::from(meta.is_signer),
+                is_writable: u8::from(meta.is_writable),
+            })
+            .collect(),
+        data: ix.data,
+    }
+}
+
+/// `InitializeNonceAccount` is only exposed as the second half of
+/// `create_nonce_account`.
+fn initialize(label: &str, nonce: Pubkey, authority: Pubkey) -> NonceInstructionTestVector {
+    let [_, initialize] =
+        system_instruction::create_nonce_account(&key(1), &nonce, &authority, 1_447_680)
+            .try_into()
+            .unwrap();
+    vector(label, nonce, Some(authority), initialize)
+}
+
+fn official_fixture() -> Fixture {
+    let nonce = key(2);
+    Fixture {
+        vectors: vec![
+            initialize("initialize", nonce, key(3)),
+            initialize("initialize_self_authority", nonce, nonce),
+            vector(
+                "upgrade",
+                nonce,
+                None,
+                system_instruction::upgrade_nonce_account(nonce),
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_nonce_instruction_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_nonce_instruction_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn data_decodes_to_the_recorded_variant() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let instruction: SystemInstruction = bincode::deserialize(&vector.data).unwrap();
+        match (instruction, vector.authority) {
+            (SystemInstruction::InitializeNonceAccount(authority), Some(expected)) => {
+                assert_eq!(vector.discriminant, 6);
+                assert_eq!(authority.to_bytes(), expected);
+                assert_eq!(vector.data.len(), 4 + 32);
+                assert_eq!(vector.accounts.len(), 3);
+            }
+            (SystemInstruction::UpgradeNonceAccount, None) => {
+                assert_eq!(vector.discriminant, 12);
+                assert_eq!(vector.data.len(), 4);
+                assert_eq!(vector.accounts.len(), 1);
+            }
+            (instruction, _) => panic!("{}: unexpected {instruction:?}", vector.label),
+        }
+        assert_eq!(vector.accounts[0].pubkey, vector.nonce_account);
+        assert_eq!(vector.accounts[0].is_writable, 1);
+        assert!(vector.accounts.iter().all(|meta| meta.is_signer == 0));
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_nonce_instruction_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
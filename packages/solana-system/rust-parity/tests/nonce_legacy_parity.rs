@@ -0,0 +1,165 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_hash::Hash;
+use solana_nonce::{
+    state::{DurableNonce, State},
+    versions::Versions,
+};
+use solana_pubkey::Pubkey;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_nonce_legacy_vectors.json");
+const SLOT_PROGRESSION_JSON: &str =
+    include_str!("../../src/official_slot_progression_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct NonceLegacyTestVector {
+    name: String,
+    authority: [u8; 32],
+    /// What a legacy account stores verbatim as its nonce.
+    blockhash: [u8; 32],
+    lamports_per_signature: u64,
+    legacy_encoded: Vec<u8>,
+    upgraded_encoded: Vec<u8>,
+    /// `sha256("DURABLE_NONCE" || blockhash)`, stored after the upgrade.
+    upgraded_durable_nonce: [u8; 32],
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<NonceLegacyTestVector>,
+}
+
+#[derive(Deserialize)]
+struct SlotStep {
+    blockhash: [u8; 32],
+    durable_nonce: [u8; 32],
+    nonce_account_data: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+struct SlotProgressionFixture {
+    lamports_per_signature: u64,
+    nonce_authority: [u8; 32],
+    steps: Vec<SlotStep>,
+}
+
+fn slot_progression() -> SlotProgressionFixture {
+    serde_json::from_str(SLOT_PROGRESSION_JSON).unwrap()
+}
+
+fn legacy_vector(
+    name: &str,
+    authority: [u8; 32],
+    blockhash: [u8; 32],
+    lamports_per_signature: u64,
+) -> NonceLegacyTestVector {
+    // `DurableNonce` has no constructor that skips the domain-separation
+    // hash; legacy accounts hold the raw blockhash in the same slot.
+    let raw_nonce: DurableNonce = bincode::deserialize(&blockhash).unwrap();
+    let legacy = Versions::Legacy(Box::new(State::new_initialized(
+        &Pubkey::from(authority),
+        raw_nonce,
+        lamports_per_signature,
+    )));
+    let legacy_encoded = bincode::serialize(&legacy).unwrap();
+
+    let upgraded = legacy.upgrade().expect("initialized legacy nonce upgrades");
+    let State::Initialized(data) = upgraded.state() else {
+        unreachable!();
+    };
+    NonceLegacyTestVector {
+        name: name.to_string(),
+        authority,
+        blockhash,
+        lamports_per_signature,
+        legacy_encoded,
+        upgraded_encoded: bincode::serialize(&upgraded).unwrap(),
+        upgraded_durable_nonce: data.blockhash().to_bytes(),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    // The first vector upgrades a legacy copy of the first slot-progression
+    // nonce account, so its upgraded bytes line up with that fixture.
+    let progression = slot_progression();
+    Fixture {
+        vectors: vec![
+            legacy_vector(
+                "slot_progression_first_step",
+                progression.nonce_authority,
+                progression.steps[0].blockhash,
+                progression.lamports_per_signature,
+            ),
+            legacy_vector("custom_fee", [0x42; 32], [0x9c; 32], 10_000),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_nonce_legacy_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_nonce_legacy_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn upgrade_moves_the_nonce_out_of_the_blockhash_domain() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        assert_eq!(vector.legacy_encoded.len(), State::size());
+        assert_eq!(vector.upgraded_encoded.len(), State::size());
+        assert_eq!(vector.legacy_encoded[..4], [0, 0, 0, 0]);
+        assert_eq!(vector.upgraded_encoded[..4], [1, 0, 0, 0]);
+        // Only the version tag and the nonce change.
+        assert_eq!(vector.legacy_encoded[4..40], vector.upgraded_encoded[4..40]);
+        assert_eq!(vector.legacy_encoded[40..72], vector.blockhash);
+        assert_eq!(
+            vector.upgraded_encoded[40..72],
+            vector.upgraded_durable_nonce
+        );
+        assert_ne!(vector.upgraded_durable_nonce, vector.blockhash);
+        assert_eq!(vector.legacy_encoded[72..], vector.upgraded_encoded[72..]);
+
+        // A durable transaction uses the stored nonce as its recent
+        // blockhash. Legacy nonces never verify one, and an upgraded nonce
+        // is not upgraded again.
+        let upgraded: Versions = bincode::deserialize(&vector.upgraded_encoded).unwrap();
+        let nonce = Hash::new_from_array(vector.upgraded_durable_nonce);
+        assert!(upgraded.verify_recent_blockhash(&nonce).is_some());
+        let legacy: Versions = bincode::deserialize(&vector.legacy_encoded).unwrap();
+        let blockhash = Hash::new_from_array(vector.blockhash);
+        assert!(legacy.verify_recent_blockhash(&blockhash).is_none());
+        assert_eq!(upgraded.upgrade(), None);
+    }
+
+    let progression = slot_progression();
+    let first = &fixture.vectors[0];
+    assert_eq!(
+        first.upgraded_durable_nonce,
+        progression.steps[0].durable_nonce
+    );
+    assert_eq!(
+        first.upgraded_encoded,
+        progression.steps[0].nonce_account_data
+    );
+
+    // Uninitialized legacy nonces are left alone until initialized.
+    assert_eq!(
+        Versions::Legacy(Box::new(State::Uninitialized)).upgrade(),
+        None
+    );
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_nonce_legacy_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
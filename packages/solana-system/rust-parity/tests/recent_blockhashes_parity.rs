@@ -0,0 +1,138 @@
+#![allow(deprecated)]
+
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_fee_structure::FeeStructure;
+use solana_hash::Hash;
+use solana_sha256_hasher::hashv;
+use solana_sysvar::{
+    recent_blockhashes::{IterItem, RecentBlockhashes, MAX_ENTRIES},
+    SysvarSerialize,
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_recent_blockhashes_vectors.json");
+
+/// Each entry is a blockhash followed by its `FeeCalculator`'s
+/// `lamports_per_signature`.
+const ENTRY_LEN: usize = 32 + 8;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct EntryVector {
+    blockhash: [u8; 32],
+    lamports_per_signature: u64,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct RecentBlockhashesTestVector {
+    name: String,
+    /// Newest first, as the bank writes them.
+    entries: Vec<EntryVector>,
+    /// bincode `RecentBlockhashes`: `u64` entry count, then the entries.
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    max_entries: usize,
+    entry_len: usize,
+    /// `RecentBlockhashes::size_of()`, the account size with every entry
+    /// filled.
+    account_data_len: usize,
+    vectors: Vec<RecentBlockhashesTestVector>,
+}
+
+fn blockhash(block_height: u64) -> Hash {
+    hashv(&[b"recent_blockhashes", &block_height.to_le_bytes()])
+}
+
+/// The `count` most recent blocks below `tip`, with the fee rising by one
+/// lamport per block so each entry's fee is distinguishable.
+fn vector(name: &str, tip: u64, count: u64) -> RecentBlockhashesTestVector {
+    let base_fee = FeeStructure::default().lamports_per_signature;
+    let hashes: Vec<(u64, Hash, u64)> = (tip - count..tip)
+        .rev()
+        .map(|height| (height, blockhash(height), base_fee + height))
+        .collect();
+    let sysvar: RecentBlockhashes = hashes
+        .iter()
+        .map(|(height, hash, fee)| IterItem(*height, hash, *fee))
+        .collect();
+
+    RecentBlockhashesTestVector {
+        name: name.to_string(),
+        entries: sysvar
+            .iter()
+            .map(|entry| EntryVector {
+                blockhash: entry.blockhash.to_bytes(),
+                lamports_per_signature: entry.fee_calculator.lamports_per_signature,
+            })
+            .collect(),
+        data: bincode::serialize(&sysvar).unwrap(),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        max_entries: MAX_ENTRIES,
+        entry_len: ENTRY_LEN,
+        account_data_len: RecentBlockhashes::size_of(),
+        vectors: vec![
+            vector("empty", 1_000, 0),
+            vector("one_entry", 1_000, 1),
+            vector("max_entries", 1_000, MAX_ENTRIES as u64),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_recent_blockhashes_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_recent_blockhashes_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn data_is_u64_count_then_blockhash_and_fee_entries() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let data = &vector.data;
+        let (count, body) = data.split_at(8);
+        assert_eq!(
+            u64::from_le_bytes(count.try_into().unwrap()),
+            vector.entries.len() as u64,
+            "{}",
+            vector.name
+        );
+        assert_eq!(body.len(), vector.entries.len() * fixture.entry_len);
+        for (entry, bytes) in vector.entries.iter().zip(body.chunks(fixture.entry_len)) {
+            assert_eq!(bytes[..32], entry.blockhash);
+            assert_eq!(
+                bytes[32..],
+                entry.lamports_per_signature.to_le_bytes(),
+                "{}",
+                vector.name
+            );
+        }
+
+        let decoded: RecentBlockhashes = bincode::deserialize(data).unwrap();
+        assert_eq!(decoded.len(), vector.entries.len());
+    }
+
+    let full = fixture.vectors.last().unwrap();
+    assert_eq!(full.entries.len(), fixture.max_entries);
+    assert_eq!(full.data.len(), fixture.account_data_len);
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_recent_blockhashes_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,126 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_hash::Hash;
+use solana_sha256_hasher::hashv;
+use solana_slot_hashes::MAX_ENTRIES;
+use solana_sysvar::{slot_hashes::SlotHashes, SysvarSerialize};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_slot_hashes_vectors.json");
+
+/// Each entry is a `u64` slot followed by its bank hash.
+const ENTRY_LEN: usize = 8 + 32;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct SlotHashVector {
+    slot: u64,
+    hash: [u8; 32],
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct SlotHashesTestVector {
+    name: String,
+    /// Descending by slot, as `SlotHashes::add` keeps them.
+    entries: Vec<SlotHashVector>,
+    /// bincode `SlotHashes`: `u64` entry count, then the entries.
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    max_entries: usize,
+    entry_len: usize,
+    /// `SlotHashes::size_of()`, the sysvar account size with every entry
+    /// filled.
+    account_data_len: usize,
+    vectors: Vec<SlotHashesTestVector>,
+}
+
+fn bank_hash(slot: u64) -> Hash {
+    hashv(&[b"slot_hashes", &slot.to_le_bytes()])
+}
+
+/// Record each slot in `slots` the way the bank does after freezing it.
+fn vector(name: &str, slots: impl IntoIterator<Item = u64>) -> SlotHashesTestVector {
+    let mut slot_hashes = SlotHashes::default();
+    for slot in slots {
+        slot_hashes.add(slot, bank_hash(slot));
+    }
+    SlotHashesTestVector {
+        name: name.to_string(),
+        entries: slot_hashes
+            .iter()
+            .map(|(slot, hash)| SlotHashVector {
+                slot: *slot,
+                hash: hash.to_bytes(),
+            })
+            .collect(),
+        data: bincode::serialize(&slot_hashes).unwrap(),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        max_entries: MAX_ENTRIES,
+        entry_len: ENTRY_LEN,
+        account_data_len: SlotHashes::size_of(),
+        vectors: vec![
+            vector("empty", []),
+            // Skipped and out-of-order slots still land newest first.
+            vector("short", [7, 3, 12, 4]),
+            // Older slots fall off once the list is full.
+            vector("max_entries", 1..=(MAX_ENTRIES as u64 + 88)),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_slot_hashes_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_slot_hashes_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn data_is_u64_count_then_descending_slot_hash_entries() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let data = &vector.data;
+        let (count, body) = data.split_at(8);
+        assert_eq!(
+            u64::from_le_bytes(count.try_into().unwrap()),
+            vector.entries.len() as u64,
+            "{}",
+            vector.name
+        );
+        assert_eq!(body.len(), vector.entries.len() * fixture.entry_len);
+        for (entry, bytes) in vector.entries.iter().zip(body.chunks(fixture.entry_len)) {
+            assert_eq!(bytes[..8], entry.slot.to_le_bytes(), "{}", vector.name);
+            assert_eq!(bytes[8..], entry.hash);
+        }
+        assert!(vector
+            .entries
+            .windows(2)
+            .all(|pair| pair[0].slot > pair[1].slot));
+
+        let decoded: SlotHashes = bincode::deserialize(data).unwrap();
+        assert_eq!(decoded.len(), vector.entries.len());
+    }
+
+    let full = fixture.vectors.last().unwrap();
+    assert_eq!(full.entries.len(), fixture.max_entries);
+    assert_eq!(full.data.len(), fixture.account_data_len);
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_slot_hashes_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,183 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_sha256_hasher::hashv;
+use solana_slot_history::{Check, SlotHistory, MAX_ENTRIES};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_slot_history_vectors.json");
+
+/// bincode `BitVec<u64>` is its `Option<Box<[u64]>>` blocks (tag byte, then
+/// a `u64` block count) followed by the `u64` bit length; `next_slot`
+/// comes last.
+const BLOCKS_OFFSET: usize = 1 + 8;
+const BLOCK_COUNT: usize = (MAX_ENTRIES / 64) as usize;
+const BIT_LEN_OFFSET: usize = BLOCKS_OFFSET + BLOCK_COUNT * 8;
+const NEXT_SLOT_OFFSET: usize = BIT_LEN_OFFSET + 8;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct BlockVector {
+    index: usize,
+    value: u64,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct CheckVector {
+    slot: u64,
+    /// `"found"`, `"not_found"`, `"too_old"`, or `"future"`.
+    check: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct SlotHistoryDataTestVector {
+    name: String,
+    /// Slots passed to `SlotHistory::add`, in order, after `default()`.
+    added_slots: Vec<u64>,
+    next_slot: u64,
+    /// Every non-zero `u64` block of the bitvec; the rest are zero.
+    set_blocks: Vec<BlockVector>,
+    /// The full account data is 128 KiB, so only its hash is recorded.
+    data_sha256: [u8; 32],
+    checks: Vec<CheckVector>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    max_entries: u64,
+    block_count: usize,
+    blocks_offset: usize,
+    bit_len_offset: usize,
+    next_slot_offset: usize,
+    data_len: usize,
+    vectors: Vec<SlotHistoryDataTestVector>,
+}
+
+fn check_name(check: Check) -> &'static str {
+    match check {
+        Check::Found => "found",
+        Check::NotFound => "not_found",
+        Check::TooOld => "too_old",
+        Check::Future => "future",
+    }
+}
+
+fn vector(name: &str, added_slots: &[u64], checked_slots: &[u64]) -> SlotHistoryDataTestVector {
+    let mut history = SlotHistory::default();
+    for &slot in added_slots {
+        history.add(slot);
+    }
+    let data = bincode::serialize(&history).unwrap();
+    SlotHistoryDataTestVector {
+        name: name.to_string(),
+        added_slots: added_slots.to_vec(),
+        next_slot: history.next_slot,
+        set_blocks: data[BLOCKS_OFFSET..BIT_LEN_OFFSET]
+            .chunks(8)
+            .map(|block| u64::from_le_bytes(block.try_into().unwrap()))
+            .enumerate()
+            .filter(|&(_, value)| value != 0)
+            .map(|(index, value)| BlockVector { index, value })
+            .collect(),
+        data_sha256: hashv(&[&data]).to_bytes(),
+        checks: checked_slots
+            .iter()
+            .map(|&slot| CheckVector {
+                slot,
+                check: check_name(history.check(slot)).to_string(),
+            })
+            .collect(),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        max_entries: MAX_ENTRIES,
+        block_count: BLOCK_COUNT,
+        blocks_offset: BLOCKS_OFFSET,
+        bit_len_offset: BIT_LEN_OFFSET,
+        next_slot_offset: NEXT_SLOT_OFFSET,
+        data_len: bincode::serialize(&SlotHistory::default()).unwrap().len(),
+        vectors: vec![
+            // Genesis: only slot 0 is present.
+            vector("default", &[], &[0, 1]),
+            vector(
+                "sparse",
+                &[2, 5, 64, 130],
+                &[0, 1, 2, 3, 5, 63, 64, 130, 131],
+            ),
+            // Slot 20 stays in range; slots 0..=5 age out and their bits are
+            // reused by MAX_ENTRIES..=MAX_ENTRIES + 5.
+            vector(
+                "wraparound",
+                &[20, MAX_ENTRIES + 5],
+                &[
+                    0,
+                    5,
+                    6,
+                    20,
+                    21,
+                    MAX_ENTRIES,
+                    MAX_ENTRIES + 5,
+                    MAX_ENTRIES + 6,
+                ],
+            ),
+            // Jumping a full history ahead clears every block first.
+            vector(
+                "jump_past_history",
+                &[3, 3 * MAX_ENTRIES + 7],
+                &[
+                    3,
+                    2 * MAX_ENTRIES + 7,
+                    2 * MAX_ENTRIES + 8,
+                    3 * MAX_ENTRIES + 3,
+                    3 * MAX_ENTRIES + 7,
+                ],
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_slot_history_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_slot_history_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn data_rebuilds_from_set_blocks_and_next_slot() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    assert_eq!(fixture.data_len, fixture.next_slot_offset + 8);
+    for vector in &fixture.vectors {
+        let mut data = vec![0; fixture.data_len];
+        data[0] = 1;
+        data[1..BLOCKS_OFFSET].copy_from_slice(&(fixture.block_count as u64).to_le_bytes());
+        for block in &vector.set_blocks {
+            data[fixture.blocks_offset + block.index * 8..][..8]
+                .copy_from_slice(&block.value.to_le_bytes());
+        }
+        data[fixture.bit_len_offset..][..8].copy_from_slice(&fixture.max_entries.to_le_bytes());
+        data[fixture.next_slot_offset..].copy_from_slice(&vector.next_slot.to_le_bytes());
+        assert_eq!(
+            hashv(&[&data]).to_bytes(),
+            vector.data_sha256,
+            "{}",
+            vector.name
+        );
+
+        let history: SlotHistory = bincode::deserialize(&data).unwrap();
+        assert_eq!(history.next_slot, vector.next_slot);
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_slot_history_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
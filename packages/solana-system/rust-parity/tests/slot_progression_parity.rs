@@ -0,0 +1,128 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_fee_structure::FeeStructure;
+use solana_hash::Hash;
+use solana_nonce::{
+    state::{DurableNonce, State},
+    versions::Versions,
+};
+use solana_pubkey::Pubkey;
+use solana_sha256_hasher::hashv;
+use solana_slot_hashes::SlotHashes;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_slot_progression_vectors.json");
+const STEPS: u64 = 5;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct SlotStep {
+    slot: u64,
+    blockhash: [u8; 32],
+    durable_nonce: [u8; 32],
+    nonce_account_data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    seed: [u8; 32],
+    start_slot: u64,
+    lamports_per_signature: u64,
+    nonce_authority: [u8; 32],
+    steps: Vec<SlotStep>,
+    slot_hashes_data: Vec<u8>,
+}
+
+/// `hash(slot) = sha256(hash(slot - 1) || slot.to_le_bytes())`, with the
+/// seed standing in for the hash before `start_slot`.
+fn next_blockhash(prev: &Hash, slot: u64) -> Hash {
+    hashv(&[prev.as_ref(), &slot.to_le_bytes()])
+}
+
+fn official_fixture() -> Fixture {
+    let seed = [0x5e; 32];
+    let start_slot = 100;
+    let lamports_per_signature = FeeStructure::default().lamports_per_signature;
+    let nonce_authority = Pubkey::from([0x0a; 32]);
+
+    let mut prev = Hash::new_from_array(seed);
+    let mut entries = Vec::new();
+    let steps = (start_slot..start_slot + STEPS)
+        .map(|slot| {
+            let blockhash = next_blockhash(&prev, slot);
+            prev = blockhash;
+            entries.push((slot, blockhash));
+
+            // What `AdvanceNonceAccount` stores when the bank's blockhash is
+            // `blockhash`.
+            let durable_nonce = DurableNonce::from_blockhash(&blockhash);
+            let versions = Versions::new(State::new_initialized(
+                &nonce_authority,
+                durable_nonce,
+                lamports_per_signature,
+            ));
+            SlotStep {
+                slot,
+                blockhash: blockhash.to_bytes(),
+                durable_nonce: durable_nonce.as_hash().to_bytes(),
+                nonce_account_data: bincode::serialize(&versions).unwrap(),
+            }
+        })
+        .collect();
+
+    Fixture {
+        seed,
+        start_slot,
+        lamports_per_signature,
+        nonce_authority: nonce_authority.to_bytes(),
+        steps,
+        slot_hashes_data: bincode::serialize(&SlotHashes::new(&entries)).unwrap(),
+    }
+}
+
+#[test]
+fn fixture_matches_official_slot_progression_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_slot_progression_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn nonce_accounts_and_slot_hashes_decode_to_the_progression() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let slot_hashes: SlotHashes = bincode::deserialize(&fixture.slot_hashes_data).unwrap();
+    assert_eq!(slot_hashes.len(), fixture.steps.len());
+    assert_eq!(
+        slot_hashes.first().unwrap().0,
+        fixture.steps.last().unwrap().slot
+    );
+
+    for step in &fixture.steps {
+        let blockhash = Hash::new_from_array(step.blockhash);
+        assert_eq!(slot_hashes.get(&step.slot), Some(&blockhash));
+
+        assert_eq!(step.nonce_account_data.len(), State::size());
+        let versions: Versions = bincode::deserialize(&step.nonce_account_data).unwrap();
+        let State::Initialized(data) = versions.state() else {
+            panic!("slot {} nonce account is not initialized", step.slot);
+        };
+        assert_eq!(data.authority.to_bytes(), fixture.nonce_authority);
+        assert_eq!(data.blockhash().to_bytes(), step.durable_nonce);
+        assert_eq!(
+            data.get_lamports_per_signature(),
+            fixture.lamports_per_signature
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_slot_progression_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
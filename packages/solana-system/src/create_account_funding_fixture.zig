@@ -0,0 +1,21 @@
+const std = @import("std");
+
+pub const CreateAccountFundingTestVector = struct {
+    name: []const u8,
+    space: u64,
+    minimum_balance: u64,
+    data: []const u8,
+};
+
+pub const Fixture = struct {
+    vectors: []const CreateAccountFundingTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_create_account_funding_vectors.json"),
+        .{},
+    );
+}
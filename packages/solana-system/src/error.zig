@@ -0,0 +1,92 @@
+//! System Program custom error-code parity.
+//!
+//! Mirrors `solana_system_interface::error::SystemError` so callers can map
+//! the `InstructionError::Custom(code)` a failed system CPI or transaction
+//! reports back to the variant that produced it.
+//!
+//! The module provides three layers:
+//!
+//! - `SystemError` — the raw `enum(u32)` discriminator space
+//! - `Error` — an `ErrorCode(...)` wrapper for typed entrypoints
+//! - `tryFrom`, `toStr`, `toU64` — decode / render / re-emit helpers
+
+const std = @import("std");
+const sol = @import("solana_program_sdk");
+
+pub const SystemError = enum(u32) {
+    AccountAlreadyInUse = 0,
+    ResultWithNegativeLamports = 1,
+    InvalidProgramId = 2,
+    InvalidAccountDataLength = 3,
+    MaxSeedLengthExceeded = 4,
+    AddressWithSeedMismatch = 5,
+    NonceNoRecentBlockhashes = 6,
+    NonceBlockhashNotExpired = 7,
+    NonceUnexpectedBlockhashValue = 8,
+};
+
+/// `MaxSeedLengthExceeded` shares its name with the `ProgramError` variant;
+/// through this wrapper it reports custom code 4, as the system program does.
+pub const Error = sol.ErrorCode(SystemError, error{
+    AccountAlreadyInUse,
+    ResultWithNegativeLamports,
+    InvalidProgramId,
+    InvalidAccountDataLength,
+    MaxSeedLengthExceeded,
+    AddressWithSeedMismatch,
+    NonceNoRecentBlockhashes,
+    NonceBlockhashNotExpired,
+    NonceUnexpectedBlockhashValue,
+});
+
+pub fn tryFrom(code: u32) sol.ProgramError!SystemError {
+    return std.meta.intToEnum(SystemError, code) catch error.InvalidArgument;
+}
+
+pub fn toStr(err: SystemError) []const u8 {
+    return switch (err) {
+        .AccountAlreadyInUse => "an account with the same address already exists",
+        .ResultWithNegativeLamports => "account does not have enough SOL to perform the operation",
+        .InvalidProgramId => "cannot assign account to this program id",
+        .InvalidAccountDataLength => "cannot allocate account data of this length",
+        .MaxSeedLengthExceeded => "length of requested seed is too long",
+        .AddressWithSeedMismatch => "provided address does not match addressed derived from seed",
+        .NonceNoRecentBlockhashes => "advancing stored nonce requires a populated RecentBlockhashes sysvar",
+        .NonceBlockhashNotExpired => "stored nonce is still in recent_blockhashes",
+        .NonceUnexpectedBlockhashValue => "specified nonce does not match stored nonce",
+    };
+}
+
+pub inline fn toU64(err: SystemError) u64 {
+    return sol.customError(@intFromEnum(err));
+}
+
+test "system error: official vectors cover every variant" {
+    const system_error_fixture = @import("system_error_fixture.zig");
+    var parsed = try system_error_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqual(@typeInfo(SystemError).@"enum".fields.len, fixture.vectors.len);
+
+    for (fixture.vectors) |vector| {
+        const err = try tryFrom(vector.code);
+        try std.testing.expectEqualStrings(vector.name, @tagName(err));
+        try std.testing.expectEqualStrings(vector.message, toStr(err));
+        try std.testing.expectEqual(sol.customError(vector.code), toU64(err));
+
+        // bincode `InstructionError::Custom(code)`: the variant index, then the code.
+        try std.testing.expectEqual(@as(usize, 8), vector.instruction_error.len);
+        try std.testing.expectEqual(fixture.custom_variant_index, std.mem.readInt(u32, vector.instruction_error[0..4], .little));
+        try std.testing.expectEqual(vector.code, std.mem.readInt(u32, vector.instruction_error[4..8], .little));
+    }
+    try std.testing.expectError(error.InvalidArgument, tryFrom(@intCast(fixture.vectors.len)));
+}
+
+test "system error: ErrorCode wrapper preserves custom u32 values" {
+    try std.testing.expectEqual(@as(u64, 8), Error.catchToU64(error.NonceUnexpectedBlockhashValue));
+    try std.testing.expectEqual(@as(u64, 4), Error.catchToU64(error.MaxSeedLengthExceeded));
+    try std.testing.expectEqual(
+        sol.program_error.errorToU64(error.InvalidArgument),
+        Error.catchToU64(error.InvalidArgument),
+    );
+}
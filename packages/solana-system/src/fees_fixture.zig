@@ -0,0 +1,22 @@
+const std = @import("std");
+
+pub const FeesSysvarTestVector = struct {
+    name: []const u8,
+    lamports_per_signature: u64,
+    data: []const u8,
+};
+
+pub const Fixture = struct {
+    sysvar_id: [32]u8,
+    account_data_len: usize,
+    vectors: []const FeesSysvarTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_fees_vectors.json"),
+        .{},
+    );
+}
@@ -0,0 +1,29 @@
+const std = @import("std");
+
+pub const AccountFixture = struct {
+    pubkey: [32]u8,
+    is_signer: u8,
+    is_writable: u8,
+};
+
+pub const NonceInstructionTestVector = struct {
+    label: []const u8,
+    discriminant: u32,
+    nonce_account: [32]u8,
+    authority: ?[32]u8,
+    accounts: []const AccountFixture,
+    data: []const u8,
+};
+
+pub const Fixture = struct {
+    vectors: []const NonceInstructionTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_nonce_instruction_vectors.json"),
+        .{},
+    );
+}
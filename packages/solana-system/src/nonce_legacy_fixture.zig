@@ -0,0 +1,24 @@
+const std = @import("std");
+
+pub const NonceLegacyTestVector = struct {
+    name: []const u8,
+    authority: [32]u8,
+    blockhash: [32]u8,
+    lamports_per_signature: u64,
+    legacy_encoded: []const u8,
+    upgraded_encoded: []const u8,
+    upgraded_durable_nonce: [32]u8,
+};
+
+pub const Fixture = struct {
+    vectors: []const NonceLegacyTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_nonce_legacy_vectors.json"),
+        .{},
+    );
+}
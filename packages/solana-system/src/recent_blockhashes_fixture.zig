@@ -0,0 +1,28 @@
+const std = @import("std");
+
+pub const EntryVector = struct {
+    blockhash: [32]u8,
+    lamports_per_signature: u64,
+};
+
+pub const RecentBlockhashesTestVector = struct {
+    name: []const u8,
+    entries: []const EntryVector,
+    data: []const u8,
+};
+
+pub const Fixture = struct {
+    max_entries: usize,
+    entry_len: usize,
+    account_data_len: usize,
+    vectors: []const RecentBlockhashesTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_recent_blockhashes_vectors.json"),
+        .{},
+    );
+}
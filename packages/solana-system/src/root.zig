@@ -11,11 +11,19 @@ pub const RECENT_BLOCKHASHES_ID: Pubkey = sol.pubkey.comptimeFromBase58("SysvarR
 pub const RENT_ID: Pubkey = sol.rent_id;
 pub const MAX_SEED_LEN: usize = sol.pda.MAX_SEED_LEN;
 pub const NONCE_STATE_SIZE: u64 = 80;
+pub const DURABLE_NONCE_HASH_PREFIX = "DURABLE_NONCE";
 
 pub const Error = error{
     SeedTooLong,
+    InvalidNonceAccount,
+    UninitializedNonceAccount,
 };
 
+/// System Program custom-error helpers.
+pub const system_error = @import("error.zig");
+pub const SystemError = system_error.SystemError;
+pub const SystemErrorSet = system_error.Error;
+
 pub const SystemInstruction = enum(u32) {
     create_account = 0,
     assign = 1,
@@ -54,6 +62,21 @@ pub const TransferWithSeedData = [TRANSFER_WITH_SEED_DATA_CAPACITY]u8;
 pub const NonceAuthorityData = [NONCE_AUTHORITY_DATA_LEN]u8;
 pub const DiscriminantOnlyData = [DISCRIMINANT_ONLY_DATA_LEN]u8;
 
+/// `nonce::Versions` tag. Legacy accounts store the raw blockhash as their
+/// nonce; `UpgradeNonceAccount` rewrites them as `current`.
+pub const NonceVersion = enum(u32) {
+    legacy = 0,
+    current = 1,
+};
+
+/// An initialized nonce account (`Versions(State::Initialized(Data))`).
+pub const NonceAccount = struct {
+    version: NonceVersion,
+    authority: Pubkey,
+    durable_nonce: [sol.hash.HASH_BYTES]u8,
+    lamports_per_signature: u64,
+};
+
 pub fn createAccount(
     from: *const Pubkey,
     to: *const Pubkey,
@@ -282,6 +305,57 @@ pub fn upgradeNonceAccount(
     return .{ .program_id = &PROGRAM_ID, .accounts = metas, .data = data };
 }
 
+/// Durable nonce a nonce account stores after advancing while the bank's
+/// blockhash is `blockhash`: `sha256("DURABLE_NONCE" || blockhash)`.
+pub fn durableNonceFromBlockhash(blockhash: *const [sol.hash.HASH_BYTES]u8) [sol.hash.HASH_BYTES]u8 {
+    var out: [sol.hash.HASH_BYTES]u8 = undefined;
+    var hasher = std.crypto.hash.sha2.Sha256.init(.{});
+    hasher.update(DURABLE_NONCE_HASH_PREFIX);
+    hasher.update(blockhash);
+    hasher.final(&out);
+    return out;
+}
+
+/// Parse nonce account data of either version.
+pub fn parseNonceAccount(data: []const u8) Error!NonceAccount {
+    if (data.len < NONCE_STATE_SIZE) return error.InvalidNonceAccount;
+    const version: NonceVersion = switch (std.mem.readInt(u32, data[0..4], .little)) {
+        0 => .legacy,
+        1 => .current,
+        else => return error.InvalidNonceAccount,
+    };
+    switch (std.mem.readInt(u32, data[4..8], .little)) {
+        0 => return error.UninitializedNonceAccount,
+        1 => {},
+        else => return error.InvalidNonceAccount,
+    }
+    return .{
+        .version = version,
+        .authority = data[8..40].*,
+        .durable_nonce = data[40..72].*,
+        .lamports_per_signature = std.mem.readInt(u64, data[72..80], .little),
+    };
+}
+
+/// What `UpgradeNonceAccount` stores for a legacy account: the same data
+/// with the raw blockhash moved into the durable-nonce domain. Returns
+/// null for accounts that are already current.
+pub fn upgradedNonceAccount(account: NonceAccount) ?NonceAccount {
+    if (account.version == .current) return null;
+    var upgraded = account;
+    upgraded.version = .current;
+    upgraded.durable_nonce = durableNonceFromBlockhash(&account.durable_nonce);
+    return upgraded;
+}
+
+pub fn writeNonceAccount(account: *const NonceAccount, out: *[NONCE_STATE_SIZE]u8) void {
+    std.mem.writeInt(u32, out[0..4], @intFromEnum(account.version), .little);
+    std.mem.writeInt(u32, out[4..8], 1, .little);
+    @memcpy(out[8..40], &account.authority);
+    @memcpy(out[40..72], &account.durable_nonce);
+    std.mem.writeInt(u64, out[72..80], account.lamports_per_signature, .little);
+}
+
 fn writeDiscriminant(tag: SystemInstruction, data: []u8) void {
     std.debug.assert(data.len >= 4);
     std.mem.writeInt(u32, data[0..4], @intFromEnum(tag), .little);
@@ -358,6 +432,34 @@ test "createAccount builds canonical system create-account instruction" {
     try std.testing.expectEqual(@as(u8, 1), ix.accounts[1].is_signer);
 }
 
+test "createAccount funds the rent-exempt minimum for the requested space" {
+    const funding_fixture = @import("create_account_funding_fixture.zig");
+    var parsed = try funding_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+
+    const from: Pubkey = .{1} ** sol.PUBKEY_BYTES;
+    const to: Pubkey = .{2} ** sol.PUBKEY_BYTES;
+    const owner: Pubkey = .{3} ** sol.PUBKEY_BYTES;
+    const rent: sol.rent.Rent.Data = .{};
+
+    for (parsed.value.vectors) |vector| {
+        const lamports = rent.getMinimumBalance(vector.space);
+        try std.testing.expectEqual(vector.minimum_balance, lamports);
+        try std.testing.expect(rent.isExempt(lamports, vector.space));
+        // One lamport short still creates the account, but leaves it rent-paying.
+        try std.testing.expect(!rent.isExempt(lamports - 1, vector.space));
+
+        var metas: [2]AccountMeta = undefined;
+        var data: CreateAccountData = undefined;
+        const ix = createAccount(&from, &to, lamports, vector.space, &owner, &metas, &data);
+        try std.testing.expectEqualSlices(u8, vector.data, ix.data);
+        try std.testing.expectEqualSlices(u8, &from, ix.accounts[0].pubkey);
+        try std.testing.expectEqual(@as(u8, 1), ix.accounts[0].is_writable);
+        try std.testing.expectEqualSlices(u8, &to, ix.accounts[1].pubkey);
+        try std.testing.expectEqual(@as(u8, 1), ix.accounts[1].is_writable);
+    }
+}
+
 test "assign and allocate build canonical payloads" {
     const account: Pubkey = .{1} ** sol.PUBKEY_BYTES;
     const owner: Pubkey = .{9} ** sol.PUBKEY_BYTES;
@@ -468,6 +570,260 @@ test "nonce discriminant-only builders encode canonical tags" {
     try std.testing.expectEqual(@as(u8, 1), upgrade_ix.accounts[0].is_writable);
 }
 
+test "initialize and upgrade nonce instructions match official Rust vectors" {
+    const nonce_instruction_fixture = @import("nonce_instruction_fixture.zig");
+    var parsed = try nonce_instruction_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+
+    var init_metas: [3]AccountMeta = undefined;
+    var authority_data: NonceAuthorityData = undefined;
+    var upgrade_metas: [1]AccountMeta = undefined;
+    var upgrade_data: DiscriminantOnlyData = undefined;
+    for (parsed.value.vectors) |*vector| {
+        const ix = if (vector.authority) |*authority| blk: {
+            try std.testing.expectEqual(@intFromEnum(SystemInstruction.initialize_nonce_account), vector.discriminant);
+            break :blk initializeNonceAccount(&vector.nonce_account, &RECENT_BLOCKHASHES_ID, &RENT_ID, authority, &init_metas, &authority_data);
+        } else blk: {
+            try std.testing.expectEqual(@intFromEnum(SystemInstruction.upgrade_nonce_account), vector.discriminant);
+            break :blk upgradeNonceAccount(&vector.nonce_account, &upgrade_metas, &upgrade_data);
+        };
+
+        try std.testing.expectEqualSlices(u8, &PROGRAM_ID, ix.program_id);
+        try std.testing.expectEqualSlices(u8, vector.data, ix.data);
+        try std.testing.expectEqual(vector.accounts.len, ix.accounts.len);
+        for (vector.accounts, ix.accounts) |expected, actual| {
+            try std.testing.expectEqualSlices(u8, &expected.pubkey, actual.pubkey);
+            try std.testing.expectEqual(expected.is_signer, actual.is_signer);
+            try std.testing.expectEqual(expected.is_writable, actual.is_writable);
+        }
+    }
+}
+
+test "integer boundary sweep matches official Rust encodings" {
+    const boundary_fixture = @import("boundary_fixture.zig");
+    var parsed = try boundary_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    try std.testing.expectEqual(@as(usize, 22), parsed.value.vectors.len);
+
+    const from: Pubkey = .{1} ** sol.PUBKEY_BYTES;
+    const to: Pubkey = .{2} ** sol.PUBKEY_BYTES;
+
+    for (parsed.value.vectors) |vector| {
+        var transfer_metas: [2]AccountMeta = undefined;
+        var transfer_data: TransferData = undefined;
+        var allocate_metas: [1]AccountMeta = undefined;
+        var allocate_data: AllocateData = undefined;
+
+        const ix = if (std.mem.eql(u8, vector.family, "system_transfer"))
+            transfer(&from, &to, vector.value, &transfer_metas, &transfer_data)
+        else if (std.mem.eql(u8, vector.family, "system_allocate"))
+            allocate(&to, vector.value, &allocate_metas, &allocate_data)
+        else
+            return error.UnknownBoundaryFamily;
+
+        try std.testing.expectEqualSlices(u8, vector.data, ix.data);
+        try std.testing.expectEqual(vector.value, std.mem.readInt(u64, ix.data[vector.offset..][0..8], .little));
+    }
+}
+
+test "slot progression and durable nonces match official Rust vectors" {
+    const slot_progression_fixture = @import("slot_progression_fixture.zig");
+    var parsed = try slot_progression_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqual(@as(usize, 5), fixture.steps.len);
+
+    // hash(slot) = sha256(hash(slot - 1) || slot_le), seeded before start_slot.
+    var prev: [sol.hash.HASH_BYTES]u8 = fixture.seed;
+    for (fixture.steps, 0..) |step, i| {
+        try std.testing.expectEqual(fixture.start_slot + i, step.slot);
+        var slot_le: [8]u8 = undefined;
+        std.mem.writeInt(u64, &slot_le, step.slot, .little);
+        var hasher = std.crypto.hash.sha2.Sha256.init(.{});
+        hasher.update(&prev);
+        hasher.update(&slot_le);
+        hasher.final(&prev);
+        try std.testing.expectEqualSlices(u8, &step.blockhash, &prev);
+
+        const durable_nonce = durableNonceFromBlockhash(&step.blockhash);
+        try std.testing.expectEqualSlices(u8, &step.durable_nonce, &durable_nonce);
+
+        // nonce::Versions::Current(State::Initialized(Data { authority,
+        // durable_nonce, fee_calculator })) as written by AdvanceNonceAccount.
+        const data = step.nonce_account_data;
+        try std.testing.expectEqual(NONCE_STATE_SIZE, data.len);
+        try std.testing.expectEqual(@as(u32, 1), std.mem.readInt(u32, data[0..4], .little));
+        try std.testing.expectEqual(@as(u32, 1), std.mem.readInt(u32, data[4..8], .little));
+        try std.testing.expectEqualSlices(u8, &fixture.nonce_authority, data[8..40]);
+        try std.testing.expectEqualSlices(u8, &durable_nonce, data[40..72]);
+        try std.testing.expectEqual(fixture.lamports_per_signature, std.mem.readInt(u64, data[72..80], .little));
+    }
+
+    const entries = sol.slot_hashes.SlotHash.from(fixture.slot_hashes_data);
+    try std.testing.expectEqual(fixture.steps.len, entries.len);
+    for (entries, 0..) |entry, i| {
+        // SlotHashes is newest first.
+        const step = fixture.steps[fixture.steps.len - 1 - i];
+        try std.testing.expectEqual(step.slot, entry.slot);
+        try std.testing.expectEqualSlices(u8, &step.blockhash, &entry.hash);
+    }
+}
+
+test "legacy nonce accounts upgrade like the official Rust vectors" {
+    const nonce_legacy_fixture = @import("nonce_legacy_fixture.zig");
+    var parsed = try nonce_legacy_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    try std.testing.expectEqual(@as(usize, 2), parsed.value.vectors.len);
+
+    for (parsed.value.vectors) |vector| {
+        const legacy = try parseNonceAccount(vector.legacy_encoded);
+        try std.testing.expectEqual(NonceVersion.legacy, legacy.version);
+        try std.testing.expectEqualSlices(u8, &vector.authority, &legacy.authority);
+        try std.testing.expectEqualSlices(u8, &vector.blockhash, &legacy.durable_nonce);
+        try std.testing.expectEqual(vector.lamports_per_signature, legacy.lamports_per_signature);
+
+        const upgraded = upgradedNonceAccount(legacy).?;
+        try std.testing.expectEqualSlices(u8, &vector.upgraded_durable_nonce, &upgraded.durable_nonce);
+        var encoded: [NONCE_STATE_SIZE]u8 = undefined;
+        writeNonceAccount(&upgraded, &encoded);
+        try std.testing.expectEqualSlices(u8, vector.upgraded_encoded, &encoded);
+        writeNonceAccount(&legacy, &encoded);
+        try std.testing.expectEqualSlices(u8, vector.legacy_encoded, &encoded);
+
+        try std.testing.expectEqual(upgraded, try parseNonceAccount(vector.upgraded_encoded));
+        try std.testing.expectEqual(@as(?NonceAccount, null), upgradedNonceAccount(upgraded));
+    }
+
+    // The first vector is a legacy copy of the first slot-progression nonce.
+    const slot_progression_fixture = @import("slot_progression_fixture.zig");
+    var progression = try slot_progression_fixture.load(std.testing.allocator);
+    defer progression.deinit();
+    try std.testing.expectEqualSlices(
+        u8,
+        progression.value.steps[0].nonce_account_data,
+        parsed.value.vectors[0].upgraded_encoded,
+    );
+
+    var uninitialized = [_]u8{0} ** NONCE_STATE_SIZE;
+    try std.testing.expectError(error.UninitializedNonceAccount, parseNonceAccount(&uninitialized));
+    uninitialized[0] = 2;
+    try std.testing.expectError(error.InvalidNonceAccount, parseNonceAccount(&uninitialized));
+    try std.testing.expectError(error.InvalidNonceAccount, parseNonceAccount(uninitialized[0..8]));
+}
+
+test "recent blockhashes sysvar data matches official Rust vectors" {
+    const recent_blockhashes_fixture = @import("recent_blockhashes_fixture.zig");
+    var parsed = try recent_blockhashes_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    const Entry = sol.recent_blockhashes.Entry;
+    try std.testing.expectEqual(@as(usize, sol.recent_blockhashes.RecentBlockhashes.max_entries), fixture.max_entries);
+    try std.testing.expectEqual(@as(usize, @sizeOf(Entry)), fixture.entry_len);
+    try std.testing.expectEqual(@sizeOf(u64) + fixture.max_entries * @sizeOf(Entry), fixture.account_data_len);
+
+    for (fixture.vectors) |vector| {
+        try std.testing.expectEqual(@sizeOf(u64) + vector.entries.len * @sizeOf(Entry), vector.data.len);
+        const entries = Entry.from(vector.data);
+        try std.testing.expectEqual(vector.entries.len, entries.len);
+        for (vector.entries, entries) |expected, entry| {
+            try std.testing.expectEqualSlices(u8, &expected.blockhash, &entry.blockhash);
+            try std.testing.expectEqual(expected.lamports_per_signature, entry.lamports_per_signature);
+        }
+    }
+
+    const full = fixture.vectors[fixture.vectors.len - 1];
+    try std.testing.expectEqual(fixture.max_entries, full.entries.len);
+    try std.testing.expectEqual(fixture.account_data_len, full.data.len);
+}
+
+test "full SlotHashes sysvar data matches official Rust vectors" {
+    const slot_hashes_fixture = @import("slot_hashes_fixture.zig");
+    var parsed = try slot_hashes_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    const SlotHash = sol.slot_hashes.SlotHash;
+    try std.testing.expectEqual(@as(usize, sol.slot_hashes.SlotHashes.max_entries), fixture.max_entries);
+    try std.testing.expectEqual(@as(usize, @sizeOf(SlotHash)), fixture.entry_len);
+    try std.testing.expectEqual(@sizeOf(u64) + fixture.max_entries * @sizeOf(SlotHash), fixture.account_data_len);
+
+    for (fixture.vectors) |vector| {
+        try std.testing.expectEqual(@sizeOf(u64) + vector.entries.len * @sizeOf(SlotHash), vector.data.len);
+        const entries = SlotHash.from(vector.data);
+        try std.testing.expectEqual(vector.entries.len, entries.len);
+        for (vector.entries, entries) |expected, entry| {
+            try std.testing.expectEqual(expected.slot, entry.slot);
+            try std.testing.expectEqualSlices(u8, &expected.hash, &entry.hash);
+        }
+    }
+
+    const full = fixture.vectors[fixture.vectors.len - 1];
+    try std.testing.expectEqual(fixture.max_entries, full.entries.len);
+    try std.testing.expectEqual(fixture.account_data_len, full.data.len);
+}
+
+test "SlotHistory bitvec data and checks match official Rust vectors" {
+    const slot_history_fixture = @import("slot_history_fixture.zig");
+    var parsed = try slot_history_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqual(fixture.max_entries / 64, @as(u64, fixture.block_count));
+    try std.testing.expectEqual(fixture.blocks_offset + fixture.block_count * 8, fixture.bit_len_offset);
+    try std.testing.expectEqual(fixture.next_slot_offset + 8, fixture.data_len);
+
+    const data = try std.testing.allocator.alloc(u8, fixture.data_len);
+    defer std.testing.allocator.free(data);
+
+    for (fixture.vectors) |vector| {
+        // Option<Box<[u64]>> tag and block count, blocks, bit length, next_slot.
+        @memset(data, 0);
+        data[0] = 1;
+        std.mem.writeInt(u64, data[1..9], fixture.block_count, .little);
+        for (vector.set_blocks) |block| {
+            std.mem.writeInt(u64, data[fixture.blocks_offset + block.index * 8 ..][0..8], block.value, .little);
+        }
+        std.mem.writeInt(u64, data[fixture.bit_len_offset..][0..8], fixture.max_entries, .little);
+        std.mem.writeInt(u64, data[fixture.next_slot_offset..][0..8], vector.next_slot, .little);
+
+        var digest: [32]u8 = undefined;
+        std.crypto.hash.sha2.Sha256.hash(data, &digest, .{});
+        try std.testing.expectEqualSlices(u8, &vector.data_sha256, &digest);
+
+        // SlotHistory::check over the rebuilt bytes.
+        const newest = vector.next_slot - 1;
+        const oldest = vector.next_slot -| fixture.max_entries;
+        for (vector.checks) |case| {
+            const check: []const u8 = if (case.slot > newest)
+                "future"
+            else if (case.slot < oldest)
+                "too_old"
+            else blk: {
+                const bit = case.slot % fixture.max_entries;
+                const block_index: usize = @intCast(bit / 64);
+                const block = std.mem.readInt(u64, data[fixture.blocks_offset + block_index * 8 ..][0..8], .little);
+                const shift: u6 = @intCast(bit % 64);
+                break :blk if ((block >> shift) & 1 == 1) "found" else "not_found";
+            };
+            try std.testing.expectEqualStrings(case.check, check);
+        }
+    }
+}
+
+test "fees sysvar data matches official Rust vectors" {
+    const fees_fixture = @import("fees_fixture.zig");
+    var parsed = try fees_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    const fees_id = sol.pubkey.comptimeFromBase58("SysvarFees111111111111111111111111111111111");
+    try std.testing.expectEqualSlices(u8, &fees_id, &fixture.sysvar_id);
+    try std.testing.expectEqual(@as(usize, @sizeOf(u64)), fixture.account_data_len);
+
+    for (fixture.vectors) |vector| {
+        var data: [@sizeOf(u64)]u8 = undefined;
+        std.mem.writeInt(u64, &data, vector.lamports_per_signature, .little);
+        try std.testing.expectEqualSlices(u8, vector.data, &data);
+    }
+}
+
 test "public surface guards" {
     try std.testing.expect(@hasDecl(@This(), "createAccount"));
     try std.testing.expect(@hasDecl(@This(), "transfer"));
@@ -476,6 +832,20 @@ test "public surface guards" {
     try std.testing.expect(@hasDecl(@This(), "createAccountWithSeed"));
     try std.testing.expect(@hasDecl(@This(), "initializeNonceAccount"));
     try std.testing.expect(@hasDecl(@This(), "withdrawNonceAccount"));
+    try std.testing.expect(@hasDecl(@This(), "durableNonceFromBlockhash"));
+    try std.testing.expect(@hasDecl(@This(), "parseNonceAccount"));
+    try std.testing.expect(@hasDecl(@This(), "upgradedNonceAccount"));
+    try std.testing.expect(@hasDecl(@This(), "SystemError"));
     try std.testing.expect(!@hasDecl(@This(), "rpc"));
     try std.testing.expect(!@hasDecl(@This(), "wallet"));
 }
+
+test {
+    std.testing.refAllDecls(@This());
+}
+
+test "NONCE_STATE_SIZE matches the official nonce account size" {
+    var parsed = try @import("rent_vectors").loadStandardAccountMinimums(std.testing.allocator);
+    defer parsed.deinit();
+    try std.testing.expectEqual(NONCE_STATE_SIZE, parsed.value.get("nonce").?.data_len);
+}
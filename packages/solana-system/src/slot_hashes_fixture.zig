@@ -0,0 +1,28 @@
+const std = @import("std");
+
+pub const SlotHashVector = struct {
+    slot: u64,
+    hash: [32]u8,
+};
+
+pub const SlotHashesTestVector = struct {
+    name: []const u8,
+    entries: []const SlotHashVector,
+    data: []const u8,
+};
+
+pub const Fixture = struct {
+    max_entries: usize,
+    entry_len: usize,
+    account_data_len: usize,
+    vectors: []const SlotHashesTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_slot_hashes_vectors.json"),
+        .{},
+    );
+}
@@ -0,0 +1,39 @@
+const std = @import("std");
+
+pub const BlockVector = struct {
+    index: usize,
+    value: u64,
+};
+
+pub const CheckVector = struct {
+    slot: u64,
+    check: []const u8,
+};
+
+pub const SlotHistoryDataTestVector = struct {
+    name: []const u8,
+    added_slots: []const u64,
+    next_slot: u64,
+    set_blocks: []const BlockVector,
+    data_sha256: [32]u8,
+    checks: []const CheckVector,
+};
+
+pub const Fixture = struct {
+    max_entries: u64,
+    block_count: usize,
+    blocks_offset: usize,
+    bit_len_offset: usize,
+    next_slot_offset: usize,
+    data_len: usize,
+    vectors: []const SlotHistoryDataTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_slot_history_vectors.json"),
+        .{},
+    );
+}
@@ -0,0 +1,26 @@
+const std = @import("std");
+
+pub const SlotStep = struct {
+    slot: u64,
+    blockhash: [32]u8,
+    durable_nonce: [32]u8,
+    nonce_account_data: []const u8,
+};
+
+pub const Fixture = struct {
+    seed: [32]u8,
+    start_slot: u64,
+    lamports_per_signature: u64,
+    nonce_authority: [32]u8,
+    steps: []const SlotStep,
+    slot_hashes_data: []const u8,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_slot_progression_vectors.json"),
+        .{},
+    );
+}
@@ -0,0 +1,22 @@
+const std = @import("std");
+
+pub const SystemErrorTestVector = struct {
+    name: []const u8,
+    code: u32,
+    message: []const u8,
+    instruction_error: []const u8,
+};
+
+pub const Fixture = struct {
+    custom_variant_index: u32,
+    vectors: []const SystemErrorTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_system_error_vectors.json"),
+        .{},
+    );
+}
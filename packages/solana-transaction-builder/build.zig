@@ -6,6 +6,12 @@ pub fn build(b: *std.Build) void {
     const target = b.standardTargetOptions(.{});
     const optimize = b.standardOptimizeOption(.{});
 
+    const sol_dep = b.dependency("solana_program_sdk", .{
+        .target = target,
+        .optimize = optimize,
+    });
+    const sol_mod = sol_dep.module("solana_program_sdk");
+
     const tx_dep = b.dependency("solana_tx", .{
         .target = target,
         .optimize = optimize,
@@ -65,6 +71,7 @@ pub fn build(b: *std.Build) void {
         .target = target,
         .optimize = optimize,
         .imports = &.{
+            .{ .name = "solana_program_sdk", .module = sol_mod },
             .{ .name = "solana_tx", .module = tx_mod },
             .{ .name = "solana_keypair", .module = keypair_mod },
             .{ .name = "solana_system", .module = system_mod },
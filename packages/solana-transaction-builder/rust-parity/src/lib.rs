@@ -0,0 +1 @@
+// Rust parity is exercised by integration tests.
@@ -0,0 +1,118 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_message::v0::MessageAddressTableLookup;
+use solana_pubkey::Pubkey;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_address_table_lookup_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AddressTableLookupTestVector {
+    name: String,
+    account_key: [u8; 32],
+    writable_indexes: Vec<u8>,
+    readonly_indexes: Vec<u8>,
+    /// bincode `MessageAddressTableLookup`: the key, then each index list
+    /// behind a short-vec length, as embedded in a v0 message.
+    serialized: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<AddressTableLookupTestVector>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+fn vector(name: &str, writable: Vec<u8>, readonly: Vec<u8>) -> AddressTableLookupTestVector {
+    let lookup = MessageAddressTableLookup {
+        account_key: key(0x30),
+        writable_indexes: writable,
+        readonly_indexes: readonly,
+    };
+    AddressTableLookupTestVector {
+        name: name.to_string(),
+        account_key: lookup.account_key.to_bytes(),
+        serialized: bincode::serialize(&lookup).unwrap(),
+        writable_indexes: lookup.writable_indexes,
+        readonly_indexes: lookup.readonly_indexes,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        vectors: vec![
+            vector("empty", Vec::new(), Vec::new()),
+            vector("single_writable", vec![3], Vec::new()),
+            vector("single_readonly", Vec::new(), vec![200]),
+            vector("single_each", vec![0], vec![255]),
+            // 127 is the longest list with a one-byte length.
+            vector("writable_127", (0..127).collect(), vec![127]),
+            vector("writable_128", (0..128).collect(), Vec::new()),
+            vector("readonly_256", Vec::new(), (0..=255).collect()),
+            vector(
+                "both_two_byte",
+                (0..130).collect(),
+                (0..=255).rev().collect(),
+            ),
+        ],
+    }
+}
+
+/// Short-vec length prefix: seven bits per byte, high bit set on all but
+/// the last.
+fn short_vec(mut len: usize) -> Vec<u8> {
+    let mut out = Vec::new();
+    loop {
+        let byte = (len & 0x7f) as u8;
+        len >>= 7;
+        if len == 0 {
+            out.push(byte);
+            return out;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+#[test]
+fn fixture_matches_official_address_table_lookup_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_address_table_lookup_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn serialized_is_key_then_short_vec_index_lists() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    assert!(fixture
+        .vectors
+        .iter()
+        .any(|vector| vector.readonly_indexes.len() > 127));
+    for vector in &fixture.vectors {
+        let mut expected = vector.account_key.to_vec();
+        expected.extend(short_vec(vector.writable_indexes.len()));
+        expected.extend(&vector.writable_indexes);
+        expected.extend(short_vec(vector.readonly_indexes.len()));
+        expected.extend(&vector.readonly_indexes);
+        assert_eq!(vector.serialized, expected, "{}", vector.name);
+
+        let lookup: MessageAddressTableLookup = bincode::deserialize(&vector.serialized).unwrap();
+        assert_eq!(lookup.writable_indexes, vector.writable_indexes);
+        assert_eq!(lookup.readonly_indexes, vector.readonly_indexes);
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_address_table_lookup_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
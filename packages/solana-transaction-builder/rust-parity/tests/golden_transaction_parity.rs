@@ -0,0 +1,235 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use solana_compute_budget::compute_budget_limits::ComputeBudgetLimits;
+use solana_compute_budget_interface::ComputeBudgetInstruction;
+use solana_fee_structure::FeeStructure;
+use solana_hash::Hash;
+use solana_instruction::Instruction;
+use solana_keypair::Keypair;
+use solana_message::Message;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+use solana_system_interface::instruction as system_instruction;
+use solana_transaction::Transaction;
+use spl_associated_token_account_interface::{
+    address::get_associated_token_address, instruction::create_associated_token_account_idempotent,
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_golden_transactions.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct GoldenTransaction {
+    label: String,
+    instructions: Vec<String>,
+    account_keys: Vec<[u8; 32]>,
+    transaction: Vec<u8>,
+    transaction_base64: String,
+    /// `ComputeBudgetLimits::get_prioritization_fee` for the transaction's
+    /// compute budget instructions; 0 without them.
+    prioritization_fee: u64,
+    fee: u64,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    payer_seed: [u8; 32],
+    recipient_seed: [u8; 32],
+    mint: [u8; 32],
+    nonce_account: [u8; 32],
+    recent_blockhash: [u8; 32],
+    durable_nonce: [u8; 32],
+    /// `FeeStructure::default().lamports_per_signature`.
+    lamports_per_signature: u64,
+    /// The compute budget the `sol-transfer-with-compute-budget` entry sets.
+    compute_unit_limit: u32,
+    compute_unit_price: u64,
+    golden_transactions: Vec<GoldenTransaction>,
+}
+
+fn keypair(seed: [u8; 32]) -> Keypair {
+    Keypair::new_from_array(seed)
+}
+
+fn golden(
+    label: &str,
+    description: &[&str],
+    payer: &Keypair,
+    blockhash: [u8; 32],
+    instructions: &[Instruction],
+    prioritization_fee: u64,
+) -> GoldenTransaction {
+    let message = Message::new_with_blockhash(
+        instructions,
+        Some(&payer.pubkey()),
+        &Hash::new_from_array(blockhash),
+    );
+    let transaction = Transaction::new(&[payer], message, Hash::new_from_array(blockhash));
+    let bytes = bincode::serialize(&transaction).unwrap();
+    let signatures = u64::from(transaction.message.header.num_required_signatures);
+
+    GoldenTransaction {
+        label: label.to_string(),
+        instructions: description.iter().map(|step| step.to_string()).collect(),
+        account_keys: transaction
+            .message
+            .account_keys
+            .iter()
+            .map(Pubkey::to_bytes)
+            .collect(),
+        transaction_base64: STANDARD.encode(&bytes),
+        transaction: bytes,
+        prioritization_fee,
+        fee: signatures * FeeStructure::default().lamports_per_signature + prioritization_fee,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let payer_seed = [1; 32];
+    let recipient_seed = [2; 32];
+    let mint = Pubkey::from([7; 32]);
+    let nonce_account = Pubkey::from([6; 32]);
+    let recent_blockhash = [9; 32];
+    let durable_nonce = [8; 32];
+
+    let payer = keypair(payer_seed);
+    let payer_pubkey = payer.pubkey();
+    let recipient = keypair(recipient_seed).pubkey();
+    let payer_ata = get_associated_token_address(&payer_pubkey, &mint);
+    let recipient_ata = get_associated_token_address(&recipient, &mint);
+
+    let transfer = system_instruction::transfer(&payer_pubkey, &recipient, 1_000_000);
+    let token_transfer = spl_token_interface::instruction::transfer(
+        &spl_token_interface::ID,
+        &payer_ata,
+        &recipient_ata,
+        &payer_pubkey,
+        &[],
+        42,
+    )
+    .unwrap();
+
+    let compute_unit_limit = 200_000u32;
+    let compute_unit_price = 10_000u64;
+    let prioritization_fee = ComputeBudgetLimits {
+        compute_unit_limit,
+        compute_unit_price,
+        ..ComputeBudgetLimits::default()
+    }
+    .get_prioritization_fee();
+
+    let golden_transactions = vec![
+        golden(
+            "sol-transfer",
+            &["system.transfer"],
+            &payer,
+            recent_blockhash,
+            std::slice::from_ref(&transfer),
+            0,
+        ),
+        golden(
+            "sol-transfer-with-compute-budget",
+            &[
+                "compute_budget.set_compute_unit_limit",
+                "compute_budget.set_compute_unit_price",
+                "system.transfer",
+            ],
+            &payer,
+            recent_blockhash,
+            &[
+                ComputeBudgetInstruction::set_compute_unit_limit(compute_unit_limit),
+                ComputeBudgetInstruction::set_compute_unit_price(compute_unit_price),
+                transfer.clone(),
+            ],
+            prioritization_fee,
+        ),
+        golden(
+            "spl-token-transfer-between-atas",
+            &["spl_token.transfer"],
+            &payer,
+            recent_blockhash,
+            std::slice::from_ref(&token_transfer),
+            0,
+        ),
+        golden(
+            "create-ata-then-spl-token-transfer",
+            &[
+                "spl_associated_token_account.create_idempotent",
+                "spl_token.transfer",
+            ],
+            &payer,
+            recent_blockhash,
+            &[
+                create_associated_token_account_idempotent(
+                    &payer_pubkey,
+                    &recipient,
+                    &mint,
+                    &spl_token_interface::ID,
+                ),
+                token_transfer,
+            ],
+            0,
+        ),
+        golden(
+            "durable-nonce-sol-transfer",
+            &["system.advance_nonce_account", "system.transfer"],
+            &payer,
+            durable_nonce,
+            &[
+                system_instruction::advance_nonce_account(&nonce_account, &payer_pubkey),
+                transfer,
+            ],
+            0,
+        ),
+    ];
+
+    Fixture {
+        payer_seed,
+        recipient_seed,
+        mint: mint.to_bytes(),
+        nonce_account: nonce_account.to_bytes(),
+        recent_blockhash,
+        durable_nonce,
+        lamports_per_signature: FeeStructure::default().lamports_per_signature,
+        compute_unit_limit,
+        compute_unit_price,
+        golden_transactions,
+    }
+}
+
+#[test]
+fn fixture_matches_official_golden_transactions() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_golden_transactions.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn golden_transactions_are_fully_signed_and_decodable() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let payer = keypair(fixture.payer_seed).pubkey();
+
+    for case in &fixture.golden_transactions {
+        let transaction: Transaction = bincode::deserialize(&case.transaction).unwrap();
+        assert!(transaction.is_signed(), "{}", case.label);
+        transaction.verify().unwrap();
+        assert_eq!(transaction.message.account_keys[0], payer, "{}", case.label);
+        assert_eq!(
+            STANDARD.decode(&case.transaction_base64).unwrap(),
+            case.transaction,
+            "{}",
+            case.label
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_golden_transactions() {
+    println!("{}", serde_json::to_string_pretty(&official_fixture()).unwrap());
+}
@@ -0,0 +1,138 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+use solana_rent::Rent;
+use solana_system_interface::instruction::{self as system_instruction, SystemInstruction};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_nonce_account_pair_vectors.json");
+
+/// `solana_nonce::state::State::size()`.
+const NONCE_STATE_SIZE: usize = 80;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AccountFixture {
+    pubkey: [u8; 32],
+    is_signer: u8,
+    is_writable: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct InstructionFixture {
+    program_id: [u8; 32],
+    accounts: Vec<AccountFixture>,
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct NonceAccountPairTestVector {
+    label: String,
+    payer: [u8; 32],
+    nonce_account: [u8; 32],
+    authority: [u8; 32],
+    /// `Rent::default().minimum_balance(80)`.
+    lamports: u64,
+    /// `CreateAccount` then `InitializeNonceAccount`, in transaction order.
+    instructions: Vec<InstructionFixture>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    nonce_state_size: usize,
+    vectors: Vec<NonceAccountPairTestVector>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+fn instruction_fixture(ix: &Instruction) -> InstructionFixture {
+    InstructionFixture {
+        program_id: ix.program_id.to_bytes(),
+        accounts: ix
+            .accounts
+            .iter()
+            .map(|meta| AccountFixture {
+                pubkey: meta.pubkey.to_bytes(),
+                is_signer: u8::from(meta.is_signer),
+                is_writable: u8::from(meta.is_writable),
+            })
+            .collect(),
+        data: ix.data.clone(),
+    }
+}
+
+fn vector(
+    label: &str,
+    payer: Pubkey,
+    nonce: Pubkey,
+    authority: Pubkey,
+) -> NonceAccountPairTestVector {
+    let lamports = Rent::default().minimum_balance(NONCE_STATE_SIZE);
+    let instructions =
+        system_instruction::create_nonce_account(&payer, &nonce, &authority, lamports);
+    NonceAccountPairTestVector {
+        label: label.to_string(),
+        payer: payer.to_bytes(),
+        nonce_account: nonce.to_bytes(),
+        authority: authority.to_bytes(),
+        lamports,
+        instructions: instructions.iter().map(instruction_fixture).collect(),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        nonce_state_size: NONCE_STATE_SIZE,
+        vectors: vec![
+            vector("separate_authority", key(1), key(2), key(3)),
+            vector("payer_authority", key(1), key(2), key(1)),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_nonce_account_pair_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_nonce_account_pair_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn pair_is_create_account_then_initialize_nonce_account() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        assert_eq!(vector.lamports, 1_447_680);
+        let [create, initialize] = vector.instructions.as_slice() else {
+            panic!("{}: expected two instructions", vector.label);
+        };
+        let create: SystemInstruction = bincode::deserialize(&create.data).unwrap();
+        assert_eq!(
+            create,
+            SystemInstruction::CreateAccount {
+                lamports: vector.lamports,
+                space: fixture.nonce_state_size as u64,
+                owner: solana_system_interface::program::ID,
+            }
+        );
+        let initialize: SystemInstruction = bincode::deserialize(&initialize.data).unwrap();
+        assert_eq!(
+            initialize,
+            SystemInstruction::InitializeNonceAccount(Pubkey::from(vector.authority))
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_nonce_account_pair_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,128 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_hash::Hash;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_keypair::Keypair;
+use solana_message::Message;
+use solana_packet::PACKET_DATA_SIZE;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_packet_size_vectors.json");
+
+const PAYER_SEED: [u8; 32] = [3; 32];
+const RECENT_BLOCKHASH: [u8; 32] = [9; 32];
+const MEMO_PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct PacketSizeVector {
+    label: String,
+    /// Length of the memo data padded to hit `serialized_len`.
+    memo_len: usize,
+    serialized_len: usize,
+    /// `serialized_len > PACKET_DATA_SIZE`.
+    exceeds_limit: bool,
+    /// bincode `Transaction`, signed by the payer.
+    transaction: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    packet_data_size: usize,
+    payer_seed: [u8; 32],
+    recent_blockhash: [u8; 32],
+    vectors: Vec<PacketSizeVector>,
+}
+
+/// ASCII `a..z` repeated, so the data stays a valid UTF-8 memo.
+fn memo_data(len: usize) -> Vec<u8> {
+    (0..len).map(|i| b'a' + (i % 26) as u8).collect()
+}
+
+/// A memo signed by the payer: one signature, two keys, one instruction.
+fn memo_transaction(payer: &Keypair, memo_len: usize) -> Transaction {
+    let instruction = Instruction::new_with_bytes(
+        MEMO_PROGRAM_ID,
+        &memo_data(memo_len),
+        vec![AccountMeta::new_readonly(payer.pubkey(), true)],
+    );
+    let blockhash = Hash::new_from_array(RECENT_BLOCKHASH);
+    let message = Message::new_with_blockhash(&[instruction], Some(&payer.pubkey()), &blockhash);
+    Transaction::new(&[payer], message, blockhash)
+}
+
+/// Pads the memo until the signed transaction is exactly `target` bytes.
+fn vector(label: &str, payer: &Keypair, target: usize) -> PacketSizeVector {
+    let (memo_len, transaction) = (0..PACKET_DATA_SIZE)
+        .map(|memo_len| {
+            let transaction = bincode::serialize(&memo_transaction(payer, memo_len)).unwrap();
+            (memo_len, transaction)
+        })
+        .find(|(_, transaction)| transaction.len() == target)
+        .unwrap();
+    PacketSizeVector {
+        label: label.to_string(),
+        memo_len,
+        serialized_len: transaction.len(),
+        exceeds_limit: transaction.len() > PACKET_DATA_SIZE,
+        transaction,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let payer = Keypair::new_from_array(PAYER_SEED);
+    Fixture {
+        packet_data_size: PACKET_DATA_SIZE,
+        payer_seed: PAYER_SEED,
+        recent_blockhash: RECENT_BLOCKHASH,
+        vectors: vec![
+            vector("one_below_limit", &payer, PACKET_DATA_SIZE - 1),
+            vector("exactly_at_limit", &payer, PACKET_DATA_SIZE),
+            vector("one_over_limit", &payer, PACKET_DATA_SIZE + 1),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_packet_size_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_packet_size_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn only_transactions_past_packet_data_size_exceed_the_limit() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    assert_eq!(fixture.packet_data_size, 1232);
+    for vector in &fixture.vectors {
+        let transaction: Transaction = bincode::deserialize(&vector.transaction).unwrap();
+        assert!(transaction.verify().is_ok(), "{}", vector.label);
+        assert_eq!(
+            transaction.message.instructions[0].data.len(),
+            vector.memo_len
+        );
+        assert_eq!(vector.transaction.len(), vector.serialized_len);
+        assert_eq!(
+            vector.exceeds_limit,
+            vector.label == "one_over_limit",
+            "{}",
+            vector.label
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_packet_size_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,126 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_hash::Hash;
+use solana_keypair::Keypair;
+use solana_message::Message;
+use solana_signature::Signature;
+use solana_signer::Signer;
+use solana_system_interface::instruction as system_instruction;
+use solana_transaction::Transaction;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_partially_signed_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct PartiallySignedVector {
+    label: String,
+    /// Which signature slots hold a real signature rather than
+    /// `Signature::default()`.
+    signed_slots: Vec<bool>,
+    is_signed: bool,
+    /// bincode `Transaction`.
+    transaction: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    /// Fee payer: signature slot 0.
+    first_seed: [u8; 32],
+    /// Transfers back to the payer: signature slot 1.
+    second_seed: [u8; 32],
+    recent_blockhash: [u8; 32],
+    message_data: Vec<u8>,
+    vectors: Vec<PartiallySignedVector>,
+}
+
+const FIRST_SEED: [u8; 32] = [1; 32];
+const SECOND_SEED: [u8; 32] = [2; 32];
+const RECENT_BLOCKHASH: [u8; 32] = [9; 32];
+
+/// A 2-of-2 message: both keys move lamports, so both must sign.
+fn message(first: &Keypair, second: &Keypair) -> Message {
+    Message::new_with_blockhash(
+        &[
+            system_instruction::transfer(&first.pubkey(), &second.pubkey(), 1_000_000),
+            system_instruction::transfer(&second.pubkey(), &first.pubkey(), 500),
+        ],
+        Some(&first.pubkey()),
+        &Hash::new_from_array(RECENT_BLOCKHASH),
+    )
+}
+
+fn vector(label: &str, message: &Message, signers: &[&Keypair]) -> PartiallySignedVector {
+    let mut transaction = Transaction::new_unsigned(message.clone());
+    transaction.partial_sign(signers, Hash::new_from_array(RECENT_BLOCKHASH));
+    PartiallySignedVector {
+        label: label.to_string(),
+        signed_slots: transaction
+            .signatures
+            .iter()
+            .map(|signature| *signature != Signature::default())
+            .collect(),
+        is_signed: transaction.is_signed(),
+        transaction: bincode::serialize(&transaction).unwrap(),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let first = Keypair::new_from_array(FIRST_SEED);
+    let second = Keypair::new_from_array(SECOND_SEED);
+    let message = message(&first, &second);
+    Fixture {
+        first_seed: FIRST_SEED,
+        second_seed: SECOND_SEED,
+        recent_blockhash: RECENT_BLOCKHASH,
+        message_data: message.serialize(),
+        vectors: vec![
+            vector("unsigned", &message, &[]),
+            vector("first_only", &message, &[&first]),
+            vector("second_only", &message, &[&second]),
+            vector("both", &message, &[&first, &second]),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_partially_signed_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_partially_signed_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn unsigned_slots_are_zero_and_only_full_transactions_verify() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let transaction: Transaction = bincode::deserialize(&vector.transaction).unwrap();
+        assert_eq!(transaction.message_data(), fixture.message_data);
+        assert_eq!(transaction.signatures.len(), vector.signed_slots.len());
+        for (signature, signed) in transaction.signatures.iter().zip(&vector.signed_slots) {
+            assert_eq!(signature.as_ref() != [0; 64], *signed, "{}", vector.label);
+        }
+        assert_eq!(
+            vector.is_signed,
+            vector.signed_slots.iter().all(|signed| *signed)
+        );
+        assert_eq!(
+            transaction.verify().is_ok(),
+            vector.is_signed,
+            "{}",
+            vector.label
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_partially_signed_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,412 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use k256::ecdsa::{RecoveryId, Signature as Secp256k1Signature, SigningKey, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use solana_ed25519_program::{offsets_to_ed25519_instruction, Ed25519SignatureOffsets};
+use solana_hash::Hash;
+use solana_instruction::Instruction;
+use solana_keypair::Keypair;
+use solana_message::Message;
+use solana_pubkey::Pubkey;
+use solana_secp256k1_program::{eth_address_from_pubkey, sign_message, SecpSignatureOffsets};
+use solana_signature::Signature;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+const FIXTURE_JSON: &str =
+    include_str!("../../src/official_precompile_cross_instruction_vectors.json");
+const CARRIER_INSTRUCTION_INDEX: u8 = 0;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Ed25519Signer {
+    seed: [u8; 32],
+    public_key: [u8; 32],
+    message: Vec<u8>,
+    signature: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Ed25519OffsetsFixture {
+    signature_offset: u16,
+    signature_instruction_index: u16,
+    public_key_offset: u16,
+    public_key_instruction_index: u16,
+    message_data_offset: u16,
+    message_data_size: u16,
+    message_instruction_index: u16,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Ed25519Case {
+    signers: Vec<Ed25519Signer>,
+    carrier_data: Vec<u8>,
+    offsets: Vec<Ed25519OffsetsFixture>,
+    precompile_data: Vec<u8>,
+    account_keys: Vec<[u8; 32]>,
+    transaction: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Secp256k1Signer {
+    secret_key: [u8; 32],
+    public_key: Vec<u8>,
+    eth_address: [u8; 20],
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    recovery_id: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Secp256k1OffsetsFixture {
+    signature_offset: u16,
+    signature_instruction_index: u8,
+    eth_address_offset: u16,
+    eth_address_instruction_index: u8,
+    message_data_offset: u16,
+    message_data_size: u16,
+    message_instruction_index: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Secp256k1Case {
+    signers: Vec<Secp256k1Signer>,
+    carrier_data: Vec<u8>,
+    offsets: Vec<Secp256k1OffsetsFixture>,
+    precompile_data: Vec<u8>,
+    account_keys: Vec<[u8; 32]>,
+    transaction: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    payer_seed: [u8; 32],
+    carrier_program_id: [u8; 32],
+    recent_blockhash: [u8; 32],
+    ed25519: Ed25519Case,
+    secp256k1: Secp256k1Case,
+}
+
+fn offset(len: usize) -> u16 {
+    u16::try_from(len).unwrap()
+}
+
+fn signed_transaction(
+    payer: &Keypair,
+    blockhash: [u8; 32],
+    instructions: &[Instruction],
+) -> (Vec<[u8; 32]>, Vec<u8>) {
+    let blockhash = Hash::new_from_array(blockhash);
+    let message = Message::new_with_blockhash(instructions, Some(&payer.pubkey()), &blockhash);
+    let transaction = Transaction::new(&[payer], message, blockhash);
+    let account_keys = transaction
+        .message
+        .account_keys
+        .iter()
+        .map(Pubkey::to_bytes)
+        .collect();
+    (account_keys, bincode::serialize(&transaction).unwrap())
+}
+
+fn carrier(program_id: &Pubkey, data: Vec<u8>) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![],
+        data,
+    }
+}
+
+// Carrier layout: every (public key, signature) pair first, then every
+// message, so the offsets table has to reach past the other signers' entries.
+fn ed25519_case(
+    payer: &Keypair,
+    carrier_program_id: &Pubkey,
+    blockhash: [u8; 32],
+    inputs: &[([u8; 32], &[u8])],
+) -> Ed25519Case {
+    let signers: Vec<Ed25519Signer> = inputs
+        .iter()
+        .map(|(seed, message)| {
+            let signer = Keypair::new_from_array(*seed);
+            Ed25519Signer {
+                seed: *seed,
+                public_key: signer.pubkey().to_bytes(),
+                message: message.to_vec(),
+                signature: signer.sign_message(message).as_ref().to_vec(),
+            }
+        })
+        .collect();
+
+    let mut carrier_data = Vec::new();
+    let mut key_offsets = Vec::new();
+    for signer in &signers {
+        key_offsets.push(offset(carrier_data.len()));
+        carrier_data.extend_from_slice(&signer.public_key);
+        carrier_data.extend_from_slice(&signer.signature);
+    }
+    let mut message_offsets = Vec::new();
+    for signer in &signers {
+        message_offsets.push(offset(carrier_data.len()));
+        carrier_data.extend_from_slice(&signer.message);
+    }
+
+    let index = u16::from(CARRIER_INSTRUCTION_INDEX);
+    let offsets: Vec<Ed25519SignatureOffsets> = signers
+        .iter()
+        .enumerate()
+        .map(|(i, signer)| Ed25519SignatureOffsets {
+            signature_offset: key_offsets[i] + 32,
+            signature_instruction_index: index,
+            public_key_offset: key_offsets[i],
+            public_key_instruction_index: index,
+            message_data_offset: message_offsets[i],
+            message_data_size: offset(signer.message.len()),
+            message_instruction_index: index,
+        })
+        .collect();
+
+    let precompile = offsets_to_ed25519_instruction(&offsets);
+    let (account_keys, transaction) = signed_transaction(
+        payer,
+        blockhash,
+        &[
+            carrier(carrier_program_id, carrier_data.clone()),
+            precompile.clone(),
+        ],
+    );
+
+    Ed25519Case {
+        signers,
+        carrier_data,
+        offsets: offsets
+            .iter()
+            .map(|entry| Ed25519OffsetsFixture {
+                signature_offset: entry.signature_offset,
+                signature_instruction_index: entry.signature_instruction_index,
+                public_key_offset: entry.public_key_offset,
+                public_key_instruction_index: entry.public_key_instruction_index,
+                message_data_offset: entry.message_data_offset,
+                message_data_size: entry.message_data_size,
+                message_instruction_index: entry.message_instruction_index,
+            })
+            .collect(),
+        precompile_data: precompile.data,
+        account_keys,
+        transaction,
+    }
+}
+
+// Carrier layout: every (eth address, signature, recovery id) triple first,
+// then every message.
+fn secp256k1_case(
+    payer: &Keypair,
+    carrier_program_id: &Pubkey,
+    blockhash: [u8; 32],
+    inputs: &[([u8; 32], &[u8])],
+) -> Secp256k1Case {
+    let signers: Vec<Secp256k1Signer> = inputs
+        .iter()
+        .map(|(secret_key, message)| {
+            let signing_key = SigningKey::from_slice(secret_key).unwrap();
+            let encoded = signing_key.verifying_key().to_encoded_point(false);
+            let public_key: [u8; 64] = encoded.as_bytes()[1..].try_into().unwrap();
+            let (signature, recovery_id) = sign_message(secret_key, message).unwrap();
+            Secp256k1Signer {
+                secret_key: *secret_key,
+                public_key: public_key.to_vec(),
+                eth_address: eth_address_from_pubkey(&public_key),
+                message: message.to_vec(),
+                signature: signature.to_vec(),
+                recovery_id,
+            }
+        })
+        .collect();
+
+    let mut carrier_data = Vec::new();
+    let mut address_offsets = Vec::new();
+    for signer in &signers {
+        address_offsets.push(offset(carrier_data.len()));
+        carrier_data.extend_from_slice(&signer.eth_address);
+        carrier_data.extend_from_slice(&signer.signature);
+        carrier_data.push(signer.recovery_id);
+    }
+    let mut message_offsets = Vec::new();
+    for signer in &signers {
+        message_offsets.push(offset(carrier_data.len()));
+        carrier_data.extend_from_slice(&signer.message);
+    }
+
+    let offsets: Vec<SecpSignatureOffsets> = signers
+        .iter()
+        .enumerate()
+        .map(|(i, signer)| SecpSignatureOffsets {
+            signature_offset: address_offsets[i] + 20,
+            signature_instruction_index: CARRIER_INSTRUCTION_INDEX,
+            eth_address_offset: address_offsets[i],
+            eth_address_instruction_index: CARRIER_INSTRUCTION_INDEX,
+            message_data_offset: message_offsets[i],
+            message_data_size: offset(signer.message.len()),
+            message_instruction_index: CARRIER_INSTRUCTION_INDEX,
+        })
+        .collect();
+
+    let mut precompile_data = vec![u8::try_from(offsets.len()).unwrap()];
+    for entry in &offsets {
+        precompile_data.extend_from_slice(&bincode::serialize(entry).unwrap());
+    }
+    let precompile = Instruction {
+        program_id: solana_sdk_ids::secp256k1_program::id(),
+        accounts: vec![],
+        data: precompile_data.clone(),
+    };
+    let (account_keys, transaction) = signed_transaction(
+        payer,
+        blockhash,
+        &[
+            carrier(carrier_program_id, carrier_data.clone()),
+            precompile,
+        ],
+    );
+
+    Secp256k1Case {
+        signers,
+        carrier_data,
+        offsets: offsets
+            .iter()
+            .map(|entry| Secp256k1OffsetsFixture {
+                signature_offset: entry.signature_offset,
+                signature_instruction_index: entry.signature_instruction_index,
+                eth_address_offset: entry.eth_address_offset,
+                eth_address_instruction_index: entry.eth_address_instruction_index,
+                message_data_offset: entry.message_data_offset,
+                message_data_size: entry.message_data_size,
+                message_instruction_index: entry.message_instruction_index,
+            })
+            .collect(),
+        precompile_data,
+        account_keys,
+        transaction,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let payer_seed = [1; 32];
+    let carrier_program_id = Pubkey::from([0x4d; 32]);
+    let recent_blockhash = [9; 32];
+    let payer = Keypair::new_from_array(payer_seed);
+
+    Fixture {
+        payer_seed,
+        carrier_program_id: carrier_program_id.to_bytes(),
+        recent_blockhash,
+        ed25519: ed25519_case(
+            &payer,
+            &carrier_program_id,
+            recent_blockhash,
+            &[
+                ([0x31; 32], b"relayer: withdraw 100 to alice"),
+                ([0x32; 32], b"co-signer approves withdrawal #7"),
+            ],
+        ),
+        secp256k1: secp256k1_case(
+            &payer,
+            &carrier_program_id,
+            recent_blockhash,
+            &[
+                ([0x41; 32], b"bridge: mint 250 wrapped tokens"),
+                ([0x42; 32], b"guardian attests bridge message #3"),
+            ],
+        ),
+    }
+}
+
+fn slice(data: &[u8], offset: u16, len: usize) -> &[u8] {
+    &data[usize::from(offset)..usize::from(offset) + len]
+}
+
+#[test]
+fn fixture_matches_official_precompile_cross_instruction_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_precompile_cross_instruction_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn ed25519_offsets_resolve_to_valid_signatures_in_the_carrier() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let case = &fixture.ed25519;
+    let transaction: Transaction = bincode::deserialize(&case.transaction).unwrap();
+    transaction.verify().unwrap();
+    let carrier_ix = &transaction.message.instructions[0];
+    let precompile_ix = &transaction.message.instructions[1];
+    assert_eq!(carrier_ix.data, case.carrier_data);
+    assert_eq!(precompile_ix.data, case.precompile_data);
+    assert_eq!(
+        transaction.message.account_keys[usize::from(precompile_ix.program_id_index)],
+        solana_sdk_ids::ed25519_program::id()
+    );
+
+    for (entry, signer) in case.offsets.iter().zip(&case.signers) {
+        assert_eq!(entry.signature_instruction_index, 0);
+        assert_eq!(entry.public_key_instruction_index, 0);
+        assert_eq!(entry.message_instruction_index, 0);
+        let public_key = slice(&case.carrier_data, entry.public_key_offset, 32);
+        let signature = slice(&case.carrier_data, entry.signature_offset, 64);
+        let message = slice(
+            &case.carrier_data,
+            entry.message_data_offset,
+            usize::from(entry.message_data_size),
+        );
+        assert_eq!(public_key, signer.public_key);
+        assert_eq!(message, signer.message);
+        assert!(Signature::try_from(signature)
+            .unwrap()
+            .verify(public_key, message));
+    }
+}
+
+#[test]
+fn secp256k1_offsets_recover_the_carried_eth_addresses() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let case = &fixture.secp256k1;
+    let transaction: Transaction = bincode::deserialize(&case.transaction).unwrap();
+    transaction.verify().unwrap();
+    assert_eq!(transaction.message.instructions[0].data, case.carrier_data);
+    assert_eq!(
+        transaction.message.instructions[1].data,
+        case.precompile_data
+    );
+
+    for (entry, signer) in case.offsets.iter().zip(&case.signers) {
+        let eth_address = slice(&case.carrier_data, entry.eth_address_offset, 20);
+        let signature = slice(&case.carrier_data, entry.signature_offset, 64);
+        let recovery_id = case.carrier_data[usize::from(entry.signature_offset) + 64];
+        let message = slice(
+            &case.carrier_data,
+            entry.message_data_offset,
+            usize::from(entry.message_data_size),
+        );
+        let recovered = VerifyingKey::recover_from_prehash(
+            solana_keccak_hasher::hash(message).as_bytes(),
+            &Secp256k1Signature::from_slice(signature).unwrap(),
+            RecoveryId::from_byte(recovery_id).unwrap(),
+        )
+        .unwrap();
+        let encoded = recovered.to_encoded_point(false);
+        let public_key: [u8; 64] = encoded.as_bytes()[1..].try_into().unwrap();
+        assert_eq!(public_key.as_slice(), signer.public_key);
+        assert_eq!(eth_address, eth_address_from_pubkey(&public_key));
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_precompile_cross_instruction_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,189 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_ed25519_program::new_ed25519_instruction_with_signature;
+use solana_secp256k1_program::new_secp256k1_instruction_with_signature;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_precompile_layout_constants.json");
+const CROSS_INSTRUCTION_JSON: &str =
+    include_str!("../../src/official_precompile_cross_instruction_vectors.json");
+
+const MESSAGE: &[u8] = b"layout probe";
+const ED25519_PUBLIC_KEY: [u8; 32] = [0x11; 32];
+const ETH_ADDRESS: [u8; 20] = [0x33; 20];
+const SIGNATURE: [u8; 64] = [0x22; 64];
+const RECOVERY_ID: u8 = 1;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct PrecompileLayoutConstants {
+    program: String,
+    program_id: [u8; 32],
+    count_len: usize,
+    header_len: usize,
+    offsets_struct_len: usize,
+    data_start: usize,
+    payload_order: Vec<String>,
+    message: Vec<u8>,
+    single_signature_data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    layouts: Vec<PrecompileLayoutConstants>,
+}
+
+#[derive(Deserialize)]
+struct CrossInstructionCase {
+    offsets: Vec<serde_json::Value>,
+    precompile_data: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+struct CrossInstructionFixture {
+    ed25519: CrossInstructionCase,
+    secp256k1: CrossInstructionCase,
+}
+
+fn read_u16(data: &[u8], offset: usize) -> usize {
+    usize::from(u16::from_le_bytes([data[offset], data[offset + 1]]))
+}
+
+/// Sort payload field names by where the real builder placed them.
+fn payload_order(mut fields: Vec<(usize, &str)>) -> Vec<String> {
+    fields.sort();
+    fields
+        .into_iter()
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
+
+fn ed25519_layout() -> PrecompileLayoutConstants {
+    use solana_ed25519_program::{
+        DATA_START, SIGNATURE_OFFSETS_SERIALIZED_SIZE, SIGNATURE_OFFSETS_START,
+    };
+
+    let data =
+        new_ed25519_instruction_with_signature(MESSAGE, &SIGNATURE, &ED25519_PUBLIC_KEY).data;
+    // Ed25519SignatureOffsets: signature, signature ix, public key, public
+    // key ix, message, message size, message ix (all u16).
+    let offsets = &data[SIGNATURE_OFFSETS_START..];
+    let order = payload_order(vec![
+        (read_u16(offsets, 0), "signature"),
+        (read_u16(offsets, 4), "public_key"),
+        (read_u16(offsets, 8), "message"),
+    ]);
+
+    PrecompileLayoutConstants {
+        program: "ed25519".to_string(),
+        program_id: solana_sdk_ids::ed25519_program::id().to_bytes(),
+        count_len: 1,
+        header_len: SIGNATURE_OFFSETS_START,
+        offsets_struct_len: SIGNATURE_OFFSETS_SERIALIZED_SIZE,
+        data_start: DATA_START,
+        payload_order: order,
+        message: MESSAGE.to_vec(),
+        single_signature_data: data,
+    }
+}
+
+fn secp256k1_layout() -> PrecompileLayoutConstants {
+    use solana_secp256k1_program::{DATA_START, SIGNATURE_OFFSETS_SERIALIZED_SIZE};
+
+    let data =
+        new_secp256k1_instruction_with_signature(MESSAGE, &SIGNATURE, RECOVERY_ID, &ETH_ADDRESS)
+            .data;
+    // SecpSignatureOffsets: signature u16, signature ix u8, eth address u16,
+    // eth address ix u8, message u16, message size u16, message ix u8. The
+    // recovery id trails the signature.
+    let header_len = DATA_START - SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+    let offsets = &data[header_len..];
+    let signature_offset = read_u16(offsets, 0);
+    let order = payload_order(vec![
+        (signature_offset, "signature"),
+        (signature_offset + SIGNATURE.len(), "recovery_id"),
+        (read_u16(offsets, 3), "eth_address"),
+        (read_u16(offsets, 6), "message"),
+    ]);
+
+    PrecompileLayoutConstants {
+        program: "secp256k1".to_string(),
+        program_id: solana_sdk_ids::secp256k1_program::id().to_bytes(),
+        count_len: 1,
+        header_len,
+        offsets_struct_len: SIGNATURE_OFFSETS_SERIALIZED_SIZE,
+        data_start: DATA_START,
+        payload_order: order,
+        message: MESSAGE.to_vec(),
+        single_signature_data: data,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        layouts: vec![ed25519_layout(), secp256k1_layout()],
+    }
+}
+
+#[test]
+fn fixture_matches_official_precompile_layout_constants() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_precompile_layout_constants.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn layout_constants_describe_the_recorded_instructions() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let cross: CrossInstructionFixture = serde_json::from_str(CROSS_INSTRUCTION_JSON).unwrap();
+
+    for (layout, case) in fixture
+        .layouts
+        .iter()
+        .zip([&cross.ed25519, &cross.secp256k1])
+    {
+        assert_eq!(
+            layout.data_start,
+            layout.header_len + layout.offsets_struct_len
+        );
+        // Any bytes between the count and the first offsets struct are
+        // zero padding.
+        assert!(layout.header_len >= layout.count_len);
+
+        let single = &layout.single_signature_data;
+        assert_eq!(single[0], 1, "{}", layout.program);
+        assert!(single[layout.count_len..layout.header_len]
+            .iter()
+            .all(|&b| b == 0));
+        assert!(single.ends_with(&layout.message), "{}", layout.program);
+
+        let multi = &case.precompile_data;
+        assert_eq!(
+            usize::from(multi[0]),
+            case.offsets.len(),
+            "{}",
+            layout.program
+        );
+        assert!(multi[layout.count_len..layout.header_len]
+            .iter()
+            .all(|&b| b == 0));
+        assert_eq!(
+            multi.len(),
+            layout.header_len + case.offsets.len() * layout.offsets_struct_len,
+            "{}",
+            layout.program
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_precompile_layout_constants() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,149 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_compute_budget_interface::ComputeBudgetInstruction;
+use solana_hash::Hash;
+use solana_instruction::Instruction;
+use solana_keypair::Keypair;
+use solana_message::Message;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+use solana_system_interface::instruction as system_instruction;
+use solana_transaction::Transaction;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_signing_payload_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct SigningPayloadVector {
+    label: String,
+    /// Seeds of the required signers, in account-key order.
+    signer_seeds: Vec<[u8; 32]>,
+    recent_blockhash: [u8; 32],
+    /// `Transaction::message_data()`: the bytes every signature covers.
+    message_data: Vec<u8>,
+    /// One ed25519 signature per signer seed, in the same order.
+    signatures: Vec<Vec<u8>>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    payer_seed: [u8; 32],
+    recipient_seed: [u8; 32],
+    nonce_account: [u8; 32],
+    vectors: Vec<SigningPayloadVector>,
+}
+
+/// The seeds and blockhashes of the golden transactions.
+const PAYER_SEED: [u8; 32] = [1; 32];
+const RECIPIENT_SEED: [u8; 32] = [2; 32];
+const RECENT_BLOCKHASH: [u8; 32] = [9; 32];
+const DURABLE_NONCE: [u8; 32] = [8; 32];
+
+fn vector(
+    label: &str,
+    signers: &[&Keypair],
+    blockhash: [u8; 32],
+    instructions: &[Instruction],
+) -> SigningPayloadVector {
+    let message = Message::new_with_blockhash(
+        instructions,
+        Some(&signers[0].pubkey()),
+        &Hash::new_from_array(blockhash),
+    );
+    let transaction = Transaction::new(signers, message, Hash::new_from_array(blockhash));
+    let signer_keys = &transaction.message.account_keys
+        [..usize::from(transaction.message.header.num_required_signatures)];
+    SigningPayloadVector {
+        label: label.to_string(),
+        signer_seeds: signer_keys
+            .iter()
+            .map(|key| {
+                let signer = signers.iter().find(|signer| signer.pubkey() == *key);
+                *signer.unwrap().secret_bytes()
+            })
+            .collect(),
+        recent_blockhash: blockhash,
+        message_data: transaction.message_data(),
+        signatures: transaction
+            .signatures
+            .iter()
+            .map(|signature| signature.as_ref().to_vec())
+            .collect(),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let payer = Keypair::new_from_array(PAYER_SEED);
+    let recipient = Keypair::new_from_array(RECIPIENT_SEED);
+    let nonce_account = Pubkey::from([6; 32]);
+    let transfer = system_instruction::transfer(&payer.pubkey(), &recipient.pubkey(), 1_000_000);
+
+    Fixture {
+        payer_seed: PAYER_SEED,
+        recipient_seed: RECIPIENT_SEED,
+        nonce_account: nonce_account.to_bytes(),
+        vectors: vec![
+            vector(
+                "sol-transfer",
+                &[&payer],
+                RECENT_BLOCKHASH,
+                std::slice::from_ref(&transfer),
+            ),
+            vector(
+                "multi-instruction-two-signers",
+                &[&payer, &recipient],
+                RECENT_BLOCKHASH,
+                &[
+                    ComputeBudgetInstruction::set_compute_unit_limit(200_000),
+                    transfer.clone(),
+                    system_instruction::transfer(&recipient.pubkey(), &payer.pubkey(), 500),
+                ],
+            ),
+            vector(
+                "durable-nonce-sol-transfer",
+                &[&payer],
+                DURABLE_NONCE,
+                &[
+                    system_instruction::advance_nonce_account(&nonce_account, &payer.pubkey()),
+                    transfer,
+                ],
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_signing_payload_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_signing_payload_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn signatures_cover_the_serialized_message() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let message: Message = bincode::deserialize(&vector.message_data).unwrap();
+        assert_eq!(message.serialize(), vector.message_data, "{}", vector.label);
+        assert_eq!(message.recent_blockhash.to_bytes(), vector.recent_blockhash);
+        assert_eq!(vector.signatures.len(), vector.signer_seeds.len());
+        for (seed, signature) in vector.signer_seeds.iter().zip(&vector.signatures) {
+            let keypair = Keypair::new_from_array(*seed);
+            let expected = keypair.sign_message(&vector.message_data);
+            assert_eq!(expected.as_ref(), signature.as_slice(), "{}", vector.label);
+        }
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_signing_payload_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,296 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_hash::Hash;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_message::{v0, AddressLookupTableAccount, VersionedMessage};
+use solana_pubkey::Pubkey;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_v0_key_ordering.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AccountFixture {
+    pubkey: [u8; 32],
+    is_signer: u8,
+    is_writable: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct InstructionFixture {
+    program_id: [u8; 32],
+    accounts: Vec<AccountFixture>,
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct LookupTableFixture {
+    account_key: [u8; 32],
+    addresses: Vec<[u8; 32]>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AddressTableLookupFixture {
+    account_key: [u8; 32],
+    writable_indexes: Vec<u8>,
+    readonly_indexes: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct CombinedIndex {
+    pubkey: [u8; 32],
+    index: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct V0KeyOrderingCase {
+    label: String,
+    instructions_description: Vec<String>,
+    instructions: Vec<InstructionFixture>,
+    lookup_tables: Vec<LookupTableFixture>,
+    static_keys: Vec<[u8; 32]>,
+    loaded_writable: Vec<[u8; 32]>,
+    loaded_readonly: Vec<[u8; 32]>,
+    address_table_lookups: Vec<AddressTableLookupFixture>,
+    combined_index_of: Vec<CombinedIndex>,
+    message: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    payer: [u8; 32],
+    recent_blockhash: [u8; 32],
+    cases: Vec<V0KeyOrderingCase>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+fn table(account_key: u8, addresses: &[u8]) -> AddressLookupTableAccount {
+    AddressLookupTableAccount {
+        key: key(account_key),
+        addresses: addresses.iter().copied().map(key).collect(),
+    }
+}
+
+fn case(
+    label: &str,
+    description: &[&str],
+    payer: &Pubkey,
+    recent_blockhash: [u8; 32],
+    instructions: &[Instruction],
+    lookup_tables: &[AddressLookupTableAccount],
+) -> V0KeyOrderingCase {
+    let message = v0::Message::try_compile(
+        payer,
+        instructions,
+        lookup_tables,
+        Hash::new_from_array(recent_blockhash),
+    )
+    .unwrap();
+
+    let mut loaded_writable = Vec::new();
+    let mut loaded_readonly = Vec::new();
+    for lookup in &message.address_table_lookups {
+        let table = lookup_tables
+            .iter()
+            .find(|table| table.key == lookup.account_key)
+            .unwrap();
+        loaded_writable.extend(
+            lookup
+                .writable_indexes
+                .iter()
+                .map(|index| table.addresses[usize::from(*index)]),
+        );
+        loaded_readonly.extend(
+            lookup
+                .readonly_indexes
+                .iter()
+                .map(|index| table.addresses[usize::from(*index)]),
+        );
+    }
+
+    let combined_index_of = message
+        .account_keys
+        .iter()
+        .chain(&loaded_writable)
+        .chain(&loaded_readonly)
+        .enumerate()
+        .map(|(index, pubkey)| CombinedIndex {
+            pubkey: pubkey.to_bytes(),
+            index: u8::try_from(index).unwrap(),
+        })
+        .collect();
+
+    V0KeyOrderingCase {
+        label: label.to_string(),
+        instructions_description: description.iter().map(|step| step.to_string()).collect(),
+        instructions: instructions
+            .iter()
+            .map(|ix| InstructionFixture {
+                program_id: ix.program_id.to_bytes(),
+                accounts: ix
+                    .accounts
+                    .iter()
+                    .map(|meta| AccountFixture {
+                        pubkey: meta.pubkey.to_bytes(),
+                        is_signer: u8::from(meta.is_signer),
+                        is_writable: u8::from(meta.is_writable),
+                    })
+                    .collect(),
+                data: ix.data.clone(),
+            })
+            .collect(),
+        lookup_tables: lookup_tables
+            .iter()
+            .map(|table| LookupTableFixture {
+                account_key: table.key.to_bytes(),
+                addresses: table.addresses.iter().map(Pubkey::to_bytes).collect(),
+            })
+            .collect(),
+        static_keys: message.account_keys.iter().map(Pubkey::to_bytes).collect(),
+        loaded_writable: loaded_writable.iter().map(Pubkey::to_bytes).collect(),
+        loaded_readonly: loaded_readonly.iter().map(Pubkey::to_bytes).collect(),
+        address_table_lookups: message
+            .address_table_lookups
+            .iter()
+            .map(|lookup| AddressTableLookupFixture {
+                account_key: lookup.account_key.to_bytes(),
+                writable_indexes: lookup.writable_indexes.clone(),
+                readonly_indexes: lookup.readonly_indexes.clone(),
+            })
+            .collect(),
+        combined_index_of,
+        message: VersionedMessage::V0(message).serialize(),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let payer = key(0x10);
+    let recent_blockhash = [0x0b; 32];
+
+    let one_table = [Instruction::new_with_bytes(
+        key(0x02),
+        &[0xaa],
+        vec![
+            AccountMeta::new(key(0x40), false),
+            AccountMeta::new_readonly(key(0x30), false),
+            AccountMeta::new(key(0x20), false),
+            AccountMeta::new_readonly(key(0x50), true),
+        ],
+    )];
+
+    // 0x45 sits in both tables and must load from the first; 0x35 and 0x33
+    // are listed in table order but loaded in pubkey order.
+    let two_tables = [
+        Instruction::new_with_bytes(
+            key(0x03),
+            &[0x01],
+            vec![
+                AccountMeta::new(key(0x45), false),
+                AccountMeta::new(key(0x41), false),
+                AccountMeta::new_readonly(key(0x35), false),
+                AccountMeta::new_readonly(key(0x31), false),
+            ],
+        ),
+        Instruction::new_with_bytes(
+            key(0x04),
+            &[0x02],
+            vec![
+                AccountMeta::new(key(0x48), false),
+                AccountMeta::new_readonly(key(0x33), false),
+                AccountMeta::new_readonly(key(0x45), false),
+            ],
+        ),
+    ];
+
+    // The signer and the invoked program are both in the table but stay static.
+    let static_wins = [Instruction::new_with_bytes(
+        key(0x05),
+        &[0x03],
+        vec![
+            AccountMeta::new(key(0x72), false),
+            AccountMeta::new_readonly(key(0x70), false),
+            AccountMeta::new(key(0x60), true),
+        ],
+    )];
+
+    Fixture {
+        payer: payer.to_bytes(),
+        recent_blockhash,
+        cases: vec![
+            case(
+                "one-table",
+                &["program(0x02): writable 0x40, readonly 0x30, writable 0x20, readonly signer 0x50"],
+                &payer,
+                recent_blockhash,
+                &one_table,
+                &[table(0xa1, &[0x30, 0x99, 0x40])],
+            ),
+            case(
+                "two-tables-first-table-wins",
+                &[
+                    "program(0x03): writable 0x45, writable 0x41, readonly 0x35, readonly 0x31",
+                    "program(0x04): writable 0x48, readonly 0x33, readonly 0x45",
+                ],
+                &payer,
+                recent_blockhash,
+                &two_tables,
+                &[
+                    table(0xa1, &[0x45, 0x35, 0x33]),
+                    table(0xa2, &[0x41, 0x31, 0x48, 0x45]),
+                ],
+            ),
+            case(
+                "signer-and-program-stay-static",
+                &["program(0x05): writable 0x72, readonly 0x70, writable signer 0x60"],
+                &payer,
+                recent_blockhash,
+                &static_wins,
+                &[table(0xa3, &[0x60, 0x05, 0x70])],
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_v0_key_ordering() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_v0_key_ordering.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn combined_indexes_follow_static_then_writable_then_readonly() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+
+    for case in &fixture.cases {
+        let combined: Vec<[u8; 32]> = case
+            .static_keys
+            .iter()
+            .chain(&case.loaded_writable)
+            .chain(&case.loaded_readonly)
+            .copied()
+            .collect();
+        assert_eq!(combined.len(), case.combined_index_of.len(), "{}", case.label);
+        for entry in &case.combined_index_of {
+            assert_eq!(
+                combined[usize::from(entry.index)],
+                entry.pubkey,
+                "{}",
+                case.label
+            );
+        }
+        assert_eq!(case.static_keys[0], fixture.payer, "{}", case.label);
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_v0_key_ordering() {
+    println!("{}", serde_json::to_string_pretty(&official_fixture()).unwrap());
+}
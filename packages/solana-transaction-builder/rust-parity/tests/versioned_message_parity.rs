@@ -0,0 +1,225 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_hash::Hash;
+use solana_message::{
+    compiled_instruction::CompiledInstruction, legacy, v0, MessageHeader, VersionedMessage,
+};
+use solana_pubkey::Pubkey;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_versioned_message_vectors.json");
+
+/// Set on the first byte of every versioned message; legacy messages start
+/// with `num_required_signatures`, which is always below it.
+const MESSAGE_VERSION_PREFIX: u8 = 0x80;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct HeaderFixture {
+    num_required_signatures: u8,
+    num_readonly_signed_accounts: u8,
+    num_readonly_unsigned_accounts: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct CompiledInstructionFixture {
+    program_id_index: u8,
+    accounts: Vec<u8>,
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AddressTableLookupFixture {
+    account_key: [u8; 32],
+    writable_indexes: Vec<u8>,
+    readonly_indexes: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct VersionedMessageTestVector {
+    name: String,
+    /// `None` for legacy messages, which carry no version prefix.
+    version: Option<u8>,
+    header: HeaderFixture,
+    account_keys: Vec<[u8; 32]>,
+    recent_blockhash: [u8; 32],
+    instructions: Vec<CompiledInstructionFixture>,
+    address_table_lookups: Vec<AddressTableLookupFixture>,
+    /// bincode `VersionedMessage`, prefix included.
+    serialized: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<VersionedMessageTestVector>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+fn header(signers: u8, readonly_signed: u8, readonly_unsigned: u8) -> MessageHeader {
+    MessageHeader {
+        num_required_signatures: signers,
+        num_readonly_signed_accounts: readonly_signed,
+        num_readonly_unsigned_accounts: readonly_unsigned,
+    }
+}
+
+fn lookup(account_key: u8, writable: &[u8], readonly: &[u8]) -> v0::MessageAddressTableLookup {
+    v0::MessageAddressTableLookup {
+        account_key: key(account_key),
+        writable_indexes: writable.to_vec(),
+        readonly_indexes: readonly.to_vec(),
+    }
+}
+
+fn vector(name: &str, message: VersionedMessage) -> VersionedMessageTestVector {
+    let serialized = bincode::serialize(&message).unwrap();
+    let (version, lookups) = match &message {
+        VersionedMessage::Legacy(_) => (None, &[][..]),
+        VersionedMessage::V0(message) => (Some(0), &message.address_table_lookups[..]),
+    };
+    let header = message.header();
+    VersionedMessageTestVector {
+        name: name.to_string(),
+        version,
+        header: HeaderFixture {
+            num_required_signatures: header.num_required_signatures,
+            num_readonly_signed_accounts: header.num_readonly_signed_accounts,
+            num_readonly_unsigned_accounts: header.num_readonly_unsigned_accounts,
+        },
+        account_keys: message
+            .static_account_keys()
+            .iter()
+            .map(Pubkey::to_bytes)
+            .collect(),
+        recent_blockhash: message.recent_blockhash().to_bytes(),
+        instructions: message
+            .instructions()
+            .iter()
+            .map(|ix| CompiledInstructionFixture {
+                program_id_index: ix.program_id_index,
+                accounts: ix.accounts.clone(),
+                data: ix.data.clone(),
+            })
+            .collect(),
+        address_table_lookups: lookups
+            .iter()
+            .map(|lookup| AddressTableLookupFixture {
+                account_key: lookup.account_key.to_bytes(),
+                writable_indexes: lookup.writable_indexes.clone(),
+                readonly_indexes: lookup.readonly_indexes.clone(),
+            })
+            .collect(),
+        serialized,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let recent_blockhash = Hash::new_from_array(std::array::from_fn(|i| i as u8));
+
+    // Payer, a writable account, and the program; the second instruction
+    // reads from both lookup tables.
+    let static_keys = vec![key(0x10), key(0x20), key(0x02)];
+    let instructions = vec![
+        CompiledInstruction::new_from_raw_parts(2, vec![0xaa, 0xbb], vec![0, 1]),
+        CompiledInstruction::new_from_raw_parts(2, vec![0x01], vec![1, 3, 4, 5, 6]),
+    ];
+
+    let legacy = legacy::Message {
+        header: header(1, 0, 1),
+        account_keys: static_keys.clone(),
+        recent_blockhash,
+        instructions: vec![instructions[0].clone()],
+    };
+    let v0_without_lookups = v0::Message {
+        header: header(1, 0, 1),
+        account_keys: static_keys.clone(),
+        recent_blockhash,
+        instructions: vec![instructions[0].clone()],
+        address_table_lookups: Vec::new(),
+    };
+    let v0_with_lookups = v0::Message {
+        header: header(1, 0, 1),
+        account_keys: static_keys.clone(),
+        recent_blockhash,
+        instructions: instructions.clone(),
+        address_table_lookups: vec![lookup(0x30, &[0, 2], &[5]), lookup(0x31, &[], &[1])],
+    };
+    // Two signers, one of them readonly.
+    let v0_multiple_signers = v0::Message {
+        header: header(2, 1, 1),
+        account_keys: vec![key(0x10), key(0x11), key(0x02)],
+        recent_blockhash,
+        instructions: vec![CompiledInstruction::new_from_raw_parts(
+            2,
+            Vec::new(),
+            vec![0, 1, 3],
+        )],
+        address_table_lookups: vec![lookup(0x30, &[7], &[])],
+    };
+
+    Fixture {
+        vectors: vec![
+            vector("legacy", VersionedMessage::Legacy(legacy)),
+            vector(
+                "v0_without_lookups",
+                VersionedMessage::V0(v0_without_lookups),
+            ),
+            vector("v0_with_lookups", VersionedMessage::V0(v0_with_lookups)),
+            vector(
+                "v0_multiple_signers",
+                VersionedMessage::V0(v0_multiple_signers),
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_versioned_message_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_versioned_message_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn version_prefix_is_only_present_on_v0_messages() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    assert!(fixture
+        .vectors
+        .iter()
+        .any(|vector| vector.version.is_none()));
+    for vector in &fixture.vectors {
+        let first = vector.serialized[0];
+        match vector.version {
+            None => {
+                assert_eq!(first & MESSAGE_VERSION_PREFIX, 0, "{}", vector.name);
+                assert_eq!(first, vector.header.num_required_signatures);
+            }
+            Some(version) => {
+                assert_eq!(first, MESSAGE_VERSION_PREFIX | version, "{}", vector.name);
+                assert_eq!(vector.serialized[1], vector.header.num_required_signatures);
+            }
+        }
+
+        let message: VersionedMessage = bincode::deserialize(&vector.serialized).unwrap();
+        assert_eq!(message.serialize(), vector.serialized, "{}", vector.name);
+        assert_eq!(
+            matches!(message, VersionedMessage::Legacy(_)),
+            vector.version.is_none()
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_versioned_message_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,22 @@
+const std = @import("std");
+
+pub const AddressTableLookupTestVector = struct {
+    name: []const u8,
+    account_key: [32]u8,
+    writable_indexes: []const u8,
+    readonly_indexes: []const u8,
+    serialized: []const u8,
+};
+
+pub const Fixture = struct {
+    vectors: []const AddressTableLookupTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_address_table_lookup_vectors.json"),
+        .{},
+    );
+}
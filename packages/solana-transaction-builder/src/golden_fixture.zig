@@ -0,0 +1,33 @@
+const std = @import("std");
+
+pub const GoldenTransaction = struct {
+    label: []const u8,
+    instructions: []const []const u8,
+    account_keys: []const [32]u8,
+    transaction: []const u8,
+    transaction_base64: []const u8,
+    prioritization_fee: u64,
+    fee: u64,
+};
+
+pub const Fixture = struct {
+    payer_seed: [32]u8,
+    recipient_seed: [32]u8,
+    mint: [32]u8,
+    nonce_account: [32]u8,
+    recent_blockhash: [32]u8,
+    durable_nonce: [32]u8,
+    lamports_per_signature: u64,
+    compute_unit_limit: u32,
+    compute_unit_price: u64,
+    golden_transactions: []const GoldenTransaction,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_golden_transactions.json"),
+        .{},
+    );
+}
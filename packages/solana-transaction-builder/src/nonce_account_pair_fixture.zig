@@ -0,0 +1,36 @@
+const std = @import("std");
+
+pub const AccountFixture = struct {
+    pubkey: [32]u8,
+    is_signer: u8,
+    is_writable: u8,
+};
+
+pub const InstructionFixture = struct {
+    program_id: [32]u8,
+    accounts: []const AccountFixture,
+    data: []const u8,
+};
+
+pub const NonceAccountPairTestVector = struct {
+    label: []const u8,
+    payer: [32]u8,
+    nonce_account: [32]u8,
+    authority: [32]u8,
+    lamports: u64,
+    instructions: []const InstructionFixture,
+};
+
+pub const Fixture = struct {
+    nonce_state_size: usize,
+    vectors: []const NonceAccountPairTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_nonce_account_pair_vectors.json"),
+        .{},
+    );
+}
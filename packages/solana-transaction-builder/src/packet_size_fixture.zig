@@ -0,0 +1,25 @@
+const std = @import("std");
+
+pub const PacketSizeVector = struct {
+    label: []const u8,
+    memo_len: usize,
+    serialized_len: usize,
+    exceeds_limit: bool,
+    transaction: []const u8,
+};
+
+pub const Fixture = struct {
+    packet_data_size: usize,
+    payer_seed: [32]u8,
+    recent_blockhash: [32]u8,
+    vectors: []const PacketSizeVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_packet_size_vectors.json"),
+        .{},
+    );
+}
@@ -0,0 +1,25 @@
+const std = @import("std");
+
+pub const PartiallySignedVector = struct {
+    label: []const u8,
+    signed_slots: []const bool,
+    is_signed: bool,
+    transaction: []const u8,
+};
+
+pub const Fixture = struct {
+    first_seed: [32]u8,
+    second_seed: [32]u8,
+    recent_blockhash: [32]u8,
+    message_data: []const u8,
+    vectors: []const PartiallySignedVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_partially_signed_vectors.json"),
+        .{},
+    );
+}
@@ -0,0 +1,72 @@
+const std = @import("std");
+
+pub const Ed25519Signer = struct {
+    seed: [32]u8,
+    public_key: [32]u8,
+    message: []const u8,
+    signature: [64]u8,
+};
+
+pub const Ed25519Offsets = struct {
+    signature_offset: u16,
+    signature_instruction_index: u16,
+    public_key_offset: u16,
+    public_key_instruction_index: u16,
+    message_data_offset: u16,
+    message_data_size: u16,
+    message_instruction_index: u16,
+};
+
+pub const Ed25519Case = struct {
+    signers: []const Ed25519Signer,
+    carrier_data: []const u8,
+    offsets: []const Ed25519Offsets,
+    precompile_data: []const u8,
+    account_keys: []const [32]u8,
+    transaction: []const u8,
+};
+
+pub const Secp256k1Signer = struct {
+    secret_key: [32]u8,
+    public_key: [64]u8,
+    eth_address: [20]u8,
+    message: []const u8,
+    signature: [64]u8,
+    recovery_id: u8,
+};
+
+pub const Secp256k1Offsets = struct {
+    signature_offset: u16,
+    signature_instruction_index: u8,
+    eth_address_offset: u16,
+    eth_address_instruction_index: u8,
+    message_data_offset: u16,
+    message_data_size: u16,
+    message_instruction_index: u8,
+};
+
+pub const Secp256k1Case = struct {
+    signers: []const Secp256k1Signer,
+    carrier_data: []const u8,
+    offsets: []const Secp256k1Offsets,
+    precompile_data: []const u8,
+    account_keys: []const [32]u8,
+    transaction: []const u8,
+};
+
+pub const Fixture = struct {
+    payer_seed: [32]u8,
+    carrier_program_id: [32]u8,
+    recent_blockhash: [32]u8,
+    ed25519: Ed25519Case,
+    secp256k1: Secp256k1Case,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_precompile_cross_instruction_vectors.json"),
+        .{},
+    );
+}
@@ -0,0 +1,26 @@
+const std = @import("std");
+
+pub const Layout = struct {
+    program: []const u8,
+    program_id: [32]u8,
+    count_len: usize,
+    header_len: usize,
+    offsets_struct_len: usize,
+    data_start: usize,
+    payload_order: []const []const u8,
+    message: []const u8,
+    single_signature_data: []const u8,
+};
+
+pub const Fixture = struct {
+    layouts: []const Layout,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_precompile_layout_constants.json"),
+        .{},
+    );
+}
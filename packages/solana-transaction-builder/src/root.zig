@@ -1,6 +1,7 @@
 //! `solana_transaction_builder` — host-side transaction assembly helpers.
 
 const std = @import("std");
+const sol = @import("solana_program_sdk");
 const tx = @import("solana_tx");
 const keypair = @import("solana_keypair");
 const system = @import("solana_system");
@@ -10,6 +11,16 @@ const spl_token = @import("spl_token");
 const spl_token_2022 = @import("spl_token_2022");
 const spl_ata = @import("spl_ata");
 const zk_proof = @import("solana_zk_elgamal_proof");
+const golden_fixture = @import("golden_fixture.zig");
+const v0_key_ordering_fixture = @import("v0_key_ordering_fixture.zig");
+const precompile_cross_instruction_fixture = @import("precompile_cross_instruction_fixture.zig");
+const precompile_layout_constants_fixture = @import("precompile_layout_constants_fixture.zig");
+const versioned_message_fixture = @import("versioned_message_fixture.zig");
+const address_table_lookup_fixture = @import("address_table_lookup_fixture.zig");
+const signing_payload_fixture = @import("signing_payload_fixture.zig");
+const partially_signed_fixture = @import("partially_signed_fixture.zig");
+const packet_size_fixture = @import("packet_size_fixture.zig");
+const nonce_account_pair_fixture = @import("nonce_account_pair_fixture.zig");
 
 pub const Pubkey = tx.Pubkey;
 pub const Instruction = tx.Instruction;
@@ -1287,6 +1298,9 @@ pub fn compileV0MessageWithLookupTables(
         }
         try upsertV0Key(&keys, &key_count, ix.program_id, false, false, true);
     }
+    // Match `v0::Message::try_compile`: the payer stays first, every other
+    // key is visited in pubkey order for both static placement and lookups.
+    std.mem.sort(V0KeyFlags, keys[1..key_count], {}, v0KeyLessThan);
 
     var selected: [tx.MAX_LEGACY_ACCOUNT_KEYS]LookupSelection = undefined;
     var selected_count: usize = 0;
@@ -1457,6 +1471,10 @@ fn upsertV0Key(
     key_count.* += 1;
 }
 
+fn v0KeyLessThan(_: void, a: V0KeyFlags, b: V0KeyFlags) bool {
+    return std.mem.order(u8, &a.key, &b.key) == .lt;
+}
+
 fn writeV0StaticKeysAndSelections(
     keys: []const V0KeyFlags,
     lookup_tables: []const LookupTableCandidate,
@@ -1857,6 +1875,40 @@ test "createNonceAccountInstructionsWithSysvars keeps caller supplied sysvars" {
     try std.testing.expectEqualSlices(u8, &rent, instructions.initializeNonceAccount().accounts[2].pubkey);
 }
 
+test "createNonceAccountInstructions matches official create_nonce_account pairs" {
+    var parsed = try nonce_account_pair_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    try std.testing.expectEqual(@as(usize, system.NONCE_STATE_SIZE), parsed.value.nonce_state_size);
+
+    const rent: sol.rent.Rent.Data = .{};
+    for (parsed.value.vectors) |*vector| {
+        try std.testing.expectEqual(vector.lamports, rent.getMinimumBalance(system.NONCE_STATE_SIZE));
+
+        var create_metas: [2]SystemAccountMeta = undefined;
+        var create_data: SystemCreateAccountData = undefined;
+        var init_metas: [3]SystemAccountMeta = undefined;
+        var init_data: SystemNonceAuthorityData = undefined;
+        const instructions = createNonceAccountInstructions(&vector.payer, &vector.nonce_account, &vector.authority, vector.lamports, .{
+            .create_account_metas = &create_metas,
+            .create_account_data = &create_data,
+            .initialize_nonce_metas = &init_metas,
+            .initialize_nonce_data = &init_data,
+        });
+
+        try std.testing.expectEqual(vector.instructions.len, instructions.slice().len);
+        for (vector.instructions, instructions.slice()) |expected, actual| {
+            try std.testing.expectEqualSlices(u8, &expected.program_id, actual.program_id);
+            try std.testing.expectEqualSlices(u8, expected.data, actual.data);
+            try std.testing.expectEqual(expected.accounts.len, actual.accounts.len);
+            for (expected.accounts, actual.accounts) |expected_meta, actual_meta| {
+                try std.testing.expectEqualSlices(u8, &expected_meta.pubkey, actual_meta.pubkey);
+                try std.testing.expectEqual(expected_meta.is_signer, actual_meta.is_signer);
+                try std.testing.expectEqual(expected_meta.is_writable, actual_meta.is_writable);
+            }
+        }
+    }
+}
+
 test "computeBudgetPrelude builds ordered compute budget prelude" {
     var heap_data: ComputeBudgetRequestHeapFrameData = undefined;
     var limit_data: ComputeBudgetSetComputeUnitLimitData = undefined;
@@ -3082,6 +3134,697 @@ test "buildAndSignLegacyTransaction reports missing signer and small buffers" {
     );
 }
 
+fn expectGoldenTransaction(
+    golden: golden_fixture.GoldenTransaction,
+    lamports_per_signature: u64,
+    payer: Keypair,
+    recent_blockhash: *const [HASH_BYTES]u8,
+    instructions: []const Instruction,
+) !void {
+    var account_keys: [8]Pubkey = undefined;
+    var compiled: [3]tx.CompiledInstruction = undefined;
+    var indices: [16]u8 = undefined;
+    var message_bytes: [512]u8 = undefined;
+    var signatures: [1]Signature = undefined;
+    var transaction_bytes: [640]u8 = undefined;
+
+    const payer_pubkey = payer.publicKey();
+    const built = try buildAndSignLegacyTransaction(
+        &payer_pubkey,
+        recent_blockhash,
+        instructions,
+        &.{payer},
+        .{
+            .account_keys = &account_keys,
+            .compiled_instructions = &compiled,
+            .instruction_account_indices = &indices,
+            .message_bytes = &message_bytes,
+            .signatures = &signatures,
+            .transaction_bytes = &transaction_bytes,
+        },
+    );
+
+    try std.testing.expectEqual(golden.instructions.len, instructions.len);
+    try std.testing.expectEqual(golden.account_keys.len, built.message.account_keys.len);
+    for (golden.account_keys, built.message.account_keys) |*expected, *actual| {
+        try std.testing.expectEqualSlices(u8, expected, actual);
+    }
+    try std.testing.expectEqualSlices(u8, golden.transaction, built.transaction_bytes);
+
+    var base64_buf: [std.base64.standard.Encoder.calcSize(640)]u8 = undefined;
+    const encoded = std.base64.standard.Encoder.encode(&base64_buf, built.transaction_bytes);
+    try std.testing.expectEqualStrings(golden.transaction_base64, encoded);
+
+    const signature_fee = lamports_per_signature * @as(u64, built.message.header.num_required_signatures);
+    try std.testing.expectEqual(golden.fee, signature_fee + golden.prioritization_fee);
+    try keypair.verify(built.signatures[0], built.message_bytes, &payer_pubkey);
+}
+
+test "golden wallet transactions match official Rust serialization" {
+    var parsed = try golden_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqual(@as(usize, 5), fixture.golden_transactions.len);
+
+    const payer = try Keypair.fromSeed(fixture.payer_seed);
+    const recipient = try Keypair.fromSeed(fixture.recipient_seed);
+    const payer_pubkey = payer.publicKey();
+    const recipient_pubkey = recipient.publicKey();
+    const mint: Pubkey = fixture.mint;
+    const nonce_account: Pubkey = fixture.nonce_account;
+    const payer_ata = spl_ata.findAddress(&payer_pubkey, &mint, &spl_token.PROGRAM_ID).address;
+    const recipient_ata = spl_ata.findAddress(&recipient_pubkey, &mint, &spl_token.PROGRAM_ID).address;
+
+    var heap_data: ComputeBudgetRequestHeapFrameData = undefined;
+    var limit_data: ComputeBudgetSetComputeUnitLimitData = undefined;
+    var price_data: ComputeBudgetSetComputeUnitPriceData = undefined;
+    var loaded_data: ComputeBudgetSetLoadedAccountsDataSizeLimitData = undefined;
+    const compute_budget_buffers: ComputeBudgetInstructionBuffers = .{
+        .request_heap_frame_data = &heap_data,
+        .set_compute_unit_limit_data = &limit_data,
+        .set_compute_unit_price_data = &price_data,
+        .set_loaded_accounts_data_size_limit_data = &loaded_data,
+    };
+
+    var transfer_metas: [2]SystemAccountMeta = undefined;
+    var transfer_data: SystemTransferData = undefined;
+    const transfer_ix = system.transfer(&payer_pubkey, &recipient_pubkey, 1_000_000, &transfer_metas, &transfer_data);
+    try expectGoldenTransaction(fixture.golden_transactions[0], fixture.lamports_per_signature, payer, &fixture.recent_blockhash, &.{transfer_ix});
+
+    var budget_transfer_metas: [2]SystemAccountMeta = undefined;
+    var budget_transfer_data: SystemTransferData = undefined;
+    const budget_transfer = transferWithComputeBudget(
+        &payer_pubkey,
+        &recipient_pubkey,
+        1_000_000,
+        .{ .compute_unit_limit = fixture.compute_unit_limit, .compute_unit_price_micro_lamports = fixture.compute_unit_price },
+        .{
+            .compute_budget = compute_budget_buffers,
+            .transfer_metas = &budget_transfer_metas,
+            .transfer_data = &budget_transfer_data,
+        },
+    );
+    try std.testing.expectEqual(
+        compute_budget.prioritizationFee(fixture.compute_unit_limit, fixture.compute_unit_price),
+        fixture.golden_transactions[1].prioritization_fee,
+    );
+    try expectGoldenTransaction(fixture.golden_transactions[1], fixture.lamports_per_signature, payer, &fixture.recent_blockhash, budget_transfer.slice());
+
+    var token_metas: TokenTransferMetas = undefined;
+    var token_data: TokenTransferData = undefined;
+    const token_ix = spl_token.instruction.transfer(&payer_ata, &recipient_ata, &payer_pubkey, 42, &token_metas, &token_data);
+    try expectGoldenTransaction(fixture.golden_transactions[2], fixture.lamports_per_signature, payer, &fixture.recent_blockhash, &.{token_ix});
+
+    var ata_scratch: AtaCreateIdempotentScratch = undefined;
+    var ata_transfer_metas: TokenTransferMetas = undefined;
+    var ata_transfer_data: TokenTransferData = undefined;
+    const ata_transfer = createAtaAndTokenTransferWithComputeBudget(
+        &payer_pubkey,
+        &recipient_pubkey,
+        &payer_ata,
+        &mint,
+        &payer_pubkey,
+        42,
+        .{},
+        .{
+            .compute_budget = compute_budget_buffers,
+            .ata_scratch = &ata_scratch,
+            .transfer_metas = &ata_transfer_metas,
+            .transfer_data = &ata_transfer_data,
+        },
+    );
+    try expectGoldenTransaction(fixture.golden_transactions[3], fixture.lamports_per_signature, payer, &fixture.recent_blockhash, ata_transfer.slice());
+
+    var advance_metas: [3]SystemAccountMeta = undefined;
+    var advance_data: system.DiscriminantOnlyData = undefined;
+    const advance_ix = system.advanceNonceAccount(
+        &nonce_account,
+        &system.RECENT_BLOCKHASHES_ID,
+        &payer_pubkey,
+        &advance_metas,
+        &advance_data,
+    );
+    try expectGoldenTransaction(fixture.golden_transactions[4], fixture.lamports_per_signature, payer, &fixture.durable_nonce, &.{ advance_ix, transfer_ix });
+}
+
+test "compileV0MessageWithLookupTables matches official v0 key ordering" {
+    var parsed = try v0_key_ordering_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqual(@as(usize, 3), fixture.cases.len);
+
+    for (fixture.cases) |case| {
+        var metas: [2][8]tx.AccountMeta = undefined;
+        var instructions: [2]Instruction = undefined;
+        for (case.instructions, 0..) |*fixture_ix, i| {
+            for (fixture_ix.accounts, 0..) |*fixture_meta, j| {
+                metas[i][j] = tx.AccountMeta.init(
+                    &fixture_meta.pubkey,
+                    fixture_meta.is_writable != 0,
+                    fixture_meta.is_signer != 0,
+                );
+            }
+            instructions[i] = Instruction.init(
+                &fixture_ix.program_id,
+                metas[i][0..fixture_ix.accounts.len],
+                fixture_ix.data,
+            );
+        }
+
+        var tables: [2]LookupTableCandidate = undefined;
+        for (case.lookup_tables, 0..) |*fixture_table, i| {
+            tables[i] = .{
+                .account_key = &fixture_table.account_key,
+                .table = .{
+                    .meta = .{
+                        .deactivation_slot = std.math.maxInt(u64),
+                        .last_extended_slot = 1,
+                        .last_extended_slot_start_index = 0,
+                        .authority = null,
+                    },
+                    .addresses = fixture_table.addresses,
+                },
+            };
+        }
+
+        var static_keys: [8]Pubkey = undefined;
+        var compiled: [2]tx.CompiledInstruction = undefined;
+        var ix_indices: [8]u8 = undefined;
+        var lookups: [2]tx.MessageAddressTableLookup = undefined;
+        var writable_indexes: [4]u8 = undefined;
+        var readonly_indexes: [4]u8 = undefined;
+        const message = try compileV0MessageWithLookupTables(
+            &fixture.payer,
+            &fixture.recent_blockhash,
+            instructions[0..case.instructions.len],
+            tables[0..case.lookup_tables.len],
+            .{
+                .static_account_keys = &static_keys,
+                .compiled_instructions = &compiled,
+                .instruction_account_indices = &ix_indices,
+                .address_table_lookups = &lookups,
+                .writable_lookup_indexes = &writable_indexes,
+                .readonly_lookup_indexes = &readonly_indexes,
+            },
+        );
+
+        try std.testing.expectEqual(case.static_keys.len, message.account_keys.len);
+        for (case.static_keys, message.account_keys) |*expected, *actual| {
+            try std.testing.expectEqualSlices(u8, expected, actual);
+        }
+        try std.testing.expectEqual(case.address_table_lookups.len, message.address_table_lookups.len);
+        for (case.address_table_lookups, message.address_table_lookups) |expected, actual| {
+            try std.testing.expectEqualSlices(u8, &expected.account_key, actual.account_key);
+            try std.testing.expectEqualSlices(u8, expected.writable_indexes, actual.writable_indexes);
+            try std.testing.expectEqualSlices(u8, expected.readonly_indexes, actual.readonly_indexes);
+        }
+
+        var message_bytes: [512]u8 = undefined;
+        const serialized = try tx.serializeV0Message(message, &message_bytes);
+        try std.testing.expectEqualSlices(u8, case.message, serialized);
+    }
+}
+
+fn expectPrecompileTransaction(
+    payer: Keypair,
+    recent_blockhash: *const [HASH_BYTES]u8,
+    carrier_ix: Instruction,
+    precompile_ix: Instruction,
+    expected_account_keys: []const [32]u8,
+    expected_transaction: []const u8,
+) !void {
+    var account_keys: [4]Pubkey = undefined;
+    var compiled: [2]tx.CompiledInstruction = undefined;
+    var indices: [4]u8 = undefined;
+    var message_bytes: [1024]u8 = undefined;
+    var signatures: [1]Signature = undefined;
+    var transaction_bytes: [1024]u8 = undefined;
+
+    const payer_pubkey = payer.publicKey();
+    const built = try buildAndSignLegacyTransaction(
+        &payer_pubkey,
+        recent_blockhash,
+        &.{ carrier_ix, precompile_ix },
+        &.{payer},
+        .{
+            .account_keys = &account_keys,
+            .compiled_instructions = &compiled,
+            .instruction_account_indices = &indices,
+            .message_bytes = &message_bytes,
+            .signatures = &signatures,
+            .transaction_bytes = &transaction_bytes,
+        },
+    );
+
+    try std.testing.expectEqual(expected_account_keys.len, built.message.account_keys.len);
+    for (expected_account_keys, built.message.account_keys) |*expected, *actual| {
+        try std.testing.expectEqualSlices(u8, expected, actual);
+    }
+    try std.testing.expectEqualSlices(u8, expected_transaction, built.transaction_bytes);
+}
+
+test "precompile offsets into a carrier instruction match official Rust transactions" {
+    var parsed = try precompile_cross_instruction_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    const payer = try Keypair.fromSeed(fixture.payer_seed);
+    const carrier_program_id: Pubkey = fixture.carrier_program_id;
+    // The carrier is instruction 0, so every offset entry names it by index
+    // instead of the precompile's own `u16::MAX` / absolute self reference.
+    const carrier_index: u8 = 0;
+
+    {
+        const ed25519 = sol.ed25519_instruction;
+        const case = fixture.ed25519;
+        try std.testing.expectEqual(@as(usize, 2), case.signers.len);
+
+        var carrier: [512]u8 = undefined;
+        var len: usize = 0;
+        var offsets: [2]ed25519.SignatureOffsets = undefined;
+        for (case.signers, &offsets) |*signer, *entry| {
+            const signer_keypair = try Keypair.fromSeed(signer.seed);
+            const public_key = signer_keypair.publicKey();
+            const signature = try signer_keypair.sign(signer.message);
+            try std.testing.expectEqualSlices(u8, &signer.public_key, &public_key);
+            try std.testing.expectEqualSlices(u8, &signer.signature, &signature);
+
+            entry.public_key_offset = @intCast(len);
+            entry.public_key_instruction_index = carrier_index;
+            @memcpy(carrier[len..][0..ed25519.PUBKEY_SERIALIZED_SIZE], &public_key);
+            len += ed25519.PUBKEY_SERIALIZED_SIZE;
+
+            entry.signature_offset = @intCast(len);
+            entry.signature_instruction_index = carrier_index;
+            @memcpy(carrier[len..][0..ed25519.SIGNATURE_SERIALIZED_SIZE], &signature);
+            len += ed25519.SIGNATURE_SERIALIZED_SIZE;
+        }
+        for (case.signers, &offsets) |*signer, *entry| {
+            entry.message_data_offset = @intCast(len);
+            entry.message_data_size = @intCast(signer.message.len);
+            entry.message_instruction_index = carrier_index;
+            @memcpy(carrier[len..][0..signer.message.len], signer.message);
+            len += signer.message.len;
+        }
+        try std.testing.expectEqualSlices(u8, case.carrier_data, carrier[0..len]);
+
+        try std.testing.expectEqual(case.offsets.len, offsets.len);
+        for (case.offsets, offsets) |expected, actual| {
+            try std.testing.expectEqual(expected.signature_offset, actual.signature_offset);
+            try std.testing.expectEqual(expected.signature_instruction_index, actual.signature_instruction_index);
+            try std.testing.expectEqual(expected.public_key_offset, actual.public_key_offset);
+            try std.testing.expectEqual(expected.public_key_instruction_index, actual.public_key_instruction_index);
+            try std.testing.expectEqual(expected.message_data_offset, actual.message_data_offset);
+            try std.testing.expectEqual(expected.message_data_size, actual.message_data_size);
+            try std.testing.expectEqual(expected.message_instruction_index, actual.message_instruction_index);
+        }
+
+        var scratch: [64]u8 = undefined;
+        const precompile_ix = try ed25519.buildInstruction(&offsets, &.{}, &scratch);
+        try std.testing.expectEqualSlices(u8, case.precompile_data, precompile_ix.data);
+
+        try expectPrecompileTransaction(
+            payer,
+            &fixture.recent_blockhash,
+            .{ .program_id = &carrier_program_id, .accounts = &.{}, .data = carrier[0..len] },
+            precompile_ix,
+            case.account_keys,
+            case.transaction,
+        );
+    }
+
+    {
+        const secp256k1 = sol.secp256k1_instruction;
+        const case = fixture.secp256k1;
+        try std.testing.expectEqual(@as(usize, 2), case.signers.len);
+
+        var carrier: [512]u8 = undefined;
+        var len: usize = 0;
+        var offsets: [2]secp256k1.SignatureOffsets = undefined;
+        for (case.signers, &offsets) |*signer, *entry| {
+            const eth_address = try secp256k1.constructEthAddress(&signer.public_key);
+            try std.testing.expectEqualSlices(u8, &signer.eth_address, &eth_address);
+
+            entry.eth_address_offset = @intCast(len);
+            entry.eth_address_instruction_index = carrier_index;
+            @memcpy(carrier[len..][0..secp256k1.HASHED_PUBKEY_SERIALIZED_SIZE], &eth_address);
+            len += secp256k1.HASHED_PUBKEY_SERIALIZED_SIZE;
+
+            entry.signature_offset = @intCast(len);
+            entry.signature_instruction_index = carrier_index;
+            @memcpy(carrier[len..][0..secp256k1.SIGNATURE_SERIALIZED_SIZE], &signer.signature);
+            len += secp256k1.SIGNATURE_SERIALIZED_SIZE;
+            carrier[len] = signer.recovery_id;
+            len += secp256k1.RECOVERY_ID_SERIALIZED_SIZE;
+        }
+        for (case.signers, &offsets) |*signer, *entry| {
+            entry.message_data_offset = @intCast(len);
+            entry.message_data_size = @intCast(signer.message.len);
+            entry.message_instruction_index = carrier_index;
+            @memcpy(carrier[len..][0..signer.message.len], signer.message);
+            len += signer.message.len;
+        }
+        try std.testing.expectEqualSlices(u8, case.carrier_data, carrier[0..len]);
+
+        try std.testing.expectEqual(case.offsets.len, offsets.len);
+        for (case.offsets, offsets) |expected, actual| {
+            try std.testing.expectEqual(expected.signature_offset, actual.signature_offset);
+            try std.testing.expectEqual(expected.signature_instruction_index, actual.signature_instruction_index);
+            try std.testing.expectEqual(expected.eth_address_offset, actual.eth_address_offset);
+            try std.testing.expectEqual(expected.eth_address_instruction_index, actual.eth_address_instruction_index);
+            try std.testing.expectEqual(expected.message_data_offset, actual.message_data_offset);
+            try std.testing.expectEqual(expected.message_data_size, actual.message_data_size);
+            try std.testing.expectEqual(expected.message_instruction_index, actual.message_instruction_index);
+        }
+
+        var scratch: [64]u8 = undefined;
+        const precompile_ix = try secp256k1.buildInstruction(&offsets, &.{}, &scratch);
+        try std.testing.expectEqualSlices(u8, case.precompile_data, precompile_ix.data);
+
+        try expectPrecompileTransaction(
+            payer,
+            &fixture.recent_blockhash,
+            .{ .program_id = &carrier_program_id, .accounts = &.{}, .data = carrier[0..len] },
+            precompile_ix,
+            case.account_keys,
+            case.transaction,
+        );
+    }
+}
+
+test "precompile layout constants match official Rust builders" {
+    var parsed = try precompile_layout_constants_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const layouts = parsed.value.layouts;
+    try std.testing.expectEqual(@as(usize, 2), layouts.len);
+    // Fixed inputs the Rust generator feeds `new_*_instruction_with_signature`.
+    const signature = [_]u8{0x22} ** 64;
+
+    {
+        const ed25519 = sol.ed25519_instruction;
+        const layout = layouts[0];
+        try std.testing.expectEqualStrings("ed25519", layout.program);
+        try std.testing.expectEqualSlices(u8, &layout.program_id, &ed25519.PROGRAM_ID);
+        try std.testing.expectEqual(layout.header_len, ed25519.SIGNATURE_OFFSETS_START);
+        try std.testing.expectEqual(layout.offsets_struct_len, ed25519.SIGNATURE_OFFSETS_SERIALIZED_SIZE);
+        try std.testing.expectEqual(layout.data_start, ed25519.DATA_START);
+        try std.testing.expectEqual(@as(usize, @sizeOf(ed25519.SignatureOffsets)), layout.offsets_struct_len);
+
+        const expected_order = [_][]const u8{ "public_key", "signature", "message" };
+        try std.testing.expectEqual(expected_order.len, layout.payload_order.len);
+        for (expected_order, layout.payload_order) |expected, actual| {
+            try std.testing.expectEqualStrings(expected, actual);
+        }
+
+        const public_key = [_]u8{0x11} ** ed25519.PUBKEY_SERIALIZED_SIZE;
+        var scratch: [128]u8 = undefined;
+        const ix = try ed25519.verify(layout.message, &public_key, &signature, &scratch);
+        try std.testing.expectEqualSlices(u8, layout.single_signature_data, ix.data);
+        try std.testing.expectEqual(ed25519.encodedLen(layout.message.len).?, ix.data.len);
+    }
+
+    {
+        const secp256k1 = sol.secp256k1_instruction;
+        const layout = layouts[1];
+        try std.testing.expectEqualStrings("secp256k1", layout.program);
+        try std.testing.expectEqualSlices(u8, &layout.program_id, &secp256k1.PROGRAM_ID);
+        try std.testing.expectEqual(layout.header_len, secp256k1.DATA_START - secp256k1.SIGNATURE_OFFSETS_SERIALIZED_SIZE);
+        try std.testing.expectEqual(layout.offsets_struct_len, secp256k1.SIGNATURE_OFFSETS_SERIALIZED_SIZE);
+        try std.testing.expectEqual(layout.data_start, secp256k1.DATA_START);
+
+        const expected_order = [_][]const u8{ "eth_address", "signature", "recovery_id", "message" };
+        try std.testing.expectEqual(expected_order.len, layout.payload_order.len);
+        for (expected_order, layout.payload_order) |expected, actual| {
+            try std.testing.expectEqualStrings(expected, actual);
+        }
+
+        const eth_address = [_]u8{0x33} ** secp256k1.HASHED_PUBKEY_SERIALIZED_SIZE;
+        var scratch: [128]u8 = undefined;
+        const ix = try secp256k1.verifyFirst(layout.message, &eth_address, &signature, 1, &scratch);
+        try std.testing.expectEqualSlices(u8, layout.single_signature_data, ix.data);
+        try std.testing.expectEqual(secp256k1.encodedLen(layout.message.len).?, ix.data.len);
+    }
+
+    for (layouts) |layout| {
+        try std.testing.expectEqual(@as(u8, 1), layout.single_signature_data[0]);
+        for (layout.single_signature_data[layout.count_len..layout.header_len]) |pad| {
+            try std.testing.expectEqual(@as(u8, 0), pad);
+        }
+    }
+}
+
+test "legacy and v0 message serialization matches official VersionedMessage bytes" {
+    var parsed = try versioned_message_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqual(@as(usize, 4), fixture.vectors.len);
+
+    for (fixture.vectors) |*vector| {
+        const header: tx.MessageHeader = .{
+            .num_required_signatures = vector.header.num_required_signatures,
+            .num_readonly_signed_accounts = vector.header.num_readonly_signed_accounts,
+            .num_readonly_unsigned_accounts = vector.header.num_readonly_unsigned_accounts,
+        };
+        var compiled: [2]tx.CompiledInstruction = undefined;
+        for (vector.instructions, 0..) |fixture_ix, i| {
+            compiled[i] = .{
+                .program_id_index = fixture_ix.program_id_index,
+                .accounts = fixture_ix.accounts,
+                .data = fixture_ix.data,
+            };
+        }
+        var lookups: [2]tx.MessageAddressTableLookup = undefined;
+        for (vector.address_table_lookups, 0..) |*fixture_lookup, i| {
+            lookups[i] = .{
+                .account_key = &fixture_lookup.account_key,
+                .writable_indexes = fixture_lookup.writable_indexes,
+                .readonly_indexes = fixture_lookup.readonly_indexes,
+            };
+        }
+
+        var out: [256]u8 = undefined;
+        const bytes = if (vector.version) |version| blk: {
+            try std.testing.expectEqual(@as(u8, 0), version);
+            try std.testing.expectEqual(@as(u8, 0x80), vector.serialized[0]);
+            break :blk try tx.serializeV0Message(.{
+                .header = header,
+                .account_keys = vector.account_keys,
+                .recent_blockhash = &vector.recent_blockhash,
+                .instructions = compiled[0..vector.instructions.len],
+                .address_table_lookups = lookups[0..vector.address_table_lookups.len],
+            }, &out);
+        } else blk: {
+            try std.testing.expectEqual(@as(usize, 0), vector.address_table_lookups.len);
+            try std.testing.expectEqual(@as(u8, 0), vector.serialized[0] & 0x80);
+            break :blk try tx.serializeLegacyMessage(.{
+                .header = header,
+                .account_keys = vector.account_keys,
+                .recent_blockhash = &vector.recent_blockhash,
+                .instructions = compiled[0..vector.instructions.len],
+            }, &out);
+        };
+        try std.testing.expectEqualSlices(u8, vector.serialized, bytes);
+    }
+}
+
+test "address table lookup encoding matches official MessageAddressTableLookup bytes" {
+    var parsed = try address_table_lookup_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqual(@as(usize, 8), fixture.vectors.len);
+
+    for (fixture.vectors) |*vector| {
+        const lookup: tx.MessageAddressTableLookup = .{
+            .account_key = &vector.account_key,
+            .writable_indexes = vector.writable_indexes,
+            .readonly_indexes = vector.readonly_indexes,
+        };
+        try std.testing.expectEqual(vector.serialized.len, try tx.serializedAddressTableLookupLen(lookup));
+
+        var out: [32 + 2 + 256 + 2 + 256]u8 = undefined;
+        const bytes = try tx.serializeAddressTableLookup(lookup, &out);
+        try std.testing.expectEqualSlices(u8, vector.serialized, bytes);
+
+        const writable_prefix_len = try tx.shortVecLen(vector.writable_indexes.len);
+        try std.testing.expectEqual(vector.writable_indexes.len >= 128, writable_prefix_len == 2);
+        try std.testing.expectError(
+            error.OutputTooSmall,
+            tx.serializeAddressTableLookup(lookup, out[0 .. bytes.len - 1]),
+        );
+    }
+}
+
+fn expectSigningPayload(
+    vector: signing_payload_fixture.SigningPayloadVector,
+    payer: Keypair,
+    signers: []const Keypair,
+    instructions: []const Instruction,
+) !void {
+    // The fixture bytes alone reproduce every signature.
+    try std.testing.expectEqual(vector.signer_seeds.len, vector.signatures.len);
+    for (vector.signer_seeds, vector.signatures) |seed, *expected| {
+        const signer = try Keypair.fromSeed(seed);
+        try std.testing.expectEqualSlices(u8, expected, &(try signer.sign(vector.message_data)));
+    }
+
+    var account_keys: [8]Pubkey = undefined;
+    var compiled: [3]tx.CompiledInstruction = undefined;
+    var indices: [16]u8 = undefined;
+    var message_bytes: [512]u8 = undefined;
+    var signatures: [2]Signature = undefined;
+    var transaction_bytes: [768]u8 = undefined;
+
+    const payer_pubkey = payer.publicKey();
+    const built = try buildAndSignLegacyTransaction(
+        &payer_pubkey,
+        &vector.recent_blockhash,
+        instructions,
+        signers,
+        .{
+            .account_keys = &account_keys,
+            .compiled_instructions = &compiled,
+            .instruction_account_indices = &indices,
+            .message_bytes = &message_bytes,
+            .signatures = &signatures,
+            .transaction_bytes = &transaction_bytes,
+        },
+    );
+    try std.testing.expectEqualSlices(u8, vector.message_data, built.message_bytes);
+    try std.testing.expectEqual(vector.signatures.len, built.signatures.len);
+    for (vector.signatures, built.signatures) |*expected, *actual| {
+        try std.testing.expectEqualSlices(u8, expected, actual);
+    }
+}
+
+test "signing payloads and signatures match official Transaction::message_data" {
+    var parsed = try signing_payload_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqual(@as(usize, 3), fixture.vectors.len);
+
+    const payer = try Keypair.fromSeed(fixture.payer_seed);
+    const recipient = try Keypair.fromSeed(fixture.recipient_seed);
+    const payer_pubkey = payer.publicKey();
+    const recipient_pubkey = recipient.publicKey();
+    const nonce_account: Pubkey = fixture.nonce_account;
+
+    var transfer_metas: [2]SystemAccountMeta = undefined;
+    var transfer_data: SystemTransferData = undefined;
+    const transfer_ix = system.transfer(&payer_pubkey, &recipient_pubkey, 1_000_000, &transfer_metas, &transfer_data);
+    try expectSigningPayload(fixture.vectors[0], payer, &.{payer}, &.{transfer_ix});
+
+    var limit_data: ComputeBudgetSetComputeUnitLimitData = undefined;
+    var refund_metas: [2]SystemAccountMeta = undefined;
+    var refund_data: SystemTransferData = undefined;
+    try expectSigningPayload(fixture.vectors[1], payer, &.{ recipient, payer }, &.{
+        compute_budget.setComputeUnitLimit(200_000, &limit_data),
+        transfer_ix,
+        system.transfer(&recipient_pubkey, &payer_pubkey, 500, &refund_metas, &refund_data),
+    });
+
+    var advance_metas: [3]SystemAccountMeta = undefined;
+    var advance_data: system.DiscriminantOnlyData = undefined;
+    const advance_ix = system.advanceNonceAccount(
+        &nonce_account,
+        &system.RECENT_BLOCKHASHES_ID,
+        &payer_pubkey,
+        &advance_metas,
+        &advance_data,
+    );
+    try expectSigningPayload(fixture.vectors[2], payer, &.{payer}, &.{ advance_ix, transfer_ix });
+}
+
+test "partially signed transactions match official Transaction::partial_sign" {
+    var parsed = try partially_signed_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqual(@as(usize, 4), fixture.vectors.len);
+
+    const first = try Keypair.fromSeed(fixture.first_seed);
+    const second = try Keypair.fromSeed(fixture.second_seed);
+    const first_pubkey = first.publicKey();
+    const second_pubkey = second.publicKey();
+
+    var out_metas: [2]SystemAccountMeta = undefined;
+    var out_data: SystemTransferData = undefined;
+    var back_metas: [2]SystemAccountMeta = undefined;
+    var back_data: SystemTransferData = undefined;
+    var account_keys: [3]Pubkey = undefined;
+    var compiled: [2]tx.CompiledInstruction = undefined;
+    var indices: [4]u8 = undefined;
+    const message = try tx.compileLegacyMessage(
+        &first_pubkey,
+        &fixture.recent_blockhash,
+        &.{
+            system.transfer(&first_pubkey, &second_pubkey, 1_000_000, &out_metas, &out_data),
+            system.transfer(&second_pubkey, &first_pubkey, 500, &back_metas, &back_data),
+        },
+        &account_keys,
+        &compiled,
+        &indices,
+    );
+    var message_bytes: [256]u8 = undefined;
+    const message_data = try tx.serializeLegacyMessage(message, &message_bytes);
+    try std.testing.expectEqualSlices(u8, fixture.message_data, message_data);
+
+    const signers = [_]Keypair{ first, second };
+    for (fixture.vectors) |vector| {
+        var signatures: [2]Signature = undefined;
+        try std.testing.expectEqual(signatures.len, vector.signed_slots.len);
+        for (&signatures, vector.signed_slots, signers) |*signature, signed, signer| {
+            signature.* = if (signed) try signer.sign(message_data) else .{0} ** tx.SIGNATURE_BYTES;
+        }
+        try std.testing.expectEqual(vector.is_signed, tx.isSigned(&signatures));
+
+        var transaction_bytes: [512]u8 = undefined;
+        try std.testing.expectEqualSlices(
+            u8,
+            vector.transaction,
+            try tx.serializeLegacyTransaction(&signatures, message, &transaction_bytes),
+        );
+    }
+}
+
+test "memo transactions around PACKET_DATA_SIZE match official serialized lengths" {
+    var parsed = try packet_size_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqual(tx.PACKET_DATA_SIZE, fixture.packet_data_size);
+
+    const payer = try Keypair.fromSeed(fixture.payer_seed);
+    const payer_pubkey = payer.publicKey();
+    var memo: [tx.PACKET_DATA_SIZE]u8 = undefined;
+    for (&memo, 0..) |*byte, i| byte.* = 'a' + @as(u8, @intCast(i % 26));
+
+    for (fixture.vectors) |vector| {
+        const metas = [_]tx.AccountMeta{tx.AccountMeta.init(&payer_pubkey, false, true)};
+        const ix = Instruction.init(&sol.spl_memo_program_id, &metas, memo[0..vector.memo_len]);
+
+        var account_keys: [2]Pubkey = undefined;
+        var compiled: [1]tx.CompiledInstruction = undefined;
+        var indices: [1]u8 = undefined;
+        var message_bytes: [tx.PACKET_DATA_SIZE]u8 = undefined;
+        var signatures: [1]Signature = undefined;
+        var transaction_bytes: [tx.PACKET_DATA_SIZE + 64]u8 = undefined;
+        const built = try buildAndSignLegacyTransaction(
+            &payer_pubkey,
+            &fixture.recent_blockhash,
+            &.{ix},
+            &.{payer},
+            .{
+                .account_keys = &account_keys,
+                .compiled_instructions = &compiled,
+                .instruction_account_indices = &indices,
+                .message_bytes = &message_bytes,
+                .signatures = &signatures,
+                .transaction_bytes = &transaction_bytes,
+            },
+        );
+        try std.testing.expectEqualSlices(u8, vector.transaction, built.transaction_bytes);
+        try std.testing.expectEqual(vector.serialized_len, try tx.serializedLegacyTransactionLen(built.signatures, built.message));
+        try std.testing.expectEqual(vector.exceeds_limit, vector.serialized_len > tx.PACKET_DATA_SIZE);
+    }
+}
+
 test "public surface guards" {
     try std.testing.expect(@hasDecl(@This(), "buildAndSignLegacyTransaction"));
     try std.testing.expect(@hasDecl(@This(), "buildAndSignV0Transaction"));
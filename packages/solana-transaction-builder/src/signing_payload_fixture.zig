@@ -0,0 +1,25 @@
+const std = @import("std");
+
+pub const SigningPayloadVector = struct {
+    label: []const u8,
+    signer_seeds: []const [32]u8,
+    recent_blockhash: [32]u8,
+    message_data: []const u8,
+    signatures: []const [64]u8,
+};
+
+pub const Fixture = struct {
+    payer_seed: [32]u8,
+    recipient_seed: [32]u8,
+    nonce_account: [32]u8,
+    vectors: []const SigningPayloadVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_signing_payload_vectors.json"),
+        .{},
+    );
+}
@@ -0,0 +1,57 @@
+const std = @import("std");
+
+pub const AccountFixture = struct {
+    pubkey: [32]u8,
+    is_signer: u8,
+    is_writable: u8,
+};
+
+pub const InstructionFixture = struct {
+    program_id: [32]u8,
+    accounts: []const AccountFixture,
+    data: []const u8,
+};
+
+pub const LookupTableFixture = struct {
+    account_key: [32]u8,
+    addresses: []const [32]u8,
+};
+
+pub const AddressTableLookupFixture = struct {
+    account_key: [32]u8,
+    writable_indexes: []const u8,
+    readonly_indexes: []const u8,
+};
+
+pub const CombinedIndex = struct {
+    pubkey: [32]u8,
+    index: u8,
+};
+
+pub const V0KeyOrderingCase = struct {
+    label: []const u8,
+    instructions_description: []const []const u8,
+    instructions: []const InstructionFixture,
+    lookup_tables: []const LookupTableFixture,
+    static_keys: []const [32]u8,
+    loaded_writable: []const [32]u8,
+    loaded_readonly: []const [32]u8,
+    address_table_lookups: []const AddressTableLookupFixture,
+    combined_index_of: []const CombinedIndex,
+    message: []const u8,
+};
+
+pub const Fixture = struct {
+    payer: [32]u8,
+    recent_blockhash: [32]u8,
+    cases: []const V0KeyOrderingCase,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_v0_key_ordering.json"),
+        .{},
+    );
+}
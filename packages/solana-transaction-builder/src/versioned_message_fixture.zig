@@ -0,0 +1,43 @@
+const std = @import("std");
+
+pub const HeaderFixture = struct {
+    num_required_signatures: u8,
+    num_readonly_signed_accounts: u8,
+    num_readonly_unsigned_accounts: u8,
+};
+
+pub const CompiledInstructionFixture = struct {
+    program_id_index: u8,
+    accounts: []const u8,
+    data: []const u8,
+};
+
+pub const AddressTableLookupFixture = struct {
+    account_key: [32]u8,
+    writable_indexes: []const u8,
+    readonly_indexes: []const u8,
+};
+
+pub const VersionedMessageTestVector = struct {
+    name: []const u8,
+    version: ?u8,
+    header: HeaderFixture,
+    account_keys: []const [32]u8,
+    recent_blockhash: [32]u8,
+    instructions: []const CompiledInstructionFixture,
+    address_table_lookups: []const AddressTableLookupFixture,
+    serialized: []const u8,
+};
+
+pub const Fixture = struct {
+    vectors: []const VersionedMessageTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_versioned_message_vectors.json"),
+        .{},
+    );
+}
@@ -17,6 +17,9 @@ pub const SIGNATURE_BYTES: usize = 64;
 pub const MAX_LEGACY_ACCOUNT_KEYS: usize = 256;
 pub const VERSIONED_MESSAGE_PREFIX: u8 = 0x80;
 pub const V0_MESSAGE_VERSION: u8 = 0;
+/// Largest serialized transaction the cluster accepts: an IPv6 MTU minus
+/// the IP and UDP headers, as `solana_packet::PACKET_DATA_SIZE`.
+pub const PACKET_DATA_SIZE: usize = 1280 - 40 - 8;
 pub const Signature = [SIGNATURE_BYTES]u8;
 
 pub const Error = codec.Error || error{
@@ -227,6 +230,15 @@ pub fn serializeLegacyTransaction(
     return out[0..pos];
 }
 
+/// Whether every signature slot is filled. An unsigned slot holds the
+/// all-zero default signature, as `Transaction::partial_sign` leaves it.
+pub fn isSigned(signatures: []const Signature) bool {
+    for (signatures) |signature| {
+        if (std.mem.allEqual(u8, &signature, 0)) return false;
+    }
+    return true;
+}
+
 pub fn serializedV0MessageLen(message: V0Message) Error!usize {
     var len: usize = 1;
     len += try serializedLegacyMessageLen(.{
@@ -237,9 +249,7 @@ pub fn serializedV0MessageLen(message: V0Message) Error!usize {
     });
     len += try shortVecLen(message.address_table_lookups.len);
     for (message.address_table_lookups) |lookup| {
-        len += PUBKEY_BYTES;
-        len += (try shortVecLen(lookup.writable_indexes.len)) + lookup.writable_indexes.len;
-        len += (try shortVecLen(lookup.readonly_indexes.len)) + lookup.readonly_indexes.len;
+        len += try serializedAddressTableLookupLen(lookup);
     }
     return len;
 }
@@ -262,17 +272,34 @@ pub fn serializeV0Message(message: V0Message, out: []u8) Error![]u8 {
 
     pos += try writeShortVec(message.address_table_lookups.len, out[pos..]);
     for (message.address_table_lookups) |lookup| {
-        @memcpy(out[pos..][0..PUBKEY_BYTES], lookup.account_key);
-        pos += PUBKEY_BYTES;
+        const lookup_bytes = try serializeAddressTableLookup(lookup, out[pos..]);
+        pos += lookup_bytes.len;
+    }
+
+    return out[0..pos];
+}
 
-        pos += try writeShortVec(lookup.writable_indexes.len, out[pos..]);
-        @memcpy(out[pos..][0..lookup.writable_indexes.len], lookup.writable_indexes);
-        pos += lookup.writable_indexes.len;
+pub fn serializedAddressTableLookupLen(lookup: MessageAddressTableLookup) Error!usize {
+    return PUBKEY_BYTES +
+        (try shortVecLen(lookup.writable_indexes.len)) + lookup.writable_indexes.len +
+        (try shortVecLen(lookup.readonly_indexes.len)) + lookup.readonly_indexes.len;
+}
 
-        pos += try writeShortVec(lookup.readonly_indexes.len, out[pos..]);
-        @memcpy(out[pos..][0..lookup.readonly_indexes.len], lookup.readonly_indexes);
-        pos += lookup.readonly_indexes.len;
-    }
+pub fn serializeAddressTableLookup(lookup: MessageAddressTableLookup, out: []u8) Error![]u8 {
+    const needed = try serializedAddressTableLookupLen(lookup);
+    if (out.len < needed) return error.OutputTooSmall;
+
+    var pos: usize = 0;
+    @memcpy(out[pos..][0..PUBKEY_BYTES], lookup.account_key);
+    pos += PUBKEY_BYTES;
+
+    pos += try writeShortVec(lookup.writable_indexes.len, out[pos..]);
+    @memcpy(out[pos..][0..lookup.writable_indexes.len], lookup.writable_indexes);
+    pos += lookup.writable_indexes.len;
+
+    pos += try writeShortVec(lookup.readonly_indexes.len, out[pos..]);
+    @memcpy(out[pos..][0..lookup.readonly_indexes.len], lookup.readonly_indexes);
+    pos += lookup.readonly_indexes.len;
 
     return out[0..pos];
 }
@@ -341,6 +368,9 @@ fn upsertKey(
     key_count.* += 1;
 }
 
+/// Writes keys in the runtime's four-segment order. The payer stays at
+/// index 0; every other key is sorted by pubkey bytes within its segment,
+/// matching `solana_message::Message::new`.
 fn writeCanonicalKeys(keys: []const KeyFlags, out: []Pubkey) struct {
     signed_writable: usize,
     signed_readonly: usize,
@@ -348,39 +378,12 @@ fn writeCanonicalKeys(keys: []const KeyFlags, out: []Pubkey) struct {
     unsigned_readonly: usize,
 } {
     var cursor: usize = 0;
-    var signed_writable: usize = 0;
-    var signed_readonly: usize = 0;
-    var unsigned_writable: usize = 0;
-    var unsigned_readonly: usize = 0;
+    const signed_writable = writeKeySegment(keys, out, &cursor, true, true);
+    const signed_readonly = writeKeySegment(keys, out, &cursor, true, false);
+    const unsigned_writable = writeKeySegment(keys, out, &cursor, false, true);
+    const unsigned_readonly = writeKeySegment(keys, out, &cursor, false, false);
 
-    for (keys) |entry| {
-        if (entry.is_signer and entry.is_writable) {
-            out[cursor] = entry.key;
-            cursor += 1;
-            signed_writable += 1;
-        }
-    }
-    for (keys) |entry| {
-        if (entry.is_signer and !entry.is_writable) {
-            out[cursor] = entry.key;
-            cursor += 1;
-            signed_readonly += 1;
-        }
-    }
-    for (keys) |entry| {
-        if (!entry.is_signer and entry.is_writable) {
-            out[cursor] = entry.key;
-            cursor += 1;
-            unsigned_writable += 1;
-        }
-    }
-    for (keys) |entry| {
-        if (!entry.is_signer and !entry.is_writable) {
-            out[cursor] = entry.key;
-            cursor += 1;
-            unsigned_readonly += 1;
-        }
-    }
+    if (signed_writable > 1) sortKeys(out[1..signed_writable]);
 
     return .{
         .signed_writable = signed_writable,
@@ -390,6 +393,33 @@ fn writeCanonicalKeys(keys: []const KeyFlags, out: []Pubkey) struct {
     };
 }
 
+fn writeKeySegment(
+    keys: []const KeyFlags,
+    out: []Pubkey,
+    cursor: *usize,
+    is_signer: bool,
+    is_writable: bool,
+) usize {
+    const start = cursor.*;
+    for (keys) |entry| {
+        if (entry.is_signer == is_signer and entry.is_writable == is_writable) {
+            out[cursor.*] = entry.key;
+            cursor.* += 1;
+        }
+    }
+    // The payer segment is sorted by the caller so index 0 stays pinned.
+    if (!(is_signer and is_writable)) sortKeys(out[start..cursor.*]);
+    return cursor.* - start;
+}
+
+fn sortKeys(segment: []Pubkey) void {
+    std.mem.sort(Pubkey, segment, {}, pubkeyLessThan);
+}
+
+fn pubkeyLessThan(_: void, a: Pubkey, b: Pubkey) bool {
+    return std.mem.order(u8, &a, &b) == .lt;
+}
+
 fn findKey(keys: []const Pubkey, target: *const Pubkey) ?u8 {
     for (keys, 0..) |*key, i| {
         if (sol.pubkey.pubkeyEq(key, target)) return @intCast(i);
@@ -456,6 +486,45 @@ test "compileLegacyMessage orders payer signers writable accounts and programs c
     try std.testing.expectEqualSlices(u8, &.{ 2, 3, 1 }, message.instructions[0].accounts);
 }
 
+test "compileLegacyMessage sorts keys by pubkey within each segment" {
+    const payer: Pubkey = .{9} ** 32;
+    const signer_b: Pubkey = .{6} ** 32;
+    const signer_a: Pubkey = .{2} ** 32;
+    const writable_b: Pubkey = .{5} ** 32;
+    const writable_a: Pubkey = .{1} ** 32;
+    const program: Pubkey = .{3} ** 32;
+    const recent: [HASH_BYTES]u8 = .{7} ** HASH_BYTES;
+
+    var metas = [_]AccountMeta{
+        AccountMeta.writable(&writable_b),
+        AccountMeta.signerWritable(&signer_b),
+        AccountMeta.writable(&writable_a),
+        AccountMeta.signerWritable(&signer_a),
+    };
+    const ix = Instruction.init(&program, &metas, &.{});
+
+    var keys: [8]Pubkey = undefined;
+    var compiled: [1]CompiledInstruction = undefined;
+    var ix_indices: [4]u8 = undefined;
+    const message = try compileLegacyMessage(
+        &payer,
+        &recent,
+        &.{ix},
+        &keys,
+        &compiled,
+        &ix_indices,
+    );
+
+    try std.testing.expectEqual(@as(u8, 3), message.header.num_required_signatures);
+    try std.testing.expectEqualSlices(u8, &payer, &message.account_keys[0]);
+    try std.testing.expectEqualSlices(u8, &signer_a, &message.account_keys[1]);
+    try std.testing.expectEqualSlices(u8, &signer_b, &message.account_keys[2]);
+    try std.testing.expectEqualSlices(u8, &writable_a, &message.account_keys[3]);
+    try std.testing.expectEqualSlices(u8, &writable_b, &message.account_keys[4]);
+    try std.testing.expectEqualSlices(u8, &program, &message.account_keys[5]);
+    try std.testing.expectEqualSlices(u8, &.{ 4, 2, 3, 1 }, message.instructions[0].accounts);
+}
+
 test "serializeLegacyMessage emits canonical legacy message bytes" {
     const payer: Pubkey = .{1} ** 32;
     const memo_program: Pubkey = .{2} ** 32;
@@ -539,6 +608,15 @@ test "serializeLegacyTransaction enforces required signature count" {
     );
 }
 
+test "isSigned requires every signature slot to be non-zero" {
+    const zero: Signature = .{0} ** SIGNATURE_BYTES;
+    const signed: Signature = .{1} ** SIGNATURE_BYTES;
+    try std.testing.expect(isSigned(&.{ signed, signed }));
+    try std.testing.expect(!isSigned(&.{ signed, zero }));
+    try std.testing.expect(!isSigned(&.{ zero, signed }));
+    try std.testing.expect(isSigned(&.{}));
+}
+
 test "serializeV0Message appends address table lookups after the message body" {
     const payer: Pubkey = .{1} ** 32;
     const program: Pubkey = .{2} ** 32;
@@ -664,8 +742,11 @@ test "@import(\"solana_tx\") exposes the intended v0.1 surface" {
     try std.testing.expect(@hasDecl(@This(), "compileLegacyMessage"));
     try std.testing.expect(@hasDecl(@This(), "serializeLegacyMessage"));
     try std.testing.expect(@hasDecl(@This(), "serializeLegacyTransaction"));
+    try std.testing.expect(@hasDecl(@This(), "isSigned"));
+    try std.testing.expectEqual(@as(usize, 1232), PACKET_DATA_SIZE);
     try std.testing.expect(@hasDecl(@This(), "serializeV0Message"));
     try std.testing.expect(@hasDecl(@This(), "serializeV0Transaction"));
+    try std.testing.expect(@hasDecl(@This(), "serializeAddressTableLookup"));
     try std.testing.expect(@hasDecl(@This(), "MessageAddressTableLookup"));
     try std.testing.expect(@hasDecl(@This(), "CompiledInstruction"));
     try std.testing.expect(!@hasDecl(@This(), "rpc"));
@@ -0,0 +1,239 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_hash::Hash;
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+use solana_vote_interface::{
+    instruction::{self as vote_instruction, VoteInstruction},
+    state::{Lockout, TowerSync, Vote, VoteAuthorize, VoteInit, VoteStateUpdate},
+};
+use std::collections::VecDeque;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_instruction_accounts.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AccountFixture {
+    pubkey: [u8; 32],
+    is_signer: u8,
+    is_writable: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct InstructionCase {
+    label: String,
+    accounts: Vec<AccountFixture>,
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    cases: Vec<InstructionCase>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+fn hash(byte: u8) -> Hash {
+    Hash::new_from_array([byte; 32])
+}
+
+/// `VoteInstruction` has no name accessor, so derive the snake_case variant
+/// name from its `Debug` output.
+fn variant_name(data: &[u8]) -> String {
+    let instruction: VoteInstruction = bincode::deserialize(data).unwrap();
+    let debug = format!("{instruction:?}");
+    let mut name = String::new();
+    for ch in debug.chars().take_while(char::is_ascii_alphanumeric) {
+        if ch.is_ascii_uppercase() && !name.is_empty() {
+            name.push('_');
+        }
+        name.push(ch.to_ascii_lowercase());
+    }
+    name
+}
+
+fn case(label: &str, ix: Instruction) -> InstructionCase {
+    assert_eq!(ix.program_id, solana_vote_interface::program::ID);
+    InstructionCase {
+        label: label.to_string(),
+        accounts: ix
+            .accounts
+            .iter()
+            .map(|meta| AccountFixture {
+                pubkey: meta.pubkey.to_bytes(),
+                is_signer: u8::from(meta.is_signer),
+                is_writable: u8::from(meta.is_writable),
+            })
+            .collect(),
+        data: ix.data,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let vote_account = key(1);
+    let node = key(2);
+    let voter = key(3);
+    let withdrawer = key(4);
+    let to = key(5);
+    let new_authority = key(6);
+    let base = key(7);
+    let owner = key(8);
+    let payer = key(9);
+
+    let vote_init = VoteInit {
+        node_pubkey: node,
+        authorized_voter: voter,
+        authorized_withdrawer: withdrawer,
+        commission: 7,
+    };
+    let vote = Vote {
+        slots: vec![10, 11],
+        hash: hash(9),
+        timestamp: Some(-5),
+    };
+    let lockouts = VecDeque::from(vec![
+        Lockout::new_with_confirmation_count(100, 3),
+        Lockout::new_with_confirmation_count(105, 4),
+    ]);
+    let update = VoteStateUpdate::new(lockouts.clone(), Some(90), hash(8));
+    let tower = TowerSync::new(lockouts, None, hash(8), hash(6));
+    let proof_hash = hash(7);
+
+    let create = vote_instruction::create_account_with_config(
+        &payer,
+        &vote_account,
+        &vote_init,
+        1_000,
+        Default::default(),
+    );
+
+    Fixture {
+        cases: vec![
+            case("initialize_account", create[1].clone()),
+            case(
+                "authorize",
+                vote_instruction::authorize(
+                    &vote_account,
+                    &voter,
+                    &new_authority,
+                    VoteAuthorize::Voter,
+                ),
+            ),
+            case(
+                "authorize_checked",
+                vote_instruction::authorize_checked(
+                    &vote_account,
+                    &withdrawer,
+                    &new_authority,
+                    VoteAuthorize::Withdrawer,
+                ),
+            ),
+            case(
+                "authorize_with_seed",
+                vote_instruction::authorize_with_seed(
+                    &vote_account,
+                    &base,
+                    &owner,
+                    "vote-seed",
+                    &new_authority,
+                    VoteAuthorize::Withdrawer,
+                ),
+            ),
+            case(
+                "authorize_checked_with_seed",
+                vote_instruction::authorize_checked_with_seed(
+                    &vote_account,
+                    &base,
+                    &owner,
+                    "vote-seed",
+                    &new_authority,
+                    VoteAuthorize::Voter,
+                ),
+            ),
+            case(
+                "update_validator_identity",
+                vote_instruction::update_validator_identity(&vote_account, &withdrawer, &node),
+            ),
+            case(
+                "update_commission",
+                vote_instruction::update_commission(&vote_account, &withdrawer, 7),
+            ),
+            case(
+                "withdraw",
+                vote_instruction::withdraw(&vote_account, &withdrawer, 500, &to),
+            ),
+            case(
+                "vote",
+                vote_instruction::vote(&vote_account, &voter, vote.clone()),
+            ),
+            case(
+                "vote_switch",
+                vote_instruction::vote_switch(&vote_account, &voter, vote, proof_hash),
+            ),
+            case(
+                "update_vote_state",
+                vote_instruction::update_vote_state(&vote_account, &voter, update.clone()),
+            ),
+            case(
+                "update_vote_state_switch",
+                vote_instruction::update_vote_state_switch(
+                    &vote_account,
+                    &voter,
+                    update.clone(),
+                    proof_hash,
+                ),
+            ),
+            case(
+                "compact_update_vote_state",
+                vote_instruction::compact_update_vote_state(&vote_account, &voter, update.clone()),
+            ),
+            case(
+                "compact_update_vote_state_switch",
+                vote_instruction::compact_update_vote_state_switch(
+                    &vote_account,
+                    &voter,
+                    update,
+                    proof_hash,
+                ),
+            ),
+            case(
+                "tower_sync",
+                vote_instruction::tower_sync(&vote_account, &voter, tower.clone()),
+            ),
+            case(
+                "tower_sync_switch",
+                vote_instruction::tower_sync_switch(&vote_account, &voter, tower, proof_hash),
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_instruction_accounts() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_instruction_accounts.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn data_decodes_to_the_labelled_variant() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+
+    for case in &fixture.cases {
+        assert_eq!(variant_name(&case.data), case.label, "{}", case.label);
+        assert_eq!(case.accounts[0].pubkey, key(1).to_bytes(), "{}", case.label);
+        assert_eq!(case.accounts[0].is_writable, 1, "{}", case.label);
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_instruction_accounts() {
+    println!("{}", serde_json::to_string_pretty(&official_fixture()).unwrap());
+}
@@ -1,12 +1,16 @@
 use solana_hash::Hash;
 use solana_instruction::AccountMeta;
 use solana_pubkey::Pubkey;
+use solana_rent::Rent;
 use solana_vote_interface::{
     instruction as vote_instruction,
-    state::{Lockout, TowerSync, Vote, VoteAuthorize, VoteInit, VoteStateUpdate},
+    state::{Lockout, TowerSync, Vote, VoteAuthorize, VoteInit, VoteState, VoteStateUpdate},
 };
 use std::collections::VecDeque;
 
+const STANDARD_ACCOUNT_MINIMUMS_JSON: &str =
+    include_str!("../../../../src/rent/standard_account_minimums_vectors.json");
+
 fn key(byte: u8) -> Pubkey {
     Pubkey::from([byte; 32])
 }
@@ -234,3 +238,23 @@ fn official_vote_state_and_tower_builders_match_zig_typed_payload_layout() {
     assert_eq!(&tower_switch.data[0..4], &[15, 0, 0, 0]);
     assert_eq!(&tower_switch.data[82..114], &[7; 32]);
 }
+
+#[test]
+fn official_vote_state_size_matches_the_standard_minimums_fixture() {
+    // The root SDK's src/rent/standard_account_minimums_vectors.json is
+    // generated from Rent::default(); the "vote" entry is what
+    // root.zig's size constant is checked against.
+    let fixture: serde_json::Value =
+        serde_json::from_str(STANDARD_ACCOUNT_MINIMUMS_JSON).expect("standard minimums fixture");
+    let entry = fixture["vectors"]
+        .as_array()
+        .expect("vectors")
+        .iter()
+        .find(|vector| vector["name"] == "vote")
+        .expect("vote entry");
+    assert_eq!(entry["data_len"], VoteState::size_of() as u64);
+    assert_eq!(
+        entry["minimum_balance"],
+        Rent::default().minimum_balance(VoteState::size_of())
+    );
+}
@@ -0,0 +1,152 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_hash::Hash;
+use solana_pubkey::Pubkey;
+use solana_vote_interface::{
+    instruction::{self as vote_instruction, VoteInstruction},
+    state::{Vote, MAX_LOCKOUT_HISTORY},
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_vote_instruction_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct VoteInstructionTestVector {
+    name: String,
+    /// `"vote"` or `"vote_switch"`.
+    instruction: String,
+    slots: Vec<u64>,
+    hash: [u8; 32],
+    timestamp: Option<i64>,
+    /// The `VoteSwitch` proof hash that follows the `Vote` payload.
+    proof_hash: Option<[u8; 32]>,
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vote_account: [u8; 32],
+    authorized_voter: [u8; 32],
+    vectors: Vec<VoteInstructionTestVector>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+fn vote_hash() -> Hash {
+    Hash::new_from_array(std::array::from_fn(|i| i as u8))
+}
+
+fn proof_hash() -> Hash {
+    Hash::new_from_array([0xab; 32])
+}
+
+fn vector(
+    name: &str,
+    vote: Vote,
+    proof_hash: Option<Hash>,
+    vote_account: &Pubkey,
+    authorized_voter: &Pubkey,
+) -> VoteInstructionTestVector {
+    let (instruction, ix) = match proof_hash {
+        None => (
+            "vote",
+            vote_instruction::vote(vote_account, authorized_voter, vote.clone()),
+        ),
+        Some(proof_hash) => (
+            "vote_switch",
+            vote_instruction::vote_switch(vote_account, authorized_voter, vote.clone(), proof_hash),
+        ),
+    };
+    VoteInstructionTestVector {
+        name: name.to_string(),
+        instruction: instruction.to_string(),
+        slots: vote.slots,
+        hash: vote.hash.to_bytes(),
+        timestamp: vote.timestamp,
+        proof_hash: proof_hash.map(|hash| hash.to_bytes()),
+        data: ix.data,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let vote_account = key(1);
+    let authorized_voter = key(2);
+    let slot_lists = [
+        ("empty", Vec::new()),
+        ("one_slot", vec![42]),
+        (
+            "max_lockout_history_slots",
+            (100..100 + MAX_LOCKOUT_HISTORY as u64).collect(),
+        ),
+    ];
+
+    let mut vectors = Vec::new();
+    for (instruction, proof_hash) in [("vote", None), ("vote_switch", Some(proof_hash()))] {
+        for (slots_name, slots) in &slot_lists {
+            for (timestamp_name, timestamp) in
+                [("timestamp", Some(1_700_000_000)), ("no_timestamp", None)]
+            {
+                vectors.push(vector(
+                    &format!("{instruction}_{slots_name}_{timestamp_name}"),
+                    Vote {
+                        slots: slots.clone(),
+                        hash: vote_hash(),
+                        timestamp,
+                    },
+                    proof_hash,
+                    &vote_account,
+                    &authorized_voter,
+                ));
+            }
+        }
+    }
+
+    Fixture {
+        vote_account: vote_account.to_bytes(),
+        authorized_voter: authorized_voter.to_bytes(),
+        vectors,
+    }
+}
+
+#[test]
+fn fixture_matches_official_vote_instruction_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_vote_instruction_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn data_decodes_back_to_the_recorded_fields() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let expected = Vote {
+            slots: vector.slots.clone(),
+            hash: Hash::new_from_array(vector.hash),
+            timestamp: vector.timestamp,
+        };
+        let decoded: VoteInstruction = bincode::deserialize(&vector.data).unwrap();
+        match (decoded, vector.proof_hash) {
+            (VoteInstruction::Vote(vote), None) => assert_eq!(vote, expected),
+            (VoteInstruction::VoteSwitch(vote, proof_hash), Some(expected_proof)) => {
+                assert_eq!(vote, expected);
+                assert_eq!(proof_hash.to_bytes(), expected_proof);
+            }
+            (other, _) => panic!("{}: unexpected {other:?}", vector.name),
+        }
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_vote_instruction_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,203 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_clock::Clock;
+use solana_pubkey::Pubkey;
+use solana_vote_interface::state::{VoteInit, VoteState, VoteStateVersions, MAX_LOCKOUT_HISTORY};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_vote_state_vectors.json");
+
+/// `prior_voters` is a fixed `CircBuf` of 32 `(Pubkey, Epoch, Epoch)`
+/// entries followed by its `u64` index and `bool` empty flag.
+const PRIOR_VOTERS_LEN: usize = 32 * (32 + 8 + 8) + 8 + 1;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct LockoutVector {
+    slot: u64,
+    confirmation_count: u32,
+    latency: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AuthorizedVoterVector {
+    epoch: u64,
+    pubkey: [u8; 32],
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct EpochCreditsVector {
+    epoch: u64,
+    credits: u64,
+    prev_credits: u64,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct VoteStateTestVector {
+    name: String,
+    node_pubkey: [u8; 32],
+    authorized_withdrawer: [u8; 32],
+    commission: u8,
+    votes: Vec<LockoutVector>,
+    root_slot: Option<u64>,
+    authorized_voters: Vec<AuthorizedVoterVector>,
+    epoch_credits: Vec<EpochCreditsVector>,
+    last_timestamp_slot: u64,
+    last_timestamp: i64,
+    /// bincode `VoteStateVersions::Current`. `VoteState::serialize` writes
+    /// the same bytes into a `VoteState::size_of()` account and leaves the
+    /// rest zeroed.
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vote_state_size: usize,
+    prior_voters_len: usize,
+    vectors: Vec<VoteStateTestVector>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+fn initialized() -> VoteState {
+    VoteState::new(
+        &VoteInit {
+            node_pubkey: key(1),
+            authorized_voter: key(2),
+            authorized_withdrawer: key(3),
+            commission: 5,
+        },
+        &Clock::default(),
+    )
+}
+
+/// Vote on every slot in `slots`, each landing one slot later.
+fn vote_on(state: &mut VoteState, slots: impl IntoIterator<Item = u64>, epoch: u64) {
+    for slot in slots {
+        state.process_next_vote_slot(slot, epoch, slot + 1);
+    }
+}
+
+/// Hand the voter to `voter` from `target_epoch`, as `Authorize` does.
+fn rotate_voter(state: &mut VoteState, voter: Pubkey, current_epoch: u64, target_epoch: u64) {
+    state
+        .set_new_authorized_voter(&voter, current_epoch, target_epoch, |_| Ok(()))
+        .unwrap();
+}
+
+fn populated() -> VoteState {
+    let mut state = initialized();
+    vote_on(&mut state, 1..=5, 0);
+    state.increment_credits(0, 4);
+    state.increment_credits(1, 7);
+    rotate_voter(&mut state, key(4), 0, 1);
+    rotate_voter(&mut state, key(5), 1, 2);
+    state.process_timestamp(5, 1_700_000_000).unwrap();
+    state
+}
+
+/// A full tower: the oldest lockouts have been rooted, and the voter has
+/// rotated past the prior-voters buffer capacity so its index wraps.
+fn max_lockout_history() -> VoteState {
+    let mut state = initialized();
+    vote_on(&mut state, 1..=(MAX_LOCKOUT_HISTORY as u64 + 4), 3);
+    for epoch in 1..=33u64 {
+        rotate_voter(&mut state, key(0x40 + epoch as u8), epoch - 1, epoch);
+    }
+    state.process_timestamp(35, 1_700_000_035).unwrap();
+    state
+}
+
+fn vector(name: &str, state: VoteState) -> VoteStateTestVector {
+    let data = bincode::serialize(&VoteStateVersions::new_current(state.clone())).unwrap();
+    VoteStateTestVector {
+        name: name.to_string(),
+        node_pubkey: state.node_pubkey.to_bytes(),
+        authorized_withdrawer: state.authorized_withdrawer.to_bytes(),
+        commission: state.commission,
+        votes: state
+            .votes
+            .iter()
+            .map(|vote| LockoutVector {
+                slot: vote.slot(),
+                confirmation_count: vote.confirmation_count(),
+                latency: vote.latency,
+            })
+            .collect(),
+        root_slot: state.root_slot,
+        authorized_voters: state
+            .authorized_voters()
+            .iter()
+            .map(|(&epoch, pubkey)| AuthorizedVoterVector {
+                epoch,
+                pubkey: pubkey.to_bytes(),
+            })
+            .collect(),
+        epoch_credits: state
+            .epoch_credits()
+            .iter()
+            .map(|&(epoch, credits, prev_credits)| EpochCreditsVector {
+                epoch,
+                credits,
+                prev_credits,
+            })
+            .collect(),
+        last_timestamp_slot: state.last_timestamp.slot,
+        last_timestamp: state.last_timestamp.timestamp,
+        data,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        vote_state_size: VoteState::size_of(),
+        prior_voters_len: PRIOR_VOTERS_LEN,
+        vectors: vec![
+            vector("initialized", initialized()),
+            vector("populated", populated()),
+            vector("max_lockout_history", max_lockout_history()),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_vote_state_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_vote_state_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn vote_state_serialize_writes_the_recorded_bytes_into_the_account() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let max = fixture
+        .vectors
+        .iter()
+        .find(|vector| vector.name == "max_lockout_history")
+        .unwrap();
+    assert_eq!(max.votes.len(), MAX_LOCKOUT_HISTORY);
+    assert!(max.root_slot.is_some());
+
+    for vector in &fixture.vectors {
+        let state = VoteState::deserialize(&vector.data).unwrap();
+        let mut account = vec![0; fixture.vote_state_size];
+        VoteState::serialize(&VoteStateVersions::new_current(state), &mut account).unwrap();
+        let (head, tail) = account.split_at(vector.data.len());
+        assert_eq!(head, vector.data, "{}", vector.name);
+        assert!(tail.iter().all(|&b| b == 0), "{}", vector.name);
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_vote_state_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
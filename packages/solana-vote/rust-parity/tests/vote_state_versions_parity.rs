@@ -0,0 +1,166 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_clock::Clock;
+use solana_pubkey::Pubkey;
+use solana_vote_interface::state::{
+    BlockTimestamp, Lockout, VoteInit, VoteState, VoteState1_14_11, VoteStateVersions,
+};
+use std::collections::VecDeque;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_vote_state_versions_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct VoteStateVersionTestVector {
+    name: String,
+    /// `u32` bincode variant index of `VoteStateVersions`.
+    discriminant: u32,
+    node_pubkey: [u8; 32],
+    authorized_withdrawer: [u8; 32],
+    commission: u8,
+    root_slot: Option<u64>,
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<VoteStateVersionTestVector>,
+}
+
+/// `VoteState0_23_5` is private to `solana-vote-interface`, so this mirrors
+/// its serde layout; the crate's own deserializer must accept the result as
+/// `VoteStateVersions::V0_23_5`.
+#[derive(Serialize)]
+struct VoteState0_23_5 {
+    node_pubkey: Pubkey,
+    authorized_voter: Pubkey,
+    authorized_voter_epoch: u64,
+    /// `CircBuf<(Pubkey, Epoch, Epoch, Slot)>`: 32 entries then the index.
+    prior_voters: ([(Pubkey, u64, u64, u64); 32], u64),
+    authorized_withdrawer: Pubkey,
+    commission: u8,
+    votes: VecDeque<Lockout>,
+    root_slot: Option<u64>,
+    epoch_credits: Vec<(u64, u64, u64)>,
+    last_timestamp: BlockTimestamp,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+fn votes() -> VecDeque<Lockout> {
+    VecDeque::from([
+        Lockout::new_with_confirmation_count(10, 2),
+        Lockout::new_with_confirmation_count(11, 1),
+    ])
+}
+
+fn last_timestamp() -> BlockTimestamp {
+    BlockTimestamp {
+        slot: 11,
+        timestamp: 1_700_000_011,
+    }
+}
+
+/// Each version gets its own commission so a decoder that picks the wrong
+/// layout reads the wrong byte.
+fn state(commission: u8) -> VoteState {
+    let mut state = VoteState::new(
+        &VoteInit {
+            node_pubkey: key(1),
+            authorized_voter: key(2),
+            authorized_withdrawer: key(3),
+            commission,
+        },
+        &Clock::default(),
+    );
+    state.votes = votes().into_iter().map(Into::into).collect();
+    state.root_slot = Some(9);
+    state.epoch_credits = vec![(0, 12, 0)];
+    state.last_timestamp = last_timestamp();
+    state
+}
+
+fn v0_23_5_bytes() -> Vec<u8> {
+    let state = VoteState0_23_5 {
+        node_pubkey: key(1),
+        authorized_voter: key(2),
+        authorized_voter_epoch: 0,
+        prior_voters: ([(Pubkey::default(), 0, 0, 0); 32], 31),
+        authorized_withdrawer: key(3),
+        commission: 7,
+        votes: votes(),
+        root_slot: Some(9),
+        epoch_credits: vec![(0, 12, 0)],
+        last_timestamp: last_timestamp(),
+    };
+    bincode::serialize(&(0u32, state)).unwrap()
+}
+
+fn vector(name: &str, data: Vec<u8>) -> VoteStateVersionTestVector {
+    let versions: VoteStateVersions = bincode::deserialize(&data).unwrap();
+    let discriminant = match &versions {
+        VoteStateVersions::V0_23_5(_) => 0,
+        VoteStateVersions::V1_14_11(_) => 1,
+        VoteStateVersions::Current(_) => 2,
+    };
+    let state = versions.convert_to_current();
+    VoteStateVersionTestVector {
+        name: name.to_string(),
+        discriminant,
+        node_pubkey: state.node_pubkey.to_bytes(),
+        authorized_withdrawer: state.authorized_withdrawer.to_bytes(),
+        commission: state.commission,
+        root_slot: state.root_slot,
+        data,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let v1_14_11 = VoteStateVersions::V1_14_11(Box::new(VoteState1_14_11::from(state(8))));
+    let current = VoteStateVersions::new_current(state(9));
+    Fixture {
+        vectors: vec![
+            vector("v0_23_5", v0_23_5_bytes()),
+            vector("v1_14_11", bincode::serialize(&v1_14_11).unwrap()),
+            vector("current", bincode::serialize(&current).unwrap()),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_vote_state_versions_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_vote_state_versions_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn each_version_round_trips_through_vote_state_versions() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for (expected, vector) in fixture.vectors.iter().enumerate() {
+        assert_eq!(vector.discriminant, expected as u32);
+        assert_eq!(
+            vector.data[..4],
+            vector.discriminant.to_le_bytes(),
+            "{}",
+            vector.name
+        );
+        let versions: VoteStateVersions = bincode::deserialize(&vector.data).unwrap();
+        assert_eq!(bincode::serialize(&versions).unwrap(), vector.data);
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_vote_state_versions_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,295 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_account::{Account, AccountSharedData};
+use solana_clock::Clock;
+use solana_instruction::error::InstructionError;
+use solana_program_runtime::{loaded_programs::ProgramCacheEntry, with_mock_invoke_context};
+use solana_pubkey::Pubkey;
+use solana_rent::Rent;
+use solana_sdk_ids::{native_loader, sysvar};
+use solana_timings::ExecuteTimings;
+use solana_transaction_context::InstructionAccount;
+use solana_vote_interface::{
+    error::VoteError,
+    instruction as vote_instruction,
+    state::{VoteInit, VoteState, VoteStateVersions},
+};
+use solana_vote_program::vote_processor::Entrypoint;
+use std::sync::Arc;
+
+const FIXTURE_JSON: &str = include_str!("../../src/vote_withdraw_rent_floor_vectors.json");
+
+const CURRENT_EPOCH: u64 = 10;
+/// Lamports every vote account holds above the rent-exempt minimum.
+const SURPLUS: u64 = 1_000_000;
+
+/// The SDK's `vector_filter.Tag` vocabulary. Deserializing into it rejects
+/// any other tag.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Tag {
+    Error,
+    Boundary,
+    Large,
+    Random,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct WithdrawRentFloorTestVector {
+    name: String,
+    /// Vote account lamports before the withdraw.
+    balance: u64,
+    lamports: u64,
+    current_epoch: u64,
+    /// Epoch of the last `epoch_credits` entry, if any.
+    last_epoch_with_credits: Option<u64>,
+    /// bincode `VoteInstruction::Withdraw`.
+    data: Vec<u8>,
+    /// `None` when the vote program accepts the withdraw; otherwise the
+    /// `InstructionError` name, with `Custom` codes spelled as the
+    /// `VoteError` variant.
+    expected_error: Option<String>,
+    /// `error` when `expected_error` is set; `boundary` for the lamport
+    /// and epoch limits either side of a rejection.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<Tag>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vote_state_size: usize,
+    rent_exempt_minimum: u64,
+    vectors: Vec<WithdrawRentFloorTestVector>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+fn vote_pubkey() -> Pubkey {
+    key(1)
+}
+
+fn withdrawer() -> Pubkey {
+    key(2)
+}
+
+fn recipient() -> Pubkey {
+    key(4)
+}
+
+fn sysvar_account<T: Serialize>(value: &T) -> AccountSharedData {
+    AccountSharedData::from(Account {
+        lamports: 1,
+        data: bincode::serialize(value).unwrap(),
+        owner: sysvar::id(),
+        executable: false,
+        rent_epoch: 0,
+    })
+}
+
+fn vote_account(balance: u64, last_epoch_with_credits: Option<u64>) -> AccountSharedData {
+    let mut vote_state = VoteState::new(
+        &VoteInit {
+            node_pubkey: key(3),
+            authorized_voter: key(3),
+            authorized_withdrawer: withdrawer(),
+            commission: 0,
+        },
+        &Clock::default(),
+    );
+    if let Some(epoch) = last_epoch_with_credits {
+        vote_state.epoch_credits.push((epoch, 100, 0));
+    }
+    let mut data = vec![0; VoteState::size_of()];
+    bincode::serialize_into(&mut data[..], &VoteStateVersions::new_current(vote_state)).unwrap();
+    AccountSharedData::from(Account {
+        lamports: balance,
+        data,
+        owner: solana_vote_interface::program::ID,
+        executable: false,
+        rent_epoch: 0,
+    })
+}
+
+/// Run `VoteInstruction::Withdraw` through the vote program's builtin
+/// entrypoint, the same harness `mock_process_instruction` wraps, but
+/// returning the result instead of asserting it.
+fn process_withdraw(
+    data: &[u8],
+    balance: u64,
+    last_epoch_with_credits: Option<u64>,
+) -> Result<(), InstructionError> {
+    let clock = Clock {
+        epoch: CURRENT_EPOCH,
+        ..Clock::default()
+    };
+    let transaction_accounts = vec![
+        (
+            vote_pubkey(),
+            vote_account(balance, last_epoch_with_credits),
+        ),
+        (recipient(), AccountSharedData::default()),
+        (withdrawer(), AccountSharedData::default()),
+        (sysvar::clock::id(), sysvar_account(&clock)),
+        (sysvar::rent::id(), sysvar_account(&Rent::default())),
+        (
+            solana_vote_interface::program::ID,
+            AccountSharedData::new(0, 0, &native_loader::id()),
+        ),
+    ];
+    let instruction_accounts = [(0, false, true), (1, false, true), (2, true, false)].map(
+        |(index, is_signer, is_writable)| InstructionAccount {
+            index_in_transaction: index,
+            index_in_caller: index,
+            index_in_callee: index,
+            is_signer,
+            is_writable,
+        },
+    );
+    let program_index = transaction_accounts.len() as u16 - 1;
+    // Also brings `ProgramCacheForTxBatch` into scope.
+    with_mock_invoke_context!(invoke_context, transaction_context, transaction_accounts);
+    let mut program_cache = ProgramCacheForTxBatch::default();
+    program_cache.replenish(
+        solana_vote_interface::program::ID,
+        Arc::new(ProgramCacheEntry::new_builtin(0, 0, Entrypoint::vm)),
+    );
+    invoke_context.program_cache_for_tx_batch = &mut program_cache;
+    invoke_context.process_instruction(
+        data,
+        &instruction_accounts,
+        &[program_index],
+        &mut 0,
+        &mut ExecuteTimings::default(),
+    )
+}
+
+fn error_name(error: InstructionError) -> String {
+    match error {
+        InstructionError::Custom(code) if code == VoteError::ActiveVoteAccountClose as u32 => {
+            format!("{:?}", VoteError::ActiveVoteAccountClose)
+        }
+        error => format!("{error:?}"),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let rent_exempt_minimum = Rent::default().minimum_balance(VoteState::size_of());
+    let balance = rent_exempt_minimum + SURPLUS;
+    // (name, lamports, last_epoch_with_credits, boundary)
+    let cases = [
+        ("leaves_floor_plus_one", SURPLUS - 1, None, true),
+        ("leaves_floor", SURPLUS, None, true),
+        ("leaves_floor_minus_one", SURPLUS + 1, None, true),
+        ("leaves_one_lamport", balance - 1, None, false),
+        (
+            "leaves_floor_with_recent_credits",
+            SURPLUS,
+            Some(CURRENT_EPOCH),
+            false,
+        ),
+        ("close_without_credits", balance, None, false),
+        (
+            "close_credits_two_epochs_ago",
+            balance,
+            Some(CURRENT_EPOCH - 2),
+            true,
+        ),
+        (
+            "close_credits_previous_epoch",
+            balance,
+            Some(CURRENT_EPOCH - 1),
+            true,
+        ),
+        (
+            "close_credits_current_epoch",
+            balance,
+            Some(CURRENT_EPOCH),
+            false,
+        ),
+        ("more_than_balance", balance + 1, None, true),
+    ];
+    Fixture {
+        vote_state_size: VoteState::size_of(),
+        rent_exempt_minimum,
+        vectors: cases
+            .into_iter()
+            .map(|(name, lamports, last_epoch_with_credits, boundary)| {
+                let ix = vote_instruction::withdraw(
+                    &vote_pubkey(),
+                    &withdrawer(),
+                    lamports,
+                    &recipient(),
+                );
+                let result = process_withdraw(&ix.data, balance, last_epoch_with_credits);
+                let tags = [(Tag::Error, result.is_err()), (Tag::Boundary, boundary)]
+                    .into_iter()
+                    .filter_map(|(tag, applies)| applies.then_some(tag))
+                    .collect();
+                WithdrawRentFloorTestVector {
+                    name: name.to_string(),
+                    balance,
+                    lamports,
+                    current_epoch: CURRENT_EPOCH,
+                    last_epoch_with_credits,
+                    data: ix.data,
+                    expected_error: result.err().map(error_name),
+                    tags,
+                }
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn fixture_matches_official_vote_withdraw_rent_floor_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "vote_withdraw_rent_floor_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn vectors_cover_every_withdraw_outcome() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let outcomes: Vec<_> = fixture
+        .vectors
+        .iter()
+        .map(|vector| vector.expected_error.as_deref())
+        .collect();
+    for outcome in [
+        None,
+        Some("InsufficientFunds"),
+        Some("ActiveVoteAccountClose"),
+    ] {
+        assert!(outcomes.contains(&outcome), "{outcome:?}");
+    }
+}
+
+#[test]
+fn tags_come_from_the_fixed_vocabulary() {
+    assert!(serde_json::from_str::<Tag>("\"erorr\"").is_err());
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        assert_eq!(
+            vector.tags.contains(&Tag::Error),
+            vector.expected_error.is_some(),
+            "{}",
+            vector.name
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_vote_withdraw_rent_floor_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,26 @@
+const std = @import("std");
+
+pub const AccountFixture = struct {
+    pubkey: [32]u8,
+    is_signer: u8,
+    is_writable: u8,
+};
+
+pub const InstructionCase = struct {
+    label: []const u8,
+    accounts: []const AccountFixture,
+    data: []const u8,
+};
+
+pub const Fixture = struct {
+    cases: []const InstructionCase,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_instruction_accounts.json"),
+        .{},
+    );
+}
@@ -15,6 +15,7 @@ pub const PROGRAM_ID: Pubkey = sol.pubkey.comptimeFromBase58("Vote11111111111111
 pub const CLOCK_ID: Pubkey = sol.pubkey.comptimeFromBase58("SysvarC1ock11111111111111111111111111111111");
 pub const SLOT_HASHES_ID: Pubkey = sol.slot_hashes_id;
 pub const RENT_ID: Pubkey = sol.rent_id;
+pub const VOTE_STATE_SIZE: u64 = 3762;
 pub const MAX_SEED_LEN: usize = sol.pda.MAX_SEED_LEN;
 
 pub const Error = codec.Error || error{
@@ -101,11 +102,11 @@ pub const AuthorizeCheckedWithSeedData = [AUTHORIZE_CHECKED_WITH_SEED_DATA_CAPAC
 
 pub fn initializeAccount(
     vote_account: *const Pubkey,
-    vote_init: VoteInit,
+    vote_init: *const VoteInit,
     metas: *[4]AccountMeta,
     data: *InitializeAccountData,
 ) Instruction {
-    writeInitializeAccountData(vote_init, data);
+    writeInitializeAccountData(vote_init.*, data);
     metas[0] = AccountMeta.writable(vote_account);
     metas[1] = AccountMeta.readonly(&RENT_ID);
     metas[2] = AccountMeta.readonly(&CLOCK_ID);
@@ -234,6 +235,33 @@ pub fn withdraw(
     return instruction(metas[0..], data);
 }
 
+/// Why the vote program rejects a `Withdraw`: `InsufficientFunds` is the
+/// `InstructionError`, `ActiveVoteAccountClose` is `VoteError` 18.
+pub const WithdrawError = error{
+    InsufficientFunds,
+    ActiveVoteAccountClose,
+};
+
+/// The balance rules the vote program applies to `Withdraw`: the account
+/// must keep at least `rent_exempt_minimum`, or be emptied entirely, and it
+/// may only be emptied once no credits were earned in the current or
+/// previous epoch.
+pub fn validateWithdraw(
+    balance: u64,
+    lamports: u64,
+    rent_exempt_minimum: u64,
+    last_epoch_with_credits: ?u64,
+    current_epoch: u64,
+) WithdrawError!void {
+    const remaining = std.math.sub(u64, balance, lamports) catch return error.InsufficientFunds;
+    if (remaining == 0) {
+        const epoch = last_epoch_with_credits orelse return;
+        if (current_epoch -| epoch < 2) return error.ActiveVoteAccountClose;
+        return;
+    }
+    if (remaining < rent_exempt_minimum) return error.InsufficientFunds;
+}
+
 pub fn voteRaw(
     vote_account: *const Pubkey,
     authorized_voter: *const Pubkey,
@@ -706,7 +734,7 @@ test "initializeAccount encodes VoteInit and canonical accounts" {
     var metas: [4]AccountMeta = undefined;
     var data: InitializeAccountData = undefined;
 
-    const ix = initializeAccount(&vote_account, vote_init, &metas, &data);
+    const ix = initializeAccount(&vote_account, &vote_init, &metas, &data);
     try std.testing.expectEqualSlices(u8, &PROGRAM_ID, ix.program_id);
     try std.testing.expectEqual(@as(usize, 4), ix.accounts.len);
     try std.testing.expectEqual(@as(u8, 1), ix.accounts[0].is_writable);
@@ -816,6 +844,61 @@ test "account management builders encode canonical discriminants and metas" {
     try std.testing.expectEqual(@as(u8, 1), withdraw_ix.accounts[2].is_signer);
 }
 
+test "withdraw validation matches the official vote program around the rent floor" {
+    const withdraw_fixture = @import("withdraw_rent_floor_fixture.zig");
+    var parsed = try withdraw_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    const rent: sol.rent.Rent.Data = .{};
+    try std.testing.expectEqual(VOTE_STATE_SIZE, fixture.vote_state_size);
+    try std.testing.expectEqual(rent.getMinimumBalance(VOTE_STATE_SIZE), fixture.rent_exempt_minimum);
+
+    const vote_account: Pubkey = .{1} ** 32;
+    const to: Pubkey = .{4} ** 32;
+    const withdrawer: Pubkey = .{2} ** 32;
+    for (fixture.vectors) |vector| {
+        var metas: [3]AccountMeta = undefined;
+        var data: WithdrawData = undefined;
+        const ix = withdraw(&vote_account, &to, &withdrawer, vector.lamports, &metas, &data);
+        try std.testing.expectEqualSlices(u8, vector.data, ix.data);
+
+        const result = validateWithdraw(
+            vector.balance,
+            vector.lamports,
+            fixture.rent_exempt_minimum,
+            vector.last_epoch_with_credits,
+            vector.current_epoch,
+        );
+        if (vector.expected_error) |expected| {
+            const err = if (result) |_| return error.TestExpectedError else |err| err;
+            try std.testing.expectEqualStrings(expected, @errorName(err));
+        } else {
+            try result;
+        }
+    }
+}
+
+test "tag filter splits the withdraw vectors by outcome" {
+    const withdraw_fixture = @import("withdraw_rent_floor_fixture.zig");
+    var parsed = try withdraw_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const vectors = parsed.value.vectors;
+
+    const rejected = try withdraw_fixture.vectorsWithTags(std.testing.allocator, vectors, .{ .include = &.{.@"error"} });
+    defer std.testing.allocator.free(rejected);
+    for (rejected) |vector| try std.testing.expect(vector.expected_error != null);
+
+    const accepted = try withdraw_fixture.vectorsWithTags(std.testing.allocator, vectors, .{ .exclude = &.{.@"error"} });
+    defer std.testing.allocator.free(accepted);
+    for (accepted) |vector| try std.testing.expect(vector.expected_error == null);
+    try std.testing.expectEqual(vectors.len, rejected.len + accepted.len);
+
+    const accepted_boundaries = try withdraw_fixture.vectorsWithTags(std.testing.allocator, accepted, .{ .include = &.{.boundary} });
+    defer std.testing.allocator.free(accepted_boundaries);
+    try std.testing.expectEqual(@as(usize, 3), accepted_boundaries.len);
+    try std.testing.expectEqualStrings("leaves_floor", accepted_boundaries[1].name);
+}
+
 test "runtime vote raw builders encode discriminants payloads and canonical metas" {
     const vote_account: Pubkey = .{1} ** 32;
     const voter: Pubkey = .{2} ** 32;
@@ -994,6 +1077,207 @@ test "typed tower-sync builders encode compact tower payloads" {
     try std.testing.expectEqualSlices(u8, &proof_hash, switch_ix.data[90..122]);
 }
 
+test "integer boundary sweep matches official Rust encodings" {
+    const boundary_fixture = @import("boundary_fixture.zig");
+    var parsed = try boundary_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    try std.testing.expectEqual(@as(usize, 11), parsed.value.vectors.len);
+
+    const vote_account: Pubkey = .{1} ** 32;
+    const withdrawer: Pubkey = .{2} ** 32;
+    const to: Pubkey = .{4} ** 32;
+
+    for (parsed.value.vectors) |vector| {
+        try std.testing.expectEqualStrings("vote_withdraw", vector.family);
+
+        var metas: [3]AccountMeta = undefined;
+        var data: WithdrawData = undefined;
+        const ix = withdraw(&vote_account, &to, &withdrawer, vector.value, &metas, &data);
+
+        try std.testing.expectEqualSlices(u8, vector.data, ix.data);
+        try std.testing.expectEqual(vector.value, std.mem.readInt(u64, ix.data[vector.offset..][0..8], .little));
+    }
+}
+
+test "builders match official account metas for every instruction variant" {
+    const accounts_fixture = @import("instruction_accounts_fixture.zig");
+    var parsed = try accounts_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const cases = parsed.value.cases;
+    try std.testing.expectEqual(@as(usize, 16), cases.len);
+
+    const vote_account: Pubkey = .{1} ** 32;
+    const node: Pubkey = .{2} ** 32;
+    const voter: Pubkey = .{3} ** 32;
+    const withdrawer: Pubkey = .{4} ** 32;
+    const to: Pubkey = .{5} ** 32;
+    const new_authority: Pubkey = .{6} ** 32;
+    const base: Pubkey = .{7} ** 32;
+    const owner: Pubkey = .{8} ** 32;
+
+    const vote_init: VoteInit = .{
+        .node_pubkey = node,
+        .authorized_voter = voter,
+        .authorized_withdrawer = withdrawer,
+        .commission = 7,
+    };
+    const vote_payload: Vote = .{ .slots = &.{ 10, 11 }, .hash = .{9} ** 32, .timestamp = -5 };
+    const lockouts = [_]Lockout{
+        .{ .slot = 100, .confirmation_count = 3 },
+        .{ .slot = 105, .confirmation_count = 4 },
+    };
+    const update: VoteStateUpdate = .{ .lockouts = &lockouts, .root = 90, .hash = .{8} ** 32 };
+    const tower: TowerSync = .{ .lockouts = &lockouts, .hash = .{8} ** 32, .block_id = .{6} ** 32 };
+    const proof_hash: Hash = .{7} ** 32;
+
+    var metas: [16][4]AccountMeta = undefined;
+    var initialize_data: InitializeAccountData = undefined;
+    var authorize_data: AuthorizeData = undefined;
+    var authorize_checked_data: AuthorizeCheckedData = undefined;
+    var with_seed_data: AuthorizeWithSeedData = undefined;
+    var checked_with_seed_data: AuthorizeCheckedWithSeedData = undefined;
+    var identity_data: DiscriminantOnlyData = undefined;
+    var commission_data: UpdateCommissionData = undefined;
+    var withdraw_data: WithdrawData = undefined;
+    var payload_data: [8][256]u8 = undefined;
+
+    const built = [_]Instruction{
+        initializeAccount(&vote_account, &vote_init, metas[0][0..4], &initialize_data),
+        authorize(&vote_account, &voter, &new_authority, .voter, metas[1][0..3], &authorize_data),
+        authorizeChecked(&vote_account, &withdrawer, &new_authority, .withdrawer, metas[2][0..4], &authorize_checked_data),
+        try authorizeWithSeed(&vote_account, &base, &owner, "vote-seed", &new_authority, .withdrawer, metas[3][0..3], &with_seed_data),
+        try authorizeCheckedWithSeed(&vote_account, &base, &owner, "vote-seed", &new_authority, .voter, metas[4][0..4], &checked_with_seed_data),
+        updateValidatorIdentity(&vote_account, &withdrawer, &node, metas[5][0..3], &identity_data),
+        updateCommission(&vote_account, &withdrawer, 7, metas[6][0..2], &commission_data),
+        withdraw(&vote_account, &to, &withdrawer, 500, metas[7][0..3], &withdraw_data),
+        try vote(&vote_account, &voter, vote_payload, metas[8][0..4], &payload_data[0]),
+        try voteSwitch(&vote_account, &voter, vote_payload, &proof_hash, metas[9][0..4], &payload_data[1]),
+        try updateVoteState(&vote_account, &voter, update, metas[10][0..2], &payload_data[2]),
+        try updateVoteStateSwitch(&vote_account, &voter, update, &proof_hash, metas[11][0..2], &payload_data[3]),
+        try compactUpdateVoteState(&vote_account, &voter, update, metas[12][0..2], &payload_data[4]),
+        try compactUpdateVoteStateSwitch(&vote_account, &voter, update, &proof_hash, metas[13][0..2], &payload_data[5]),
+        try towerSync(&vote_account, &voter, tower, metas[14][0..2], &payload_data[6]),
+        try towerSyncSwitch(&vote_account, &voter, tower, &proof_hash, metas[15][0..2], &payload_data[7]),
+    };
+
+    for (cases, built) |case, ix| {
+        const tag: VoteInstruction = @enumFromInt(std.mem.readInt(u32, ix.data[0..4], .little));
+        try std.testing.expectEqualStrings(case.label, @tagName(tag));
+        try std.testing.expectEqualSlices(u8, case.data, ix.data);
+        try std.testing.expectEqual(case.accounts.len, ix.accounts.len);
+        for (case.accounts, ix.accounts) |expected, actual| {
+            try std.testing.expectEqualSlices(u8, &expected.pubkey, actual.pubkey);
+            try std.testing.expectEqual(expected.is_signer, actual.is_signer);
+            try std.testing.expectEqual(expected.is_writable, actual.is_writable);
+        }
+    }
+}
+
+test "official VoteState bytes follow the bincode Current layout" {
+    const vote_state_fixture = @import("vote_state_fixture.zig");
+    var parsed = try vote_state_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqual(VOTE_STATE_SIZE, fixture.vote_state_size);
+    try std.testing.expectEqual(@as(usize, 3), fixture.vectors.len);
+
+    for (fixture.vectors) |vector| {
+        try std.testing.expect(vector.data.len <= VOTE_STATE_SIZE);
+        var reader: vote_state_fixture.Reader = .{ .bytes = vector.data };
+        // VoteStateVersions::Current.
+        try std.testing.expectEqual(@as(u32, 2), try reader.int(u32));
+        try std.testing.expectEqual(vector.node_pubkey, try reader.pubkey());
+        try std.testing.expectEqual(vector.authorized_withdrawer, try reader.pubkey());
+        try std.testing.expectEqual(vector.commission, try reader.int(u8));
+
+        try std.testing.expectEqual(@as(u64, vector.votes.len), try reader.int(u64));
+        for (vector.votes) |vote| {
+            try std.testing.expectEqual(vote.latency, try reader.int(u8));
+            try std.testing.expectEqual(vote.slot, try reader.int(u64));
+            try std.testing.expectEqual(vote.confirmation_count, try reader.int(u32));
+        }
+        try std.testing.expectEqual(vector.root_slot, try reader.optionU64());
+
+        try std.testing.expectEqual(@as(u64, vector.authorized_voters.len), try reader.int(u64));
+        for (vector.authorized_voters) |voter| {
+            try std.testing.expectEqual(voter.epoch, try reader.int(u64));
+            try std.testing.expectEqual(voter.pubkey, try reader.pubkey());
+        }
+        _ = try reader.take(fixture.prior_voters_len);
+
+        try std.testing.expectEqual(@as(u64, vector.epoch_credits.len), try reader.int(u64));
+        for (vector.epoch_credits) |credits| {
+            try std.testing.expectEqual(credits.epoch, try reader.int(u64));
+            try std.testing.expectEqual(credits.credits, try reader.int(u64));
+            try std.testing.expectEqual(credits.prev_credits, try reader.int(u64));
+        }
+        try std.testing.expectEqual(vector.last_timestamp_slot, try reader.int(u64));
+        try std.testing.expectEqual(vector.last_timestamp, try reader.int(i64));
+        try std.testing.expectEqual(vector.data.len, reader.offset);
+    }
+}
+
+test "official VoteStateVersions bytes branch on the version discriminant" {
+    const vote_state_fixture = @import("vote_state_fixture.zig");
+    const vote_state_versions_fixture = @import("vote_state_versions_fixture.zig");
+    var parsed = try vote_state_versions_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqual(@as(usize, 3), fixture.vectors.len);
+
+    for (fixture.vectors) |vector| {
+        var reader: vote_state_fixture.Reader = .{ .bytes = vector.data };
+        const discriminant = try reader.int(u32);
+        try std.testing.expectEqual(vector.discriminant, discriminant);
+        try std.testing.expectEqual(vector.node_pubkey, try reader.pubkey());
+
+        // 0 = V0_23_5, 1 = V1_14_11, 2 = Current.
+        const landed_vote_latency_len: usize = switch (discriminant) {
+            0 => blk: {
+                // authorized_voter, its epoch, and a 32-entry prior-voters
+                // buffer of (Pubkey, Epoch, Epoch, Slot) plus its index.
+                _ = try reader.take(32 + 8 + 32 * (32 + 8 + 8 + 8) + 8);
+                break :blk 0;
+            },
+            1 => 0,
+            2 => 1,
+            else => return error.UnknownVoteStateVersion,
+        };
+        try std.testing.expectEqual(vector.authorized_withdrawer, try reader.pubkey());
+        try std.testing.expectEqual(vector.commission, try reader.int(u8));
+
+        const vote_count: usize = @intCast(try reader.int(u64));
+        _ = try reader.take(vote_count * (landed_vote_latency_len + 8 + 4));
+        try std.testing.expectEqual(vector.root_slot, try reader.optionU64());
+    }
+}
+
+test "typed vote builders match official Vote and VoteSwitch vectors" {
+    const vote_instruction_fixture = @import("vote_instruction_fixture.zig");
+    var parsed = try vote_instruction_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqual(@as(usize, 12), fixture.vectors.len);
+
+    for (fixture.vectors) |vector| {
+        const vote_payload: Vote = .{
+            .slots = vector.slots,
+            .hash = vector.hash,
+            .timestamp = vector.timestamp,
+        };
+        var metas: [4]AccountMeta = undefined;
+        var data: [512]u8 = undefined;
+        const ix = if (vector.proof_hash) |proof_hash| blk: {
+            try std.testing.expectEqualStrings("vote_switch", vector.instruction);
+            break :blk try voteSwitch(&fixture.vote_account, &fixture.authorized_voter, vote_payload, &proof_hash, &metas, &data);
+        } else blk: {
+            try std.testing.expectEqualStrings("vote", vector.instruction);
+            break :blk try vote(&fixture.vote_account, &fixture.authorized_voter, vote_payload, &metas, &data);
+        };
+        try std.testing.expectEqualSlices(u8, vector.data, ix.data);
+    }
+}
+
 test "public surface guards" {
     try std.testing.expect(@hasDecl(@This(), "initializeAccount"));
     try std.testing.expect(@hasDecl(@This(), "authorize"));
@@ -1017,3 +1301,9 @@ test "public surface guards" {
     try std.testing.expect(!@hasDecl(@This(), "rpc"));
     try std.testing.expect(!@hasDecl(@This(), "wallet"));
 }
+
+test "VOTE_STATE_SIZE matches the official vote account size" {
+    var parsed = try @import("rent_vectors").loadStandardAccountMinimums(std.testing.allocator);
+    defer parsed.deinit();
+    try std.testing.expectEqual(VOTE_STATE_SIZE, parsed.value.get("vote").?.data_len);
+}
@@ -0,0 +1,26 @@
+const std = @import("std");
+
+pub const VoteInstructionTestVector = struct {
+    name: []const u8,
+    instruction: []const u8,
+    slots: []const u64,
+    hash: [32]u8,
+    timestamp: ?i64,
+    proof_hash: ?[32]u8,
+    data: []const u8,
+};
+
+pub const Fixture = struct {
+    vote_account: [32]u8,
+    authorized_voter: [32]u8,
+    vectors: []const VoteInstructionTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_vote_instruction_vectors.json"),
+        .{},
+    );
+}
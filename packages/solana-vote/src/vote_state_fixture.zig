@@ -0,0 +1,77 @@
+const std = @import("std");
+
+pub const LockoutVector = struct {
+    slot: u64,
+    confirmation_count: u32,
+    latency: u8,
+};
+
+pub const AuthorizedVoterVector = struct {
+    epoch: u64,
+    pubkey: [32]u8,
+};
+
+pub const EpochCreditsVector = struct {
+    epoch: u64,
+    credits: u64,
+    prev_credits: u64,
+};
+
+pub const VoteStateTestVector = struct {
+    name: []const u8,
+    node_pubkey: [32]u8,
+    authorized_withdrawer: [32]u8,
+    commission: u8,
+    votes: []const LockoutVector,
+    root_slot: ?u64,
+    authorized_voters: []const AuthorizedVoterVector,
+    epoch_credits: []const EpochCreditsVector,
+    last_timestamp_slot: u64,
+    last_timestamp: i64,
+    data: []const u8,
+};
+
+pub const Fixture = struct {
+    vote_state_size: usize,
+    prior_voters_len: usize,
+    vectors: []const VoteStateTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_vote_state_vectors.json"),
+        .{},
+    );
+}
+
+/// Forward-only bincode reader for walking serialized vote state.
+pub const Reader = struct {
+    bytes: []const u8,
+    offset: usize = 0,
+
+    pub fn int(self: *Reader, comptime T: type) !T {
+        const width = @divExact(@typeInfo(T).int.bits, 8);
+        const field = try self.take(width);
+        return std.mem.readInt(T, field[0..width], .little);
+    }
+
+    pub fn pubkey(self: *Reader) ![32]u8 {
+        return (try self.take(32))[0..32].*;
+    }
+
+    pub fn optionU64(self: *Reader) !?u64 {
+        return switch (try self.int(u8)) {
+            0 => null,
+            1 => try self.int(u64),
+            else => error.InvalidOptionTag,
+        };
+    }
+
+    pub fn take(self: *Reader, len: usize) ![]const u8 {
+        if (self.bytes.len - self.offset < len) return error.InputTooShort;
+        defer self.offset += len;
+        return self.bytes[self.offset..][0..len];
+    }
+};
@@ -0,0 +1,24 @@
+const std = @import("std");
+
+pub const VoteStateVersionTestVector = struct {
+    name: []const u8,
+    discriminant: u32,
+    node_pubkey: [32]u8,
+    authorized_withdrawer: [32]u8,
+    commission: u8,
+    root_slot: ?u64,
+    data: []const u8,
+};
+
+pub const Fixture = struct {
+    vectors: []const VoteStateVersionTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_vote_state_versions_vectors.json"),
+        .{},
+    );
+}
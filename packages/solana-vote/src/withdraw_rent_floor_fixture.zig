@@ -0,0 +1,38 @@
+const std = @import("std");
+const vector_filter = @import("vector_filter");
+
+pub const WithdrawRentFloorTestVector = struct {
+    name: []const u8,
+    balance: u64,
+    lamports: u64,
+    current_epoch: u64,
+    last_epoch_with_credits: ?u64,
+    data: []const u8,
+    expected_error: ?[]const u8,
+    tags: []const vector_filter.Tag = &.{},
+};
+
+pub const Fixture = struct {
+    vote_state_size: u64,
+    rent_exempt_minimum: u64,
+    vectors: []const WithdrawRentFloorTestVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("vote_withdraw_rent_floor_vectors.json"),
+        .{},
+    );
+}
+
+/// The vectors whose tags pass `filter`, in order. The caller owns the
+/// returned slice; the vectors still borrow from the parsed fixture.
+pub fn vectorsWithTags(
+    allocator: std.mem.Allocator,
+    vectors: []const WithdrawRentFloorTestVector,
+    filter: vector_filter.TagFilter,
+) ![]WithdrawRentFloorTestVector {
+    return vector_filter.withTags(WithdrawRentFloorTestVector, allocator, vectors, filter);
+}
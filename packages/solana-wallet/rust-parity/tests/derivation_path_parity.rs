@@ -0,0 +1,115 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_derivation_path::DerivationPath;
+use solana_keypair::seed_derivable::keypair_from_seed_and_derivation_path;
+use solana_seed_phrase::generate_seed_from_seed_phrase_and_passphrase;
+use solana_signer::Signer;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_derivation_path_vectors.json");
+
+/// BIP39 test mnemonics for all-zero entropy, as in the mnemonic vectors.
+const MNEMONIC_12: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+const MNEMONIC_24: &str = "abandon abandon abandon abandon abandon abandon abandon abandon \
+     abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+     abandon abandon abandon abandon art";
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct DerivationPathTestVector {
+    name: String,
+    /// 64-byte BIP39 seed, empty passphrase.
+    seed: Vec<u8>,
+    /// `None` derives with `DerivationPath::default()`.
+    account: Option<u32>,
+    change: Option<u32>,
+    /// Every component is hardened.
+    path: String,
+    keypair: Vec<u8>,
+    pubkey: [u8; 32],
+    pubkey_base58: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<DerivationPathTestVector>,
+}
+
+fn path_string(path: &DerivationPath) -> String {
+    path.path()
+        .iter()
+        .fold("m".to_string(), |out, index| format!("{out}/{index}"))
+}
+
+fn vector(
+    name: &str,
+    mnemonic: &str,
+    account: Option<u32>,
+    change: Option<u32>,
+) -> DerivationPathTestVector {
+    let seed = generate_seed_from_seed_phrase_and_passphrase(mnemonic, "");
+    let path = account.map(|account| DerivationPath::new_bip44(Some(account), change));
+    let keypair = keypair_from_seed_and_derivation_path(&seed, path.clone()).unwrap();
+    DerivationPathTestVector {
+        name: name.to_string(),
+        seed,
+        account,
+        change,
+        path: path_string(&path.unwrap_or_default()),
+        keypair: keypair.to_bytes().to_vec(),
+        pubkey: keypair.pubkey().to_bytes(),
+        pubkey_base58: keypair.pubkey().to_string(),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        vectors: vec![
+            vector("12_words_default", MNEMONIC_12, None, None),
+            vector("12_words_account_0", MNEMONIC_12, Some(0), None),
+            vector("12_words_account_1", MNEMONIC_12, Some(1), None),
+            vector("12_words_account_0_change_0", MNEMONIC_12, Some(0), Some(0)),
+            vector("12_words_account_1_change_0", MNEMONIC_12, Some(1), Some(0)),
+            vector("12_words_account_0_change_1", MNEMONIC_12, Some(0), Some(1)),
+            vector("24_words_default", MNEMONIC_24, None, None),
+            vector("24_words_account_0_change_0", MNEMONIC_24, Some(0), Some(0)),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_derivation_path_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_derivation_path_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn paths_are_hardened_bip44_with_the_solana_coin_type() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let mut expected = "m/44'/501'".to_string();
+        if let Some(account) = vector.account {
+            expected += &format!("/{account}'");
+        }
+        if let Some(change) = vector.change {
+            expected += &format!("/{change}'");
+        }
+        assert_eq!(vector.path, expected, "{}", vector.name);
+        // The derived secret is not simply the first half of the seed.
+        assert_ne!(vector.keypair[..32], vector.seed[..32], "{}", vector.name);
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_derivation_path_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,96 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_keypair::{keypair_from_seed_phrase_and_passphrase, Keypair};
+use solana_seed_phrase::generate_seed_from_seed_phrase_and_passphrase;
+use solana_signer::Signer;
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_mnemonic_vectors.json");
+
+/// BIP39 test mnemonics for all-zero entropy.
+const MNEMONIC_12: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+const MNEMONIC_24: &str = "abandon abandon abandon abandon abandon abandon abandon abandon \
+     abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+     abandon abandon abandon abandon art";
+/// BIP39 test mnemonic for 0x7f7f... entropy.
+const MNEMONIC_12_LEGAL: &str =
+    "legal winner thank year wave sausage worth useful legal winner thank yellow";
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct MnemonicTestVector {
+    name: String,
+    mnemonic: String,
+    passphrase: String,
+    /// PBKDF2-HMAC-SHA512 BIP39 seed.
+    seed: Vec<u8>,
+    /// `keypair_from_seed_phrase_and_passphrase`: the first 32 seed bytes
+    /// become the secret, with no derivation path, as `solana-keygen`
+    /// recovers without `--derivation-path`.
+    keypair: Vec<u8>,
+    pubkey: [u8; 32],
+    pubkey_base58: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<MnemonicTestVector>,
+}
+
+fn vector(name: &str, mnemonic: &str, passphrase: &str) -> MnemonicTestVector {
+    let keypair = keypair_from_seed_phrase_and_passphrase(mnemonic, passphrase).unwrap();
+    MnemonicTestVector {
+        name: name.to_string(),
+        mnemonic: mnemonic.to_string(),
+        passphrase: passphrase.to_string(),
+        seed: generate_seed_from_seed_phrase_and_passphrase(mnemonic, passphrase),
+        keypair: keypair.to_bytes().to_vec(),
+        pubkey: keypair.pubkey().to_bytes(),
+        pubkey_base58: keypair.pubkey().to_string(),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        vectors: vec![
+            vector("12_words", MNEMONIC_12, ""),
+            vector("12_words_passphrase", MNEMONIC_12, "TREZOR"),
+            vector("24_words", MNEMONIC_24, ""),
+            vector("24_words_passphrase", MNEMONIC_24, "TREZOR"),
+            vector("12_words_legal_winner", MNEMONIC_12_LEGAL, ""),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_mnemonic_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_mnemonic_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn keypair_secret_is_the_first_half_of_the_seed() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        assert_eq!(vector.seed.len(), 64, "{}", vector.name);
+        assert_eq!(vector.keypair[..32], vector.seed[..32], "{}", vector.name);
+        let keypair = Keypair::try_from(vector.keypair.as_slice()).unwrap();
+        assert_eq!(keypair.pubkey().to_bytes(), vector.pubkey);
+    }
+    // The passphrase is part of the PBKDF2 salt.
+    assert_ne!(fixture.vectors[0].seed, fixture.vectors[1].seed);
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_mnemonic_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
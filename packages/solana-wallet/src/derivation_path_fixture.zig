@@ -0,0 +1,25 @@
+const std = @import("std");
+
+pub const DerivationPathVector = struct {
+    name: []const u8,
+    seed: [64]u8,
+    account: ?u32,
+    change: ?u32,
+    path: []const u8,
+    keypair: [64]u8,
+    pubkey: [32]u8,
+    pubkey_base58: []const u8,
+};
+
+pub const Fixture = struct {
+    vectors: []const DerivationPathVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_derivation_path_vectors.json"),
+        .{},
+    );
+}
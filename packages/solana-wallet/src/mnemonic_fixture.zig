@@ -0,0 +1,24 @@
+const std = @import("std");
+
+pub const MnemonicVector = struct {
+    name: []const u8,
+    mnemonic: []const u8,
+    passphrase: []const u8,
+    seed: [64]u8,
+    keypair: [64]u8,
+    pubkey: [32]u8,
+    pubkey_base58: []const u8,
+};
+
+pub const Fixture = struct {
+    vectors: []const MnemonicVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_mnemonic_vectors.json"),
+        .{},
+    );
+}
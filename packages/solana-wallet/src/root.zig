@@ -416,6 +416,17 @@ pub fn deriveKeypairFromMnemonic(
     return Keypair.fromSeed(secret_seed);
 }
 
+/// Recover the keypair `solana-keygen` derives from a mnemonic when no
+/// derivation path is given: the first 32 BIP39 seed bytes are the secret.
+pub fn keypairFromMnemonic(
+    mnemonic: []const u8,
+    passphrase: []const u8,
+) (Error || std.crypto.errors.WeakParametersError || std.crypto.errors.OutputTooLongError || anyerror)!Keypair {
+    var seed: Bip39Seed = undefined;
+    try mnemonicToSeed(mnemonic, passphrase, &seed);
+    return Keypair.fromSeed(seed[0..keypair.SEED_BYTES].*);
+}
+
 pub fn writeEncryptedKeystoreEnvelope(
     keystore: EncryptedKeystore,
     out: []u8,
@@ -980,6 +991,40 @@ test "deriveKeypairFromMnemonic uses Solana hardened derivation paths" {
     try keypair.verify(sig, "wallet-message", &pubkey);
 }
 
+test "keypairFromMnemonic matches official seed phrase keypairs" {
+    const mnemonic_fixture = @import("mnemonic_fixture.zig");
+    var parsed = try mnemonic_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+
+    for (parsed.value.vectors) |vector| {
+        try validateMnemonicChecksum(vector.mnemonic, englishWordlistResolver());
+        var seed: Bip39Seed = undefined;
+        try mnemonicToSeed(vector.mnemonic, vector.passphrase, &seed);
+        try std.testing.expectEqualSlices(u8, &vector.seed, &seed);
+
+        const kp = try keypairFromMnemonic(vector.mnemonic, vector.passphrase);
+        try std.testing.expectEqualSlices(u8, &vector.keypair, &kp.secretKeyBytes());
+        try std.testing.expectEqualSlices(u8, &vector.pubkey, &kp.publicKey());
+    }
+}
+
+test "SLIP-0010 derivation matches official derivation path keypairs" {
+    const derivation_path_fixture = @import("derivation_path_fixture.zig");
+    var parsed = try derivation_path_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+
+    for (parsed.value.vectors) |vector| {
+        const parsed_path = try parseDerivationPath(vector.path);
+        const path = parsed_path.path();
+        var out: [64]u8 = undefined;
+        try std.testing.expectEqualStrings(vector.path, try writeDerivationPath(path, &out));
+
+        const kp = try Keypair.fromSeed(try deriveSeedFromBip39Seed(&vector.seed, path));
+        try std.testing.expectEqualSlices(u8, &vector.keypair, &kp.secretKeyBytes());
+        try std.testing.expectEqualSlices(u8, &vector.pubkey, &kp.publicKey());
+    }
+}
+
 test "wallet adapter signs with keypair-backed adapter" {
     var wallet: KeypairWallet = .{ .keypair = try keypair.Keypair.fromSeed(.{5} ** keypair.SEED_BYTES) };
     const adapter = wallet.adapter();
@@ -1197,6 +1242,7 @@ test "public surface guards" {
     try std.testing.expect(@hasDecl(@This(), "validateMnemonicChecksum"));
     try std.testing.expect(@hasDecl(@This(), "WordlistResolver"));
     try std.testing.expect(@hasDecl(@This(), "deriveKeypairFromMnemonic"));
+    try std.testing.expect(@hasDecl(@This(), "keypairFromMnemonic"));
     try std.testing.expect(@hasDecl(@This(), "WalletAdapter"));
     try std.testing.expect(@hasDecl(@This(), "EncryptedKeystore"));
     try std.testing.expect(@hasDecl(@This(), "parseEncryptedKeystoreEnvelope"));
@@ -0,0 +1,173 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_zk_sdk::encryption::{
+    elgamal::{ElGamalPubkey, ElGamalSecretKey},
+    pedersen::{Pedersen, PedersenCommitment, PedersenOpening, G, H},
+    DECRYPT_HANDLE_LEN, ELGAMAL_CIPHERTEXT_LEN, ELGAMAL_PUBKEY_LEN, ELGAMAL_SECRET_KEY_LEN,
+    PEDERSEN_COMMITMENT_LEN, PEDERSEN_OPENING_LEN,
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_elgamal_vectors.json");
+
+/// The entropy `ElGamalSecretKey::from_seed` hashes into the secret scalar.
+const SEED: [u8; 32] = [0x5e; 32];
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Sizes {
+    elgamal_pubkey: usize,
+    elgamal_secret_key: usize,
+    elgamal_ciphertext: usize,
+    pedersen_commitment: usize,
+    pedersen_opening: usize,
+    decrypt_handle: usize,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct KeypairVector {
+    seed: [u8; 32],
+    /// Little-endian scalar `s`.
+    secret_key: [u8; 32],
+    /// Compressed ristretto `s^-1 * H`.
+    pubkey: [u8; 32],
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct CommitmentVector {
+    name: String,
+    amount: u64,
+    /// Little-endian scalar `r`.
+    opening: [u8; 32],
+    /// Compressed ristretto `amount * G + r * H`.
+    commitment: [u8; 32],
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct CiphertextVector {
+    name: String,
+    amount: u64,
+    opening: [u8; 32],
+    commitment: [u8; 32],
+    /// Compressed ristretto `r * pubkey`.
+    handle: [u8; 32],
+    /// `commitment || handle`.
+    ciphertext: Vec<u8>,
+    /// `ElGamalSecretKey::decrypt_u32`.
+    decrypted: Option<u64>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    sizes: Sizes,
+    /// Compressed ristretto Pedersen generators.
+    pedersen_g: [u8; 32],
+    pedersen_h: [u8; 32],
+    keypair: KeypairVector,
+    commitments: Vec<CommitmentVector>,
+    ciphertexts: Vec<CiphertextVector>,
+}
+
+fn opening(byte: u8) -> PedersenOpening {
+    PedersenOpening::from_bytes(&[byte; 32]).unwrap()
+}
+
+fn commitment_vector(name: &str, amount: u64, opening: PedersenOpening) -> CommitmentVector {
+    CommitmentVector {
+        name: name.to_string(),
+        amount,
+        opening: opening.to_bytes(),
+        commitment: Pedersen::with(amount, &opening).to_bytes(),
+    }
+}
+
+fn ciphertext_vector(
+    name: &str,
+    secret: &ElGamalSecretKey,
+    amount: u64,
+    opening: PedersenOpening,
+) -> CiphertextVector {
+    let pubkey = ElGamalPubkey::new(secret);
+    let ciphertext = pubkey.encrypt_with(amount, &opening);
+    CiphertextVector {
+        name: name.to_string(),
+        amount,
+        opening: opening.to_bytes(),
+        commitment: ciphertext.commitment.to_bytes(),
+        handle: ciphertext.handle.to_bytes(),
+        ciphertext: ciphertext.to_bytes().to_vec(),
+        decrypted: secret.decrypt_u32(&ciphertext),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let secret = ElGamalSecretKey::from_seed(&SEED).unwrap();
+    let pubkey = ElGamalPubkey::new(&secret);
+    Fixture {
+        sizes: Sizes {
+            elgamal_pubkey: ELGAMAL_PUBKEY_LEN,
+            elgamal_secret_key: ELGAMAL_SECRET_KEY_LEN,
+            elgamal_ciphertext: ELGAMAL_CIPHERTEXT_LEN,
+            pedersen_commitment: PEDERSEN_COMMITMENT_LEN,
+            pedersen_opening: PEDERSEN_OPENING_LEN,
+            decrypt_handle: DECRYPT_HANDLE_LEN,
+        },
+        pedersen_g: PedersenCommitment::new(G).to_bytes(),
+        pedersen_h: PedersenCommitment::new(*H).to_bytes(),
+        keypair: KeypairVector {
+            seed: SEED,
+            secret_key: *secret.as_bytes(),
+            pubkey: pubkey.into(),
+        },
+        commitments: vec![
+            commitment_vector("amount_55", 55, opening(0x01)),
+            commitment_vector("amount_zero", 0, opening(0x02)),
+            commitment_vector("amount_u64_max", u64::MAX, opening(0x03)),
+        ],
+        ciphertexts: vec![
+            ciphertext_vector("amount_55", &secret, 55, opening(0x04)),
+            ciphertext_vector("amount_zero", &secret, 0, opening(0x05)),
+            ciphertext_vector("amount_u16_max", &secret, u16::MAX.into(), opening(0x06)),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_elgamal_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_elgamal_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn ciphertexts_split_into_commitment_and_handle_and_decrypt() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let secret = ElGamalSecretKey::from_seed(&fixture.keypair.seed).unwrap();
+    assert_eq!(*secret.as_bytes(), fixture.keypair.secret_key);
+
+    for vector in &fixture.ciphertexts {
+        let (commitment, handle) = vector.ciphertext.split_at(PEDERSEN_COMMITMENT_LEN);
+        assert_eq!(commitment, vector.commitment, "{}", vector.name);
+        assert_eq!(handle, vector.handle, "{}", vector.name);
+        assert_eq!(vector.decrypted, Some(vector.amount), "{}", vector.name);
+
+        // The commitment half is the plain Pedersen commitment.
+        let opening = PedersenOpening::from_bytes(&vector.opening).unwrap();
+        assert_eq!(
+            Pedersen::with(vector.amount, &opening).to_bytes(),
+            vector.commitment
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_elgamal_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
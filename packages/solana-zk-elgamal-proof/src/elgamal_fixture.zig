@@ -0,0 +1,51 @@
+const std = @import("std");
+
+pub const Sizes = struct {
+    elgamal_pubkey: usize,
+    elgamal_secret_key: usize,
+    elgamal_ciphertext: usize,
+    pedersen_commitment: usize,
+    pedersen_opening: usize,
+    decrypt_handle: usize,
+};
+
+pub const KeypairVector = struct {
+    seed: [32]u8,
+    secret_key: [32]u8,
+    pubkey: [32]u8,
+};
+
+pub const CommitmentVector = struct {
+    name: []const u8,
+    amount: u64,
+    opening: [32]u8,
+    commitment: [32]u8,
+};
+
+pub const CiphertextVector = struct {
+    name: []const u8,
+    amount: u64,
+    opening: [32]u8,
+    commitment: [32]u8,
+    handle: [32]u8,
+    ciphertext: []const u8,
+    decrypted: ?u64,
+};
+
+pub const Fixture = struct {
+    sizes: Sizes,
+    pedersen_g: [32]u8,
+    pedersen_h: [32]u8,
+    keypair: KeypairVector,
+    commitments: []const CommitmentVector,
+    ciphertexts: []const CiphertextVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_elgamal_vectors.json"),
+        .{},
+    );
+}
@@ -31,6 +31,16 @@ pub const ProofInstruction = enum(u8) {
     verify_batched_grouped_ciphertext_3_handles_validity = 12,
 };
 
+/// Encoded sizes of the `solana-zk-sdk` types proof data is built from:
+/// compressed ristretto points and little-endian scalars.
+pub const elgamal_pubkey_len: usize = 32;
+pub const elgamal_secret_key_len: usize = 32;
+pub const pedersen_commitment_len: usize = 32;
+pub const pedersen_opening_len: usize = 32;
+pub const decrypt_handle_len: usize = 32;
+/// `commitment || handle`.
+pub const elgamal_ciphertext_len: usize = pedersen_commitment_len + decrypt_handle_len;
+
 pub const ContextStateInfo = struct {
     context_state_account: *const Pubkey,
     context_state_authority: *const Pubkey,
@@ -177,6 +187,29 @@ test "verifyProof supports no context state account" {
     try std.testing.expectEqualSlices(u8, &.{ 1, 0xee }, inline_ix.data);
 }
 
+test "ElGamal and Pedersen encodings match official solana-zk-sdk vectors" {
+    const elgamal_fixture = @import("elgamal_fixture.zig");
+    var parsed = try elgamal_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+
+    try std.testing.expectEqual(elgamal_pubkey_len, fixture.sizes.elgamal_pubkey);
+    try std.testing.expectEqual(elgamal_secret_key_len, fixture.sizes.elgamal_secret_key);
+    try std.testing.expectEqual(elgamal_ciphertext_len, fixture.sizes.elgamal_ciphertext);
+    try std.testing.expectEqual(pedersen_commitment_len, fixture.sizes.pedersen_commitment);
+    try std.testing.expectEqual(pedersen_opening_len, fixture.sizes.pedersen_opening);
+    try std.testing.expectEqual(decrypt_handle_len, fixture.sizes.decrypt_handle);
+    try std.testing.expectEqual(@as(usize, 3), fixture.commitments.len);
+    try std.testing.expectEqual(@as(usize, 3), fixture.ciphertexts.len);
+
+    for (fixture.ciphertexts) |vector| {
+        try std.testing.expectEqual(elgamal_ciphertext_len, vector.ciphertext.len);
+        try std.testing.expectEqualSlices(u8, &vector.commitment, vector.ciphertext[0..pedersen_commitment_len]);
+        try std.testing.expectEqualSlices(u8, &vector.handle, vector.ciphertext[pedersen_commitment_len..]);
+        try std.testing.expectEqual(@as(?u64, vector.amount), vector.decrypted);
+    }
+}
+
 test "public surface guards" {
     try std.testing.expect(@hasDecl(@This(), "PROGRAM_ID"));
     try std.testing.expect(@hasDecl(@This(), "closeContextState"));
@@ -0,0 +1,159 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use {
+    serde::{Deserialize, Serialize},
+    solana_pubkey::Pubkey,
+    spl_associated_token_account_interface::{
+        address::{
+            get_associated_token_address_and_bump_seed,
+            get_associated_token_address_with_program_id,
+        },
+        program,
+    },
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_ata_derivation_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AtaDerivationTestVector {
+    name: String,
+    wallet: [u8; 32],
+    /// `false` for PDA owners, which have no private key.
+    wallet_on_curve: bool,
+    mint: [u8; 32],
+    token_program_id: [u8; 32],
+    address: [u8; 32],
+    bump: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    program_id: [u8; 32],
+    vectors: Vec<AtaDerivationTestVector>,
+}
+
+fn token_program_id() -> Pubkey {
+    Pubkey::from_str_const("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+}
+
+fn token_2022_program_id() -> Pubkey {
+    Pubkey::from_str_const("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb")
+}
+
+fn native_mint() -> Pubkey {
+    Pubkey::from_str_const("So11111111111111111111111111111111111111112")
+}
+
+/// The same PDA owner the Zig `derivation supports PDA wallet owners` test
+/// derives.
+fn pda_wallet() -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"wallet-owner"],
+        &Pubkey::from_str_const("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"),
+    )
+    .0
+}
+
+fn vector(
+    name: &str,
+    wallet: Pubkey,
+    mint: Pubkey,
+    token_program_id: Pubkey,
+) -> AtaDerivationTestVector {
+    let address = get_associated_token_address_with_program_id(&wallet, &mint, &token_program_id);
+    let (_, bump) = get_associated_token_address_and_bump_seed(
+        &wallet,
+        &mint,
+        &program::id(),
+        &token_program_id,
+    );
+    AtaDerivationTestVector {
+        name: name.to_string(),
+        wallet: wallet.to_bytes(),
+        wallet_on_curve: wallet.is_on_curve(),
+        mint: mint.to_bytes(),
+        token_program_id: token_program_id.to_bytes(),
+        address: address.to_bytes(),
+        bump,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    // The ed25519 base point: a wallet that has a signing key.
+    let mut base_point = [0x66; 32];
+    base_point[0] = 0x58;
+    let wallet = Pubkey::from(base_point);
+    let mint = Pubkey::from([0x33; 32]);
+
+    let mut vectors = Vec::new();
+    for (suffix, token_program_id) in [
+        ("token", token_program_id()),
+        ("token_2022", token_2022_program_id()),
+    ] {
+        vectors.extend([
+            vector(&format!("wallet_{suffix}"), wallet, mint, token_program_id),
+            vector(
+                &format!("wallet_native_mint_{suffix}"),
+                wallet,
+                native_mint(),
+                token_program_id,
+            ),
+            vector(
+                &format!("pda_wallet_{suffix}"),
+                pda_wallet(),
+                mint,
+                token_program_id,
+            ),
+        ]);
+    }
+
+    Fixture {
+        program_id: program::id().to_bytes(),
+        vectors,
+    }
+}
+
+#[test]
+fn fixture_matches_official_ata_derivation_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_ata_derivation_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn addresses_are_canonical_pdas_over_wallet_token_program_mint() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let program_id = Pubkey::from(fixture.program_id);
+    assert!(fixture.vectors.iter().any(|vector| !vector.wallet_on_curve));
+
+    for vector in &fixture.vectors {
+        let seeds: [&[u8]; 4] = [
+            &vector.wallet,
+            &vector.token_program_id,
+            &vector.mint,
+            &[vector.bump],
+        ];
+        let address = Pubkey::create_program_address(&seeds, &program_id).unwrap();
+        assert_eq!(address.to_bytes(), vector.address, "{}", vector.name);
+        assert!(!address.is_on_curve(), "{}", vector.name);
+        assert_eq!(
+            Pubkey::from(vector.wallet).is_on_curve(),
+            vector.wallet_on_curve,
+            "{}",
+            vector.name
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_ata_derivation_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,25 @@
+const std = @import("std");
+
+pub const AtaDerivationVector = struct {
+    name: []const u8,
+    wallet: [32]u8,
+    wallet_on_curve: bool,
+    mint: [32]u8,
+    token_program_id: [32]u8,
+    address: [32]u8,
+    bump: u8,
+};
+
+pub const Fixture = struct {
+    program_id: [32]u8,
+    vectors: []const AtaDerivationVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_ata_derivation_vectors.json"),
+        .{},
+    );
+}
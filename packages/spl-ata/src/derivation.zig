@@ -146,3 +146,29 @@ test "derivation supports PDA wallet owners" {
     try std.testing.expectEqual(expected.bump_seed, actual.bump_seed);
     try std.testing.expectEqualSlices(u8, &expected.address, &actual.address);
 }
+
+test "findAddress matches official get_associated_token_address vectors" {
+    const ata_derivation_fixture = @import("ata_derivation_fixture.zig");
+    var parsed = try ata_derivation_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqualSlices(u8, &id.PROGRAM_ID, &fixture.program_id);
+    try std.testing.expectEqual(@as(usize, 6), fixture.vectors.len);
+
+    var off_curve_wallets: usize = 0;
+    for (fixture.vectors) |vector| {
+        try std.testing.expectEqual(vector.wallet_on_curve, sol.pubkey.isPointOnCurve(&vector.wallet));
+        if (!vector.wallet_on_curve) off_curve_wallets += 1;
+
+        const actual = findAddress(&vector.wallet, &vector.mint, &vector.token_program_id);
+        try std.testing.expectEqual(vector.bump, actual.bump_seed);
+        try std.testing.expectEqualSlices(u8, &vector.address, &actual.address);
+
+        const helper = if (sol.pubkey.pubkeyEq(&vector.token_program_id, &sol.spl_token_program_id))
+            findAddressClassic(&vector.wallet, &vector.mint)
+        else
+            findAddressToken2022(&vector.wallet, &vector.mint);
+        try std.testing.expectEqualSlices(u8, &vector.address, &helper.address);
+    }
+    try std.testing.expectEqual(@as(usize, 2), off_curve_wallets);
+}
@@ -0,0 +1,129 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use {
+    serde::{Deserialize, Serialize},
+    solana_instruction::AccountMeta,
+    solana_pubkey::Pubkey,
+    spl_memo::{build_memo, processor::process_instruction},
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_memo_instruction_vectors.json");
+
+/// Message length the `instruction.memo` docs quote as the practical cap.
+const MAX_MEMO_LEN: usize = 566;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AccountMetaVector {
+    pubkey: [u8; 32],
+    is_signer: bool,
+    is_writable: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct MemoInstructionTestVector {
+    name: String,
+    /// `build_memo` instruction data: the memo bytes, unprefixed.
+    data: Vec<u8>,
+    accounts: Vec<AccountMetaVector>,
+    /// Whether the memo processor accepts `data`; invalid UTF-8 is
+    /// rejected with `InvalidInstructionData`.
+    valid_utf8: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    program_id: [u8; 32],
+    vectors: Vec<MemoInstructionTestVector>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+fn meta_vector(meta: &AccountMeta) -> AccountMetaVector {
+    AccountMetaVector {
+        pubkey: meta.pubkey.to_bytes(),
+        is_signer: meta.is_signer,
+        is_writable: meta.is_writable,
+    }
+}
+
+fn vector(name: &str, memo: &[u8], signers: &[&Pubkey]) -> MemoInstructionTestVector {
+    let ix = build_memo(memo, signers);
+    assert_eq!(ix.program_id, spl_memo::id());
+    MemoInstructionTestVector {
+        name: name.to_string(),
+        valid_utf8: process_instruction(&ix.program_id, &[], &ix.data).is_ok(),
+        data: ix.data,
+        accounts: ix.accounts.iter().map(meta_vector).collect(),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let signer_a = key(1);
+    let signer_b = key(2);
+    let signer_c = key(3);
+    let max_len = "memo".repeat(MAX_MEMO_LEN / 4) + "!!";
+
+    Fixture {
+        program_id: spl_memo::id().to_bytes(),
+        vectors: vec![
+            vector("empty", b"", &[]),
+            vector("ascii", b"hello", &[]),
+            vector("ascii_one_signer", b"audit:withdraw", &[&signer_a]),
+            vector(
+                "multi_byte_utf8",
+                "résumé 🐆 メモ".as_bytes(),
+                &[&signer_a, &signer_b],
+            ),
+            vector(
+                "max_len",
+                max_len.as_bytes(),
+                &[&signer_a, &signer_b, &signer_c],
+            ),
+            // `🐆` with its last continuation byte replaced by 0xFF.
+            vector("invalid_utf8", &[0xF0, 0x9F, 0x90, 0xFF], &[&signer_a]),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_memo_instruction_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_memo_instruction_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn vectors_cover_the_documented_shapes() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    assert!(fixture
+        .vectors
+        .iter()
+        .any(|vector| vector.data.len() == MAX_MEMO_LEN));
+    for vector in &fixture.vectors {
+        assert_eq!(
+            std::str::from_utf8(&vector.data).is_ok(),
+            vector.valid_utf8,
+            "{}",
+            vector.name
+        );
+        assert!(vector
+            .accounts
+            .iter()
+            .all(|meta| meta.is_signer && !meta.is_writable));
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_memo_instruction_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -130,3 +130,30 @@ test "memoChecked accepts oversized scratch and slices output accounts" {
     try std.testing.expectEqual(@intFromPtr(&metas[0]), @intFromPtr(ix.accounts.ptr));
     try std.testing.expectEqualStrings("note", ix.data);
 }
+
+test "memo matches official build_memo vectors" {
+    const memo_instruction_fixture = @import("memo_instruction_fixture.zig");
+    var parsed = try memo_instruction_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqualSlices(u8, &id.PROGRAM_ID, &fixture.program_id);
+    try std.testing.expectEqual(@as(usize, 6), fixture.vectors.len);
+
+    for (fixture.vectors) |vector| {
+        var signers: [3]*const Pubkey = undefined;
+        for (vector.accounts, 0..) |*account, i| signers[i] = &account.pubkey;
+        var metas: [3]AccountMeta = undefined;
+        const ix = try memoChecked(vector.data, signers[0..vector.accounts.len], &metas);
+
+        try std.testing.expectEqualSlices(u8, &id.PROGRAM_ID, ix.program_id);
+        try std.testing.expectEqualSlices(u8, vector.data, ix.data);
+        try std.testing.expectEqual(vector.accounts.len, ix.accounts.len);
+        for (vector.accounts, ix.accounts) |expected, actual| {
+            try std.testing.expectEqualSlices(u8, &expected.pubkey, actual.pubkey);
+            try std.testing.expectEqual(@intFromBool(expected.is_signer), actual.is_signer);
+            try std.testing.expectEqual(@intFromBool(expected.is_writable), actual.is_writable);
+        }
+        // The builder passes bytes through; only the program checks UTF-8.
+        try std.testing.expectEqual(vector.valid_utf8, std.unicode.utf8ValidateSlice(vector.data));
+    }
+}
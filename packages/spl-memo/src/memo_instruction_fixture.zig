@@ -0,0 +1,28 @@
+const std = @import("std");
+
+pub const AccountMetaVector = struct {
+    pubkey: [32]u8,
+    is_signer: bool,
+    is_writable: bool,
+};
+
+pub const MemoInstructionVector = struct {
+    name: []const u8,
+    data: []const u8,
+    accounts: []const AccountMetaVector,
+    valid_utf8: bool,
+};
+
+pub const Fixture = struct {
+    program_id: [32]u8,
+    vectors: []const MemoInstructionVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_memo_instruction_vectors.json"),
+        .{},
+    );
+}
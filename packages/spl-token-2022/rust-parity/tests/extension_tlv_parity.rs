@@ -0,0 +1,315 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use {
+    serde::{Deserialize, Serialize},
+    solana_pubkey::Pubkey,
+    spl_token_2022::{
+        extension::{
+            immutable_owner::ImmutableOwner,
+            mint_close_authority::MintCloseAuthority,
+            transfer_fee::{TransferFee, TransferFeeConfig},
+            BaseStateWithExtensions, BaseStateWithExtensionsMut, ExtensionType,
+            StateWithExtensions, StateWithExtensionsMut,
+        },
+        state::{Account, AccountState, Mint, PackedSizeOf},
+    },
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_extension_tlv_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct TransferFeeFields {
+    epoch: u64,
+    maximum_fee: u64,
+    transfer_fee_basis_points: u16,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct TransferFeeConfigFields {
+    transfer_fee_config_authority: Option<[u8; 32]>,
+    withdraw_withheld_authority: Option<[u8; 32]>,
+    withheld_amount: u64,
+    older_transfer_fee: TransferFeeFields,
+    newer_transfer_fee: TransferFeeFields,
+}
+
+/// One TLV entry as `StateWithExtensions` finds it in `data`. Only the
+/// field set matching `extension_type` is present.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ExtensionRecord {
+    extension_type: u16,
+    name: String,
+    /// Offset of the `u16` type / `u16` length header.
+    header_offset: usize,
+    value_offset: usize,
+    length: usize,
+    close_authority: Option<[u8; 32]>,
+    transfer_fee_config: Option<TransferFeeConfigFields>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ExtensionTlvTestVector {
+    name: String,
+    /// `AccountType` byte at offset 165: 1 = mint, 2 = account.
+    account_type: u8,
+    base_len: usize,
+    data: Vec<u8>,
+    extensions: Vec<ExtensionRecord>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    account_type_offset: usize,
+    tlv_start_offset: usize,
+    vectors: Vec<ExtensionTlvTestVector>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+fn optional_key(key: impl Into<Option<Pubkey>>) -> Option<[u8; 32]> {
+    key.into().map(|key| key.to_bytes())
+}
+
+fn transfer_fee_fields(fee: &TransferFee) -> TransferFeeFields {
+    TransferFeeFields {
+        epoch: fee.epoch.into(),
+        maximum_fee: fee.maximum_fee.into(),
+        transfer_fee_basis_points: fee.transfer_fee_basis_points.into(),
+    }
+}
+
+/// Where `value`, borrowed from `data`, starts.
+fn offset_in<V>(data: &[u8], value: &V) -> usize {
+    value as *const V as usize - data.as_ptr() as usize
+}
+
+fn record<V>(extension_type: ExtensionType, data: &[u8], value: &V) -> ExtensionRecord {
+    let value_offset = offset_in(data, value);
+    let length = std::mem::size_of_val(value);
+    ExtensionRecord {
+        extension_type: extension_type.into(),
+        name: format!("{extension_type:?}"),
+        header_offset: value_offset - 4,
+        value_offset,
+        length,
+        close_authority: None,
+        transfer_fee_config: None,
+    }
+}
+
+/// Records every extension of a packed mint, in TLV order.
+fn mint_records(data: &[u8]) -> Vec<ExtensionRecord> {
+    let state = StateWithExtensions::<Mint>::unpack(data).unwrap();
+    let mut records: Vec<ExtensionRecord> = state
+        .get_extension_types()
+        .unwrap()
+        .into_iter()
+        .map(|extension_type| match extension_type {
+            ExtensionType::MintCloseAuthority => {
+                let extension = state.get_extension::<MintCloseAuthority>().unwrap();
+                ExtensionRecord {
+                    close_authority: optional_key(extension.close_authority),
+                    ..record(extension_type, data, extension)
+                }
+            }
+            ExtensionType::TransferFeeConfig => {
+                let config = state.get_extension::<TransferFeeConfig>().unwrap();
+                ExtensionRecord {
+                    transfer_fee_config: Some(TransferFeeConfigFields {
+                        transfer_fee_config_authority: optional_key(
+                            config.transfer_fee_config_authority,
+                        ),
+                        withdraw_withheld_authority: optional_key(
+                            config.withdraw_withheld_authority,
+                        ),
+                        withheld_amount: config.withheld_amount.into(),
+                        older_transfer_fee: transfer_fee_fields(&config.older_transfer_fee),
+                        newer_transfer_fee: transfer_fee_fields(&config.newer_transfer_fee),
+                    }),
+                    ..record(extension_type, data, config)
+                }
+            }
+            other => panic!("unexpected mint extension {other:?}"),
+        })
+        .collect();
+    records.sort_by_key(|record| record.value_offset);
+    records
+}
+
+fn account_records(data: &[u8]) -> Vec<ExtensionRecord> {
+    let state = StateWithExtensions::<Account>::unpack(data).unwrap();
+    state
+        .get_extension_types()
+        .unwrap()
+        .into_iter()
+        .map(|extension_type| match extension_type {
+            ExtensionType::ImmutableOwner => record(
+                extension_type,
+                data,
+                state.get_extension::<ImmutableOwner>().unwrap(),
+            ),
+            other => panic!("unexpected account extension {other:?}"),
+        })
+        .collect()
+}
+
+fn base_mint() -> Mint {
+    Mint {
+        mint_authority: Some(key(0x01)).into(),
+        supply: 1_000_000,
+        decimals: 6,
+        is_initialized: true,
+        freeze_authority: None.into(),
+    }
+}
+
+fn transfer_fee_config() -> TransferFeeConfig {
+    TransferFeeConfig {
+        transfer_fee_config_authority: Some(key(0x03)).try_into().unwrap(),
+        withdraw_withheld_authority: Some(key(0x04)).try_into().unwrap(),
+        withheld_amount: 77.into(),
+        older_transfer_fee: TransferFee {
+            epoch: 4.into(),
+            maximum_fee: 5_000.into(),
+            transfer_fee_basis_points: 25.into(),
+        },
+        newer_transfer_fee: TransferFee {
+            epoch: 9.into(),
+            maximum_fee: u64::MAX.into(),
+            transfer_fee_basis_points: 10_000.into(),
+        },
+    }
+}
+
+/// Build a mint the way the token program does: allocate every extension,
+/// then write the base state and account type.
+fn mint_vector(name: &str, extension_types: &[ExtensionType]) -> ExtensionTlvTestVector {
+    let len = ExtensionType::try_calculate_account_len::<Mint>(extension_types).unwrap();
+    let mut data = vec![0; len];
+    let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+    for extension_type in extension_types {
+        match extension_type {
+            ExtensionType::MintCloseAuthority => {
+                state
+                    .init_extension::<MintCloseAuthority>(true)
+                    .unwrap()
+                    .close_authority = Some(key(0x02)).try_into().unwrap();
+            }
+            ExtensionType::TransferFeeConfig => {
+                *state.init_extension::<TransferFeeConfig>(true).unwrap() = transfer_fee_config();
+            }
+            other => panic!("unexpected mint extension {other:?}"),
+        }
+    }
+    state.base = base_mint();
+    state.pack_base();
+    state.init_account_type().unwrap();
+
+    ExtensionTlvTestVector {
+        name: name.to_string(),
+        account_type: data[Account::SIZE_OF],
+        base_len: Mint::SIZE_OF,
+        extensions: mint_records(&data),
+        data,
+    }
+}
+
+fn immutable_owner_account_vector() -> ExtensionTlvTestVector {
+    let extension_types = [ExtensionType::ImmutableOwner];
+    let len = ExtensionType::try_calculate_account_len::<Account>(&extension_types).unwrap();
+    let mut data = vec![0; len];
+    let mut state = StateWithExtensionsMut::<Account>::unpack_uninitialized(&mut data).unwrap();
+    state.init_extension::<ImmutableOwner>(true).unwrap();
+    state.base = Account {
+        mint: key(0x11),
+        owner: key(0x22),
+        amount: 500,
+        state: AccountState::Initialized,
+        ..Account::default()
+    };
+    state.pack_base();
+    state.init_account_type().unwrap();
+
+    ExtensionTlvTestVector {
+        name: "account_immutable_owner".to_string(),
+        account_type: data[Account::SIZE_OF],
+        base_len: Account::SIZE_OF,
+        extensions: account_records(&data),
+        data,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        account_type_offset: Account::SIZE_OF,
+        tlv_start_offset: Account::SIZE_OF + 1,
+        vectors: vec![
+            mint_vector("mint_close_authority", &[ExtensionType::MintCloseAuthority]),
+            mint_vector("transfer_fee_config", &[ExtensionType::TransferFeeConfig]),
+            mint_vector(
+                "transfer_fee_config_and_mint_close_authority",
+                &[
+                    ExtensionType::TransferFeeConfig,
+                    ExtensionType::MintCloseAuthority,
+                ],
+            ),
+            immutable_owner_account_vector(),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_extension_tlv_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "official_extension_tlv_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn tlv_records_are_contiguous_after_the_padded_base() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let data = &vector.data;
+        // Mints are zero-padded up to the account length so the type byte
+        // lands at the same offset for both kinds.
+        assert!(
+            data[vector.base_len..fixture.account_type_offset]
+                .iter()
+                .all(|&b| b == 0),
+            "{}",
+            vector.name
+        );
+        assert_eq!(data[fixture.account_type_offset], vector.account_type);
+
+        let mut offset = fixture.tlv_start_offset;
+        for record in &vector.extensions {
+            assert_eq!(record.header_offset, offset, "{}", vector.name);
+            let header = &data[offset..offset + 4];
+            assert_eq!(
+                u16::from_le_bytes([header[0], header[1]]),
+                record.extension_type
+            );
+            assert_eq!(
+                usize::from(u16::from_le_bytes([header[2], header[3]])),
+                record.length
+            );
+            offset = record.value_offset + record.length;
+        }
+        assert_eq!(offset, data.len(), "{}", vector.name);
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_extension_tlv_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
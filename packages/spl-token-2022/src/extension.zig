@@ -1556,6 +1556,62 @@ test "unsupported known out-of-scope extensions stay unparsed while scanner skip
     }
 }
 
+test "TLV walk and views match official StateWithExtensions bytes" {
+    const extension_tlv_fixture = @import("extension_tlv_fixture.zig");
+    var parsed = try extension_tlv_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqual(tlv.ACCOUNT_TYPE_OFFSET, fixture.account_type_offset);
+    try std.testing.expectEqual(tlv.TLV_START_OFFSET, fixture.tlv_start_offset);
+    try std.testing.expectEqual(@as(usize, 4), fixture.vectors.len);
+
+    const no_authority = [_]u8{0} ** 32;
+    for (fixture.vectors) |vector| {
+        const data = vector.data;
+        const kind: state.AccountType = @enumFromInt(vector.account_type);
+        const layout = switch (kind) {
+            .mint => try tlv.parseMint(data),
+            .account => try tlv.parseAccount(data),
+            .uninitialized => return error.TestUnexpectedResult,
+        };
+        try std.testing.expectEqual(kind, layout.kind);
+        try std.testing.expectEqual(vector.base_len, layout.base_data.len);
+
+        var it = layout.iterator();
+        for (vector.extensions) |expected| {
+            const record = (try it.next()).?;
+            try std.testing.expectEqual(expected.extension_type, record.extension_type);
+            try std.testing.expectEqual(expected.length, record.value.len);
+            try std.testing.expectEqual(expected.value_offset, @intFromPtr(record.value.ptr) - @intFromPtr(data.ptr));
+            try std.testing.expectEqual(expected.header_offset + 4, expected.value_offset);
+
+            switch (@as(ExtensionType, @enumFromInt(record.extension_type))) {
+                .mint_close_authority => {
+                    const view = try getMintCloseAuthority(data);
+                    try std.testing.expectEqual(expected.close_authority orelse no_authority, view.close_authority);
+                },
+                .transfer_fee_config => {
+                    const config = expected.transfer_fee_config.?;
+                    const view = try getTransferFeeConfig(data);
+                    try std.testing.expectEqual(config.transfer_fee_config_authority orelse no_authority, view.transfer_fee_config_authority);
+                    try std.testing.expectEqual(config.withdraw_withheld_authority orelse no_authority, view.withdraw_withheld_authority);
+                    try std.testing.expectEqual(config.withheld_amount, view.withheld_amount);
+                    inline for (.{ "older_transfer_fee", "newer_transfer_fee" }) |field| {
+                        const fee = @field(config, field);
+                        const fee_view = @field(view, field);
+                        try std.testing.expectEqual(fee.epoch, fee_view.epoch);
+                        try std.testing.expectEqual(fee.maximum_fee, fee_view.maximum_fee);
+                        try std.testing.expectEqual(fee.transfer_fee_basis_points, fee_view.transfer_fee_basis_points);
+                    }
+                },
+                .immutable_owner => _ = try getImmutableOwner(data),
+                else => return error.TestUnexpectedResult,
+            }
+        }
+        try std.testing.expect((try it.next()) == null);
+    }
+}
+
 test {
     std.testing.refAllDecls(@This());
 }
@@ -0,0 +1,48 @@
+const std = @import("std");
+
+pub const TransferFee = struct {
+    epoch: u64,
+    maximum_fee: u64,
+    transfer_fee_basis_points: u16,
+};
+
+pub const TransferFeeConfig = struct {
+    transfer_fee_config_authority: ?[32]u8,
+    withdraw_withheld_authority: ?[32]u8,
+    withheld_amount: u64,
+    older_transfer_fee: TransferFee,
+    newer_transfer_fee: TransferFee,
+};
+
+pub const ExtensionRecord = struct {
+    extension_type: u16,
+    name: []const u8,
+    header_offset: usize,
+    value_offset: usize,
+    length: usize,
+    close_authority: ?[32]u8,
+    transfer_fee_config: ?TransferFeeConfig,
+};
+
+pub const ExtensionTlvVector = struct {
+    name: []const u8,
+    account_type: u8,
+    base_len: usize,
+    data: []const u8,
+    extensions: []const ExtensionRecord,
+};
+
+pub const Fixture = struct {
+    account_type_offset: usize,
+    tlv_start_offset: usize,
+    vectors: []const ExtensionTlvVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_extension_tlv_vectors.json"),
+        .{},
+    );
+}
@@ -0,0 +1 @@
+// Test-only crate for official SPL Token Rust parity fixtures.
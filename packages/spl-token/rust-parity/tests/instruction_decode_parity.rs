@@ -0,0 +1,247 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_program_error::ProgramError;
+use solana_program_option::COption;
+use solana_pubkey::Pubkey;
+use spl_token_interface::{
+    error::TokenError,
+    instruction::{AuthorityType, TokenInstruction},
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/token_instruction_decode_vectors.json");
+
+/// The SDK's `vector_filter.Tag` vocabulary. Deserializing into it rejects
+/// any other tag.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Tag {
+    Error,
+    Boundary,
+    Large,
+    Random,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct DecodeCase {
+    label: String,
+    data: Vec<u8>,
+    expected_variant: Option<String>,
+    expected_error: Option<String>,
+    amount: Option<u64>,
+    decimals: Option<u8>,
+    /// `error` when `expected_error` is set; `boundary` for data one byte
+    /// either side of a variant's length.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<Tag>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    cases: Vec<DecodeCase>,
+}
+
+fn variant_name(instruction: &TokenInstruction) -> &'static str {
+    match instruction {
+        TokenInstruction::InitializeMint { .. } => "initialize_mint",
+        TokenInstruction::InitializeAccount => "initialize_account",
+        TokenInstruction::InitializeMultisig { .. } => "initialize_multisig",
+        TokenInstruction::Transfer { .. } => "transfer",
+        TokenInstruction::Approve { .. } => "approve",
+        TokenInstruction::Revoke => "revoke",
+        TokenInstruction::SetAuthority { .. } => "set_authority",
+        TokenInstruction::MintTo { .. } => "mint_to",
+        TokenInstruction::Burn { .. } => "burn",
+        TokenInstruction::CloseAccount => "close_account",
+        TokenInstruction::FreezeAccount => "freeze_account",
+        TokenInstruction::ThawAccount => "thaw_account",
+        TokenInstruction::TransferChecked { .. } => "transfer_checked",
+        TokenInstruction::ApproveChecked { .. } => "approve_checked",
+        TokenInstruction::MintToChecked { .. } => "mint_to_checked",
+        TokenInstruction::BurnChecked { .. } => "burn_checked",
+        TokenInstruction::InitializeAccount2 { .. } => "initialize_account2",
+        TokenInstruction::SyncNative => "sync_native",
+        TokenInstruction::InitializeAccount3 { .. } => "initialize_account3",
+        TokenInstruction::InitializeMultisig2 { .. } => "initialize_multisig2",
+        TokenInstruction::InitializeMint2 { .. } => "initialize_mint2",
+        TokenInstruction::GetAccountDataSize => "get_account_data_size",
+        TokenInstruction::InitializeImmutableOwner => "initialize_immutable_owner",
+        TokenInstruction::AmountToUiAmount { .. } => "amount_to_ui_amount",
+        TokenInstruction::UiAmountToAmount { .. } => "ui_amount_to_amount",
+    }
+}
+
+fn amount_and_decimals(instruction: &TokenInstruction) -> (Option<u64>, Option<u8>) {
+    match *instruction {
+        TokenInstruction::Transfer { amount }
+        | TokenInstruction::Approve { amount }
+        | TokenInstruction::MintTo { amount }
+        | TokenInstruction::Burn { amount }
+        | TokenInstruction::AmountToUiAmount { amount } => (Some(amount), None),
+        TokenInstruction::TransferChecked { amount, decimals }
+        | TokenInstruction::ApproveChecked { amount, decimals }
+        | TokenInstruction::MintToChecked { amount, decimals }
+        | TokenInstruction::BurnChecked { amount, decimals } => (Some(amount), Some(decimals)),
+        TokenInstruction::InitializeMint { decimals, .. }
+        | TokenInstruction::InitializeMint2 { decimals, .. } => (None, Some(decimals)),
+        _ => (None, None),
+    }
+}
+
+fn error_name(error: ProgramError) -> String {
+    assert_eq!(error, TokenError::InvalidInstruction.into());
+    "InvalidInstruction".to_string()
+}
+
+fn case(label: &str, data: Vec<u8>) -> DecodeCase {
+    match TokenInstruction::unpack(&data) {
+        Ok(instruction) => {
+            let (amount, decimals) = amount_and_decimals(&instruction);
+            DecodeCase {
+                label: label.to_string(),
+                expected_variant: Some(variant_name(&instruction).to_string()),
+                expected_error: None,
+                amount,
+                decimals,
+                data,
+                tags: vec![],
+            }
+        }
+        Err(error) => DecodeCase {
+            label: label.to_string(),
+            data,
+            expected_variant: None,
+            expected_error: Some(error_name(error)),
+            amount: None,
+            decimals: None,
+            tags: vec![Tag::Error],
+        },
+    }
+}
+
+fn boundary_case(label: &str, data: Vec<u8>) -> DecodeCase {
+    let mut case = case(label, data);
+    case.tags.push(Tag::Boundary);
+    case
+}
+
+fn official_fixture() -> Fixture {
+    let authority = Pubkey::from([0x21; 32]);
+    let freeze_authority = Pubkey::from([0x32; 32]);
+
+    let transfer = TokenInstruction::Transfer { amount: 1_000_000 }.pack();
+    let transfer_checked = TokenInstruction::TransferChecked {
+        amount: 42,
+        decimals: 6,
+    }
+    .pack();
+    let initialize_mint_none = TokenInstruction::InitializeMint {
+        decimals: 9,
+        mint_authority: authority,
+        freeze_authority: COption::None,
+    }
+    .pack();
+    let initialize_mint_some = TokenInstruction::InitializeMint {
+        decimals: 9,
+        mint_authority: authority,
+        freeze_authority: COption::Some(freeze_authority),
+    }
+    .pack();
+    let set_authority_some = TokenInstruction::SetAuthority {
+        authority_type: AuthorityType::CloseAccount,
+        new_authority: COption::Some(authority),
+    }
+    .pack();
+
+    let mut transfer_trailing = transfer.clone();
+    transfer_trailing.push(0xee);
+    let mut set_authority_bad_type = set_authority_some.clone();
+    set_authority_bad_type[1] = 4;
+    let mut initialize_mint_bad_tag = initialize_mint_some.clone();
+    initialize_mint_bad_tag[34] = 2;
+
+    Fixture {
+        cases: vec![
+            case("transfer", transfer.clone()),
+            boundary_case("transfer-trailing-byte", transfer_trailing),
+            case("transfer-checked", transfer_checked.clone()),
+            case(
+                "initialize-mint-without-freeze-authority",
+                initialize_mint_none,
+            ),
+            case(
+                "initialize-mint-with-freeze-authority",
+                initialize_mint_some.clone(),
+            ),
+            case("set-authority-close-account", set_authority_some.clone()),
+            case(
+                "ui-amount-to-amount",
+                TokenInstruction::UiAmountToAmount { ui_amount: "1.5" }.pack(),
+            ),
+            boundary_case("empty", vec![]),
+            case("unknown-discriminant-250", vec![250]),
+            case("batch-discriminant-255", vec![255, 0]),
+            boundary_case("transfer-amount-7-bytes", transfer[..8].to_vec()),
+            boundary_case(
+                "transfer-checked-missing-decimals",
+                transfer_checked[..9].to_vec(),
+            ),
+            boundary_case(
+                "initialize-mint-missing-freeze-option",
+                initialize_mint_some[..34].to_vec(),
+            ),
+            case(
+                "initialize-mint-truncated-freeze-authority",
+                initialize_mint_some[..40].to_vec(),
+            ),
+            case("initialize-mint-bad-option-tag", initialize_mint_bad_tag),
+            case("set-authority-unknown-type", set_authority_bad_type),
+            case("ui-amount-to-amount-invalid-utf8", vec![24, 0xff]),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_instruction_decode() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match(
+        "token_instruction_decode_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn every_case_has_exactly_one_outcome() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+
+    for case in &fixture.cases {
+        assert_ne!(
+            case.expected_variant.is_some(),
+            case.expected_error.is_some(),
+            "{}",
+            case.label
+        );
+    }
+}
+
+#[test]
+fn tags_come_from_the_fixed_vocabulary() {
+    assert!(serde_json::from_str::<Tag>("\"erorr\"").is_err());
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for case in &fixture.cases {
+        assert_eq!(
+            case.tags.contains(&Tag::Error),
+            case.expected_error.is_some(),
+            "{}",
+            case.label
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_instruction_decode() {
+    println!("{}", serde_json::to_string_pretty(&official_fixture()).unwrap());
+}
@@ -0,0 +1,203 @@
+#[path = "../../../../program-test/tests/common/vectors.rs"]
+mod vectors;
+
+use serde::{Deserialize, Serialize};
+use solana_program_option::COption;
+use solana_program_pack::Pack;
+use solana_pubkey::Pubkey;
+use solana_rent::Rent;
+use spl_token_interface::state::{Account, AccountState, Mint};
+
+const FIXTURE_JSON: &str = include_str!("../../src/official_state_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct MintStateTestVector {
+    name: String,
+    mint_authority: Option<[u8; 32]>,
+    supply: u64,
+    decimals: u8,
+    is_initialized: bool,
+    freeze_authority: Option<[u8; 32]>,
+    /// `Mint::pack` output.
+    packed: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AccountStateTestVector {
+    name: String,
+    mint: [u8; 32],
+    owner: [u8; 32],
+    amount: u64,
+    delegate: Option<[u8; 32]>,
+    /// `AccountState` as stored: 0 uninitialized, 1 initialized, 2 frozen.
+    state: u8,
+    /// Rent-exempt reserve of a wrapped-SOL account.
+    is_native: Option<u64>,
+    delegated_amount: u64,
+    close_authority: Option<[u8; 32]>,
+    /// `Account::pack` output.
+    packed: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    mints: Vec<MintStateTestVector>,
+    accounts: Vec<AccountStateTestVector>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+fn bytes(option: COption<Pubkey>) -> Option<[u8; 32]> {
+    Option::from(option).map(|key: Pubkey| key.to_bytes())
+}
+
+fn mint_vector(name: &str, mint: Mint) -> MintStateTestVector {
+    let mut packed = vec![0; Mint::LEN];
+    Mint::pack(mint, &mut packed).unwrap();
+    MintStateTestVector {
+        name: name.to_string(),
+        mint_authority: bytes(mint.mint_authority),
+        supply: mint.supply,
+        decimals: mint.decimals,
+        is_initialized: mint.is_initialized,
+        freeze_authority: bytes(mint.freeze_authority),
+        packed,
+    }
+}
+
+fn account_vector(name: &str, account: Account) -> AccountStateTestVector {
+    let mut packed = vec![0; Account::LEN];
+    Account::pack(account, &mut packed).unwrap();
+    AccountStateTestVector {
+        name: name.to_string(),
+        mint: account.mint.to_bytes(),
+        owner: account.owner.to_bytes(),
+        amount: account.amount,
+        delegate: bytes(account.delegate),
+        state: account.state as u8,
+        is_native: account.is_native.into(),
+        delegated_amount: account.delegated_amount,
+        close_authority: bytes(account.close_authority),
+        packed,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let mint = key(0x11);
+    let owner = key(0x22);
+    let base = Account {
+        mint,
+        owner,
+        amount: 1_000,
+        state: AccountState::Initialized,
+        ..Account::default()
+    };
+
+    Fixture {
+        mints: vec![
+            mint_vector(
+                "with_freeze_authority",
+                Mint {
+                    mint_authority: COption::Some(key(0x01)),
+                    supply: 1_000_000_000,
+                    decimals: 9,
+                    is_initialized: true,
+                    freeze_authority: COption::Some(key(0x02)),
+                },
+            ),
+            mint_vector(
+                "without_freeze_authority",
+                Mint {
+                    mint_authority: COption::Some(key(0x01)),
+                    supply: 42,
+                    decimals: 6,
+                    is_initialized: true,
+                    freeze_authority: COption::None,
+                },
+            ),
+            mint_vector(
+                "fixed_supply",
+                Mint {
+                    mint_authority: COption::None,
+                    supply: u64::MAX,
+                    decimals: 0,
+                    is_initialized: true,
+                    freeze_authority: COption::None,
+                },
+            ),
+        ],
+        accounts: vec![
+            account_vector(
+                "frozen",
+                Account {
+                    state: AccountState::Frozen,
+                    ..base
+                },
+            ),
+            account_vector(
+                "native",
+                Account {
+                    mint: spl_token_interface::native_mint::id(),
+                    amount: 5_000_000,
+                    // What `InitializeAccount` stores for a wrapped-SOL account.
+                    is_native: COption::Some(Rent::default().minimum_balance(Account::LEN)),
+                    ..base
+                },
+            ),
+            account_vector(
+                "delegated",
+                Account {
+                    delegate: COption::Some(key(0x33)),
+                    delegated_amount: 400,
+                    close_authority: COption::Some(key(0x44)),
+                    ..base
+                },
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_state_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    vectors::assert_vectors_match("official_state_vectors.json", &fixture, &official_fixture());
+}
+
+#[test]
+fn packed_bytes_unpack_to_the_recorded_fields() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+
+    for vector in &fixture.mints {
+        assert_eq!(vector.packed.len(), Mint::LEN, "{}", vector.name);
+        let mint = Mint::unpack(&vector.packed).unwrap();
+        assert_eq!(bytes(mint.mint_authority), vector.mint_authority);
+        assert_eq!(mint.supply, vector.supply);
+        assert_eq!(mint.decimals, vector.decimals);
+        assert_eq!(mint.is_initialized, vector.is_initialized);
+        assert_eq!(bytes(mint.freeze_authority), vector.freeze_authority);
+    }
+
+    for vector in &fixture.accounts {
+        assert_eq!(vector.packed.len(), Account::LEN, "{}", vector.name);
+        let account = Account::unpack(&vector.packed).unwrap();
+        assert_eq!(account.mint.to_bytes(), vector.mint);
+        assert_eq!(account.owner.to_bytes(), vector.owner);
+        assert_eq!(account.amount, vector.amount);
+        assert_eq!(bytes(account.delegate), vector.delegate);
+        assert_eq!(account.state as u8, vector.state);
+        assert_eq!(Option::from(account.is_native), vector.is_native);
+        assert_eq!(account.delegated_amount, vector.delegated_amount);
+        assert_eq!(bytes(account.close_authority), vector.close_authority);
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_state_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -2277,7 +2277,7 @@ test "spl-token cpi: initializeMint rebrands callee and preserves rent sysvar or
 
     try std.testing.expectEqual(token_program.key(), ix.program_id);
     try std.testing.expectEqual(@as(usize, 2), ix.accounts.len);
-    try std.testing.expectEqual(@as(usize, 70), ix.data.len);
+    try std.testing.expectEqual(instruction.initialize_mint_spec.data_len, ix.data.len);
     try std.testing.expectEqual(@as(u8, @intFromEnum(instruction.TokenInstruction.initialize_mint)), ix.data[0]);
     try std.testing.expectEqual(@as(u8, 6), ix.data[1]);
     try std.testing.expectEqualSlices(u8, mint.key(), ix.accounts[0].pubkey);
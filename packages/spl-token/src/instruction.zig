@@ -29,7 +29,6 @@
 
 const std = @import("std");
 const sol = @import("solana_program_sdk");
-const codec = @import("solana_codec");
 const id = @import("id.zig");
 const state = @import("state.zig");
 
@@ -207,7 +206,12 @@ pub const Spec = struct {
     data_len: usize,
 };
 
-pub const initialize_mint_spec: Spec = .{ .disc = .initialize_mint, .accounts_len = 2, .data_len = 1 + 1 + 32 + 4 + 32 };
+pub const initialize_mint_none_data_len: usize = 1 + 1 + 32 + 1;
+pub const initialize_mint_spec: Spec = .{
+    .disc = .initialize_mint,
+    .accounts_len = 2,
+    .data_len = initialize_mint_none_data_len + @sizeOf(Pubkey),
+};
 pub const initialize_account_spec: Spec = .{ .disc = .initialize_account, .accounts_len = 4, .data_len = 1 };
 pub const initialize_multisig_spec: Spec = .{ .disc = .initialize_multisig, .accounts_len = 2, .data_len = 1 + 1 };
 pub const transfer_spec: Spec = .{ .disc = .transfer, .accounts_len = 3, .data_len = 1 + 8 };
@@ -232,7 +236,12 @@ pub const initialize_account2_spec: Spec = .{ .disc = .initialize_account2, .acc
 pub const sync_native_spec: Spec = .{ .disc = .sync_native, .accounts_len = 1, .data_len = 1 };
 pub const initialize_account3_spec: Spec = .{ .disc = .initialize_account3, .accounts_len = 2, .data_len = 1 + 32 };
 pub const initialize_multisig2_spec: Spec = .{ .disc = .initialize_multisig2, .accounts_len = 1, .data_len = 1 + 1 };
-pub const initialize_mint2_spec: Spec = .{ .disc = .initialize_mint2, .accounts_len = 1, .data_len = 1 + 1 + 32 + 4 + 32 };
+pub const initialize_mint2_none_data_len: usize = 1 + 1 + 32 + 1;
+pub const initialize_mint2_spec: Spec = .{
+    .disc = .initialize_mint2,
+    .accounts_len = 1,
+    .data_len = initialize_mint2_none_data_len + @sizeOf(Pubkey),
+};
 pub const get_account_data_size_spec: Spec = .{ .disc = .get_account_data_size, .accounts_len = 1, .data_len = 1 };
 pub const initialize_immutable_owner_spec: Spec = .{ .disc = .initialize_immutable_owner, .accounts_len = 1, .data_len = 1 };
 pub const amount_to_ui_amount_spec: Spec = .{ .disc = .amount_to_ui_amount, .accounts_len = 1, .data_len = 1 + 8 };
@@ -299,22 +308,23 @@ pub inline fn isValidSignerIndex(index: usize) bool {
 
 comptime {
     const PUBKEY_LEN: usize = 32;
-    const COPTION_PUBKEY_LEN: usize = 4 + PUBKEY_LEN; // bincode COption<Pubkey>
     const AMOUNT_LEN: usize = 8;
     const DECIMALS_LEN: usize = 1;
     const AUTHORITY_TYPE_LEN: usize = 1;
     const COMPACT_OPTION_TAG_LEN: usize = 1;
+    // `TokenInstruction::pack` option: 1-byte tag + key when present.
+    const OPTION_PUBKEY_LEN: usize = COMPACT_OPTION_TAG_LEN + PUBKEY_LEN;
     const DISC_LEN: usize = 1;
 
     // Each tuple = ( spec , expected accounts , expected payload-byte sum )
     const audits = .{
-        .{ initialize_mint_spec, 2, DECIMALS_LEN + PUBKEY_LEN + COPTION_PUBKEY_LEN },
+        .{ initialize_mint_spec, 2, DECIMALS_LEN + PUBKEY_LEN + OPTION_PUBKEY_LEN },
         .{ initialize_account_spec, 4, 0 },
         .{ initialize_multisig_spec, 2, 1 },
         .{ transfer_spec, 3, AMOUNT_LEN },
         .{ approve_spec, 3, AMOUNT_LEN },
         .{ revoke_spec, 2, 0 },
-        .{ set_authority_spec, 2, AUTHORITY_TYPE_LEN + COMPACT_OPTION_TAG_LEN + PUBKEY_LEN },
+        .{ set_authority_spec, 2, AUTHORITY_TYPE_LEN + OPTION_PUBKEY_LEN },
         .{ freeze_account_spec, 3, 0 },
         .{ thaw_account_spec, 3, 0 },
         .{ transfer_checked_spec, 4, AMOUNT_LEN + DECIMALS_LEN },
@@ -328,7 +338,7 @@ comptime {
         .{ sync_native_spec, 1, 0 },
         .{ initialize_account3_spec, 2, PUBKEY_LEN },
         .{ initialize_multisig2_spec, 1, 1 },
-        .{ initialize_mint2_spec, 1, DECIMALS_LEN + PUBKEY_LEN + COPTION_PUBKEY_LEN },
+        .{ initialize_mint2_spec, 1, DECIMALS_LEN + PUBKEY_LEN + OPTION_PUBKEY_LEN },
         .{ get_account_data_size_spec, 1, 0 },
         .{ initialize_immutable_owner_spec, 1, 0 },
         .{ amount_to_ui_amount_spec, 1, AMOUNT_LEN },
@@ -377,7 +387,7 @@ const InitMintIx = sol.instruction.comptimeInstructionData(
     extern struct {
         decimals: u8,
         mint_authority: Pubkey align(1),
-        freeze_authority_tag: u32 align(1),
+        freeze_authority_tag: u8,
         freeze_authority: Pubkey align(1),
     },
 );
@@ -408,8 +418,17 @@ comptime {
     std.debug.assert(InitAccount3Ix.bytes == initialize_account3_spec.data_len);
 }
 
-fn writeInstructionCOptionPubkey(out: []u8, value: ?*const Pubkey) void {
-    _ = codec.writeCOptionPubkey(out, value) catch unreachable;
+/// Write a `TokenInstruction::pack` optional pubkey (1-byte tag, key only
+/// when present) and return the number of bytes used.
+fn writeOptionalPubkey(out: []u8, value: ?*const Pubkey) usize {
+    std.debug.assert(out.len >= 1 + @sizeOf(Pubkey));
+    if (value) |pubkey| {
+        out[0] = 1;
+        @memcpy(out[1..][0..@sizeOf(Pubkey)], pubkey);
+        return 1 + @sizeOf(Pubkey);
+    }
+    out[0] = 0;
+    return 1;
 }
 
 inline fn validateMultisigSignerCount(signer_pubkeys: []const Pubkey) MultisigInstructionError!void {
@@ -596,13 +615,13 @@ pub fn initializeMint(
     data[0] = @intFromEnum(TokenInstruction.initialize_mint);
     data[1] = decimals;
     @memcpy(data[2..34], mint_authority);
-    writeInstructionCOptionPubkey(data[34..70], freeze_authority);
+    const data_len = 34 + writeOptionalPubkey(data[34..], freeze_authority);
     metas[0] = AccountMeta.writable(mint);
     metas[1] = AccountMeta.readonly(&sol.rent_id);
     return .{
         .program_id = &id.PROGRAM_ID,
         .accounts = metas,
-        .data = data,
+        .data = data[0..data_len],
     };
 }
 
@@ -1461,11 +1480,10 @@ pub fn initializeMultisig2(
 /// Account metas: just the mint account itself (writable). Pass
 /// `null` for `freeze_authority` to leave it disabled.
 ///
-/// Body layout: `[disc(1), decimals(1), mint_auth(32),
-/// freeze_tag(4 LE), freeze_pk(32)]`. When `freeze_authority` is
-/// `null`, `freeze_tag` is zero and the 32 freeze-pubkey bytes are
-/// zero-padding (still part of the on-the-wire payload — `Mint2`
-/// always reads the fixed 67 bytes).
+/// Body layout: `[disc(1), decimals(1), mint_auth(32), freeze_tag(1),
+/// freeze_pk(32)?]`, matching `TokenInstruction::pack`. When
+/// `freeze_authority` is `null`, `freeze_tag` is zero and the
+/// returned data stops there (35 bytes instead of 67).
 pub fn initializeMint2(
     mint: *const Pubkey,
     decimals: u8,
@@ -1477,12 +1495,12 @@ pub fn initializeMint2(
     data[0] = @intFromEnum(TokenInstruction.initialize_mint2);
     data[1] = decimals;
     @memcpy(data[2..34], mint_authority);
-    writeInstructionCOptionPubkey(data[34..70], freeze_authority);
+    const data_len = 34 + writeOptionalPubkey(data[34..], freeze_authority);
     metas[0] = AccountMeta.writable(mint);
     return .{
         .program_id = &id.PROGRAM_ID,
         .accounts = metas,
-        .data = data,
+        .data = data[0..data_len],
     };
 }
 
@@ -1567,6 +1585,179 @@ pub fn uiAmountToAmount(
     };
 }
 
+// =============================================================================
+// Unpack — decode instruction data the way the token program does.
+// =============================================================================
+
+/// Error returned by `unpack`. It is a subset of `TokenErrorSet.ErrorSet`,
+/// so `TokenErrorSet.catchToU64` re-emits it as custom code 12 exactly like
+/// the Rust program.
+pub const UnpackError = error{InvalidInstruction};
+
+/// `InitializeMint` / `InitializeMint2` payload.
+pub const InitializeMintArgs = struct {
+    decimals: u8,
+    mint_authority: Pubkey,
+    freeze_authority: ?Pubkey,
+};
+
+/// `Transfer` / `Approve` / `MintTo` / `Burn` / `AmountToUiAmount` payload.
+pub const AmountArgs = struct {
+    amount: u64,
+};
+
+/// Payload shared by the `*Checked` variants.
+pub const AmountDecimalsArgs = struct {
+    amount: u64,
+    decimals: u8,
+};
+
+/// `InitializeMultisig` / `InitializeMultisig2` payload.
+pub const MultisigArgs = struct {
+    m: u8,
+};
+
+/// `InitializeAccount2` / `InitializeAccount3` payload.
+pub const OwnerArgs = struct {
+    owner: Pubkey,
+};
+
+/// `SetAuthority` payload.
+pub const SetAuthorityArgs = struct {
+    authority_type: AuthorityType,
+    new_authority: ?Pubkey,
+};
+
+/// `UiAmountToAmount` payload. `ui_amount` borrows from the input and is
+/// guaranteed to be valid UTF-8.
+pub const UiAmountArgs = struct {
+    ui_amount: []const u8,
+};
+
+/// Decoded SPL Token instruction. Tag names match `TokenInstruction`;
+/// `batch` is not part of the classic interface and never decodes.
+pub const UnpackedInstruction = union(enum) {
+    initialize_mint: InitializeMintArgs,
+    initialize_account,
+    initialize_multisig: MultisigArgs,
+    transfer: AmountArgs,
+    approve: AmountArgs,
+    revoke,
+    set_authority: SetAuthorityArgs,
+    mint_to: AmountArgs,
+    burn: AmountArgs,
+    close_account,
+    freeze_account,
+    thaw_account,
+    transfer_checked: AmountDecimalsArgs,
+    approve_checked: AmountDecimalsArgs,
+    mint_to_checked: AmountDecimalsArgs,
+    burn_checked: AmountDecimalsArgs,
+    initialize_account2: OwnerArgs,
+    sync_native,
+    initialize_account3: OwnerArgs,
+    initialize_multisig2: MultisigArgs,
+    initialize_mint2: InitializeMintArgs,
+    get_account_data_size,
+    initialize_immutable_owner,
+    amount_to_ui_amount: AmountArgs,
+    ui_amount_to_amount: UiAmountArgs,
+};
+
+/// Decode `input` with the acceptance rules of
+/// `spl_token_interface::instruction::TokenInstruction::unpack`:
+///
+/// - bytes after a complete payload are ignored;
+/// - an optional pubkey is a 1-byte tag (`0` = none, `1` = some) followed by
+///   the key only when present;
+/// - unknown discriminants, short payloads, bad option tags, unknown
+///   authority types, and non-UTF-8 UI amounts are `InvalidInstruction`.
+pub fn unpack(input: []const u8) UnpackError!UnpackedInstruction {
+    const tag = sol.instruction.parseTag(TokenInstruction, input) orelse return error.InvalidInstruction;
+    const rest = input[1..];
+    return switch (tag) {
+        .initialize_mint => .{ .initialize_mint = try unpackInitializeMint(rest) },
+        .initialize_account => .initialize_account,
+        .initialize_multisig => .{ .initialize_multisig = try unpackMultisig(rest) },
+        .transfer => .{ .transfer = try unpackAmount(rest) },
+        .approve => .{ .approve = try unpackAmount(rest) },
+        .revoke => .revoke,
+        .set_authority => .{ .set_authority = try unpackSetAuthority(rest) },
+        .mint_to => .{ .mint_to = try unpackAmount(rest) },
+        .burn => .{ .burn = try unpackAmount(rest) },
+        .close_account => .close_account,
+        .freeze_account => .freeze_account,
+        .thaw_account => .thaw_account,
+        .transfer_checked => .{ .transfer_checked = try unpackAmountDecimals(rest) },
+        .approve_checked => .{ .approve_checked = try unpackAmountDecimals(rest) },
+        .mint_to_checked => .{ .mint_to_checked = try unpackAmountDecimals(rest) },
+        .burn_checked => .{ .burn_checked = try unpackAmountDecimals(rest) },
+        .initialize_account2 => .{ .initialize_account2 = try unpackOwner(rest) },
+        .sync_native => .sync_native,
+        .initialize_account3 => .{ .initialize_account3 = try unpackOwner(rest) },
+        .initialize_multisig2 => .{ .initialize_multisig2 = try unpackMultisig(rest) },
+        .initialize_mint2 => .{ .initialize_mint2 = try unpackInitializeMint(rest) },
+        .get_account_data_size => .get_account_data_size,
+        .initialize_immutable_owner => .initialize_immutable_owner,
+        .amount_to_ui_amount => .{ .amount_to_ui_amount = try unpackAmount(rest) },
+        .ui_amount_to_amount => {
+            if (!std.unicode.utf8ValidateSlice(rest)) return error.InvalidInstruction;
+            return .{ .ui_amount_to_amount = .{ .ui_amount = rest } };
+        },
+        .batch => error.InvalidInstruction,
+    };
+}
+
+fn unpackAmount(rest: []const u8) UnpackError!AmountArgs {
+    const amount = sol.instruction.tryReadUnaligned(u64, rest, 0) orelse return error.InvalidInstruction;
+    return .{ .amount = amount };
+}
+
+fn unpackAmountDecimals(rest: []const u8) UnpackError!AmountDecimalsArgs {
+    const amount = sol.instruction.tryReadUnaligned(u64, rest, 0) orelse return error.InvalidInstruction;
+    const decimals = sol.instruction.tryReadUnaligned(u8, rest, 8) orelse return error.InvalidInstruction;
+    return .{ .amount = amount, .decimals = decimals };
+}
+
+fn unpackMultisig(rest: []const u8) UnpackError!MultisigArgs {
+    if (rest.len < 1) return error.InvalidInstruction;
+    return .{ .m = rest[0] };
+}
+
+fn unpackOwner(rest: []const u8) UnpackError!OwnerArgs {
+    if (rest.len < @sizeOf(Pubkey)) return error.InvalidInstruction;
+    return .{ .owner = rest[0..@sizeOf(Pubkey)].* };
+}
+
+fn unpackInitializeMint(rest: []const u8) UnpackError!InitializeMintArgs {
+    if (rest.len < 1 + @sizeOf(Pubkey)) return error.InvalidInstruction;
+    return .{
+        .decimals = rest[0],
+        .mint_authority = rest[1..][0..@sizeOf(Pubkey)].*,
+        .freeze_authority = try unpackOptionalPubkey(rest[1 + @sizeOf(Pubkey) ..]),
+    };
+}
+
+fn unpackSetAuthority(rest: []const u8) UnpackError!SetAuthorityArgs {
+    const authority_type = sol.instruction.parseTag(AuthorityType, rest) orelse return error.InvalidInstruction;
+    return .{
+        .authority_type = authority_type,
+        .new_authority = try unpackOptionalPubkey(rest[1..]),
+    };
+}
+
+fn unpackOptionalPubkey(rest: []const u8) UnpackError!?Pubkey {
+    if (rest.len < 1) return error.InvalidInstruction;
+    return switch (rest[0]) {
+        0 => null,
+        1 => if (rest.len < 1 + @sizeOf(Pubkey))
+            error.InvalidInstruction
+        else
+            rest[1..][0..@sizeOf(Pubkey)].*,
+        else => error.InvalidInstruction,
+    };
+}
+
 // =============================================================================
 // Tests — byte-level fidelity vs. canonical Rust encoding.
 // =============================================================================
@@ -1612,6 +1803,153 @@ fn signerPubkeys(comptime count: usize, start: u8) [count]Pubkey {
     return keys;
 }
 
+fn decodedAmount(decoded: UnpackedInstruction) ?u64 {
+    return switch (decoded) {
+        .transfer, .approve, .mint_to, .burn, .amount_to_ui_amount => |args| args.amount,
+        .transfer_checked, .approve_checked, .mint_to_checked, .burn_checked => |args| args.amount,
+        else => null,
+    };
+}
+
+fn decodedDecimals(decoded: UnpackedInstruction) ?u8 {
+    return switch (decoded) {
+        .transfer_checked, .approve_checked, .mint_to_checked, .burn_checked => |args| args.decimals,
+        .initialize_mint, .initialize_mint2 => |args| args.decimals,
+        else => null,
+    };
+}
+
+test "unpack matches official Rust TokenInstruction::unpack outcomes" {
+    const decode_fixture = @import("instruction_decode_fixture.zig");
+    var parsed = try decode_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    try std.testing.expectEqual(@as(usize, 17), parsed.value.cases.len);
+
+    for (parsed.value.cases) |case| {
+        if (case.expected_error) |expected_error| {
+            try std.testing.expectEqualStrings("InvalidInstruction", expected_error);
+            try std.testing.expectError(error.InvalidInstruction, unpack(case.data));
+            continue;
+        }
+
+        const decoded = try unpack(case.data);
+        try std.testing.expectEqualStrings(case.expected_variant.?, @tagName(decoded));
+        try std.testing.expectEqual(case.amount, decodedAmount(decoded));
+        try std.testing.expectEqual(case.decimals, decodedDecimals(decoded));
+    }
+}
+
+test "happy-path decode cases skip every error vector" {
+    const decode_fixture = @import("instruction_decode_fixture.zig");
+    var parsed = try decode_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+
+    const happy = try decode_fixture.casesWithTags(std.testing.allocator, parsed.value.cases, .{ .exclude = &.{.@"error"} });
+    defer std.testing.allocator.free(happy);
+    try std.testing.expectEqual(@as(usize, 7), happy.len);
+    for (happy) |case| _ = try unpack(case.data);
+
+    const boundaries = try decode_fixture.casesWithTags(std.testing.allocator, parsed.value.cases, .{ .include = &.{.boundary} });
+    defer std.testing.allocator.free(boundaries);
+    try std.testing.expectEqual(@as(usize, 5), boundaries.len);
+    for (boundaries) |case| {
+        if (case.expected_error == null) {
+            try std.testing.expectEqualStrings("transfer-trailing-byte", case.label);
+        } else {
+            try std.testing.expectError(error.InvalidInstruction, unpack(case.data));
+        }
+    }
+}
+
+test "unpack decodes optional pubkeys and UI amounts" {
+    const authority: Pubkey = .{0x21} ** 32;
+    const freeze_authority: Pubkey = .{0x32} ** 32;
+
+    const mint_some = [_]u8{ 0, 9 } ++ authority ++ [_]u8{1} ++ freeze_authority;
+    const mint = (try unpack(&mint_some)).initialize_mint;
+    try std.testing.expectEqualSlices(u8, &authority, &mint.mint_authority);
+    try std.testing.expectEqualSlices(u8, &freeze_authority, &mint.freeze_authority.?);
+
+    const mint_none = [_]u8{ 20, 6 } ++ authority ++ [_]u8{0};
+    try std.testing.expectEqual(@as(?Pubkey, null), (try unpack(&mint_none)).initialize_mint2.freeze_authority);
+
+    const set_none = [_]u8{ 6, @intFromEnum(AuthorityType.FreezeAccount), 0 };
+    const set = (try unpack(&set_none)).set_authority;
+    try std.testing.expectEqual(AuthorityType.FreezeAccount, set.authority_type);
+    try std.testing.expectEqual(@as(?Pubkey, null), set.new_authority);
+
+    try std.testing.expectEqualStrings("2.5", (try unpack("\x182.5")).ui_amount_to_amount.ui_amount);
+    try std.testing.expectEqual(@as(u64, 12), @import("error.zig").Error.catchToU64(error.InvalidInstruction));
+}
+
+const RoundTrip = struct {
+    seen: std.EnumSet(TokenInstruction) = std.EnumSet(TokenInstruction).initEmpty(),
+
+    fn expect(self: *RoundTrip, ix: Instruction, expected: UnpackedInstruction) !void {
+        try std.testing.expectEqualDeep(expected, try unpack(ix.data));
+        self.seen.insert(std.meta.stringToEnum(TokenInstruction, @tagName(expected)).?);
+    }
+};
+
+test "every builder's data round-trips through unpack" {
+    const a: Pubkey = .{0x41} ** 32;
+    const b: Pubkey = .{0x42} ** 32;
+    const c: Pubkey = .{0x43} ** 32;
+    const d: Pubkey = .{0x44} ** 32;
+    const signers = [_]Pubkey{ .{0x45} ** 32, .{0x46} ** 32 };
+    const amount: u64 = 0x0102_0304_0506_0708;
+    var metas: [4 + MAX_SIGNERS]AccountMeta = undefined;
+    var data: [initialize_mint_spec.data_len]u8 = undefined;
+    var rt: RoundTrip = .{};
+
+    try rt.expect(initializeMint(&a, 9, &b, &c, metas[0..2], data[0..67]), .{ .initialize_mint = .{ .decimals = 9, .mint_authority = b, .freeze_authority = c } });
+    try rt.expect(initializeMint(&a, 0, &b, null, metas[0..2], data[0..67]), .{ .initialize_mint = .{ .decimals = 0, .mint_authority = b, .freeze_authority = null } });
+    try rt.expect(initializeAccount(&a, &b, &c, metas[0..4], data[0..1]), .initialize_account);
+    try rt.expect(try initializeMultisig(&a, &signers, 2, metas[0 .. 2 + MAX_SIGNERS], data[0..2]), .{ .initialize_multisig = .{ .m = 2 } });
+    try rt.expect(transfer(&a, &b, &c, amount, metas[0..3], data[0..9]), .{ .transfer = .{ .amount = amount } });
+    try rt.expect(try transferMultisig(&a, &b, &c, &signers, amount, metas[0 .. 3 + MAX_SIGNERS], data[0..9]), .{ .transfer = .{ .amount = amount } });
+    try rt.expect(approve(&a, &b, &c, amount, metas[0..3], data[0..9]), .{ .approve = .{ .amount = amount } });
+    try rt.expect(try approveMultisig(&a, &b, &c, &signers, amount, metas[0 .. 3 + MAX_SIGNERS], data[0..9]), .{ .approve = .{ .amount = amount } });
+    try rt.expect(revoke(&a, &b, metas[0..2], data[0..1]), .revoke);
+    try rt.expect(try revokeMultisig(&a, &b, &signers, metas[0 .. 2 + MAX_SIGNERS], data[0..1]), .revoke);
+    try rt.expect(setAuthority(&a, &b, .CloseAccount, &c, metas[0..2], data[0..35]), .{ .set_authority = .{ .authority_type = .CloseAccount, .new_authority = c } });
+    try rt.expect(setAuthority(&a, &b, .MintTokens, null, metas[0..2], data[0..35]), .{ .set_authority = .{ .authority_type = .MintTokens, .new_authority = null } });
+    try rt.expect(try setAuthorityMultisig(&a, &b, &signers, .AccountOwner, &c, metas[0 .. 2 + MAX_SIGNERS], data[0..35]), .{ .set_authority = .{ .authority_type = .AccountOwner, .new_authority = c } });
+    try rt.expect(mintTo(&a, &b, &c, amount, metas[0..3], data[0..9]), .{ .mint_to = .{ .amount = amount } });
+    try rt.expect(try mintToMultisig(&a, &b, &c, &signers, amount, metas[0 .. 3 + MAX_SIGNERS], data[0..9]), .{ .mint_to = .{ .amount = amount } });
+    try rt.expect(burn(&a, &b, &c, amount, metas[0..3], data[0..9]), .{ .burn = .{ .amount = amount } });
+    try rt.expect(try burnMultisig(&a, &b, &c, &signers, amount, metas[0 .. 3 + MAX_SIGNERS], data[0..9]), .{ .burn = .{ .amount = amount } });
+    try rt.expect(closeAccount(&a, &b, &c, metas[0..3], data[0..1]), .close_account);
+    try rt.expect(try closeAccountMultisig(&a, &b, &c, &signers, metas[0 .. 3 + MAX_SIGNERS], data[0..1]), .close_account);
+    try rt.expect(freezeAccount(&a, &b, &c, metas[0..3], data[0..1]), .freeze_account);
+    try rt.expect(try freezeAccountMultisig(&a, &b, &c, &signers, metas[0 .. 3 + MAX_SIGNERS], data[0..1]), .freeze_account);
+    try rt.expect(thawAccount(&a, &b, &c, metas[0..3], data[0..1]), .thaw_account);
+    try rt.expect(try thawAccountMultisig(&a, &b, &c, &signers, metas[0 .. 3 + MAX_SIGNERS], data[0..1]), .thaw_account);
+    try rt.expect(transferChecked(&a, &b, &c, &d, amount, 6, metas[0..4], data[0..10]), .{ .transfer_checked = .{ .amount = amount, .decimals = 6 } });
+    try rt.expect(try transferCheckedMultisig(&a, &b, &c, &d, &signers, amount, 6, metas[0 .. 4 + MAX_SIGNERS], data[0..10]), .{ .transfer_checked = .{ .amount = amount, .decimals = 6 } });
+    try rt.expect(approveChecked(&a, &b, &c, &d, amount, 6, metas[0..4], data[0..10]), .{ .approve_checked = .{ .amount = amount, .decimals = 6 } });
+    try rt.expect(try approveCheckedMultisig(&a, &b, &c, &d, &signers, amount, 6, metas[0 .. 4 + MAX_SIGNERS], data[0..10]), .{ .approve_checked = .{ .amount = amount, .decimals = 6 } });
+    try rt.expect(mintToChecked(&a, &b, &c, amount, 6, metas[0..3], data[0..10]), .{ .mint_to_checked = .{ .amount = amount, .decimals = 6 } });
+    try rt.expect(try mintToCheckedMultisig(&a, &b, &c, &signers, amount, 6, metas[0 .. 3 + MAX_SIGNERS], data[0..10]), .{ .mint_to_checked = .{ .amount = amount, .decimals = 6 } });
+    try rt.expect(burnChecked(&a, &b, &c, amount, 6, metas[0..3], data[0..10]), .{ .burn_checked = .{ .amount = amount, .decimals = 6 } });
+    try rt.expect(try burnCheckedMultisig(&a, &b, &c, &signers, amount, 6, metas[0 .. 3 + MAX_SIGNERS], data[0..10]), .{ .burn_checked = .{ .amount = amount, .decimals = 6 } });
+    try rt.expect(initializeAccount2(&a, &b, &c, metas[0..3], data[0..33]), .{ .initialize_account2 = .{ .owner = c } });
+    try rt.expect(syncNative(&a, metas[0..1], data[0..1]), .sync_native);
+    try rt.expect(initializeAccount3(&a, &b, &c, metas[0..2], data[0..33]), .{ .initialize_account3 = .{ .owner = c } });
+    try rt.expect(try initializeMultisig2(&a, &signers, 1, metas[0 .. 1 + MAX_SIGNERS], data[0..2]), .{ .initialize_multisig2 = .{ .m = 1 } });
+    try rt.expect(initializeMint2(&a, 9, &b, &c, metas[0..1], data[0..67]), .{ .initialize_mint2 = .{ .decimals = 9, .mint_authority = b, .freeze_authority = c } });
+    try rt.expect(initializeMint2(&a, 0, &b, null, metas[0..1], data[0..67]), .{ .initialize_mint2 = .{ .decimals = 0, .mint_authority = b, .freeze_authority = null } });
+    try rt.expect(getAccountDataSize(&a, metas[0..1], data[0..1]), .get_account_data_size);
+    try rt.expect(initializeImmutableOwner(&a, metas[0..1], data[0..1]), .initialize_immutable_owner);
+    try rt.expect(amountToUiAmount(&a, amount, metas[0..1], data[0..9]), .{ .amount_to_ui_amount = .{ .amount = amount } });
+    try rt.expect(try uiAmountToAmount(&a, "1.25", metas[0..1], &data), .{ .ui_amount_to_amount = .{ .ui_amount = "1.25" } });
+
+    // Every opcode except the p-token `Batch` envelope has been exercised.
+    var expected_seen = std.EnumSet(TokenInstruction).initFull();
+    expected_seen.remove(.batch);
+    try std.testing.expect(rt.seen.eql(expected_seen));
+}
+
 test "v0.3 authority/freeze/native specs and discriminants stay canonical" {
     try std.testing.expectEqual(@as(u8, 4), @intFromEnum(TokenInstruction.approve));
     try std.testing.expectEqual(@as(u8, 5), @intFromEnum(TokenInstruction.revoke));
@@ -1884,18 +2222,19 @@ test "initializeMint: Some-vs-None freeze authority encoding and rent sysvar met
     var metas: [2]AccountMeta = undefined;
     var data: dataArray(initialize_mint_spec) = undefined;
 
-    _ = initializeMint(&m, 9, &auth, &fa, &metas, &data);
+    const some_ix = initializeMint(&m, 9, &auth, &fa, &metas, &data);
+    try std.testing.expectEqual(initialize_mint_spec.data_len, some_ix.data.len);
     try std.testing.expectEqual(@as(u8, 0), data[0]);
     try std.testing.expectEqual(@as(u8, 9), data[1]);
     try std.testing.expectEqualSlices(u8, &auth, data[2..34]);
-    try std.testing.expectEqual(state.COPTION_SOME, std.mem.readInt(u32, data[34..38], .little));
-    try std.testing.expectEqualSlices(u8, &fa, data[38..70]);
+    try std.testing.expectEqual(@as(u8, 1), data[34]);
+    try std.testing.expectEqualSlices(u8, &fa, data[35..67]);
     try expectMeta(metas[0], &m, 1, 0);
     try expectMeta(metas[1], &sol.rent_id, 0, 0);
 
-    _ = initializeMint(&m, 0, &auth, null, &metas, &data);
-    try std.testing.expectEqual(state.COPTION_NONE, std.mem.readInt(u32, data[34..38], .little));
-    for (data[38..70]) |b| try std.testing.expectEqual(@as(u8, 0), b);
+    const none_ix = initializeMint(&m, 0, &auth, null, &metas, &data);
+    try std.testing.expectEqual(initialize_mint_none_data_len, none_ix.data.len);
+    try std.testing.expectEqual(@as(u8, 0), none_ix.data[34]);
 }
 
 test "initializeMint2: Some-vs-None freeze authority encoding" {
@@ -1905,15 +2244,16 @@ test "initializeMint2: Some-vs-None freeze authority encoding" {
     var metas: [1]AccountMeta = undefined;
     var data: dataArray(initialize_mint2_spec) = undefined;
 
-    _ = initializeMint2(&m, 9, &auth, &fa, &metas, &data);
+    const some_ix = initializeMint2(&m, 9, &auth, &fa, &metas, &data);
+    try std.testing.expectEqual(initialize_mint2_spec.data_len, some_ix.data.len);
     try std.testing.expectEqual(@as(u8, 20), data[0]);
     try std.testing.expectEqual(@as(u8, 9), data[1]);
-    try std.testing.expectEqual(state.COPTION_SOME, std.mem.readInt(u32, data[34..38], .little));
-    try std.testing.expectEqualSlices(u8, &fa, data[38..70]);
+    try std.testing.expectEqual(@as(u8, 1), data[34]);
+    try std.testing.expectEqualSlices(u8, &fa, data[35..67]);
 
-    _ = initializeMint2(&m, 0, &auth, null, &metas, &data);
-    try std.testing.expectEqual(state.COPTION_NONE, std.mem.readInt(u32, data[34..38], .little));
-    for (data[38..70]) |b| try std.testing.expectEqual(@as(u8, 0), b);
+    const none_ix = initializeMint2(&m, 0, &auth, null, &metas, &data);
+    try std.testing.expectEqual(initialize_mint2_none_data_len, none_ix.data.len);
+    try std.testing.expectEqual(@as(u8, 0), none_ix.data[34]);
 }
 
 test "syncNative: single writable account and 1-byte body" {
@@ -0,0 +1,35 @@
+const std = @import("std");
+const vector_filter = @import("vector_filter");
+
+pub const DecodeCase = struct {
+    label: []const u8,
+    data: []const u8,
+    expected_variant: ?[]const u8,
+    expected_error: ?[]const u8,
+    amount: ?u64,
+    decimals: ?u8,
+    tags: []const vector_filter.Tag = &.{},
+};
+
+pub const Fixture = struct {
+    cases: []const DecodeCase,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("token_instruction_decode_vectors.json"),
+        .{},
+    );
+}
+
+/// The cases whose tags pass `filter`, in order. The caller owns the
+/// returned slice; the cases still borrow from the parsed fixture.
+pub fn casesWithTags(
+    allocator: std.mem.Allocator,
+    cases: []const DecodeCase,
+    filter: vector_filter.TagFilter,
+) ![]DecodeCase {
+    return vector_filter.withTags(DecodeCase, allocator, cases, filter);
+}
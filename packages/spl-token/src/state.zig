@@ -444,6 +444,38 @@ test "account: state + isNative round-trip" {
     try std.testing.expectError(error.InvalidAccountData, invalid_acc.nativeRentExemptReserveChecked());
 }
 
+test "state views decode official Mint::pack / Account::pack bytes" {
+    const state_fixture = @import("state_fixture.zig");
+    var parsed = try state_fixture.load(std.testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    try std.testing.expectEqual(@as(usize, 3), fixture.mints.len);
+    try std.testing.expectEqual(@as(usize, 3), fixture.accounts.len);
+
+    for (fixture.mints) |vector| {
+        const mint = try Mint.fromBytes(vector.packed);
+        try std.testing.expectEqual(vector.mint_authority, try mint.mintAuthorityChecked());
+        try std.testing.expectEqual(vector.supply, mint.supply);
+        try std.testing.expectEqual(vector.decimals, mint.decimals);
+        try std.testing.expectEqual(vector.is_initialized, mint.isInitialized());
+        try std.testing.expectEqual(vector.freeze_authority, try mint.freezeAuthorityChecked());
+    }
+
+    for (fixture.accounts) |vector| {
+        const acc = try Account.fromBytes(vector.packed);
+        try std.testing.expectEqualSlices(u8, &vector.mint, unpackAccountMintUnchecked(vector.packed));
+        try std.testing.expectEqualSlices(u8, &vector.owner, unpackAccountOwnerUnchecked(vector.packed));
+        try std.testing.expectEqual(vector.amount, acc.amount);
+        try std.testing.expectEqual(vector.delegate, try acc.delegateKeyChecked());
+        try std.testing.expectEqual(vector.state, @intFromEnum(acc.accountState()));
+        try std.testing.expectEqual(vector.state == @intFromEnum(AccountState.frozen), acc.isFrozen());
+        try std.testing.expectEqual(vector.is_native, try acc.nativeRentExemptReserveChecked());
+        try std.testing.expectEqual(vector.is_native != null, acc.isNative());
+        try std.testing.expectEqual(vector.delegated_amount, acc.delegated_amount);
+        try std.testing.expectEqual(vector.close_authority, try acc.closeAuthorityChecked());
+    }
+}
+
 test "account: fast-path pubkey helpers match canonical offsets" {
     var buf: [ACCOUNT_LEN]u8 = [_]u8{0} ** ACCOUNT_LEN;
     @memset(buf[ACCOUNT_MINT_OFFSET .. ACCOUNT_MINT_OFFSET + sol.PUBKEY_BYTES], 0x44);
@@ -502,3 +534,10 @@ test "multisig: threshold, signer count, initialized flag, and signer pubkeys ro
     try std.testing.expectEqualSlices(u8, &signer1, &signers[1]);
     try std.testing.expectEqualSlices(u8, &signer2, &signers[2]);
 }
+
+test "MINT_LEN and ACCOUNT_LEN match the official spl-token layouts" {
+    var parsed = try @import("rent_vectors").loadStandardAccountMinimums(std.testing.allocator);
+    defer parsed.deinit();
+    try std.testing.expectEqual(MINT_LEN, parsed.value.get("token_mint").?.data_len);
+    try std.testing.expectEqual(ACCOUNT_LEN, parsed.value.get("token_account").?.data_len);
+}
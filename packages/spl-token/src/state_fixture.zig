@@ -0,0 +1,38 @@
+const std = @import("std");
+
+pub const MintStateVector = struct {
+    name: []const u8,
+    mint_authority: ?[32]u8,
+    supply: u64,
+    decimals: u8,
+    is_initialized: bool,
+    freeze_authority: ?[32]u8,
+    packed: []const u8,
+};
+
+pub const AccountStateVector = struct {
+    name: []const u8,
+    mint: [32]u8,
+    owner: [32]u8,
+    amount: u64,
+    delegate: ?[32]u8,
+    state: u8,
+    is_native: ?u64,
+    delegated_amount: u64,
+    close_authority: ?[32]u8,
+    packed: []const u8,
+};
+
+pub const Fixture = struct {
+    mints: []const MintStateVector,
+    accounts: []const AccountStateVector,
+};
+
+pub fn load(allocator: std.mem.Allocator) !std.json.Parsed(Fixture) {
+    return std.json.parseFromSlice(
+        Fixture,
+        allocator,
+        @embedFile("official_state_vectors.json"),
+        .{},
+    );
+}
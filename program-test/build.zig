@@ -18,6 +18,9 @@ pub fn build(b: *std.Build) !void {
         .{ "example_escrow", "../examples/escrow.zig" },
         .{ "example_mock_router", "../examples/mock_router.zig" },
         .{ "example_mock_adapter", "../examples/mock_adapter.zig" },
+        .{ "example_program_id_check", "../examples/program_id_check.zig" },
+        .{ "example_duplicate_aliasing", "../examples/duplicate_aliasing.zig" },
+        .{ "example_slot_hashes", "../examples/slot_hashes.zig" },
     };
 
     inline for (programs) |p| {
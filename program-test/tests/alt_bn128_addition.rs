@@ -0,0 +1,204 @@
+//! `solana_bn254::prelude::alt_bn128_g1_addition_be` and
+//! `alt_bn128_g1_addition_le`.
+//!
+//! Records `src/crypto/alt_bn128_addition_vectors.json`: real G1 points
+//! built from the generator `(1, 2)` — doubling, a general addition, adding
+//! the identity and adding a point to its negation — plus an input that is
+//! not on the curve. Failures record the `u64` code the syscall returns. No
+//! Zig artifacts are needed.
+
+mod common;
+
+use {
+    serde::{Deserialize, Serialize},
+    solana_bn254::{
+        compression::prelude::convert_endianness,
+        prelude::{
+            alt_bn128_g1_addition_be, alt_bn128_g1_addition_le, ALT_BN128_FIELD_SIZE,
+            ALT_BN128_G1_ADDITION_INPUT_SIZE, ALT_BN128_G1_POINT_SIZE,
+        },
+    },
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/crypto/alt_bn128_addition_vectors.json");
+
+/// The BN254 base field modulus, big-endian.
+const FIELD_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c, 0xfd, 0x47,
+];
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AltBn128AdditionTestVector {
+    name: String,
+    /// `a || b`, each point `x || y` as 32-byte big-endian field elements.
+    input_be: Vec<u8>,
+    /// The same points with every field element little-endian.
+    input_le: Vec<u8>,
+    output_be: Option<Vec<u8>>,
+    output_le: Option<Vec<u8>>,
+    /// `u64::from(AltBn128Error)` when both variants reject the input.
+    expected_error: Option<u64>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    field_modulus: [u8; 32],
+    vectors: Vec<AltBn128AdditionTestVector>,
+}
+
+type G1 = [u8; ALT_BN128_G1_POINT_SIZE];
+
+fn point(x: &[u8; 32], y: &[u8; 32]) -> G1 {
+    let mut point = [0; ALT_BN128_G1_POINT_SIZE];
+    point[..ALT_BN128_FIELD_SIZE].copy_from_slice(x);
+    point[ALT_BN128_FIELD_SIZE..].copy_from_slice(y);
+    point
+}
+
+fn field_element(value: u64) -> [u8; 32] {
+    let mut bytes = [0; 32];
+    bytes[24..].copy_from_slice(&value.to_be_bytes());
+    bytes
+}
+
+/// `-P = (x, p - y)`.
+fn negate(point: &G1) -> G1 {
+    let mut negated = *point;
+    let mut borrow = 0u16;
+    for (byte, modulus) in negated[ALT_BN128_FIELD_SIZE..]
+        .iter_mut()
+        .zip(FIELD_MODULUS)
+        .rev()
+    {
+        let difference = u16::from(modulus).wrapping_sub(u16::from(*byte) + borrow);
+        *byte = difference as u8;
+        borrow = difference >> 15;
+    }
+    negated
+}
+
+fn generator() -> G1 {
+    point(&field_element(1), &field_element(2))
+}
+
+fn add_be(a: &G1, b: &G1) -> G1 {
+    let input = [a.as_slice(), b.as_slice()].concat();
+    alt_bn128_g1_addition_be(&input)
+        .unwrap()
+        .try_into()
+        .unwrap()
+}
+
+fn vector(name: &str, a: G1, b: G1) -> AltBn128AdditionTestVector {
+    let input_be = [a, b].concat();
+    let input_le = convert_endianness::<32, ALT_BN128_G1_ADDITION_INPUT_SIZE>(
+        input_be.as_slice().try_into().unwrap(),
+    );
+    let (output_be, output_le, expected_error) = match (
+        alt_bn128_g1_addition_be(&input_be),
+        alt_bn128_g1_addition_le(&input_le),
+    ) {
+        (Ok(be), Ok(le)) => (Some(be), Some(le), None),
+        (Err(be), Err(le)) => {
+            assert_eq!(be, le);
+            (None, None, Some(u64::from(be)))
+        }
+        (be, le) => panic!("{name}: endianness variants disagree: {be:?} vs {le:?}"),
+    };
+    AltBn128AdditionTestVector {
+        name: name.to_string(),
+        input_be,
+        input_le: input_le.to_vec(),
+        output_be,
+        output_le,
+        expected_error,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let identity = [0; ALT_BN128_G1_POINT_SIZE];
+    let g = generator();
+    let two_g = add_be(&g, &g);
+    Fixture {
+        field_modulus: FIELD_MODULUS,
+        vectors: vec![
+            vector("generator_plus_generator", g, g),
+            vector("generator_plus_two_generator", g, two_g),
+            vector("point_plus_identity", two_g, identity),
+            vector("identity_plus_point", identity, two_g),
+            vector("identity_plus_identity", identity, identity),
+            vector("generator_plus_negated_generator", g, negate(&g)),
+            vector("point_plus_negated_point", two_g, negate(&two_g)),
+            vector(
+                "not_on_curve",
+                point(&field_element(1), &field_element(3)),
+                g,
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_alt_bn128_addition_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    common::assert_vectors_match(
+        "alt_bn128_addition_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn outputs_agree_across_endianness_and_identities_hold() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let find = |name: &str| {
+        fixture
+            .vectors
+            .iter()
+            .find(|vector| vector.name == name)
+            .unwrap()
+    };
+    for vector in &fixture.vectors {
+        assert_eq!(vector.input_be.len(), ALT_BN128_G1_ADDITION_INPUT_SIZE);
+        match (&vector.output_be, &vector.output_le) {
+            (Some(be), Some(le)) => {
+                assert!(vector.expected_error.is_none());
+                let be: &G1 = be.as_slice().try_into().unwrap();
+                assert_eq!(convert_endianness::<32, 64>(be).as_slice(), le);
+            }
+            (None, None) => assert!(vector.expected_error.is_some()),
+            _ => panic!("{}: partial outputs", vector.name),
+        }
+    }
+
+    let point = &find("point_plus_identity").input_be[..64];
+    assert_eq!(
+        find("point_plus_identity").output_be.as_deref(),
+        Some(point)
+    );
+    assert_eq!(
+        find("identity_plus_point").output_be.as_deref(),
+        Some(point)
+    );
+    assert_eq!(
+        find("generator_plus_generator").output_be.as_deref(),
+        Some(point)
+    );
+    for name in [
+        "identity_plus_identity",
+        "generator_plus_negated_generator",
+        "point_plus_negated_point",
+    ] {
+        assert_eq!(find(name).output_be, Some(vec![0; 64]), "{name}");
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_alt_bn128_addition_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,189 @@
+//! `solana_bn254::prelude::alt_bn128_g1_multiplication_be` and
+//! `alt_bn128_g1_multiplication_le`.
+//!
+//! Records `src/crypto/alt_bn128_multiplication_vectors.json`: the generator
+//! `(1, 2)` times the scalars 0, 1 and 2, a scalar just below the base field
+//! modulus, the group order and scalars beyond it, and a point off the curve
+//! that both variants reject. The syscall does not reduce the scalar, so
+//! anything past the order wraps around the group. No Zig artifacts are
+//! needed.
+
+mod common;
+
+use {
+    serde::{Deserialize, Serialize},
+    solana_bn254::{
+        compression::prelude::convert_endianness,
+        prelude::{
+            alt_bn128_g1_addition_be, alt_bn128_g1_multiplication_be,
+            alt_bn128_g1_multiplication_le, ALT_BN128_FIELD_SIZE,
+            ALT_BN128_G1_MULTIPLICATION_INPUT_SIZE, ALT_BN128_G1_POINT_SIZE,
+        },
+    },
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/crypto/alt_bn128_multiplication_vectors.json");
+
+/// The BN254 base field modulus `p`, big-endian.
+const FIELD_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c, 0xfd, 0x47,
+];
+
+/// The order `r` of G1, big-endian.
+const GROUP_ORDER: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AltBn128MultiplicationTestVector {
+    name: String,
+    /// `point || scalar`, every 32-byte field element big-endian.
+    input_be: Vec<u8>,
+    /// The same input with every 32-byte field element little-endian.
+    input_le: Vec<u8>,
+    output_be: Option<Vec<u8>>,
+    output_le: Option<Vec<u8>>,
+    /// `u64::from(AltBn128Error)` when both variants reject the input.
+    expected_error: Option<u64>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    field_modulus: [u8; 32],
+    group_order: [u8; 32],
+    vectors: Vec<AltBn128MultiplicationTestVector>,
+}
+
+type G1 = [u8; ALT_BN128_G1_POINT_SIZE];
+
+fn field_element(value: u64) -> [u8; 32] {
+    let mut bytes = [0; 32];
+    bytes[24..].copy_from_slice(&value.to_be_bytes());
+    bytes
+}
+
+fn generator() -> G1 {
+    let mut point = [0; ALT_BN128_G1_POINT_SIZE];
+    point[..ALT_BN128_FIELD_SIZE].copy_from_slice(&field_element(1));
+    point[ALT_BN128_FIELD_SIZE..].copy_from_slice(&field_element(2));
+    point
+}
+
+fn vector(name: &str, point: G1, scalar: [u8; 32]) -> AltBn128MultiplicationTestVector {
+    let input_be = [point.as_slice(), scalar.as_slice()].concat();
+    let input_le = convert_endianness::<32, ALT_BN128_G1_MULTIPLICATION_INPUT_SIZE>(
+        input_be.as_slice().try_into().unwrap(),
+    );
+    let (output_be, output_le, expected_error) = match (
+        alt_bn128_g1_multiplication_be(&input_be),
+        alt_bn128_g1_multiplication_le(&input_le),
+    ) {
+        (Ok(be), Ok(le)) => (Some(be), Some(le), None),
+        (Err(be), Err(le)) => {
+            assert_eq!(be, le);
+            (None, None, Some(u64::from(be)))
+        }
+        (be, le) => panic!("{name}: endianness variants disagree: {be:?} vs {le:?}"),
+    };
+    AltBn128MultiplicationTestVector {
+        name: name.to_string(),
+        input_be,
+        input_le: input_le.to_vec(),
+        output_be,
+        output_le,
+        expected_error,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let g = generator();
+    let mut field_modulus_minus_one = FIELD_MODULUS;
+    field_modulus_minus_one[31] -= 1;
+    let mut group_order_plus_two = GROUP_ORDER;
+    group_order_plus_two[31] += 2;
+    // `(1, 3)`: 27 != 1 + 3.
+    let mut not_on_curve = g;
+    not_on_curve[ALT_BN128_G1_POINT_SIZE - 1] = 3;
+    Fixture {
+        field_modulus: FIELD_MODULUS,
+        group_order: GROUP_ORDER,
+        vectors: vec![
+            vector("scalar_zero", g, field_element(0)),
+            vector("scalar_one", g, field_element(1)),
+            vector("scalar_two", g, field_element(2)),
+            vector("scalar_field_modulus_minus_one", g, field_modulus_minus_one),
+            vector("scalar_group_order", g, GROUP_ORDER),
+            vector("scalar_group_order_plus_two", g, group_order_plus_two),
+            vector("scalar_max", g, [0xff; 32]),
+            vector(
+                "identity_times_scalar_two",
+                [0; ALT_BN128_G1_POINT_SIZE],
+                field_element(2),
+            ),
+            vector("not_on_curve", not_on_curve, field_element(2)),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_alt_bn128_multiplication_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    common::assert_vectors_match(
+        "alt_bn128_multiplication_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn scalars_act_modulo_the_group_order() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let output = |name: &str| {
+        fixture
+            .vectors
+            .iter()
+            .find(|vector| vector.name == name)
+            .unwrap()
+            .output_be
+            .clone()
+            .unwrap()
+    };
+    for vector in &fixture.vectors {
+        assert_eq!(
+            vector.input_be.len(),
+            ALT_BN128_G1_MULTIPLICATION_INPUT_SIZE
+        );
+        let Some(be) = vector.output_be.as_deref() else {
+            assert!(vector.expected_error.is_some(), "{}", vector.name);
+            continue;
+        };
+        let be: &G1 = be.try_into().unwrap();
+        assert_eq!(
+            convert_endianness::<32, 64>(be).as_slice(),
+            vector.output_le.as_deref().unwrap(),
+            "{}",
+            vector.name
+        );
+    }
+
+    let g = generator();
+    let doubled = alt_bn128_g1_addition_be(&[g, g].concat()).unwrap();
+    let identity = vec![0; ALT_BN128_G1_POINT_SIZE];
+    assert_eq!(output("scalar_zero"), identity);
+    assert_eq!(output("scalar_one"), g);
+    assert_eq!(output("scalar_two"), doubled);
+    assert_eq!(output("scalar_group_order"), identity);
+    assert_eq!(output("scalar_group_order_plus_two"), doubled);
+    assert_eq!(output("identity_times_scalar_two"), identity);
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_alt_bn128_multiplication_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
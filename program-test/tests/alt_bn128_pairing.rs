@@ -0,0 +1,224 @@
+//! `solana_bn254::prelude::alt_bn128_pairing_be` and `alt_bn128_pairing_le`.
+//!
+//! Records `src/crypto/alt_bn128_pairing_vectors.json`: multi-pairing checks
+//! over the G1 generator `(1, 2)` and the EIP-197 G2 generator — the empty
+//! product, single pairs with an identity side, the non-degenerate
+//! `e(P, Q)`, the two-pair `e(aP, Q) · e(-aP, Q)` a Groth16 verifier reduces
+//! to, and a G2 point that is not on the twist. Each pairing element is
+//! `g1 (64) || g2 (128)`; the result is the 32-byte integer `1` when the
+//! product is the identity and `0` otherwise. No Zig artifacts are needed.
+
+mod common;
+
+use {
+    serde::{Deserialize, Serialize},
+    solana_bn254::{
+        compression::prelude::convert_endianness,
+        prelude::{
+            alt_bn128_g1_multiplication_be, alt_bn128_pairing_be, alt_bn128_pairing_le,
+            ALT_BN128_FIELD_SIZE, ALT_BN128_G1_POINT_SIZE, ALT_BN128_G2_POINT_SIZE,
+            ALT_BN128_PAIRING_ELEMENT_SIZE, ALT_BN128_PAIRING_OUTPUT_SIZE,
+        },
+    },
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/crypto/alt_bn128_pairing_vectors.json");
+
+/// The BN254 base field modulus `p`, big-endian.
+const FIELD_MODULUS: &str = "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47";
+
+/// The EIP-197 G2 generator as `x_c1 || x_c0 || y_c1 || y_c0`.
+const G2_GENERATOR: [&str; 4] = [
+    "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+    "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+    "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+    "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+];
+
+/// The scalar `a` in `e(aP, Q) · e(-aP, Q)`.
+const SCALAR: u64 = 5;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AltBn128PairingTestVector {
+    name: String,
+    /// Concatenated `g1 || g2` elements, every field element big-endian and
+    /// each `Fq2` coordinate as `c1 || c0`.
+    input_be: Vec<u8>,
+    /// The same elements as `alt_bn128_pairing_le` takes them: each G1
+    /// field element and each 64-byte `Fq2` coordinate byte-reversed.
+    input_le: Vec<u8>,
+    output_be: Option<Vec<u8>>,
+    output_le: Option<Vec<u8>>,
+    /// `u64::from(AltBn128Error)` when both variants reject the input.
+    expected_error: Option<u64>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    scalar: u64,
+    g1_generator: Vec<u8>,
+    g2_generator: Vec<u8>,
+    vectors: Vec<AltBn128PairingTestVector>,
+}
+
+type G1 = [u8; ALT_BN128_G1_POINT_SIZE];
+type G2 = [u8; ALT_BN128_G2_POINT_SIZE];
+
+fn from_hex(hex: &str) -> [u8; 32] {
+    std::array::from_fn(|i| u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap())
+}
+
+fn field_element(value: u64) -> [u8; 32] {
+    let mut bytes = [0; 32];
+    bytes[24..].copy_from_slice(&value.to_be_bytes());
+    bytes
+}
+
+fn g1_generator() -> G1 {
+    let mut point = [0; ALT_BN128_G1_POINT_SIZE];
+    point[..ALT_BN128_FIELD_SIZE].copy_from_slice(&field_element(1));
+    point[ALT_BN128_FIELD_SIZE..].copy_from_slice(&field_element(2));
+    point
+}
+
+fn g2_generator() -> G2 {
+    let mut point = [0; ALT_BN128_G2_POINT_SIZE];
+    for (chunk, hex) in point
+        .chunks_exact_mut(ALT_BN128_FIELD_SIZE)
+        .zip(G2_GENERATOR)
+    {
+        chunk.copy_from_slice(&from_hex(hex));
+    }
+    point
+}
+
+/// `-P = (x, p - y)`.
+fn negate(point: &G1) -> G1 {
+    let mut negated = *point;
+    let mut borrow = 0u16;
+    for (byte, modulus) in negated[ALT_BN128_FIELD_SIZE..]
+        .iter_mut()
+        .zip(from_hex(FIELD_MODULUS))
+        .rev()
+    {
+        let difference = u16::from(modulus).wrapping_sub(u16::from(*byte) + borrow);
+        *byte = difference as u8;
+        borrow = difference >> 15;
+    }
+    negated
+}
+
+fn multiply(point: &G1, scalar: u64) -> G1 {
+    let input = [point.as_slice(), &field_element(scalar)].concat();
+    alt_bn128_g1_multiplication_be(&input)
+        .unwrap()
+        .try_into()
+        .unwrap()
+}
+
+/// Reverses each G1 field element and each G2 `Fq2` coordinate.
+fn to_le(input_be: &[u8]) -> Vec<u8> {
+    input_be
+        .chunks_exact(ALT_BN128_PAIRING_ELEMENT_SIZE)
+        .flat_map(|element| {
+            let (g1, g2) = element.split_at(ALT_BN128_G1_POINT_SIZE);
+            let g1 = convert_endianness::<32, 64>(g1.try_into().unwrap());
+            let g2 = convert_endianness::<64, 128>(g2.try_into().unwrap());
+            [g1.as_slice(), g2.as_slice()].concat()
+        })
+        .collect()
+}
+
+fn vector(name: &str, pairs: &[(G1, G2)]) -> AltBn128PairingTestVector {
+    let input_be: Vec<u8> = pairs
+        .iter()
+        .flat_map(|(g1, g2)| [g1.as_slice(), g2.as_slice()].concat())
+        .collect();
+    let input_le = to_le(&input_be);
+    let (output_be, output_le, expected_error) = match (
+        alt_bn128_pairing_be(&input_be),
+        alt_bn128_pairing_le(&input_le),
+    ) {
+        (Ok(be), Ok(le)) => (Some(be), Some(le), None),
+        (Err(be), Err(le)) => {
+            assert_eq!(be, le);
+            (None, None, Some(u64::from(be)))
+        }
+        (be, le) => panic!("{name}: endianness variants disagree: {be:?} vs {le:?}"),
+    };
+    AltBn128PairingTestVector {
+        name: name.to_string(),
+        input_be,
+        input_le,
+        output_be,
+        output_le,
+        expected_error,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let p = g1_generator();
+    let q = g2_generator();
+    let a_p = multiply(&p, SCALAR);
+    let mut off_twist = q;
+    off_twist[ALT_BN128_G2_POINT_SIZE - 1] ^= 1;
+    Fixture {
+        scalar: SCALAR,
+        g1_generator: p.to_vec(),
+        g2_generator: q.to_vec(),
+        vectors: vec![
+            vector("empty", &[]),
+            vector("identity_g1_with_generator_g2", &[([0; 64], q)]),
+            vector("generator_g1_with_identity_g2", &[(p, [0; 128])]),
+            vector("generators", &[(p, q)]),
+            vector("scaled_pair_with_negation", &[(a_p, q), (negate(&a_p), q)]),
+            vector("scaled_pair_twice", &[(a_p, q), (a_p, q)]),
+            vector("g2_not_on_curve", &[(p, off_twist)]),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_alt_bn128_pairing_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    common::assert_vectors_match(
+        "alt_bn128_pairing_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn results_are_big_and_little_endian_booleans() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let mut one_be = [0; ALT_BN128_PAIRING_OUTPUT_SIZE];
+    one_be[ALT_BN128_PAIRING_OUTPUT_SIZE - 1] = 1;
+    let mut one_le = [0; ALT_BN128_PAIRING_OUTPUT_SIZE];
+    one_le[0] = 1;
+    let zero = [0; ALT_BN128_PAIRING_OUTPUT_SIZE];
+
+    for vector in &fixture.vectors {
+        assert_eq!(vector.input_be.len() % ALT_BN128_PAIRING_ELEMENT_SIZE, 0);
+        let expected = match vector.name.as_str() {
+            "generators" | "scaled_pair_twice" => Some((zero, zero)),
+            "g2_not_on_curve" => None,
+            _ => Some((one_be, one_le)),
+        };
+        match expected {
+            Some((be, le)) => {
+                assert_eq!(vector.output_be.as_deref(), Some(be.as_slice()));
+                assert_eq!(vector.output_le.as_deref(), Some(le.as_slice()));
+            }
+            None => assert!(vector.output_be.is_none() && vector.expected_error.is_some()),
+        }
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_alt_bn128_pairing_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
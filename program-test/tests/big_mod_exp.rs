@@ -0,0 +1,162 @@
+//! `solana_big_mod_exp::big_mod_exp`, cross-checked against
+//! `num_bigint::BigUint::modpow`.
+//!
+//! Records `src/crypto/big_mod_exp_vectors.json`: 32-byte and 256-byte
+//! (RSA-2048 sized) big-endian base, exponent and modulus buffers from
+//! fixed-seed pseudorandom bytes — an odd modulus, an even modulus, an
+//! exponent with its high bit set and a base larger than the modulus —
+//! plus the `modulus <= 1` short circuit. The result is always
+//! `modulus.len()` bytes. No Zig artifacts are needed.
+
+mod common;
+
+use {
+    num_bigint::BigUint,
+    serde::{Deserialize, Serialize},
+    solana_big_mod_exp::big_mod_exp,
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/crypto/big_mod_exp_vectors.json");
+
+/// Seed for the pseudorandom operands.
+const SEED: u64 = 0x5eed_0004;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct BigModExpTestVector {
+    name: String,
+    base: Vec<u8>,
+    exponent: Vec<u8>,
+    modulus: Vec<u8>,
+    /// `base^exponent mod modulus`, left-padded to `modulus.len()`.
+    result: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    seed: u64,
+    vectors: Vec<BigModExpTestVector>,
+}
+
+fn vector(name: &str, base: Vec<u8>, exponent: Vec<u8>, modulus: Vec<u8>) -> BigModExpTestVector {
+    let result = big_mod_exp(&base, &exponent, &modulus);
+    BigModExpTestVector {
+        name: name.to_string(),
+        base,
+        exponent,
+        modulus,
+        result,
+    }
+}
+
+/// Operands of `size` bytes each, drawn from one stream per size. Every
+/// modulus has its top bit set so it spans the full width.
+fn sized_vectors(size: usize) -> Vec<BigModExpTestVector> {
+    let bytes = common::pseudorandom_bytes(SEED ^ size as u64, 4 * size);
+    let (base, rest) = bytes.split_at(size);
+    let (exponent, rest) = rest.split_at(size);
+    let (odd_modulus, small_base) = rest.split_at(size);
+
+    let mut base = base.to_vec();
+    let mut exponent = exponent.to_vec();
+    let mut odd_modulus = odd_modulus.to_vec();
+    odd_modulus[0] |= 0x80;
+    odd_modulus[size - 1] |= 1;
+    // Keep `base` below the modulus and the exponent's high bit clear
+    // unless a case asks otherwise.
+    base[0] &= 0x7f;
+    exponent[0] &= 0x7f;
+    let mut even_modulus = odd_modulus.clone();
+    even_modulus[size - 1] &= !1;
+    let mut high_bit_exponent = exponent.clone();
+    high_bit_exponent[0] |= 0x80;
+    let mut large_base = small_base.to_vec();
+    large_base[0] = 0xff;
+    let mut small_modulus = odd_modulus.clone();
+    small_modulus[0] = 0x01;
+
+    vec![
+        vector(
+            &format!("odd_modulus_{size}"),
+            base.clone(),
+            exponent.clone(),
+            odd_modulus.clone(),
+        ),
+        vector(
+            &format!("even_modulus_{size}"),
+            base.clone(),
+            exponent.clone(),
+            even_modulus,
+        ),
+        vector(
+            &format!("exponent_high_bit_{size}"),
+            base,
+            high_bit_exponent,
+            odd_modulus,
+        ),
+        vector(
+            &format!("base_larger_than_modulus_{size}"),
+            large_base,
+            exponent,
+            small_modulus,
+        ),
+    ]
+}
+
+fn official_fixture() -> Fixture {
+    let mut vectors = sized_vectors(32);
+    vectors.extend(sized_vectors(256));
+    vectors.push(vector(
+        "modulus_one_256",
+        vec![0x02; 256],
+        vec![0x03; 256],
+        [vec![0; 255], vec![1]].concat(),
+    ));
+    Fixture {
+        seed: SEED,
+        vectors,
+    }
+}
+
+#[test]
+fn fixture_matches_official_big_mod_exp_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    common::assert_vectors_match("big_mod_exp_vectors.json", &fixture, &official_fixture());
+}
+
+#[test]
+fn results_match_num_bigint_modpow() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        assert_eq!(vector.result.len(), vector.modulus.len(), "{}", vector.name);
+        let base = BigUint::from_bytes_be(&vector.base);
+        let exponent = BigUint::from_bytes_be(&vector.exponent);
+        let modulus = BigUint::from_bytes_be(&vector.modulus);
+        let expected = if modulus <= BigUint::from(1u8) {
+            BigUint::ZERO
+        } else {
+            base.modpow(&exponent, &modulus)
+        };
+        assert_eq!(
+            BigUint::from_bytes_be(&vector.result),
+            expected,
+            "{}",
+            vector.name
+        );
+
+        match vector.name.rsplit_once('_').unwrap().0 {
+            "even_modulus" => assert_eq!(vector.modulus.last().unwrap() & 1, 0),
+            "exponent_high_bit" => assert!(vector.exponent[0] & 0x80 != 0),
+            "base_larger_than_modulus" => assert!(base > modulus),
+            _ => {}
+        }
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_big_mod_exp_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,112 @@
+//! `blake3::hash`, `blake3::keyed_hash` and `blake3::derive_key`.
+//!
+//! Records `src/crypto/blake3_modes_vectors.json`: each mode over the empty
+//! input, `"hello"` and 1 KiB of fixed-seed pseudorandom bytes, with the key
+//! and context the keyed and derive-key modes use. The `sol_blake3` syscall
+//! only exposes default hashing. No Zig artifacts are needed.
+
+mod common;
+
+use serde::{Deserialize, Serialize};
+
+const FIXTURE_JSON: &str = include_str!("../../src/crypto/blake3_modes_vectors.json");
+
+/// Seed for the pseudorandom input.
+const SEED: u64 = 0x5eed_0003;
+const KEY: [u8; 32] = [0x42; 32];
+const CONTEXT: &str = "solana-program-sdk-zig 2026-01-01 blake3 test vectors";
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Blake3ModeTestVector {
+    name: String,
+    /// `"hash"`, `"keyed_hash"` or `"derive_key"`.
+    mode: String,
+    input: Vec<u8>,
+    hash: [u8; 32],
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    seed: u64,
+    key: [u8; 32],
+    context: String,
+    vectors: Vec<Blake3ModeTestVector>,
+}
+
+fn official_fixture() -> Fixture {
+    let inputs = [
+        ("empty", Vec::new()),
+        ("hello", b"hello".to_vec()),
+        ("pseudorandom_1024", common::pseudorandom_bytes(SEED, 1024)),
+    ];
+    let mut vectors = Vec::new();
+    for (mode, digest) in [
+        (
+            "hash",
+            (|input| *blake3::hash(input).as_bytes()) as fn(&[u8]) -> [u8; 32],
+        ),
+        ("keyed_hash", |input| {
+            *blake3::keyed_hash(&KEY, input).as_bytes()
+        }),
+        ("derive_key", |input| blake3::derive_key(CONTEXT, input)),
+    ] {
+        for (name, input) in &inputs {
+            vectors.push(Blake3ModeTestVector {
+                name: format!("{mode}_{name}"),
+                mode: mode.to_string(),
+                input: input.clone(),
+                hash: digest(input),
+            });
+        }
+    }
+    Fixture {
+        seed: SEED,
+        key: KEY,
+        context: CONTEXT.to_string(),
+        vectors,
+    }
+}
+
+#[test]
+fn fixture_matches_official_blake3_modes_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    common::assert_vectors_match("blake3_modes_vectors.json", &fixture, &official_fixture());
+}
+
+#[test]
+fn modes_disagree_on_the_same_input() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let (hash, rest) = fixture.vectors.split_at(3);
+    let (keyed, derived) = rest.split_at(3);
+    for ((hash, keyed), derived) in hash.iter().zip(keyed).zip(derived) {
+        assert_eq!(hash.input, keyed.input);
+        assert_eq!(keyed.input, derived.input);
+        assert!(hash.hash != keyed.hash && keyed.hash != derived.hash);
+    }
+    for vector in &fixture.vectors {
+        let mut hasher = match vector.mode.as_str() {
+            "hash" => blake3::Hasher::new(),
+            "keyed_hash" => blake3::Hasher::new_keyed(&fixture.key),
+            "derive_key" => blake3::Hasher::new_derive_key(&fixture.context),
+            other => panic!("unknown mode {other}"),
+        };
+        for part in vector.input.chunks(100) {
+            hasher.update(part);
+        }
+        assert_eq!(
+            *hasher.finalize().as_bytes(),
+            vector.hash,
+            "{}",
+            vector.name
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_blake3_modes_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,125 @@
+//! bincode `Clock`, the 40-byte account data behind `SysvarC1ock`.
+//!
+//! Records `src/sysvar/clock_vectors.json`: the field values of each case
+//! and the bytes `bincode::serialize` produces for them, including negative
+//! timestamps and every field at its maximum. No Zig artifacts are needed.
+
+mod common;
+
+use {
+    serde::{Deserialize, Serialize},
+    solana_clock::Clock,
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/sysvar/clock_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ClockTestVector {
+    name: String,
+    slot: u64,
+    epoch_start_timestamp: i64,
+    epoch: u64,
+    leader_schedule_epoch: u64,
+    unix_timestamp: i64,
+    serialized: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    size: usize,
+    vectors: Vec<ClockTestVector>,
+}
+
+fn vector(name: &str, clock: Clock) -> ClockTestVector {
+    ClockTestVector {
+        name: name.to_string(),
+        slot: clock.slot,
+        epoch_start_timestamp: clock.epoch_start_timestamp,
+        epoch: clock.epoch,
+        leader_schedule_epoch: clock.leader_schedule_epoch,
+        unix_timestamp: clock.unix_timestamp,
+        serialized: bincode::serialize(&clock).unwrap(),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        size: core::mem::size_of::<Clock>(),
+        vectors: vec![
+            vector("default", Clock::default()),
+            vector(
+                "mainnet_like",
+                Clock {
+                    slot: 250_000_000,
+                    epoch_start_timestamp: 1_700_000_000,
+                    epoch: 578,
+                    leader_schedule_epoch: 579,
+                    unix_timestamp: 1_700_123_456,
+                },
+            ),
+            vector(
+                "negative_timestamps",
+                Clock {
+                    slot: 1,
+                    epoch_start_timestamp: -1,
+                    epoch: 0,
+                    leader_schedule_epoch: 1,
+                    unix_timestamp: -86_400,
+                },
+            ),
+            vector(
+                "min_timestamps",
+                Clock {
+                    slot: 0,
+                    epoch_start_timestamp: i64::MIN,
+                    epoch: 0,
+                    leader_schedule_epoch: 0,
+                    unix_timestamp: i64::MIN,
+                },
+            ),
+            vector(
+                "max_values",
+                Clock {
+                    slot: u64::MAX,
+                    epoch_start_timestamp: i64::MAX,
+                    epoch: u64::MAX,
+                    leader_schedule_epoch: u64::MAX,
+                    unix_timestamp: i64::MAX,
+                },
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_clock_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    common::assert_vectors_match("clock_vectors.json", &fixture, &official_fixture());
+}
+
+#[test]
+fn serialized_is_five_little_endian_words_in_field_order() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        assert_eq!(vector.serialized.len(), fixture.size, "{}", vector.name);
+        let mut expected = Vec::with_capacity(fixture.size);
+        expected.extend_from_slice(&vector.slot.to_le_bytes());
+        expected.extend_from_slice(&vector.epoch_start_timestamp.to_le_bytes());
+        expected.extend_from_slice(&vector.epoch.to_le_bytes());
+        expected.extend_from_slice(&vector.leader_schedule_epoch.to_le_bytes());
+        expected.extend_from_slice(&vector.unix_timestamp.to_le_bytes());
+        assert_eq!(vector.serialized, expected, "{}", vector.name);
+
+        let clock: Clock = bincode::deserialize(&vector.serialized).unwrap();
+        assert_eq!(clock.unix_timestamp, vector.unix_timestamp);
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_clock_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,273 @@
+//! Shared helpers for multi-step program-test scenarios.
+//!
+//! `ScenarioRunner` owns the full account map for a scenario, feeds it
+//! through `Mollusk::process_instruction_chain` one step at a time, and
+//! records named checkpoints so a test can assert cross-step invariants
+//! (lamport conservation, rent exemption) and dump every intermediate
+//! state as JSON for the Zig side to replay.
+//!
+//! It can also drive a deterministic slot/blockhash chain
+//! (`hash(slot) = sha256(hash(slot - 1) || slot_le)`, seeded before the
+//! first slot) through the Clock, SlotHashes and RecentBlockhashes sysvars.
+//! Mollusk has no RecentBlockhashes of its own, so the runner builds that
+//! account itself and a test must pass it in the scenario map. Mollusk
+//! pins the invoke-context blockhash to `Hash::default()`, so programs that
+//! derive state from the bank blockhash (durable nonces) are checked
+//! against `recent_blockhash()` rather than executed against it.
+//!
+//! `assert_vectors_match` (in `vectors.rs`) is for the fixture verifiers:
+//! it compares a recorded fixture with the regenerated one field by field
+//! and reports every drift at once, naming the case and the field instead
+//! of dumping both fixtures.
+
+// Each integration test compiles this module separately and uses a subset.
+#![allow(dead_code)]
+
+mod vectors;
+
+#[allow(unused_imports)]
+pub use vectors::{assert_vectors_match, pseudorandom_bytes};
+
+use {
+    mollusk_svm::{result::InstructionResult, Mollusk},
+    serde::Serialize,
+    solana_account::Account,
+    solana_hash::Hash,
+    solana_instruction::Instruction,
+    solana_pubkey::Pubkey,
+    solana_sha256_hasher::hashv,
+    solana_slot_hashes::SlotHashes,
+    std::path::Path,
+};
+
+/// Fee recorded with every RecentBlockhashes entry, the cluster default.
+pub const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+
+/// One account in a dumped checkpoint. Byte fields are plain arrays so the
+/// file parses with `std.json` into `[32]u8` / `[]const u8` like the
+/// packages' `official_*.json` fixtures.
+#[derive(Serialize)]
+pub struct AccountSnapshot {
+    pub pubkey: [u8; 32],
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub owner: [u8; 32],
+    pub executable: bool,
+    pub rent_epoch: u64,
+}
+
+#[derive(Serialize)]
+pub struct Checkpoint {
+    pub name: String,
+    pub accounts: Vec<AccountSnapshot>,
+}
+
+#[derive(Serialize)]
+struct CheckpointFile<'a> {
+    checkpoints: &'a [Checkpoint],
+}
+
+pub struct ScenarioRunner {
+    mollusk: Mollusk,
+    accounts: Vec<(Pubkey, Account)>,
+    checkpoints: Vec<Checkpoint>,
+    blockhash: Option<Hash>,
+    /// Finished slots and their hashes, newest first.
+    recent_blockhashes: Vec<(u64, Hash)>,
+}
+
+impl ScenarioRunner {
+    /// Start a scenario from `accounts`, recorded as the `"initial"`
+    /// checkpoint.
+    pub fn new(mollusk: Mollusk, accounts: Vec<(Pubkey, Account)>) -> Self {
+        let mut runner = Self {
+            mollusk,
+            accounts,
+            checkpoints: vec![],
+            blockhash: None,
+            recent_blockhashes: vec![],
+        };
+        runner.checkpoint("initial");
+        runner
+    }
+
+    /// Run `instructions` as one chain against the current account map and
+    /// keep the resulting accounts. Panics if any instruction fails.
+    pub fn process(&mut self, instructions: &[Instruction]) -> InstructionResult {
+        let result = self
+            .mollusk
+            .process_instruction_chain(instructions, &self.accounts);
+        assert!(
+            result.program_result.is_ok(),
+            "instruction chain failed after checkpoint {:?}: {:?}",
+            self.checkpoints.last().map(|c| c.name.as_str()),
+            result.program_result
+        );
+        self.accounts = result.resulting_accounts.clone();
+        result
+    }
+
+    pub fn account(&self, pubkey: &Pubkey) -> &Account {
+        self.accounts
+            .iter()
+            .find(|(key, _)| key == pubkey)
+            .map(|(_, account)| account)
+            .unwrap_or_else(|| panic!("{pubkey} is not part of the scenario"))
+    }
+
+    /// Capture the full account map under `name`.
+    pub fn checkpoint(&mut self, name: &str) {
+        let accounts = self
+            .accounts
+            .iter()
+            .map(|(pubkey, account)| AccountSnapshot {
+                pubkey: pubkey.to_bytes(),
+                lamports: account.lamports,
+                data: account.data.clone(),
+                owner: account.owner.to_bytes(),
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            })
+            .collect();
+        self.checkpoints.push(Checkpoint {
+            name: name.to_string(),
+            accounts,
+        });
+    }
+
+    /// Every checkpoint holds the same total lamports as `"initial"`.
+    /// Mollusk charges no transaction fees, so any drift is a program bug.
+    pub fn assert_lamports_conserved(&self) {
+        let total = |checkpoint: &Checkpoint| -> u128 {
+            checkpoint
+                .accounts
+                .iter()
+                .map(|account| u128::from(account.lamports))
+                .sum()
+        };
+        let expected = total(&self.checkpoints[0]);
+        for checkpoint in &self.checkpoints[1..] {
+            assert_eq!(
+                total(checkpoint),
+                expected,
+                "lamports not conserved at checkpoint {:?}",
+                checkpoint.name
+            );
+        }
+    }
+
+    /// `pubkey` currently holds at least the rent-exempt minimum for its
+    /// data length under the runner's rent sysvar.
+    pub fn assert_rent_exempt(&self, pubkey: &Pubkey) {
+        let account = self.account(pubkey);
+        let minimum = self
+            .mollusk
+            .sysvars
+            .rent
+            .minimum_balance(account.data.len());
+        assert!(
+            account.lamports >= minimum,
+            "{pubkey} holds {} lamports, below the rent-exempt minimum {minimum} for {} bytes",
+            account.lamports,
+            account.data.len()
+        );
+    }
+
+    /// Move Clock to `start_slot`, clear SlotHashes and RecentBlockhashes,
+    /// and seed the blockhash chain so the first `advance_slot` hashes
+    /// `seed || start_slot`.
+    pub fn start_slot_progression(&mut self, seed: [u8; 32], start_slot: u64) {
+        self.set_clock_slot(start_slot);
+        self.mollusk.sysvars.slot_hashes = SlotHashes::new(&[]);
+        self.recent_blockhashes.clear();
+        self.blockhash = Some(Hash::new_from_array(seed));
+        self.refresh_sysvar_accounts();
+    }
+
+    /// Finish the current slot: derive its hash from the previous one,
+    /// record it in SlotHashes and RecentBlockhashes, and move Clock to the
+    /// next slot. Returns the finished slot and its hash.
+    pub fn advance_slot(&mut self) -> (u64, Hash) {
+        let prev = self
+            .blockhash
+            .expect("start_slot_progression must run before advance_slot");
+        let slot = self.mollusk.sysvars.clock.slot;
+        let hash = hashv(&[prev.as_ref(), &slot.to_le_bytes()]);
+        self.mollusk.sysvars.slot_hashes.add(slot, hash);
+        self.recent_blockhashes.insert(0, (slot, hash));
+        #[allow(deprecated)]
+        self.recent_blockhashes
+            .truncate(solana_sysvar::recent_blockhashes::MAX_ENTRIES);
+        self.set_clock_slot(slot + 1);
+        self.blockhash = Some(hash);
+        self.refresh_sysvar_accounts();
+        (slot, hash)
+    }
+
+    /// Hash of the most recently finished slot.
+    pub fn recent_blockhash(&self) -> Hash {
+        self.blockhash
+            .expect("start_slot_progression must run before recent_blockhash")
+    }
+
+    /// The RecentBlockhashes account as the bank writes it: the entries
+    /// newest first, zero-padded to the size of a full sysvar.
+    #[allow(deprecated)]
+    pub fn keyed_account_for_recent_blockhashes_sysvar(&self) -> (Pubkey, Account) {
+        use solana_sysvar::{
+            recent_blockhashes::{IterItem, RecentBlockhashes},
+            SysvarSerialize,
+        };
+        let recent_blockhashes: RecentBlockhashes = self
+            .recent_blockhashes
+            .iter()
+            .map(|(slot, hash)| IterItem(*slot, hash, LAMPORTS_PER_SIGNATURE))
+            .collect();
+        let mut data = vec![0; RecentBlockhashes::size_of()];
+        bincode::serialize_into(&mut data[..], &recent_blockhashes).unwrap();
+        let account = Account {
+            lamports: self.mollusk.sysvars.rent.minimum_balance(data.len()),
+            data,
+            owner: solana_sdk_ids::sysvar::id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+        (solana_sdk_ids::sysvar::recent_blockhashes::id(), account)
+    }
+
+    fn set_clock_slot(&mut self, slot: u64) {
+        let sysvars = &mut self.mollusk.sysvars;
+        sysvars.clock.slot = slot;
+        sysvars.clock.epoch = sysvars.epoch_schedule.get_epoch(slot);
+        sysvars.clock.leader_schedule_epoch =
+            sysvars.epoch_schedule.get_leader_schedule_epoch(slot);
+    }
+
+    // Mollusk prefers sysvar accounts passed with the instruction over its
+    // own `Sysvars`, so any copies in the scenario map must track them.
+    fn refresh_sysvar_accounts(&mut self) {
+        let fresh = [
+            self.mollusk.sysvars.keyed_account_for_clock_sysvar(),
+            self.mollusk.sysvars.keyed_account_for_slot_hashes_sysvar(),
+            self.keyed_account_for_recent_blockhashes_sysvar(),
+        ];
+        for (pubkey, account) in fresh {
+            if let Some(entry) = self.accounts.iter_mut().find(|(key, _)| *key == pubkey) {
+                entry.1 = account;
+            }
+        }
+    }
+
+    pub fn checkpoints(&self) -> &[Checkpoint] {
+        &self.checkpoints
+    }
+
+    /// Write `{"checkpoints": [{"name", "accounts": [...]}, ...]}` to `path`.
+    pub fn dump_checkpoints(&self, path: impl AsRef<Path>) {
+        let json = serde_json::to_string_pretty(&CheckpointFile {
+            checkpoints: &self.checkpoints,
+        })
+        .unwrap();
+        std::fs::write(path, json + "\n").unwrap();
+    }
+}
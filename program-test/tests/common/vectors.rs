@@ -0,0 +1,139 @@
+//! Fixture comparison for the verifiers that regenerate an official fixture
+//! and check it against the recorded JSON.
+//!
+//! Only needs `serde` and `serde_json`, so the packages' rust-parity crates
+//! share it through a `#[path]` module instead of keeping their own copy.
+
+// Each verifier uses a subset.
+#![allow(dead_code)]
+
+use {
+    serde::Serialize,
+    serde_json::{Map, Value},
+};
+
+/// Compare the `recorded` fixture loaded from `file` with the `official` one
+/// regenerated from the upstream crates. Every field that differs is listed
+/// by path, array entries by their `name` or `label`, with byte arrays in
+/// hex, and the test fails once with the whole list.
+pub fn assert_vectors_match<T: Serialize>(file: &str, recorded: &T, official: &T) {
+    let mismatches = mismatches(recorded, official);
+    assert!(
+        mismatches.is_empty(),
+        "{file} differs from the official output in {} places:\n  {}",
+        mismatches.len(),
+        mismatches.join("\n  ")
+    );
+}
+
+/// Every difference between `recorded` and `official`, one line each.
+pub fn mismatches<T: Serialize>(recorded: &T, official: &T) -> Vec<String> {
+    let recorded = serde_json::to_value(recorded).unwrap();
+    let official = serde_json::to_value(official).unwrap();
+    let mut mismatches = vec![];
+    diff("", &recorded, &official, &mut mismatches);
+    mismatches
+}
+
+/// SplitMix64 output as little-endian bytes, so seeded inputs are
+/// reproducible without an RNG crate.
+pub fn pseudorandom_bytes(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed;
+    let mut next = move || {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    };
+    (0..len.div_ceil(8))
+        .flat_map(|_| next().to_le_bytes())
+        .take(len)
+        .collect()
+}
+
+fn diff(path: &str, recorded: &Value, official: &Value, mismatches: &mut Vec<String>) {
+    if recorded == official {
+        return;
+    }
+    match (recorded, official) {
+        (Value::Object(recorded), Value::Object(official)) => {
+            diff_fields(path, recorded, official, mismatches)
+        }
+        (Value::Array(recorded), Value::Array(official))
+            if bytes(recorded).is_none() || bytes(official).is_none() =>
+        {
+            for index in 0..recorded.len().max(official.len()) {
+                let entry = match official.get(index).or(recorded.get(index)) {
+                    Some(Value::Object(fields)) => match fields.get("name").or(fields.get("label"))
+                    {
+                        Some(Value::String(name)) => format!("{path}[{index}] ({name})"),
+                        _ => format!("{path}[{index}]"),
+                    },
+                    _ => format!("{path}[{index}]"),
+                };
+                match (recorded.get(index), official.get(index)) {
+                    (Some(recorded), Some(official)) => {
+                        diff(&entry, recorded, official, mismatches)
+                    }
+                    (None, _) => mismatches.push(format!("{entry}: missing from the fixture")),
+                    (_, None) => mismatches.push(format!("{entry}: not in the official output")),
+                }
+            }
+        }
+        _ => mismatches.push(format!(
+            "{}: expected {}, actual {}",
+            if path.is_empty() { "fixture" } else { path },
+            show(official),
+            show(recorded)
+        )),
+    }
+}
+
+fn diff_fields(
+    path: &str,
+    recorded: &Map<String, Value>,
+    official: &Map<String, Value>,
+    mismatches: &mut Vec<String>,
+) {
+    let field = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{path}.{key}")
+        }
+    };
+    for (key, official) in official {
+        match recorded.get(key) {
+            Some(recorded) => diff(&field(key), recorded, official, mismatches),
+            None => mismatches.push(format!("{}: missing from the fixture", field(key))),
+        }
+    }
+    for key in recorded.keys().filter(|key| !official.contains_key(*key)) {
+        mismatches.push(format!("{}: not in the official output", field(key)));
+    }
+}
+
+/// The array as bytes, if every element fits in one.
+fn bytes(values: &[Value]) -> Option<Vec<u8>> {
+    values
+        .iter()
+        .map(|value| value.as_u64().and_then(|byte| u8::try_from(byte).ok()))
+        .collect()
+}
+
+fn show(value: &Value) -> String {
+    match value {
+        Value::Array(values) => match bytes(values) {
+            Some(bytes) => format!(
+                "0x{}",
+                bytes
+                    .iter()
+                    .map(|byte| format!("{byte:02x}"))
+                    .collect::<String>()
+            ),
+            None => value.to_string(),
+        },
+        _ => value.to_string(),
+    }
+}
@@ -10,14 +10,19 @@
 //!   - Overflow:   delta that overflows u64 → `Custom(6001)`
 //!   - NotOwner:   wrong signer → `Custom(6000)`
 //!   - Reset:      counter goes back to 0
+//!   - Lifecycle:  Initialize → Increment → Reset via `ScenarioRunner`
+
+mod common;
 
 use {
+    common::ScenarioRunner,
     mollusk_svm::{program::keyed_account_for_system_program, Mollusk},
     solana_account::Account,
     solana_instruction::{AccountMeta, Instruction},
     solana_program_error::ProgramError,
     solana_pubkey::Pubkey,
     solana_sdk_ids::{bpf_loader_upgradeable, system_program},
+    std::path::Path,
 };
 
 // Must match `examples/counter.zig`'s `PROGRAM_ID` constant —
@@ -336,3 +341,54 @@ fn test_reset_zeroes_count() {
         .data[COUNT_OFFSET..COUNT_OFFSET + 8];
     assert_eq!(final_count, &0u64.to_le_bytes());
 }
+
+#[test]
+fn test_counter_lifecycle_scenario() {
+    let (mollusk, counter, bump) = setup();
+    let owner = fixed_owner();
+    let (system_pid, system_account) = keyed_account_for_system_program();
+    let count = |runner: &ScenarioRunner| -> [u8; 8] {
+        runner.account(&counter).data[COUNT_OFFSET..COUNT_OFFSET + 8]
+            .try_into()
+            .unwrap()
+    };
+
+    let mut runner = ScenarioRunner::new(
+        mollusk,
+        vec![
+            (owner, empty_owner_account()),
+            (counter, empty_counter_account()),
+            (system_pid, system_account),
+        ],
+    );
+
+    runner.process(&[build_initialize_ix(owner, counter, bump)]);
+    runner.checkpoint("initialized");
+    assert_eq!(runner.account(&counter).owner, program::id());
+    assert_eq!(count(&runner), 0u64.to_le_bytes());
+    runner.assert_rent_exempt(&counter);
+
+    runner.process(&[
+        build_increment_ix(owner, counter, 40),
+        build_increment_ix(owner, counter, 2),
+    ]);
+    runner.checkpoint("incremented");
+    assert_eq!(count(&runner), 42u64.to_le_bytes());
+    runner.assert_rent_exempt(&counter);
+
+    runner.process(&[build_reset_ix(owner, counter)]);
+    runner.checkpoint("reset");
+    assert_eq!(count(&runner), 0u64.to_le_bytes());
+    runner.assert_rent_exempt(&counter);
+
+    runner.assert_lamports_conserved();
+    let names: Vec<&str> = runner
+        .checkpoints()
+        .iter()
+        .map(|c| c.name.as_str())
+        .collect();
+    assert_eq!(names, ["initial", "initialized", "incremented", "reset"]);
+    runner.dump_checkpoints(
+        Path::new(env!("CARGO_TARGET_TMPDIR")).join("counter_lifecycle_checkpoints.json"),
+    );
+}
@@ -0,0 +1,268 @@
+//! `solana_curve25519::{edwards, ristretto}` — the wrappers over
+//! `sol_curve_validate_point`, `sol_curve_group_op` and
+//! `sol_curve_multiscalar_mul`.
+//!
+//! Records `src/crypto/curve25519_vectors.json`: for each curve, validation
+//! of the basepoint and of an encoding that does not decode, addition and
+//! subtraction of two multiples of the basepoint, a fixed scalar times a
+//! point, a two-point MSM and an addition with an invalid operand. Each
+//! vector carries the curve id, the syscall and `group_op` id, and the
+//! 32-byte inputs in syscall argument order. No Zig artifacts are needed.
+
+mod common;
+
+use {
+    curve25519_dalek::{
+        constants::{ED25519_BASEPOINT_POINT, RISTRETTO_BASEPOINT_POINT},
+        edwards::CompressedEdwardsY,
+        ristretto::CompressedRistretto,
+        scalar::Scalar,
+    },
+    serde::{Deserialize, Serialize},
+    solana_curve25519::{
+        curve_syscall_traits::{ADD, CURVE25519_EDWARDS, CURVE25519_RISTRETTO, MUL, SUB},
+        edwards::{self, PodEdwardsPoint},
+        ristretto::{self, PodRistrettoPoint},
+        scalar::PodScalar,
+    },
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/crypto/curve25519_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Curve25519TestVector {
+    name: String,
+    curve_id: u64,
+    /// `"validate_point"`, `"group_op"` or `"multiscalar_mul"`.
+    syscall: String,
+    /// `ADD`, `SUB` or `MUL` for `sol_curve_group_op`.
+    group_op: Option<u64>,
+    /// Little-endian canonical scalars: the left operand of `MUL`, or one
+    /// per MSM point.
+    scalars: Vec<[u8; 32]>,
+    /// Compressed points in argument order.
+    points: Vec<[u8; 32]>,
+    /// Whether the syscall returns `0`.
+    success: bool,
+    /// The output point of a successful group op or MSM.
+    result: Option<[u8; 32]>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<Curve25519TestVector>,
+}
+
+type GroupOpFn = fn(u64, &[u8; 32], &[u8; 32]) -> Option<[u8; 32]>;
+type MultiscalarMulFn = fn(&[PodScalar], &[[u8; 32]]) -> Option<[u8; 32]>;
+
+/// The curve-specific wrappers, so both curves share one vector list.
+struct Curve {
+    name: &'static str,
+    id: u64,
+    basepoint_multiple: fn(u64) -> [u8; 32],
+    /// The first `[y, 0, ...]` encoding that does not decode to a point.
+    invalid_point: fn() -> [u8; 32],
+    validate: fn(&[u8; 32]) -> bool,
+    group_op: GroupOpFn,
+    multiscalar_mul: MultiscalarMulFn,
+}
+
+fn first_invalid(decodes: impl Fn(&[u8; 32]) -> bool) -> [u8; 32] {
+    (2..=u8::MAX)
+        .map(|y| {
+            let mut bytes = [0; 32];
+            bytes[0] = y;
+            bytes
+        })
+        .find(|bytes| !decodes(bytes))
+        .unwrap()
+}
+
+const EDWARDS: Curve = Curve {
+    name: "edwards",
+    id: CURVE25519_EDWARDS,
+    basepoint_multiple: |n| {
+        (ED25519_BASEPOINT_POINT * Scalar::from(n))
+            .compress()
+            .to_bytes()
+    },
+    invalid_point: || first_invalid(|bytes| CompressedEdwardsY(*bytes).decompress().is_some()),
+    validate: |point| edwards::validate_edwards(&PodEdwardsPoint(*point)),
+    group_op: |op, left, right| {
+        let right = PodEdwardsPoint(*right);
+        match op {
+            ADD => edwards::add_edwards(&PodEdwardsPoint(*left), &right),
+            SUB => edwards::subtract_edwards(&PodEdwardsPoint(*left), &right),
+            MUL => edwards::multiply_edwards(&PodScalar(*left), &right),
+            _ => unreachable!(),
+        }
+        .map(|point| point.0)
+    },
+    multiscalar_mul: |scalars, points| {
+        let points: Vec<_> = points.iter().copied().map(PodEdwardsPoint).collect();
+        edwards::multiscalar_multiply_edwards(scalars, &points).map(|point| point.0)
+    },
+};
+
+const RISTRETTO: Curve = Curve {
+    name: "ristretto",
+    id: CURVE25519_RISTRETTO,
+    basepoint_multiple: |n| {
+        (RISTRETTO_BASEPOINT_POINT * Scalar::from(n))
+            .compress()
+            .to_bytes()
+    },
+    invalid_point: || first_invalid(|bytes| CompressedRistretto(*bytes).decompress().is_some()),
+    validate: |point| ristretto::validate_ristretto(&PodRistrettoPoint(*point)),
+    group_op: |op, left, right| {
+        let right = PodRistrettoPoint(*right);
+        match op {
+            ADD => ristretto::add_ristretto(&PodRistrettoPoint(*left), &right),
+            SUB => ristretto::subtract_ristretto(&PodRistrettoPoint(*left), &right),
+            MUL => ristretto::multiply_ristretto(&PodScalar(*left), &right),
+            _ => unreachable!(),
+        }
+        .map(|point| point.0)
+    },
+    multiscalar_mul: |scalars, points| {
+        let points: Vec<_> = points.iter().copied().map(PodRistrettoPoint).collect();
+        ristretto::multiscalar_multiply_ristretto(scalars, &points).map(|point| point.0)
+    },
+};
+
+/// The fixed full-width scalar for `MUL` and the MSM.
+fn fixed_scalar() -> [u8; 32] {
+    Scalar::from_bytes_mod_order([0x42; 32]).to_bytes()
+}
+
+fn validate_vector(curve: &Curve, name: &str, point: [u8; 32]) -> Curve25519TestVector {
+    Curve25519TestVector {
+        name: format!("{}_{name}", curve.name),
+        curve_id: curve.id,
+        syscall: "validate_point".to_string(),
+        group_op: None,
+        scalars: Vec::new(),
+        points: vec![point],
+        success: (curve.validate)(&point),
+        result: None,
+    }
+}
+
+fn group_op_vector(
+    curve: &Curve,
+    name: &str,
+    op: u64,
+    left: [u8; 32],
+    right: [u8; 32],
+) -> Curve25519TestVector {
+    let result = (curve.group_op)(op, &left, &right);
+    let (scalars, points) = match op {
+        MUL => (vec![left], vec![right]),
+        _ => (Vec::new(), vec![left, right]),
+    };
+    Curve25519TestVector {
+        name: format!("{}_{name}", curve.name),
+        curve_id: curve.id,
+        syscall: "group_op".to_string(),
+        group_op: Some(op),
+        scalars,
+        points,
+        success: result.is_some(),
+        result,
+    }
+}
+
+fn msm_vector(
+    curve: &Curve,
+    scalars: Vec<[u8; 32]>,
+    points: Vec<[u8; 32]>,
+) -> Curve25519TestVector {
+    let pod_scalars: Vec<_> = scalars.iter().copied().map(PodScalar).collect();
+    let result = (curve.multiscalar_mul)(&pod_scalars, &points);
+    Curve25519TestVector {
+        name: format!("{}_multiscalar_mul", curve.name),
+        curve_id: curve.id,
+        syscall: "multiscalar_mul".to_string(),
+        group_op: None,
+        scalars,
+        points,
+        success: result.is_some(),
+        result,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let mut vectors = Vec::new();
+    for curve in [EDWARDS, RISTRETTO] {
+        let p = (curve.basepoint_multiple)(7);
+        let q = (curve.basepoint_multiple)(11);
+        let invalid = (curve.invalid_point)();
+        vectors.extend([
+            validate_vector(&curve, "validate_basepoint", (curve.basepoint_multiple)(1)),
+            validate_vector(&curve, "validate_invalid", invalid),
+            group_op_vector(&curve, "add", ADD, p, q),
+            group_op_vector(&curve, "subtract", SUB, p, q),
+            group_op_vector(&curve, "multiply", MUL, fixed_scalar(), p),
+            msm_vector(
+                &curve,
+                vec![fixed_scalar(), Scalar::from(3u64).to_bytes()],
+                vec![p, q],
+            ),
+            group_op_vector(&curve, "add_invalid_point", ADD, p, invalid),
+        ]);
+    }
+    Fixture { vectors }
+}
+
+#[test]
+fn fixture_matches_official_curve25519_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    common::assert_vectors_match("curve25519_vectors.json", &fixture, &official_fixture());
+}
+
+#[test]
+fn results_agree_with_the_group_law() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for curve in [EDWARDS, RISTRETTO] {
+        let result = |name: &str| {
+            fixture
+                .vectors
+                .iter()
+                .find(|vector| vector.name == format!("{}_{name}", curve.name))
+                .unwrap()
+                .result
+        };
+        assert_eq!(result("add"), Some((curve.basepoint_multiple)(18)));
+        assert_eq!(
+            result("subtract"),
+            (curve.group_op)(
+                SUB,
+                &(curve.basepoint_multiple)(0),
+                &(curve.basepoint_multiple)(4)
+            )
+        );
+        assert_eq!(result("add_invalid_point"), None);
+
+        // 0x42.. * 7B + 3 * 11B == 0x42.. * 7B + 33B.
+        let multiply = result("multiply").unwrap();
+        let thirty_three = (curve.basepoint_multiple)(33);
+        assert_eq!(
+            result("multiscalar_mul"),
+            (curve.group_op)(ADD, &multiply, &thirty_three)
+        );
+    }
+    for vector in &fixture.vectors {
+        let expected = !vector.name.ends_with("invalid") && !vector.name.ends_with("invalid_point");
+        assert_eq!(vector.success, expected, "{}", vector.name);
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_curve25519_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
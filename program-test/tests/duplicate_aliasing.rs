@@ -0,0 +1,121 @@
+//! Integration test for examples/duplicate_aliasing.zig.
+//!
+//! Replays `src/entrypoint/duplicate_aliasing_vectors.json`: the same
+//! writable account sits at positions 0 and 2, the program credits it
+//! through position 0 and requires position 2 to see the credit. The
+//! runtime keeps one copy of the account, so the final balance must
+//! move by the delta exactly once.
+
+use {
+    mollusk_svm::{result::ProgramResult, Mollusk},
+    serde::Deserialize,
+    solana_account::Account,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+    solana_sdk_ids::{bpf_loader_upgradeable, system_program},
+};
+
+// Must match the `PROGRAM_ID` used by the other single-program examples.
+mod program {
+    solana_pubkey::declare_id!("Zigc1Hc97L8Pebma74jDzYiyoUvdxxcj7Gxppg9VRxK");
+}
+
+const FIXTURE_JSON: &str = include_str!("../../src/entrypoint/duplicate_aliasing_vectors.json");
+
+#[derive(Deserialize)]
+struct AliasedAccount {
+    key: [u8; 32],
+    lamports: u64,
+    program_owned: bool,
+}
+
+#[derive(Deserialize)]
+struct Operation {
+    kind: String,
+    position: usize,
+}
+
+#[derive(Deserialize)]
+struct Fixture {
+    lamports_delta: u64,
+    accounts: Vec<AliasedAccount>,
+    /// Instruction position → index into `accounts`.
+    positions: Vec<usize>,
+    operations: Vec<Operation>,
+    observed_position: usize,
+    expected_final_lamports: Vec<u64>,
+}
+
+#[test]
+fn test_duplicate_account_writes_are_visible_through_both_positions() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    // The program hard-codes: debit position 1, credit position 0,
+    // observe position 2.
+    let kinds: Vec<(&str, usize)> = fixture
+        .operations
+        .iter()
+        .map(|op| (op.kind.as_str(), op.position))
+        .collect();
+    assert_eq!(kinds, [("debit", 1), ("credit", 0)]);
+    assert_eq!(fixture.observed_position, 2);
+    assert_eq!(
+        fixture.positions[fixture.observed_position],
+        fixture.positions[0]
+    );
+
+    let mut mollusk = Mollusk::default();
+    mollusk.add_program(
+        &program::id(),
+        "zig-out/lib/example_duplicate_aliasing",
+        &bpf_loader_upgradeable::id(),
+    );
+
+    let keys: Vec<Pubkey> = fixture
+        .accounts
+        .iter()
+        .map(|a| Pubkey::new_from_array(a.key))
+        .collect();
+    let instruction = Instruction {
+        program_id: program::id(),
+        accounts: fixture
+            .positions
+            .iter()
+            .map(|&i| AccountMeta::new(keys[i], false))
+            .collect(),
+        data: fixture.lamports_delta.to_le_bytes().to_vec(),
+    };
+    let accounts: Vec<(Pubkey, Account)> = fixture
+        .accounts
+        .iter()
+        .zip(&keys)
+        .map(|(a, key)| {
+            let owner = if a.program_owned {
+                program::id()
+            } else {
+                system_program::id()
+            };
+            (*key, Account::new(a.lamports, 0, &owner))
+        })
+        .collect();
+
+    let result = mollusk.process_instruction(&instruction, &accounts);
+    assert!(
+        matches!(result.program_result, ProgramResult::Success),
+        "duplicate aliasing failed: {:?}",
+        result.program_result
+    );
+
+    for ((key, initial), expected) in keys
+        .iter()
+        .zip(&fixture.accounts)
+        .zip(&fixture.expected_final_lamports)
+    {
+        let lamports = result.get_account(key).unwrap().lamports;
+        assert_eq!(lamports, *expected, "{key}");
+        assert_eq!(
+            lamports.abs_diff(initial.lamports),
+            fixture.lamports_delta,
+            "{key} moved by more than one delta"
+        );
+    }
+}
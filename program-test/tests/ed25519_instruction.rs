@@ -0,0 +1,143 @@
+//! Self-contained ed25519 precompile instructions with real signatures.
+//!
+//! Records `src/crypto/instructions/ed25519_vectors.json`: the full data
+//! `new_ed25519_instruction_with_signature` builds for a keypair signing a
+//! message, the same keypairs `is_on_curve.rs` derives from `[n; 32]`
+//! secrets, plus the offsets, public key and signature inside it. No Zig
+//! artifacts are needed.
+
+mod common;
+
+use {
+    serde::{Deserialize, Serialize},
+    solana_ed25519_program::{
+        new_ed25519_instruction_with_signature, DATA_START, SIGNATURE_OFFSETS_START,
+    },
+    solana_keypair::Keypair,
+    solana_signature::Signature,
+    solana_signer::Signer,
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/crypto/instructions/ed25519_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct OffsetsFields {
+    signature_offset: u16,
+    signature_instruction_index: u16,
+    public_key_offset: u16,
+    public_key_instruction_index: u16,
+    message_data_offset: u16,
+    message_data_size: u16,
+    message_instruction_index: u16,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Ed25519InstructionTestVector {
+    name: String,
+    secret_key: [u8; 32],
+    public_key: [u8; 32],
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    offsets: OffsetsFields,
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<Ed25519InstructionTestVector>,
+}
+
+/// `Ed25519SignatureOffsets` is `repr(C)`: seven little-endian `u16`s.
+fn read_offsets(bytes: &[u8]) -> OffsetsFields {
+    let field = |i: usize| u16::from_le_bytes([bytes[2 * i], bytes[2 * i + 1]]);
+    OffsetsFields {
+        signature_offset: field(0),
+        signature_instruction_index: field(1),
+        public_key_offset: field(2),
+        public_key_instruction_index: field(3),
+        message_data_offset: field(4),
+        message_data_size: field(5),
+        message_instruction_index: field(6),
+    }
+}
+
+/// What the 2.x `new_ed25519_instruction(&keypair, message)` did before it
+/// was dropped: sign, then lay out the signature instruction.
+fn vector(name: &str, secret: u8, message: &[u8]) -> Ed25519InstructionTestVector {
+    let keypair = Keypair::new_from_array([secret; 32]);
+    let public_key = keypair.pubkey().to_bytes();
+    let signature: [u8; 64] = keypair.sign_message(message).into();
+    let data = new_ed25519_instruction_with_signature(message, &signature, &public_key).data;
+    Ed25519InstructionTestVector {
+        name: name.to_string(),
+        secret_key: [secret; 32],
+        public_key,
+        message: message.to_vec(),
+        signature: signature.to_vec(),
+        offsets: read_offsets(&data[SIGNATURE_OFFSETS_START..DATA_START]),
+        data,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        vectors: vec![
+            vector("keypair_1_hello", 1, b"hello"),
+            vector("keypair_2_empty_message", 2, b""),
+            vector("keypair_3_long_message", 3, &[0x5a; 200]),
+            vector(
+                "keypair_4_binary_message",
+                4,
+                &(0..=255).collect::<Vec<u8>>(),
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_ed25519_instruction_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    common::assert_vectors_match("ed25519_vectors.json", &fixture, &official_fixture());
+}
+
+#[test]
+fn offsets_locate_a_valid_signature() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let data = &vector.data;
+        let offsets = &vector.offsets;
+        assert_eq!(data[..SIGNATURE_OFFSETS_START], [1, 0]);
+        assert_eq!(usize::from(offsets.public_key_offset), DATA_START);
+        for index in [
+            offsets.signature_instruction_index,
+            offsets.public_key_instruction_index,
+            offsets.message_instruction_index,
+        ] {
+            assert_eq!(index, u16::MAX);
+        }
+
+        let public_key = &data[usize::from(offsets.public_key_offset)..][..32];
+        let signature = &data[usize::from(offsets.signature_offset)..][..64];
+        let message = &data[usize::from(offsets.message_data_offset)..]
+            [..usize::from(offsets.message_data_size)];
+        assert_eq!(public_key, vector.public_key);
+        assert_eq!(signature, vector.signature);
+        assert_eq!(message, vector.message);
+        assert!(
+            Signature::try_from(signature)
+                .unwrap()
+                .verify(public_key, message),
+            "{}",
+            vector.name
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_ed25519_instruction_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
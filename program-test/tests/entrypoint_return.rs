@@ -0,0 +1,123 @@
+//! How the runtime reads the `u64` an entrypoint returns.
+//!
+//! Records `src/entrypoint/entrypoint_return_vectors.json`: `0` is success
+//! and never converted; anything else goes through `ProgramError::from(u64)`.
+//! Builtins are exact `n << 32` codes, and every other value is `Custom`
+//! with the low 32 bits, so a value with both halves set keeps only its low
+//! half. No Zig artifacts are needed.
+
+mod common;
+
+use {
+    serde::{Deserialize, Serialize},
+    solana_program_error::{ProgramError, BUILTIN_BIT_SHIFT},
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/entrypoint/entrypoint_return_vectors.json");
+
+const SUCCESS: u64 = 0;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct EntrypointReturnTestVector {
+    name: String,
+    returned_u64: u64,
+    /// `"Success"`, or the `Debug` form of `ProgramError::from(returned_u64)`.
+    interpreted_as: String,
+    is_custom: bool,
+    custom_code: Option<u32>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<EntrypointReturnTestVector>,
+}
+
+/// The loader checks for success before converting the return value.
+fn interpret(returned_u64: u64) -> Option<ProgramError> {
+    (returned_u64 != SUCCESS).then(|| ProgramError::from(returned_u64))
+}
+
+fn vector((name, returned_u64): (&str, u64)) -> EntrypointReturnTestVector {
+    let interpreted = interpret(returned_u64);
+    let custom_code = match interpreted {
+        Some(ProgramError::Custom(code)) => Some(code),
+        _ => None,
+    };
+    EntrypointReturnTestVector {
+        name: name.to_string(),
+        returned_u64,
+        interpreted_as: interpreted.map_or("Success".to_string(), |err| format!("{err:?}")),
+        is_custom: custom_code.is_some(),
+        custom_code,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let invalid_argument = u64::from(ProgramError::InvalidArgument);
+    Fixture {
+        vectors: [
+            ("success", SUCCESS),
+            ("custom_1", 1),
+            ("custom_42", 42),
+            // `Custom(0)` sentinel: a raw `0` would read as success.
+            ("custom_0_sentinel", 1 << BUILTIN_BIT_SHIFT),
+            ("invalid_argument", invalid_argument),
+            ("custom_u32_max", u64::from(u32::MAX)),
+            ("both_halves_set", invalid_argument | 7),
+        ]
+        .into_iter()
+        .map(vector)
+        .collect(),
+    }
+}
+
+#[test]
+fn fixture_matches_official_entrypoint_return_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    common::assert_vectors_match(
+        "entrypoint_return_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn program_error_from_u64_matches_the_recorded_interpretations() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let returned = vector.returned_u64;
+        let Some(err) = interpret(returned) else {
+            assert_eq!(vector.interpreted_as, "Success");
+            assert!(!vector.is_custom);
+            continue;
+        };
+        assert_eq!(format!("{err:?}"), vector.interpreted_as);
+        match err {
+            ProgramError::Custom(code) => {
+                assert!(vector.is_custom);
+                assert_eq!(vector.custom_code, Some(code));
+                // Custom codes are the low half; `Custom(0)` alone is
+                // carried by the sentinel.
+                assert_eq!(code, returned as u32);
+                if code == 0 {
+                    assert_eq!(returned, 1 << BUILTIN_BIT_SHIFT);
+                }
+            }
+            builtin => {
+                assert!(!vector.is_custom);
+                assert_eq!(vector.custom_code, None);
+                assert_eq!(returned as u32, 0);
+                assert_eq!(u64::from(builtin), returned);
+            }
+        }
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_entrypoint_return_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,164 @@
+//! `EpochSchedule` slot and epoch arithmetic.
+//!
+//! Records `src/sysvar/epoch_schedule_vectors.json`: for a warmup schedule
+//! small enough to cross into normal epochs, the cluster default and the
+//! non-warmup schedule, what `get_epoch_and_slot_index`,
+//! `get_first_slot_in_epoch` and `get_slots_in_epoch` return, including
+//! `u64::MAX` slots and epochs. No Zig artifacts are needed.
+
+mod common;
+
+use {
+    serde::{Deserialize, Serialize},
+    solana_epoch_schedule::EpochSchedule,
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/sysvar/epoch_schedule_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct SlotVector {
+    slot: u64,
+    epoch: u64,
+    slot_index: u64,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct EpochVector {
+    epoch: u64,
+    first_slot: u64,
+    slots_in_epoch: u64,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct EpochScheduleTestVector {
+    name: String,
+    slots_per_epoch: u64,
+    leader_schedule_slot_offset: u64,
+    warmup: bool,
+    first_normal_epoch: u64,
+    first_normal_slot: u64,
+    slots: Vec<SlotVector>,
+    epochs: Vec<EpochVector>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<EpochScheduleTestVector>,
+}
+
+fn vector(
+    name: &str,
+    schedule: EpochSchedule,
+    slots: &[u64],
+    epochs: &[u64],
+) -> EpochScheduleTestVector {
+    EpochScheduleTestVector {
+        name: name.to_string(),
+        slots_per_epoch: schedule.slots_per_epoch,
+        leader_schedule_slot_offset: schedule.leader_schedule_slot_offset,
+        warmup: schedule.warmup,
+        first_normal_epoch: schedule.first_normal_epoch,
+        first_normal_slot: schedule.first_normal_slot,
+        slots: slots
+            .iter()
+            .map(|&slot| {
+                let (epoch, slot_index) = schedule.get_epoch_and_slot_index(slot);
+                SlotVector {
+                    slot,
+                    epoch,
+                    slot_index,
+                }
+            })
+            .collect(),
+        epochs: epochs
+            .iter()
+            .map(|&epoch| EpochVector {
+                epoch,
+                first_slot: schedule.get_first_slot_in_epoch(epoch),
+                slots_in_epoch: schedule.get_slots_in_epoch(epoch),
+            })
+            .collect(),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        vectors: vec![
+            vector(
+                "warmup_8192",
+                EpochSchedule::custom(8192, 8192, true),
+                &[
+                    0,
+                    31,
+                    32,
+                    95,
+                    96,
+                    223,
+                    8159,
+                    8160,
+                    16351,
+                    16352,
+                    100_000,
+                    u64::MAX,
+                ],
+                &[0, 1, 7, 8, 9, 10, 19, u64::MAX],
+            ),
+            vector(
+                "default",
+                EpochSchedule::default(),
+                &[0, 524_255, 524_256, 300_000_000, u64::MAX],
+                &[0, 13, 14, 15, 707, u64::MAX],
+            ),
+            vector(
+                "without_warmup",
+                EpochSchedule::without_warmup(),
+                &[0, 431_999, 432_000, 123_456_789, 300_000_000, u64::MAX],
+                &[0, 1, 285, 694, u64::MAX],
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_epoch_schedule_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    common::assert_vectors_match("epoch_schedule_vectors.json", &fixture, &official_fixture());
+}
+
+#[test]
+fn slots_fall_inside_their_epoch() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let schedule = EpochSchedule {
+            slots_per_epoch: vector.slots_per_epoch,
+            leader_schedule_slot_offset: vector.leader_schedule_slot_offset,
+            warmup: vector.warmup,
+            first_normal_epoch: vector.first_normal_epoch,
+            first_normal_slot: vector.first_normal_slot,
+        };
+        for row in &vector.slots {
+            assert!(
+                row.slot_index < schedule.get_slots_in_epoch(row.epoch),
+                "{} slot {}",
+                vector.name,
+                row.slot
+            );
+            assert_eq!(
+                schedule.get_first_slot_in_epoch(row.epoch) + row.slot_index,
+                row.slot,
+                "{} slot {}",
+                vector.name,
+                row.slot
+            );
+        }
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_epoch_schedule_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
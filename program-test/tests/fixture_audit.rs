@@ -0,0 +1,437 @@
+//! Repo-wide checks on the committed vector fixtures.
+//!
+//! Walks every `*.json` under `src/` and `packages/*/src/` and checks that:
+//! - keys are snake_case;
+//! - every entry of a `vectors` array has a unique `name` (or `label`), so
+//!   a drift report can say which case moved;
+//! - every family has more than one case, and a family that records errors
+//!   has both an accepted and a rejected input;
+//! - a Rust verifier includes each fixture, a Zig test embeds it, and the
+//!   verifier's header or the package README describes it;
+//! - no fixture records a timestamp or a local path, so regenerating on
+//!   another machine or day gives the same bytes;
+//! - the verifiers' comparison reports a corrupted value, and a truncated
+//!   array where there is one, at positions derived from the file name.
+//!
+//! No Zig artifacts are needed.
+
+#[path = "common/vectors.rs"]
+mod vectors;
+
+use {
+    serde_json::Value,
+    std::{
+        collections::HashSet,
+        fs,
+        hash::{DefaultHasher, Hash, Hasher},
+        path::{Path, PathBuf},
+    },
+};
+
+/// The fewest cases a `vectors` family may record.
+const MIN_VECTORS: usize = 2;
+
+/// Fields that hold the official error, or null when the input is
+/// accepted. A family that records one must show both outcomes.
+const OUTCOME_FIELDS: &[&str] = &["expected_error"];
+
+/// Subtrees recorded verbatim from another format, whose keys are not ours.
+const OPAQUE_KEYS: &[&str] = &["rpc_json"];
+
+fn repo_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .into()
+}
+
+fn collect(dir: &Path, extension: &str, out: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect(&path, extension, out);
+        } else if path.extension().is_some_and(|ext| ext == extension) {
+            out.push(path);
+        }
+    }
+}
+
+/// `dir` at the repo root and under every package, where present.
+fn source_dirs(dir: &str) -> Vec<PathBuf> {
+    let root = repo_root();
+    let mut dirs = vec![root.join(dir)];
+    for package in fs::read_dir(root.join("packages")).unwrap() {
+        dirs.push(package.unwrap().path().join(dir));
+    }
+    dirs.retain(|dir| dir.is_dir());
+    dirs
+}
+
+fn files_in(dirs: &[PathBuf], extension: &str) -> Vec<PathBuf> {
+    let mut paths = vec![];
+    for dir in dirs {
+        collect(dir, extension, &mut paths);
+    }
+    paths.sort();
+    paths
+}
+
+fn relative(path: &Path) -> String {
+    path.strip_prefix(repo_root())
+        .unwrap()
+        .display()
+        .to_string()
+}
+
+/// Every committed fixture, as its repo-relative path and parsed contents.
+fn fixtures() -> Vec<(String, Value)> {
+    files_in(&source_dirs("src"), "json")
+        .into_iter()
+        .map(|path| {
+            let file = relative(&path);
+            let value = serde_json::from_str(&fs::read_to_string(&path).unwrap())
+                .unwrap_or_else(|err| panic!("{file}: {err}"));
+            (file, value)
+        })
+        .collect()
+}
+
+/// The files each of `sources` loads through `macro_call("<path>")`, with
+/// the path resolved against the source's directory.
+fn loads(sources: &[PathBuf], macro_call: &str) -> Vec<(PathBuf, PathBuf)> {
+    let mut out = vec![];
+    for source in sources {
+        let text = fs::read_to_string(source).unwrap();
+        for (_, rest) in text
+            .match_indices(macro_call)
+            .map(|(at, _)| text.split_at(at))
+        {
+            let Some(arg) = rest[macro_call.len()..].strip_prefix("(\"") else {
+                continue;
+            };
+            let target = source
+                .parent()
+                .unwrap()
+                .join(&arg[..arg.find('"').unwrap()]);
+            if let Ok(target) = target.canonicalize() {
+                out.push((source.clone(), target));
+            }
+        }
+    }
+    out
+}
+
+fn is_snake_case(key: &str) -> bool {
+    key.starts_with(|c: char| c.is_ascii_lowercase())
+        && key
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+fn check_keys(path: &str, value: &Value, problems: &mut Vec<String>) {
+    match value {
+        Value::Object(fields) => {
+            for (key, value) in fields {
+                if !is_snake_case(key) {
+                    problems.push(format!("{path}: key {key:?} is not snake_case"));
+                }
+                if !OPAQUE_KEYS.contains(&key.as_str()) {
+                    check_keys(&format!("{path}.{key}"), value, problems);
+                }
+            }
+        }
+        Value::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                check_keys(&format!("{path}[{index}]"), value, problems);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Every `vectors` array in `value`, with its path.
+fn vector_families<'a>(path: &str, value: &'a Value, out: &mut Vec<(String, &'a [Value])>) {
+    match value {
+        Value::Object(fields) => {
+            for (key, value) in fields {
+                let field = format!("{path}.{key}");
+                if let (true, Value::Array(entries)) = (key == "vectors", value) {
+                    out.push((field.clone(), entries));
+                }
+                vector_families(&field, value, out);
+            }
+        }
+        Value::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                vector_families(&format!("{path}[{index}]"), value, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn check_vector_names(family: &str, entries: &[Value], problems: &mut Vec<String>) {
+    let mut seen = HashSet::new();
+    for (index, entry) in entries.iter().enumerate() {
+        match entry.get("name").or(entry.get("label")) {
+            Some(Value::String(name)) if !seen.insert(name) => {
+                problems.push(format!("{family}[{index}]: duplicate name {name:?}"))
+            }
+            Some(Value::String(_)) => {}
+            _ => problems.push(format!("{family}[{index}]: no name or label")),
+        }
+    }
+}
+
+fn check_coverage(family: &str, entries: &[Value], problems: &mut Vec<String>) {
+    if entries.len() < MIN_VECTORS {
+        problems.push(format!(
+            "{family}: {} vectors, fewer than {MIN_VECTORS}",
+            entries.len()
+        ));
+    }
+    for field in OUTCOME_FIELDS {
+        let outcomes: Vec<bool> = entries
+            .iter()
+            .filter_map(|entry| entry.get(*field).map(Value::is_null))
+            .collect();
+        if outcomes.is_empty() {
+            continue;
+        }
+        if !outcomes.contains(&true) {
+            problems.push(format!(
+                "{family}: every vector sets {field}, none is accepted"
+            ));
+        }
+        if !outcomes.contains(&false) {
+            problems.push(format!(
+                "{family}: no vector sets {field}, none is rejected"
+            ));
+        }
+    }
+}
+
+/// Whether `text` holds a `YYYY-MM-DDThh:mm` (or space-separated) stamp.
+fn has_timestamp(text: &str) -> bool {
+    const TEMPLATE: &[u8] = b"0000-00-00T00:00";
+    text.as_bytes().windows(TEMPLATE.len()).any(|window| {
+        window
+            .iter()
+            .zip(TEMPLATE)
+            .all(|(&byte, &expected)| match expected {
+                b'0' => byte.is_ascii_digit(),
+                b'T' => byte == b'T' || byte == b' ',
+                _ => byte == expected,
+            })
+    })
+}
+
+fn is_absolute_path(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    (text.starts_with('/') && text[1..].contains('/'))
+        || text.starts_with("~/")
+        || (bytes.len() > 2 && bytes[0].is_ascii_alphabetic() && &bytes[1..3] == b":\\")
+}
+
+/// Strings that would differ between two machines or two runs.
+fn check_reproducible(path: &str, value: &Value, local: &[String], problems: &mut Vec<String>) {
+    match value {
+        Value::Object(fields) => {
+            for (key, value) in fields {
+                check_reproducible(&format!("{path}.{key}"), value, local, problems);
+            }
+        }
+        Value::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                check_reproducible(&format!("{path}[{index}]"), value, local, problems);
+            }
+        }
+        Value::String(text) => {
+            if has_timestamp(text) {
+                problems.push(format!("{path}: {text:?} looks like a timestamp"));
+            }
+            if is_absolute_path(text) || local.iter().any(|local| text.contains(local.as_str())) {
+                problems.push(format!("{path}: {text:?} looks like a local path"));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// JSON pointers to every unsigned integer and every non-empty array.
+fn mutation_sites(
+    pointer: &str,
+    value: &Value,
+    numbers: &mut Vec<String>,
+    arrays: &mut Vec<String>,
+) {
+    match value {
+        Value::Object(fields) => {
+            for (key, value) in fields {
+                mutation_sites(&format!("{pointer}/{key}"), value, numbers, arrays);
+            }
+        }
+        Value::Array(values) => {
+            if !values.is_empty() {
+                arrays.push(pointer.to_string());
+            }
+            for (index, value) in values.iter().enumerate() {
+                mutation_sites(&format!("{pointer}/{index}"), value, numbers, arrays);
+            }
+        }
+        Value::Number(number) if number.is_u64() => numbers.push(pointer.to_string()),
+        _ => {}
+    }
+}
+
+fn pick<'a>(file: &str, mutation: &str, sites: &'a [String]) -> &'a str {
+    let mut hasher = DefaultHasher::new();
+    (file, mutation).hash(&mut hasher);
+    &sites[hasher.finish() as usize % sites.len()]
+}
+
+fn assert_no_problems(check: &str, problems: Vec<String>) {
+    assert!(
+        problems.is_empty(),
+        "{} fixture entries fail the {check} check:\n  {}",
+        problems.len(),
+        problems.join("\n  ")
+    );
+}
+
+#[test]
+fn fixture_keys_are_snake_case() {
+    let mut problems = vec![];
+    for (file, value) in fixtures() {
+        check_keys(&file, &value, &mut problems);
+    }
+    assert_no_problems("snake_case", problems);
+}
+
+#[test]
+fn every_vector_has_a_unique_name() {
+    let mut problems = vec![];
+    for (file, value) in fixtures() {
+        let mut families = vec![];
+        vector_families(&file, &value, &mut families);
+        for (family, entries) in families {
+            check_vector_names(&family, entries, &mut problems);
+        }
+    }
+    assert_no_problems("naming", problems);
+}
+
+#[test]
+fn every_family_covers_more_than_one_case_and_both_outcomes() {
+    let mut problems = vec![];
+    for (file, value) in fixtures() {
+        let mut families = vec![];
+        vector_families(&file, &value, &mut families);
+        for (family, entries) in families {
+            check_coverage(&family, entries, &mut problems);
+        }
+    }
+    assert_no_problems("coverage", problems);
+}
+
+#[test]
+fn fixtures_hold_no_timestamps_or_local_paths() {
+    let local: Vec<String> = [
+        std::env::var("HOME").ok(),
+        Some(repo_root().display().to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|path| path.len() > 1)
+    .collect();
+    let mut problems = vec![];
+    for (file, value) in fixtures() {
+        check_reproducible(&file, &value, &local, &mut problems);
+    }
+    assert_no_problems("reproducibility", problems);
+}
+
+#[test]
+fn every_fixture_has_a_verifier_a_zig_consumer_and_a_description() {
+    let mut verifier_dirs = source_dirs("rust-parity/tests");
+    verifier_dirs.push(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests"));
+    let verifiers = loads(&files_in(&verifier_dirs, "rs"), "include_str!");
+    let consumers = loads(&files_in(&source_dirs("src"), "zig"), "@embedFile");
+
+    let mut problems = vec![];
+    for fixture in files_in(&source_dirs("src"), "json") {
+        let file = relative(&fixture);
+        let name = fixture.file_name().unwrap().to_str().unwrap();
+        let fixture = fixture.canonicalize().unwrap();
+        let loaded_by = |loads: &[(PathBuf, PathBuf)]| -> Vec<PathBuf> {
+            loads
+                .iter()
+                .filter(|(_, target)| *target == fixture)
+                .map(|(source, _)| source.clone())
+                .collect()
+        };
+
+        let verified_by = loaded_by(&verifiers);
+        if verified_by.is_empty() {
+            problems.push(format!("{file}: no Rust test includes it"));
+        }
+        if loaded_by(&consumers).is_empty() {
+            problems.push(format!("{file}: no Zig test embeds it"));
+        }
+
+        // A verifier's `//!` header, or the owning package's README.
+        let in_header = verified_by.iter().any(|source| {
+            fs::read_to_string(source)
+                .unwrap()
+                .lines()
+                .take_while(|line| line.starts_with("//!"))
+                .any(|line| line.contains(name))
+        });
+        let readme = fixture
+            .ancestors()
+            .find(|dir| dir.join("src").is_dir() && dir.join("README.md").is_file())
+            .map(|dir| fs::read_to_string(dir.join("README.md")).unwrap());
+        if !in_header && !readme.is_some_and(|readme| readme.contains(name)) {
+            problems.push(format!(
+                "{file}: neither a verifier header nor the package README describes it"
+            ));
+        }
+    }
+    assert_no_problems("provenance", problems);
+}
+
+#[test]
+fn the_comparison_catches_a_flipped_value_and_a_truncated_array() {
+    let mut problems = vec![];
+    for (file, official) in fixtures() {
+        let (mut numbers, mut arrays) = (vec![], vec![]);
+        mutation_sites("", &official, &mut numbers, &mut arrays);
+        assert!(!numbers.is_empty(), "{file}: nothing to corrupt");
+
+        let mut corrupted = official.clone();
+        let site = pick(&file, "corrupt", &numbers);
+        let number = corrupted.pointer_mut(site).unwrap();
+        *number = Value::from(number.as_u64().unwrap() ^ 1);
+        if vectors::mismatches(&corrupted, &official).is_empty() {
+            problems.push(format!(
+                "{file}{site}: flipping the low bit went unreported"
+            ));
+        }
+
+        if arrays.is_empty() {
+            continue;
+        }
+        let mut truncated = official.clone();
+        let site = pick(&file, "truncate", &arrays);
+        let Value::Array(values) = truncated.pointer_mut(site).unwrap() else {
+            unreachable!();
+        };
+        values.pop();
+        if vectors::mismatches(&truncated, &official).is_empty() {
+            problems.push(format!(
+                "{file}{site}: dropping the last entry went unreported"
+            ));
+        }
+    }
+    assert_no_problems("mutation", problems);
+}
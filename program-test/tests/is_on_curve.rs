@@ -0,0 +1,114 @@
+//! `Pubkey::is_on_curve`, which tells wallet keys from PDAs.
+//!
+//! Records `src/pubkey/is_on_curve_vectors.json`: pubkeys of keypairs
+//! derived from fixed secrets (on the curve), the canonical addresses from
+//! `src/pda/create_program_address_vectors.json` (off it), the all-zero and
+//! all-0xFF keys, and fixed-seed pseudorandom bytes. No Zig artifacts are
+//! needed.
+
+mod common;
+
+use {
+    serde::{Deserialize, Serialize},
+    solana_keypair::Keypair,
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/pubkey/is_on_curve_vectors.json");
+const PDA_VECTORS_JSON: &str = include_str!("../../src/pda/create_program_address_vectors.json");
+
+/// Seed for the pseudorandom inputs.
+const SEED: u64 = 0x5eed_0002;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct IsOnCurveTestVector {
+    name: String,
+    bytes: [u8; 32],
+    is_on_curve: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    seed: u64,
+    vectors: Vec<IsOnCurveTestVector>,
+}
+
+#[derive(Deserialize)]
+struct PdaVector {
+    name: String,
+    canonical_address: [u8; 32],
+}
+
+#[derive(Deserialize)]
+struct PdaFixture {
+    vectors: Vec<PdaVector>,
+}
+
+fn vector(name: String, bytes: [u8; 32]) -> IsOnCurveTestVector {
+    IsOnCurveTestVector {
+        name,
+        bytes,
+        is_on_curve: Pubkey::new_from_array(bytes).is_on_curve(),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let keypairs = (1..=4u8).map(|secret| {
+        let pubkey = Keypair::new_from_array([secret; 32]).pubkey();
+        vector(format!("keypair_{secret}"), pubkey.to_bytes())
+    });
+    let pdas: PdaFixture = serde_json::from_str(PDA_VECTORS_JSON).unwrap();
+    let pdas = pdas
+        .vectors
+        .into_iter()
+        .map(|pda| vector(format!("pda_{}", pda.name), pda.canonical_address));
+    let fixed = [("all_zero", [0; 32]), ("all_ff", [0xff; 32])]
+        .into_iter()
+        .map(|(name, bytes)| vector(name.to_string(), bytes));
+    let random_bytes = common::pseudorandom_bytes(SEED, 8 * 32);
+    let random = random_bytes
+        .chunks_exact(32)
+        .enumerate()
+        .map(|(i, bytes)| vector(format!("pseudorandom_{i}"), bytes.try_into().unwrap()));
+    Fixture {
+        seed: SEED,
+        vectors: keypairs.chain(pdas).chain(fixed).chain(random).collect(),
+    }
+}
+
+#[test]
+fn fixture_matches_official_is_on_curve_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    common::assert_vectors_match("is_on_curve_vectors.json", &fixture, &official_fixture());
+}
+
+#[test]
+fn wallet_keys_are_on_the_curve_and_pdas_are_not() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        if vector.name.starts_with("keypair_") {
+            assert!(vector.is_on_curve, "{}", vector.name);
+        } else if vector.name.starts_with("pda_") {
+            assert!(!vector.is_on_curve, "{}", vector.name);
+        }
+    }
+    let random = fixture
+        .vectors
+        .iter()
+        .filter(|vector| vector.name.starts_with("pseudorandom_"));
+    assert!(
+        random.clone().any(|vector| vector.is_on_curve)
+            && random.clone().any(|vector| !vector.is_on_curve),
+        "pseudorandom inputs should land both on and off the curve"
+    );
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_is_on_curve_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
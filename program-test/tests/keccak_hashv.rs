@@ -0,0 +1,113 @@
+//! `solana_keccak_hasher::hashv` over several input slices.
+//!
+//! Records `src/crypto/keccak_hashv_vectors.json`: slice lists with empty
+//! slices interleaved, splits on either side of the 136-byte Keccak-256
+//! rate, and 4 KiB spread over uneven slices, each slice recorded
+//! separately. No Zig artifacts are needed.
+
+mod common;
+
+use {
+    serde::{Deserialize, Serialize},
+    solana_keccak_hasher::{hash, hashv},
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/crypto/keccak_hashv_vectors.json");
+
+/// Keccak-256 absorbs 136 bytes per permutation.
+const RATE: usize = 136;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct KeccakHashvTestVector {
+    name: String,
+    inputs: Vec<Vec<u8>>,
+    hash: [u8; 32],
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    rate: usize,
+    vectors: Vec<KeccakHashvTestVector>,
+}
+
+fn vector(name: &str, inputs: &[&[u8]]) -> KeccakHashvTestVector {
+    KeccakHashvTestVector {
+        name: name.to_string(),
+        inputs: inputs.iter().map(|input| input.to_vec()).collect(),
+        hash: hashv(inputs).to_bytes(),
+    }
+}
+
+/// `0, 1, ..., 250, 0, 1, ...` so no two slices of the same length match.
+fn pattern(len: usize, start: usize) -> Vec<u8> {
+    (start..start + len).map(|i| (i % 251) as u8).collect()
+}
+
+fn official_fixture() -> Fixture {
+    let four_kib = pattern(4096, 0);
+    let (a, rest) = four_kib.split_at(1);
+    let (b, rest) = rest.split_at(RATE - 1);
+    let (c, rest) = rest.split_at(1000);
+    let (d, e) = rest.split_at(2000);
+    Fixture {
+        rate: RATE,
+        vectors: vec![
+            vector("abc", &[b"abc"]),
+            vector("ab_c", &[b"ab", b"c"]),
+            vector("all_empty", &[b"", b"", b""]),
+            vector(
+                "hello_world_empty_interleaved",
+                &[b"", b"hello", b"", b" world", b""],
+            ),
+            vector("exactly_one_rate", &[&pattern(100, 0), &pattern(36, 100)]),
+            vector(
+                "one_short_of_rate_then_two",
+                &[&pattern(RATE - 1, 0), &[0xaa, 0xbb]],
+            ),
+            vector(
+                "rate_split_by_empty",
+                &[&pattern(RATE, 0), b"", &pattern(1, RATE)],
+            ),
+            vector(
+                "two_rates_in_three_slices",
+                &[&pattern(RATE + 10, 0), &pattern(100, 7), &pattern(36, 9)],
+            ),
+            vector("four_kib_in_five_slices", &[a, b, c, d, e]),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_keccak_hashv_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    common::assert_vectors_match("keccak_hashv_vectors.json", &fixture, &official_fixture());
+}
+
+#[test]
+fn hashv_is_hash_of_the_concatenation() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let four_kib = fixture
+        .vectors
+        .iter()
+        .find(|vector| vector.name == "four_kib_in_five_slices")
+        .unwrap();
+    assert_eq!(four_kib.inputs.concat().len(), 4096);
+
+    for vector in &fixture.vectors {
+        assert_eq!(
+            hash(&vector.inputs.concat()).to_bytes(),
+            vector.hash,
+            "{}",
+            vector.name
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_keccak_hashv_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,275 @@
+//! `Pubkey::create_program_address` with an explicit bump.
+//!
+//! Records `src/pda/create_program_address_vectors.json`: for each seed set,
+//! the canonical bump `find_program_address` settles on, every bump above it
+//! (each one hashes onto the curve, so `create_program_address` rejects it),
+//! the canonical bump itself, and the next bump below it that also yields a
+//! PDA.
+//!
+//! Also records `src/pda/pda_error_vectors.json`: seeds at and past
+//! `MAX_SEED_LEN` and `MAX_SEEDS`, with the error `create_program_address`
+//! returns or the address it derives, and whether `try_find_program_address`
+//! still has room for its bump seed. No Zig artifacts are needed.
+
+mod common;
+
+use {
+    serde::{Deserialize, Serialize},
+    solana_pubkey::{Pubkey, MAX_SEEDS, MAX_SEED_LEN},
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/pda/create_program_address_vectors.json");
+const ERROR_FIXTURE_JSON: &str = include_str!("../../src/pda/pda_error_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct BumpAttempt {
+    bump: u8,
+    succeeds: bool,
+    address: Option<[u8; 32]>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct CreateProgramAddressTestVector {
+    name: String,
+    program_id: [u8; 32],
+    /// Seeds before the bump byte.
+    seeds: Vec<Vec<u8>>,
+    canonical_bump: u8,
+    canonical_address: [u8; 32],
+    attempts: Vec<BumpAttempt>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<CreateProgramAddressTestVector>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct PdaErrorTestVector {
+    name: String,
+    /// `"create_program_address"` or `"try_find_program_address"`.
+    function: String,
+    program_id: [u8; 32],
+    seeds: Vec<Vec<u8>>,
+    /// `Debug` form of the `create_program_address` error.
+    expected_error: Option<String>,
+    /// `None` when derivation fails; `try_find_program_address` then
+    /// returns `None` rather than an error.
+    address: Option<[u8; 32]>,
+    bump: Option<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ErrorFixture {
+    max_seeds: usize,
+    max_seed_len: usize,
+    vectors: Vec<PdaErrorTestVector>,
+}
+
+fn attempt(seeds: &[Vec<u8>], bump: u8, program_id: &Pubkey) -> BumpAttempt {
+    let bump_seed = [bump];
+    let mut with_bump: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+    with_bump.push(&bump_seed);
+    let address = Pubkey::create_program_address(&with_bump, program_id).ok();
+    BumpAttempt {
+        bump,
+        succeeds: address.is_some(),
+        address: address.map(|address| address.to_bytes()),
+    }
+}
+
+fn vector(name: &str, seeds: Vec<Vec<u8>>, program_id: Pubkey) -> CreateProgramAddressTestVector {
+    let seed_slices: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+    let (canonical_address, canonical_bump) =
+        Pubkey::find_program_address(&seed_slices, &program_id);
+    let mut attempts: Vec<_> = (canonical_bump..=u8::MAX)
+        .rev()
+        .map(|bump| attempt(&seeds, bump, &program_id))
+        .collect();
+    if let Some(next) = (0..canonical_bump)
+        .rev()
+        .map(|bump| attempt(&seeds, bump, &program_id))
+        .find(|attempt| attempt.succeeds)
+    {
+        attempts.push(next);
+    }
+    CreateProgramAddressTestVector {
+        name: name.to_string(),
+        program_id: program_id.to_bytes(),
+        seeds,
+        canonical_bump,
+        canonical_address: canonical_address.to_bytes(),
+        attempts,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let program = Pubkey::new_from_array([0x42; 32]);
+    Fixture {
+        vectors: vec![
+            vector("no_seeds", Vec::new(), program),
+            vector("vault", vec![b"vault".to_vec()], program),
+            vector(
+                "vault_system_program",
+                vec![b"vault".to_vec()],
+                Pubkey::default(),
+            ),
+            vector(
+                "seed_and_key",
+                vec![b"escrow".to_vec(), vec![0x11; 32]],
+                program,
+            ),
+            // Eight bumps from 255 down hash onto the curve.
+            vector("on_curve_run", vec![b"seed".to_vec(), vec![158]], program),
+        ],
+    }
+}
+
+fn create_vector(name: &str, seeds: Vec<Vec<u8>>, program_id: Pubkey) -> PdaErrorTestVector {
+    let seed_slices: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+    let result = Pubkey::create_program_address(&seed_slices, &program_id);
+    PdaErrorTestVector {
+        name: name.to_string(),
+        function: "create_program_address".to_string(),
+        program_id: program_id.to_bytes(),
+        expected_error: result.as_ref().err().map(|err| format!("{err:?}")),
+        address: result.ok().map(|address| address.to_bytes()),
+        bump: None,
+        seeds,
+    }
+}
+
+fn find_vector(name: &str, seeds: Vec<Vec<u8>>, program_id: Pubkey) -> PdaErrorTestVector {
+    let seed_slices: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+    let found = Pubkey::try_find_program_address(&seed_slices, &program_id);
+    PdaErrorTestVector {
+        name: name.to_string(),
+        function: "try_find_program_address".to_string(),
+        program_id: program_id.to_bytes(),
+        expected_error: None,
+        address: found.map(|(address, _)| address.to_bytes()),
+        bump: found.map(|(_, bump)| bump),
+        seeds,
+    }
+}
+
+/// `count` distinct seeds of `len` bytes each.
+fn seeds(count: usize, len: usize) -> Vec<Vec<u8>> {
+    (0..count).map(|i| vec![i as u8; len]).collect()
+}
+
+fn official_error_fixture() -> ErrorFixture {
+    let program = Pubkey::new_from_array([0x42; 32]);
+    ErrorFixture {
+        max_seeds: MAX_SEEDS,
+        max_seed_len: MAX_SEED_LEN,
+        vectors: vec![
+            create_vector("create_seed_33_bytes", seeds(1, MAX_SEED_LEN + 1), program),
+            create_vector("create_17_seeds", seeds(MAX_SEEDS + 1, 1), program),
+            create_vector(
+                "create_16_seeds_of_32_bytes",
+                seeds(MAX_SEEDS, MAX_SEED_LEN),
+                program,
+            ),
+            find_vector("find_seed_33_bytes", seeds(1, MAX_SEED_LEN + 1), program),
+            // The bump seed would be the 17th.
+            find_vector(
+                "find_16_seeds_of_32_bytes",
+                seeds(MAX_SEEDS, MAX_SEED_LEN),
+                program,
+            ),
+            find_vector(
+                "find_15_seeds_of_32_bytes",
+                seeds(MAX_SEEDS - 1, MAX_SEED_LEN),
+                program,
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_create_program_address_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    common::assert_vectors_match(
+        "create_program_address_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn find_program_address_returns_the_first_bump_create_accepts() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let (rejected, accepted) = vector
+            .attempts
+            .split_at(usize::from(u8::MAX - vector.canonical_bump));
+        assert!(rejected
+            .iter()
+            .all(|attempt| !attempt.succeeds && attempt.address.is_none()));
+        assert_eq!(accepted[0].bump, vector.canonical_bump, "{}", vector.name);
+        assert_eq!(accepted[0].address, Some(vector.canonical_address));
+        assert!(accepted.iter().all(|attempt| attempt.succeeds));
+    }
+    assert!(
+        fixture
+            .vectors
+            .iter()
+            .any(|vector| vector.canonical_bump < u8::MAX),
+        "no vector exercises an on-curve bump"
+    );
+}
+
+#[test]
+fn fixture_matches_official_pda_error_vectors() {
+    let fixture: ErrorFixture = serde_json::from_str(ERROR_FIXTURE_JSON).unwrap();
+    common::assert_vectors_match(
+        "pda_error_vectors.json",
+        &fixture,
+        &official_error_fixture(),
+    );
+}
+
+#[test]
+fn seed_limit_vectors_fail_past_the_limit_and_derive_at_it() {
+    let fixture: ErrorFixture = serde_json::from_str(ERROR_FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let within_limits = vector.seeds.len() <= fixture.max_seeds
+            && vector
+                .seeds
+                .iter()
+                .all(|seed| seed.len() <= fixture.max_seed_len);
+        if vector.function == "create_program_address" {
+            assert_eq!(vector.address.is_some(), within_limits, "{}", vector.name);
+            if !within_limits {
+                assert_eq!(
+                    vector.expected_error.as_deref(),
+                    Some("MaxSeedLengthExceeded")
+                );
+            }
+        } else {
+            // The bump seed needs one of the `MAX_SEEDS` slots.
+            let has_room = within_limits && vector.seeds.len() < fixture.max_seeds;
+            assert_eq!(vector.address.is_some(), has_room, "{}", vector.name);
+            assert_eq!(vector.bump.is_some(), has_room);
+        }
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_pda_error_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_error_fixture()).unwrap()
+    );
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_create_program_address_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
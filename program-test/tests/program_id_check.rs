@@ -0,0 +1,205 @@
+//! Integration test for examples/program_id_check.zig.
+//!
+//! The runtime serializes the program id right after the instruction
+//! data, so its offset in the input region depends on every account's
+//! data length and on duplicate slots. Each case below records that
+//! `program_id_offset` and passes it as instruction data; the program
+//! checks it against where the id actually landed.
+//!
+//! Covers:
+//!   - offsets for zero accounts, unaligned data lengths, and a
+//!     duplicate account slot
+//!   - registered under a different pubkey → `Custom(6000)`
+//!   - wrong expected offset → `Custom(6001)`
+
+use {
+    mollusk_svm::{result::ProgramResult, Mollusk},
+    solana_account::Account,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_program_error::ProgramError,
+    solana_pubkey::Pubkey,
+    solana_sdk_ids::{bpf_loader_upgradeable, system_program},
+    std::collections::HashSet,
+};
+
+// Must match `examples/program_id_check.zig`'s `PROGRAM_ID` constant.
+mod program {
+    solana_pubkey::declare_id!("Zigc1Hc97L8Pebma74jDzYiyoUvdxxcj7Gxppg9VRxK");
+}
+
+const MAX_PERMITTED_DATA_INCREASE: usize = 10 * 1024;
+const BPF_ALIGN_OF_U128: usize = 8;
+
+struct EntrypointInputCase {
+    name: &'static str,
+    account_data_lens: &'static [usize],
+    /// Indices into `account_data_lens` in instruction-account order;
+    /// repeats serialize as duplicate slots.
+    account_order: &'static [usize],
+    program_id_offset: usize,
+}
+
+/// Offsets for the aligned (`bpf_loader` / upgradeable) input layout
+/// with 8 bytes of instruction data, worked by hand:
+///   num_accounts u64
+///   per unique account: dup marker, signer, writable, executable,
+///     4 padding, key, owner, lamports, data_len, data + realloc
+///     space padded to 8, rent_epoch
+///   per duplicate: dup index + 7 padding
+///   ix data_len u64, ix data, program id
+const CASES: &[EntrypointInputCase] = &[
+    EntrypointInputCase {
+        name: "no_accounts",
+        account_data_lens: &[],
+        account_order: &[],
+        program_id_offset: 8 + 8 + 8,
+    },
+    EntrypointInputCase {
+        name: "one_empty_account",
+        account_data_lens: &[0],
+        account_order: &[0],
+        program_id_offset: 8 + (88 + 10_240 + 8) + 8 + 8,
+    },
+    EntrypointInputCase {
+        name: "unaligned_data_lens",
+        account_data_lens: &[3, 165],
+        account_order: &[0, 1],
+        program_id_offset: 8 + (88 + 10_248 + 8) + (88 + 10_408 + 8) + 8 + 8,
+    },
+    EntrypointInputCase {
+        name: "duplicate_slot",
+        account_data_lens: &[3],
+        account_order: &[0, 0],
+        program_id_offset: 8 + (88 + 10_248 + 8) + 8 + 8 + 8,
+    },
+];
+
+/// Recompute `program_id_offset` from the serializer's rules, so the
+/// hand-worked table above can't drift from the layout description.
+fn serialized_program_id_offset(case: &EntrypointInputCase, ix_data_len: usize) -> usize {
+    let mut seen = HashSet::new();
+    let mut offset = std::mem::size_of::<u64>();
+    for &index in case.account_order {
+        if !seen.insert(index) {
+            offset += 8;
+            continue;
+        }
+        let data_len = case.account_data_lens[index];
+        offset += 8 + 32 + 32 + 8 + 8;
+        offset += (data_len + MAX_PERMITTED_DATA_INCREASE).next_multiple_of(BPF_ALIGN_OF_U128);
+        offset += 8;
+    }
+    offset + std::mem::size_of::<u64>() + ix_data_len
+}
+
+fn mollusk_with_program(program_id: &Pubkey) -> Mollusk {
+    let mut mollusk = Mollusk::default();
+    mollusk.add_program(
+        program_id,
+        "zig-out/lib/example_program_id_check",
+        &bpf_loader_upgradeable::id(),
+    );
+    mollusk
+}
+
+fn run_case(
+    mollusk: &Mollusk,
+    program_id: Pubkey,
+    case: &EntrypointInputCase,
+    expected_offset: u64,
+) -> ProgramResult {
+    let keys: Vec<Pubkey> = (0..case.account_data_lens.len())
+        .map(|i| Pubkey::new_from_array([0x40 + i as u8; 32]))
+        .collect();
+    let instruction = Instruction {
+        program_id,
+        accounts: case
+            .account_order
+            .iter()
+            .map(|&i| AccountMeta::new_readonly(keys[i], false))
+            .collect(),
+        data: expected_offset.to_le_bytes().to_vec(),
+    };
+    let accounts: Vec<(Pubkey, Account)> = keys
+        .iter()
+        .zip(case.account_data_lens)
+        .map(|(key, &len)| {
+            (
+                *key,
+                Account {
+                    lamports: 1_000_000_000,
+                    data: vec![0x5a; len],
+                    owner: system_program::id(),
+                    executable: false,
+                    rent_epoch: 0,
+                },
+            )
+        })
+        .collect();
+    mollusk
+        .process_instruction(&instruction, &accounts)
+        .program_result
+}
+
+#[test]
+fn test_program_id_offsets_match_layout() {
+    for case in CASES {
+        assert_eq!(
+            serialized_program_id_offset(case, std::mem::size_of::<u64>()),
+            case.program_id_offset,
+            "{}",
+            case.name
+        );
+    }
+}
+
+#[test]
+fn test_program_id_check_accepts_baked_in_id() {
+    let mollusk = mollusk_with_program(&program::id());
+    for case in CASES {
+        let result = run_case(&mollusk, program::id(), case, case.program_id_offset as u64);
+        assert!(
+            result.is_ok(),
+            "{}: program id self-check failed: {result:?}",
+            case.name
+        );
+    }
+}
+
+#[test]
+fn test_program_id_check_rejects_other_program_id_with_custom_6000() {
+    let imposter = Pubkey::new_from_array([0x77; 32]);
+    let mollusk = mollusk_with_program(&imposter);
+    for case in CASES {
+        let result = run_case(&mollusk, imposter, case, case.program_id_offset as u64);
+        match result {
+            ProgramResult::Failure(ref err) => assert_eq!(
+                err,
+                &ProgramError::Custom(6000),
+                "{}: expected Custom(6000) (WrongProgramId), got {err:?}",
+                case.name
+            ),
+            other => panic!("{}: expected WrongProgramId, got {other:?}", case.name),
+        }
+    }
+}
+
+#[test]
+fn test_program_id_check_rejects_wrong_offset_with_custom_6001() {
+    let mollusk = mollusk_with_program(&program::id());
+    let case = &CASES[2];
+    let result = run_case(
+        &mollusk,
+        program::id(),
+        case,
+        case.program_id_offset as u64 + 8,
+    );
+    match result {
+        ProgramResult::Failure(ref err) => assert_eq!(
+            err,
+            &ProgramError::Custom(6001),
+            "expected Custom(6001) (ProgramIdOffsetMismatch), got {err:?}",
+        ),
+        other => panic!("expected ProgramIdOffsetMismatch, got {other:?}"),
+    }
+}
@@ -0,0 +1,387 @@
+//! Official `Rent` vectors for `src/rent.zig` and the packages that size
+//! or charge accounts against it, all under `src/rent/` and loaded through
+//! the `rent_vectors` module. No Zig artifacts are needed.
+//!
+//! - `due_vectors.json`: `Rent::due`, the rent a non-exempt account owes
+//!   for `years_elapsed`, under the default `Rent`: balances at and just
+//!   below the exemption minimum, zero balances, and fractional
+//!   `years_elapsed` where the `f64` product is truncated to lamports.
+//! - `slots_per_year_vectors.json`: `GenesisConfig::slots_per_year` for a
+//!   given ticks-per-slot and ticks-per-second, the years elapsed after
+//!   whole epochs, each with an explicit relative `epsilon`, and two
+//!   `Rent::due` examples fed those years.
+//! - `standard_account_minimums_vectors.json`: the data length of the
+//!   nonce, stake, vote, lookup-table, token account and mint layouts,
+//!   read from their interface crates, with `Rent::minimum_balance` for
+//!   each.
+#![allow(deprecated)]
+
+mod common;
+
+use {
+    serde::{Deserialize, Serialize},
+    solana_clock::{DEFAULT_SLOTS_PER_EPOCH, DEFAULT_TICKS_PER_SECOND, DEFAULT_TICKS_PER_SLOT},
+    solana_program_pack::Pack,
+    solana_rent::{Rent, RentDue},
+    solana_time_utils::years_as_slots,
+    std::time::Duration,
+};
+
+const DUE_VECTORS_JSON: &str = include_str!("../../src/rent/due_vectors.json");
+const SLOTS_PER_YEAR_VECTORS_JSON: &str =
+    include_str!("../../src/rent/slots_per_year_vectors.json");
+const STANDARD_ACCOUNT_MINIMUMS_VECTORS_JSON: &str =
+    include_str!("../../src/rent/standard_account_minimums_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct RentDueTestVector {
+    name: String,
+    balance: u64,
+    data_len: usize,
+    years_elapsed: f64,
+    /// `"exempt"` or `"paying"`.
+    variant: String,
+    /// The `RentDue::Paying` amount.
+    amount: Option<u64>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct DueFixture {
+    lamports_per_byte_year: u64,
+    exemption_threshold: f64,
+    vectors: Vec<RentDueTestVector>,
+}
+
+fn vector(name: &str, balance: u64, data_len: usize, years_elapsed: f64) -> RentDueTestVector {
+    let (variant, amount) = match Rent::default().due(balance, data_len, years_elapsed) {
+        RentDue::Exempt => ("exempt", None),
+        RentDue::Paying(amount) => ("paying", Some(amount)),
+    };
+    RentDueTestVector {
+        name: name.to_string(),
+        balance,
+        data_len,
+        years_elapsed,
+        variant: variant.to_string(),
+        amount,
+    }
+}
+
+fn official_due_vectors() -> DueFixture {
+    let rent = Rent::default();
+    let token_account_minimum = rent.minimum_balance(165);
+    let mut vectors = vec![
+        vector("exempt_at_minimum", token_account_minimum, 165, 1.0),
+        vector("exempt_max_balance", u64::MAX, 10 * 1024 * 1024, 2.5),
+        vector("zero_elapsed", 0, 165, 0.0),
+    ];
+    for (label, years_elapsed) in [
+        ("half_year", 0.5),
+        ("one_year", 1.0),
+        ("two_and_a_half_years", 2.5),
+        ("one_third_year", 1.0 / 3.0),
+        ("one_epoch", 2.0 / 365.242_199),
+    ] {
+        vectors.push(vector(
+            &format!("zero_balance_no_data_{label}"),
+            0,
+            0,
+            years_elapsed,
+        ));
+        vectors.push(vector(
+            &format!("below_minimum_{label}"),
+            token_account_minimum - 1,
+            165,
+            years_elapsed,
+        ));
+        vectors.push(vector(
+            &format!("zero_balance_10mib_{label}"),
+            0,
+            10 * 1024 * 1024,
+            years_elapsed,
+        ));
+    }
+    DueFixture {
+        lamports_per_byte_year: rent.lamports_per_byte_year,
+        exemption_threshold: rent.exemption_threshold,
+        vectors,
+    }
+}
+
+#[test]
+fn fixture_matches_official_due_vectors() {
+    let fixture: DueFixture = serde_json::from_str(DUE_VECTORS_JSON).unwrap();
+    common::assert_vectors_match("due_vectors.json", &fixture, &official_due_vectors());
+}
+
+#[test]
+fn paying_amounts_truncate_the_f64_product() {
+    let fixture: DueFixture = serde_json::from_str(DUE_VECTORS_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let Some(amount) = vector.amount else {
+            assert_eq!(vector.variant, "exempt");
+            continue;
+        };
+        assert_eq!(vector.variant, "paying");
+        let lamports_per_year = fixture.lamports_per_byte_year * (128 + vector.data_len as u64);
+        let exact = lamports_per_year as f64 * vector.years_elapsed;
+        assert_eq!(amount, exact.trunc() as u64, "{}", vector.name);
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_due_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_due_vectors()).unwrap()
+    );
+}
+
+/// Relative tolerance for every `f64` in the slots-per-year fixture. The
+/// Zig side repeats the same operations in the same order, so this only
+/// absorbs libm or constant-folding differences.
+const EPSILON: f64 = 1e-12;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct SlotsPerYearTestVector {
+    ticks_per_slot: u64,
+    ticks_per_second: u64,
+    slots_per_epoch: u64,
+    expected_slots_per_year: f64,
+    epochs_elapsed: u64,
+    expected_years_elapsed: f64,
+    epsilon: f64,
+}
+
+/// `Rent::due` for an account short of its minimum after
+/// `slots_per_year[case]`'s elapsed epochs.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct DueAmountExample {
+    case: usize,
+    data_len: u64,
+    balance: u64,
+    minimum_balance: u64,
+    years_elapsed: f64,
+    due_amount: u64,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct SlotsPerYearFixture {
+    lamports_per_byte_year: u64,
+    slots_per_year: Vec<SlotsPerYearTestVector>,
+    due_amount: Vec<DueAmountExample>,
+}
+
+/// What `GenesisConfig::slots_per_year` computes: `PohConfig` stores the
+/// tick duration as whole microseconds, then `years_as_slots(1.0, ..)`.
+fn slots_per_year(ticks_per_slot: u64, ticks_per_second: u64) -> f64 {
+    let tick_duration = Duration::from_micros(1_000_000 / ticks_per_second);
+    years_as_slots(1.0, &tick_duration, ticks_per_slot)
+}
+
+fn slots_per_year_vector(
+    ticks_per_slot: u64,
+    ticks_per_second: u64,
+    slots_per_epoch: u64,
+    epochs_elapsed: u64,
+) -> SlotsPerYearTestVector {
+    let expected_slots_per_year = slots_per_year(ticks_per_slot, ticks_per_second);
+    SlotsPerYearTestVector {
+        ticks_per_slot,
+        ticks_per_second,
+        slots_per_epoch,
+        expected_slots_per_year,
+        epochs_elapsed,
+        // The bank sums whole-epoch slot counts before converting to years.
+        expected_years_elapsed: (epochs_elapsed * slots_per_epoch) as f64 / expected_slots_per_year,
+        epsilon: EPSILON,
+    }
+}
+
+fn official_slots_per_year_vectors() -> SlotsPerYearFixture {
+    let rent = Rent::default();
+    let slots_per_year = vec![
+        slots_per_year_vector(
+            DEFAULT_TICKS_PER_SLOT,
+            DEFAULT_TICKS_PER_SECOND,
+            DEFAULT_SLOTS_PER_EPOCH,
+            1,
+        ),
+        slots_per_year_vector(
+            DEFAULT_TICKS_PER_SLOT,
+            DEFAULT_TICKS_PER_SECOND,
+            DEFAULT_SLOTS_PER_EPOCH,
+            73,
+        ),
+        slots_per_year_vector(8, DEFAULT_TICKS_PER_SECOND, 8_192, 2),
+        slots_per_year_vector(4, 100, 32, 1),
+    ];
+
+    // One lamport short of the 165-byte token-account minimum, and an empty
+    // unfunded account.
+    let due_amount = [(0, 165, rent.minimum_balance(165) - 1), (1, 0, 0)]
+        .into_iter()
+        .map(|(case, data_len, balance)| {
+            let years_elapsed = slots_per_year[case].expected_years_elapsed;
+            let RentDue::Paying(due_amount) = rent.due(balance, data_len, years_elapsed) else {
+                panic!("{data_len}-byte account with {balance} lamports is exempt");
+            };
+            DueAmountExample {
+                case,
+                data_len: data_len as u64,
+                balance,
+                minimum_balance: rent.minimum_balance(data_len),
+                years_elapsed,
+                due_amount,
+            }
+        })
+        .collect();
+
+    SlotsPerYearFixture {
+        lamports_per_byte_year: rent.lamports_per_byte_year,
+        slots_per_year,
+        due_amount,
+    }
+}
+
+fn approx_eq(expected: f64, actual: f64, epsilon: f64) -> bool {
+    (expected - actual).abs() <= epsilon * expected.abs().max(actual.abs())
+}
+
+#[test]
+fn fixture_matches_official_slots_per_year_vectors() {
+    let fixture: SlotsPerYearFixture = serde_json::from_str(SLOTS_PER_YEAR_VECTORS_JSON).unwrap();
+    let official = official_slots_per_year_vectors();
+    assert_eq!(
+        fixture.lamports_per_byte_year,
+        official.lamports_per_byte_year
+    );
+    assert_eq!(fixture.slots_per_year.len(), official.slots_per_year.len());
+    for (recorded, official) in fixture.slots_per_year.iter().zip(&official.slots_per_year) {
+        assert!(approx_eq(
+            official.expected_slots_per_year,
+            recorded.expected_slots_per_year,
+            recorded.epsilon
+        ));
+        assert!(approx_eq(
+            official.expected_years_elapsed,
+            recorded.expected_years_elapsed,
+            recorded.epsilon
+        ));
+        assert_eq!(recorded.ticks_per_slot, official.ticks_per_slot);
+        assert_eq!(recorded.ticks_per_second, official.ticks_per_second);
+        assert_eq!(recorded.slots_per_epoch, official.slots_per_epoch);
+        assert_eq!(recorded.epochs_elapsed, official.epochs_elapsed);
+        assert_eq!(recorded.epsilon, official.epsilon);
+    }
+    assert_eq!(fixture.due_amount.len(), official.due_amount.len());
+    for (recorded, official) in fixture.due_amount.iter().zip(&official.due_amount) {
+        assert_eq!(recorded.case, official.case);
+        assert_eq!(recorded.data_len, official.data_len);
+        assert_eq!(recorded.balance, official.balance);
+        assert_eq!(recorded.minimum_balance, official.minimum_balance);
+        assert_eq!(recorded.due_amount, official.due_amount);
+    }
+}
+
+#[test]
+fn default_slots_per_year_matches_the_cluster_constants() {
+    let fixture: SlotsPerYearFixture = serde_json::from_str(SLOTS_PER_YEAR_VECTORS_JSON).unwrap();
+    let defaults = &fixture.slots_per_year[0];
+    // 400ms slots: one year is ~78.9M slots and ~182.6 default epochs.
+    assert_eq!(defaults.expected_slots_per_year as u64, 78_892_314);
+    assert!(approx_eq(
+        defaults.expected_years_elapsed * 365.242_199,
+        2.0,
+        1e-3
+    ));
+
+    let rent = Rent::default();
+    for example in &fixture.due_amount {
+        let case = &fixture.slots_per_year[example.case];
+        assert_eq!(example.years_elapsed, case.expected_years_elapsed);
+        assert!(example.balance < example.minimum_balance);
+        assert_eq!(
+            rent.due_amount(example.data_len as usize, example.years_elapsed),
+            example.due_amount
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_slots_per_year_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_slots_per_year_vectors()).unwrap()
+    );
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct StandardAccountTestVector {
+    /// Layout name the Zig side looks up.
+    name: String,
+    data_len: usize,
+    minimum_balance: u64,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct StandardAccountMinimumsFixture {
+    lamports_per_byte_year: u64,
+    exemption_threshold: f64,
+    vectors: Vec<StandardAccountTestVector>,
+}
+
+fn official_standard_account_minimums_vectors() -> StandardAccountMinimumsFixture {
+    let rent = Rent::default();
+    let vectors = [
+        ("nonce", solana_nonce::state::State::size()),
+        (
+            "stake",
+            solana_stake_interface::state::StakeStateV2::size_of(),
+        ),
+        (
+            "vote",
+            solana_vote_interface::state::VoteStateVersions::vote_state_size_of(true),
+        ),
+        (
+            "address_lookup_table_meta",
+            solana_address_lookup_table_interface::state::LOOKUP_TABLE_META_SIZE,
+        ),
+        ("token_account", spl_token_interface::state::Account::LEN),
+        ("token_mint", spl_token_interface::state::Mint::LEN),
+    ]
+    .into_iter()
+    .map(|(name, data_len)| StandardAccountTestVector {
+        name: name.to_string(),
+        data_len,
+        minimum_balance: rent.minimum_balance(data_len),
+    })
+    .collect();
+    StandardAccountMinimumsFixture {
+        lamports_per_byte_year: rent.lamports_per_byte_year,
+        exemption_threshold: rent.exemption_threshold,
+        vectors,
+    }
+}
+
+#[test]
+fn fixture_matches_official_standard_account_minimums_vectors() {
+    let fixture: StandardAccountMinimumsFixture =
+        serde_json::from_str(STANDARD_ACCOUNT_MINIMUMS_VECTORS_JSON).unwrap();
+    common::assert_vectors_match(
+        "standard_account_minimums_vectors.json",
+        &fixture,
+        &official_standard_account_minimums_vectors(),
+    );
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_standard_account_minimums_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_standard_account_minimums_vectors()).unwrap()
+    );
+}
@@ -0,0 +1,223 @@
+//! Self-contained secp256k1 precompile instructions with real signatures.
+//!
+//! Records `src/crypto/instructions/secp256k1_vectors.json`: instructions
+//! from `new_secp256k1_instruction_with_signature` over fixed secret keys
+//! and messages, plus a two-signature instruction packed the same way, each
+//! with the full data and the offsets, eth address, signature, recovery id
+//! and message it carries. No Zig artifacts are needed.
+
+mod common;
+
+use {
+    k256::ecdsa::{RecoveryId, Signature, SigningKey, VerifyingKey},
+    serde::{Deserialize, Serialize},
+    solana_secp256k1_program::{
+        eth_address_from_pubkey, new_secp256k1_instruction_with_signature, sign_message,
+        SecpSignatureOffsets, DATA_START, HASHED_PUBKEY_SERIALIZED_SIZE,
+        SIGNATURE_OFFSETS_SERIALIZED_SIZE, SIGNATURE_SERIALIZED_SIZE,
+    },
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/crypto/instructions/secp256k1_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Secp256k1Signer {
+    secret_key: [u8; 32],
+    /// Uncompressed `x || y`, without the `0x04` prefix.
+    public_key: Vec<u8>,
+    eth_address: [u8; 20],
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    recovery_id: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct OffsetsFields {
+    signature_offset: u16,
+    signature_instruction_index: u8,
+    eth_address_offset: u16,
+    eth_address_instruction_index: u8,
+    message_data_offset: u16,
+    message_data_size: u16,
+    message_instruction_index: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Secp256k1InstructionTestVector {
+    name: String,
+    signers: Vec<Secp256k1Signer>,
+    offsets: Vec<OffsetsFields>,
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<Secp256k1InstructionTestVector>,
+}
+
+fn signer(secret_key: [u8; 32], message: &[u8]) -> Secp256k1Signer {
+    let signing_key = SigningKey::from_slice(&secret_key).unwrap();
+    let encoded = signing_key.verifying_key().to_encoded_point(false);
+    let public_key: [u8; 64] = encoded.as_bytes()[1..].try_into().unwrap();
+    let (signature, recovery_id) = sign_message(&secret_key, message).unwrap();
+    Secp256k1Signer {
+        secret_key,
+        public_key: public_key.to_vec(),
+        eth_address: eth_address_from_pubkey(&public_key),
+        message: message.to_vec(),
+        signature: signature.to_vec(),
+        recovery_id,
+    }
+}
+
+fn read_offsets(data: &[u8]) -> Vec<OffsetsFields> {
+    data[1..]
+        .chunks_exact(SIGNATURE_OFFSETS_SERIALIZED_SIZE)
+        .take(usize::from(data[0]))
+        .map(|chunk| {
+            let entry: SecpSignatureOffsets = bincode::deserialize(chunk).unwrap();
+            OffsetsFields {
+                signature_offset: entry.signature_offset,
+                signature_instruction_index: entry.signature_instruction_index,
+                eth_address_offset: entry.eth_address_offset,
+                eth_address_instruction_index: entry.eth_address_instruction_index,
+                message_data_offset: entry.message_data_offset,
+                message_data_size: entry.message_data_size,
+                message_instruction_index: entry.message_instruction_index,
+            }
+        })
+        .collect()
+}
+
+fn single(name: &str, secret_key: [u8; 32], message: &[u8]) -> Secp256k1InstructionTestVector {
+    let signer = signer(secret_key, message);
+    let data = new_secp256k1_instruction_with_signature(
+        &signer.message,
+        &signer.signature.clone().try_into().unwrap(),
+        signer.recovery_id,
+        &signer.eth_address,
+    )
+    .data;
+    Secp256k1InstructionTestVector {
+        name: name.to_string(),
+        offsets: read_offsets(&data),
+        signers: vec![signer],
+        data,
+    }
+}
+
+/// The SDK only builds single-signature instructions. This lays each
+/// signer out as `new_secp256k1_instruction_with_signature` does, one
+/// `eth_address || signature || recovery_id || message` block after
+/// another behind the offsets table.
+fn multi(name: &str, inputs: &[([u8; 32], &[u8])]) -> Secp256k1InstructionTestVector {
+    let signers: Vec<Secp256k1Signer> = inputs
+        .iter()
+        .map(|(secret_key, message)| signer(*secret_key, message))
+        .collect();
+
+    let mut tail = Vec::new();
+    let mut offsets = Vec::new();
+    let tail_start = 1 + signers.len() * SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+    for signer in &signers {
+        let eth_address_offset = tail_start + tail.len();
+        let signature_offset = eth_address_offset + HASHED_PUBKEY_SERIALIZED_SIZE;
+        let message_data_offset = signature_offset + SIGNATURE_SERIALIZED_SIZE + 1;
+        offsets.push(SecpSignatureOffsets {
+            signature_offset: signature_offset as u16,
+            signature_instruction_index: 0,
+            eth_address_offset: eth_address_offset as u16,
+            eth_address_instruction_index: 0,
+            message_data_offset: message_data_offset as u16,
+            message_data_size: signer.message.len() as u16,
+            message_instruction_index: 0,
+        });
+        tail.extend_from_slice(&signer.eth_address);
+        tail.extend_from_slice(&signer.signature);
+        tail.push(signer.recovery_id);
+        tail.extend_from_slice(&signer.message);
+    }
+
+    let mut data = vec![signers.len() as u8];
+    for entry in &offsets {
+        data.extend_from_slice(&bincode::serialize(entry).unwrap());
+    }
+    data.extend_from_slice(&tail);
+    Secp256k1InstructionTestVector {
+        name: name.to_string(),
+        offsets: read_offsets(&data),
+        signers,
+        data,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        vectors: vec![
+            single("hello", [1; 32], b"hello"),
+            single("empty_message", [2; 32], b""),
+            single("long_message", [3; 32], &[0x5a; 200]),
+            multi(
+                "two_messages",
+                &[
+                    ([1; 32], b"first message"),
+                    ([2; 32], b"second, longer message"),
+                ],
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_secp256k1_instruction_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    common::assert_vectors_match("secp256k1_vectors.json", &fixture, &official_fixture());
+}
+
+#[test]
+fn offsets_recover_each_eth_address() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        assert_eq!(usize::from(vector.data[0]), vector.signers.len());
+        assert_eq!(vector.offsets.len(), vector.signers.len());
+        if vector.signers.len() == 1 {
+            assert_eq!(vector.offsets[0].eth_address_offset, DATA_START as u16);
+        }
+        for (entry, signer) in vector.offsets.iter().zip(&vector.signers) {
+            let data = &vector.data;
+            let signature = &data[usize::from(entry.signature_offset)..][..64];
+            let recovery_id = data[usize::from(entry.signature_offset) + 64];
+            let eth_address = &data[usize::from(entry.eth_address_offset)..][..20];
+            let message = &data[usize::from(entry.message_data_offset)..]
+                [..usize::from(entry.message_data_size)];
+            assert_eq!(signature, signer.signature);
+            assert_eq!(recovery_id, signer.recovery_id);
+            assert_eq!(eth_address, signer.eth_address);
+            assert_eq!(message, signer.message);
+
+            let recovered = VerifyingKey::recover_from_prehash(
+                solana_keccak_hasher::hash(message).as_bytes(),
+                &Signature::from_slice(signature).unwrap(),
+                RecoveryId::from_byte(recovery_id).unwrap(),
+            )
+            .unwrap();
+            let encoded = recovered.to_encoded_point(false);
+            let public_key: [u8; 64] = encoded.as_bytes()[1..].try_into().unwrap();
+            assert_eq!(
+                eth_address_from_pubkey(&public_key),
+                signer.eth_address,
+                "{}",
+                vector.name
+            );
+        }
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_secp256k1_instruction_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,171 @@
+//! Self-contained secp256r1 precompile instructions with real signatures.
+//!
+//! Records `src/crypto/instructions/secp256r1_vectors.json`: instructions
+//! from `new_secp256r1_instruction_with_signature` over fixed P-256 keys and
+//! messages, with the compressed public key, the raw and low-S signatures,
+//! and the offsets. The precompile rejects high-S signatures, so some
+//! messages are picked because their raw signature needs normalizing. No
+//! Zig artifacts are needed.
+
+mod common;
+
+use {
+    p256::ecdsa::{signature::Signer, Signature, SigningKey},
+    serde::{Deserialize, Serialize},
+    solana_secp256r1_program::{
+        new_secp256r1_instruction_with_signature, DATA_START, SECP256R1_HALF_ORDER,
+        SIGNATURE_OFFSETS_START,
+    },
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/crypto/instructions/secp256r1_vectors.json");
+
+/// solana-sdk 2.1.1 is the first release that pulls in
+/// `solana-secp256r1-program`.
+const SECP256R1_SINCE_SDK: &str = "2.1.1";
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct OffsetsFields {
+    signature_offset: u16,
+    signature_instruction_index: u16,
+    public_key_offset: u16,
+    public_key_instruction_index: u16,
+    message_data_offset: u16,
+    message_data_size: u16,
+    message_instruction_index: u16,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Secp256r1InstructionTestVector {
+    name: String,
+    secret_key: [u8; 32],
+    /// SEC1 compressed.
+    public_key: Vec<u8>,
+    message: Vec<u8>,
+    /// RFC 6979 `r || s` before low-S normalization.
+    raw_signature: Vec<u8>,
+    /// `r || min(s, n - s)`, the form the precompile accepts.
+    signature: Vec<u8>,
+    high_s_normalized: bool,
+    offsets: OffsetsFields,
+    data: Vec<u8>,
+    /// First solana-sdk release with the secp256r1 program.
+    since_sdk: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    half_order: [u8; 32],
+    vectors: Vec<Secp256r1InstructionTestVector>,
+}
+
+/// `Secp256r1SignatureOffsets` is `repr(C)`: seven little-endian `u16`s.
+fn read_offsets(bytes: &[u8]) -> OffsetsFields {
+    let field = |i: usize| u16::from_le_bytes([bytes[2 * i], bytes[2 * i + 1]]);
+    OffsetsFields {
+        signature_offset: field(0),
+        signature_instruction_index: field(1),
+        public_key_offset: field(2),
+        public_key_instruction_index: field(3),
+        message_data_offset: field(4),
+        message_data_size: field(5),
+        message_instruction_index: field(6),
+    }
+}
+
+fn vector(name: &str, secret_key: [u8; 32], message: &[u8]) -> Secp256r1InstructionTestVector {
+    let signing_key = SigningKey::from_slice(&secret_key).unwrap();
+    let public_key: [u8; 33] = signing_key
+        .verifying_key()
+        .to_encoded_point(true)
+        .as_bytes()
+        .try_into()
+        .unwrap();
+    let raw: Signature = signing_key.sign(message);
+    let normalized = raw.normalize_s();
+    let signature: [u8; 64] = normalized.unwrap_or(raw).to_bytes().into();
+    let data = new_secp256r1_instruction_with_signature(message, &signature, &public_key).data;
+    let offsets = read_offsets(&data[SIGNATURE_OFFSETS_START..DATA_START]);
+    Secp256r1InstructionTestVector {
+        name: name.to_string(),
+        secret_key,
+        public_key: public_key.to_vec(),
+        message: message.to_vec(),
+        raw_signature: raw.to_bytes().to_vec(),
+        signature: signature.to_vec(),
+        high_s_normalized: normalized.is_some(),
+        offsets,
+        data,
+        since_sdk: Some(SECP256R1_SINCE_SDK.to_string()),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        half_order: SECP256R1_HALF_ORDER,
+        vectors: vec![
+            vector("hello", [1; 32], b"hello"),
+            vector("empty_message", [2; 32], b""),
+            vector("long_message", [3; 32], &[0x5a; 200]),
+            vector("hello_already_low_s", [5; 32], b"hello"),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_secp256r1_instruction_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    common::assert_vectors_match("secp256r1_vectors.json", &fixture, &official_fixture());
+}
+
+#[test]
+fn signatures_are_low_s_and_verify() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    assert!(fixture
+        .vectors
+        .iter()
+        .any(|vector| vector.high_s_normalized));
+    assert!(fixture
+        .vectors
+        .iter()
+        .any(|vector| !vector.high_s_normalized));
+    for vector in &fixture.vectors {
+        let (r, s) = vector.signature.split_at(32);
+        assert_eq!(r, &vector.raw_signature[..32]);
+        assert!(s <= &fixture.half_order[..], "{}", vector.name);
+        assert_eq!(
+            vector.high_s_normalized,
+            vector.raw_signature[32..] > fixture.half_order[..]
+        );
+
+        let verifying_key = p256::ecdsa::VerifyingKey::from_sec1_bytes(&vector.public_key).unwrap();
+        let signature = Signature::from_slice(&vector.signature).unwrap();
+        p256::ecdsa::signature::Verifier::verify(&verifying_key, &vector.message, &signature)
+            .unwrap();
+
+        let data = &vector.data;
+        let offsets = &vector.offsets;
+        assert_eq!(data[..SIGNATURE_OFFSETS_START], [1, 0]);
+        assert_eq!(
+            data[usize::from(offsets.public_key_offset)..][..33],
+            vector.public_key
+        );
+        assert_eq!(
+            data[usize::from(offsets.signature_offset)..][..64],
+            vector.signature
+        );
+        assert_eq!(
+            data[usize::from(offsets.message_data_offset)..],
+            vector.message
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_secp256r1_instruction_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
@@ -0,0 +1,103 @@
+//! `solana_sha256_hasher::hashv` over several input slices.
+//!
+//! Records `src/crypto/sha256_hashv_vectors.json`: two to five slices with
+//! empty ones interleaved, a split across the 64-byte block boundary, the
+//! PDA preimage layout, and `["ab", "c"]` next to `["abc"]`, since `hashv`
+//! hashes the concatenation. No Zig artifacts are needed.
+
+mod common;
+
+use {
+    serde::{Deserialize, Serialize},
+    solana_sha256_hasher::{hash, hashv},
+};
+
+const FIXTURE_JSON: &str = include_str!("../../src/crypto/sha256_hashv_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Sha256HashvTestVector {
+    name: String,
+    inputs: Vec<Vec<u8>>,
+    hash: [u8; 32],
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<Sha256HashvTestVector>,
+}
+
+fn vector(name: &str, inputs: &[&[u8]]) -> Sha256HashvTestVector {
+    Sha256HashvTestVector {
+        name: name.to_string(),
+        inputs: inputs.iter().map(|input| input.to_vec()).collect(),
+        hash: hashv(inputs).to_bytes(),
+    }
+}
+
+fn official_fixture() -> Fixture {
+    Fixture {
+        vectors: vec![
+            vector("abc", &[b"abc"]),
+            vector("ab_c", &[b"ab", b"c"]),
+            vector("a_b_c", &[b"a", b"b", b"c"]),
+            vector("all_empty", &[b"", b"", b""]),
+            vector("empty_then_hello", &[b"", b"hello"]),
+            vector(
+                "hello_world_empty_interleaved",
+                &[b"hello", b"", b" ", b"", b"world"],
+            ),
+            vector("across_block_boundary", &[&[0x61; 63], &[0x62; 2]]),
+            vector("two_full_blocks", &[&[0x63; 64], &[0x64; 64]]),
+            vector(
+                "pda_preimage",
+                &[
+                    b"vault",
+                    &[0x11; 32],
+                    &[254],
+                    &[0x22; 32],
+                    b"ProgramDerivedAddress",
+                ],
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_sha256_hashv_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    common::assert_vectors_match("sha256_hashv_vectors.json", &fixture, &official_fixture());
+}
+
+#[test]
+fn hashv_is_hash_of_the_concatenation() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let by_name = |name: &str| {
+        fixture
+            .vectors
+            .iter()
+            .find(|vector| vector.name == name)
+            .unwrap()
+            .hash
+    };
+    assert_eq!(by_name("ab_c"), by_name("abc"));
+    assert_eq!(by_name("a_b_c"), by_name("abc"));
+
+    for vector in &fixture.vectors {
+        assert!((1..=5).contains(&vector.inputs.len()), "{}", vector.name);
+        assert_eq!(
+            hash(&vector.inputs.concat()).to_bytes(),
+            vector.hash,
+            "{}",
+            vector.name
+        );
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_sha256_hashv_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
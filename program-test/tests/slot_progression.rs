@@ -0,0 +1,159 @@
+//! Deterministic slot/blockhash progression in `ScenarioRunner`.
+//!
+//! Replays `packages/solana-system/src/official_slot_progression_vectors.json`
+//! through the harness: each `advance_slot` must produce the fixture's
+//! blockhash, the SlotHashes / Clock sysvar accounts in the scenario map must
+//! follow, and the durable nonce derived from the current blockhash must
+//! match what `AdvanceNonceAccount` would store. No Zig artifacts are needed
+//! for that part.
+//!
+//! It then replays the chain through examples/slot_hashes.zig, which reads
+//! SlotHashes and RecentBlockhashes through the SDK and returns the entry
+//! count and newest entry of each; after every `advance_slot` those must be
+//! the slot and blockhash just finished.
+
+mod common;
+
+use {
+    common::{ScenarioRunner, LAMPORTS_PER_SIGNATURE},
+    mollusk_svm::Mollusk,
+    serde::Deserialize,
+    solana_account::Account,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_nonce::state::DurableNonce,
+    solana_sdk_ids::{bpf_loader_upgradeable, sysvar},
+};
+
+// Must match the `PROGRAM_ID` used by the other single-program examples.
+mod program {
+    solana_pubkey::declare_id!("Zigc1Hc97L8Pebma74jDzYiyoUvdxxcj7Gxppg9VRxK");
+}
+
+const MAX_SLOT_HASHES: u64 = 512;
+const MAX_RECENT_BLOCKHASHES: u64 = 150;
+
+const FIXTURE_JSON: &str =
+    include_str!("../../packages/solana-system/src/official_slot_progression_vectors.json");
+
+#[derive(Deserialize)]
+struct SlotStep {
+    slot: u64,
+    blockhash: [u8; 32],
+    durable_nonce: [u8; 32],
+}
+
+#[derive(Deserialize)]
+struct Fixture {
+    seed: [u8; 32],
+    start_slot: u64,
+    steps: Vec<SlotStep>,
+    slot_hashes_data: Vec<u8>,
+}
+
+#[test]
+fn test_slot_progression_matches_fixture() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let mollusk = Mollusk::default();
+    let accounts = vec![
+        mollusk.sysvars.keyed_account_for_clock_sysvar(),
+        mollusk.sysvars.keyed_account_for_slot_hashes_sysvar(),
+    ];
+    let mut runner = ScenarioRunner::new(mollusk, accounts);
+    runner.start_slot_progression(fixture.seed, fixture.start_slot);
+
+    for step in &fixture.steps {
+        let (slot, hash) = runner.advance_slot();
+        assert_eq!(slot, step.slot);
+        assert_eq!(hash.to_bytes(), step.blockhash, "slot {slot}");
+        assert_eq!(runner.recent_blockhash(), hash);
+        assert_eq!(
+            DurableNonce::from_blockhash(&hash).as_hash().to_bytes(),
+            step.durable_nonce,
+            "slot {slot}"
+        );
+        runner.checkpoint(&format!("slot-{slot}"));
+    }
+
+    assert_eq!(
+        runner.account(&sysvar::slot_hashes::id()).data,
+        fixture.slot_hashes_data
+    );
+    // `Clock.slot` is the first field of the sysvar.
+    let clock = &runner.account(&sysvar::clock::id()).data;
+    let current_slot = u64::from_le_bytes(clock[..8].try_into().unwrap());
+    assert_eq!(
+        current_slot,
+        fixture.start_slot + fixture.steps.len() as u64
+    );
+}
+
+/// The return data of examples/slot_hashes.zig.
+#[derive(Debug, PartialEq)]
+struct SysvarView {
+    slot_hashes_len: u64,
+    newest_slot: u64,
+    newest_slot_hash: [u8; 32],
+    recent_blockhashes_len: u64,
+    newest_blockhash: [u8; 32],
+    lamports_per_signature: u64,
+}
+
+impl SysvarView {
+    fn parse(data: &[u8]) -> Self {
+        assert_eq!(data.len(), 96);
+        let u64_at =
+            |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        Self {
+            slot_hashes_len: u64_at(0),
+            newest_slot: u64_at(8),
+            newest_slot_hash: data[16..48].try_into().unwrap(),
+            recent_blockhashes_len: u64_at(48),
+            newest_blockhash: data[56..88].try_into().unwrap(),
+            lamports_per_signature: u64_at(88),
+        }
+    }
+}
+
+#[test]
+fn test_slot_hashes_example_reads_each_slot() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    let mut mollusk = Mollusk::default();
+    mollusk.add_program(
+        &program::id(),
+        "zig-out/lib/example_slot_hashes",
+        &bpf_loader_upgradeable::id(),
+    );
+    // `start_slot_progression` fills in both sysvar accounts.
+    let accounts = vec![
+        (sysvar::slot_hashes::id(), Account::default()),
+        (sysvar::recent_blockhashes::id(), Account::default()),
+    ];
+    let mut runner = ScenarioRunner::new(mollusk, accounts);
+    runner.start_slot_progression(fixture.seed, fixture.start_slot);
+    let instruction = Instruction {
+        program_id: program::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(sysvar::slot_hashes::id(), false),
+            AccountMeta::new_readonly(sysvar::recent_blockhashes::id(), false),
+        ],
+        data: vec![],
+    };
+
+    for (finished, step) in (1..).zip(&fixture.steps) {
+        runner.advance_slot();
+        let result = runner.process(std::slice::from_ref(&instruction));
+        assert_eq!(
+            SysvarView::parse(&result.return_data),
+            SysvarView {
+                slot_hashes_len: finished.min(MAX_SLOT_HASHES),
+                newest_slot: step.slot,
+                newest_slot_hash: step.blockhash,
+                recent_blockhashes_len: finished.min(MAX_RECENT_BLOCKHASHES),
+                newest_blockhash: step.blockhash,
+                lamports_per_signature: LAMPORTS_PER_SIGNATURE,
+            },
+            "slot {}",
+            step.slot
+        );
+    }
+}
@@ -0,0 +1,193 @@
+//! Layout of the `Sysvar1nstructions` account.
+//!
+//! Records `src/sysvar_instructions/instructions_sysvar_vectors.json`: the
+//! account data `construct_instructions_data` builds for a transaction's
+//! instructions, with the current index stored the way the runtime does
+//! before invoking each one. Every instruction's offset, program id, metas,
+//! and data are listed so `sol.sysvar_instructions.deserialize` can be
+//! checked against them. No Zig artifacts are needed.
+
+mod common;
+
+use {
+    serde::{Deserialize, Serialize},
+    solana_compute_budget_interface::ComputeBudgetInstruction,
+    solana_instruction::{BorrowedAccountMeta, BorrowedInstruction, Instruction},
+    solana_instructions_sysvar::{construct_instructions_data, store_current_index_checked},
+    solana_pubkey::Pubkey,
+};
+
+const FIXTURE_JSON: &str =
+    include_str!("../../src/sysvar_instructions/instructions_sysvar_vectors.json");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AccountMetaVector {
+    pubkey: [u8; 32],
+    is_signer: bool,
+    is_writable: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct SysvarInstructionVector {
+    /// Entry `i` of the `u16` offset table.
+    offset: u16,
+    program_id: [u8; 32],
+    accounts: Vec<AccountMetaVector>,
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct InstructionsSysvarTestVector {
+    name: String,
+    current_index: u16,
+    instructions: Vec<SysvarInstructionVector>,
+    /// `u16` count, `u16` offsets, the instructions, then the `u16`
+    /// current index.
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fixture {
+    vectors: Vec<InstructionsSysvarTestVector>,
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::from([byte; 32])
+}
+
+fn borrow(instruction: &Instruction) -> BorrowedInstruction<'_> {
+    BorrowedInstruction {
+        program_id: &instruction.program_id,
+        accounts: instruction
+            .accounts
+            .iter()
+            .map(|meta| BorrowedAccountMeta {
+                pubkey: &meta.pubkey,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        data: &instruction.data,
+    }
+}
+
+fn vector(
+    name: &str,
+    instructions: &[Instruction],
+    current_index: u16,
+) -> InstructionsSysvarTestVector {
+    let borrowed: Vec<_> = instructions.iter().map(borrow).collect();
+    let mut data = construct_instructions_data(&borrowed);
+    store_current_index_checked(&mut data, current_index).unwrap();
+
+    InstructionsSysvarTestVector {
+        name: name.to_string(),
+        current_index,
+        instructions: instructions
+            .iter()
+            .enumerate()
+            .map(|(i, instruction)| SysvarInstructionVector {
+                offset: u16::from_le_bytes([data[2 + 2 * i], data[3 + 2 * i]]),
+                program_id: instruction.program_id.to_bytes(),
+                accounts: instruction
+                    .accounts
+                    .iter()
+                    .map(|meta| AccountMetaVector {
+                        pubkey: meta.pubkey.to_bytes(),
+                        is_signer: meta.is_signer,
+                        is_writable: meta.is_writable,
+                    })
+                    .collect(),
+                data: instruction.data.clone(),
+            })
+            .collect(),
+        data,
+    }
+}
+
+fn official_fixture() -> Fixture {
+    let payer = key(0x10);
+    let recipient = key(0x20);
+    let compute_budget = ComputeBudgetInstruction::set_compute_unit_limit(200_000);
+    let transfer = solana_system_interface::instruction::transfer(&payer, &recipient, 1_000_000);
+    // The precompile only needs well-formed bytes here; nothing verifies
+    // the signature.
+    let ed25519 = solana_ed25519_program::new_ed25519_instruction_with_signature(
+        b"instructions sysvar",
+        &[0x5a; 64],
+        &[0x22; 32],
+    );
+
+    Fixture {
+        vectors: vec![
+            vector("transfer_only", std::slice::from_ref(&transfer), 0),
+            // The program reading the sysvar runs after the precompile.
+            vector(
+                "compute_budget_transfer_ed25519",
+                &[compute_budget, transfer, ed25519],
+                1,
+            ),
+        ],
+    }
+}
+
+#[test]
+fn fixture_matches_official_instructions_sysvar_vectors() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    common::assert_vectors_match(
+        "instructions_sysvar_vectors.json",
+        &fixture,
+        &official_fixture(),
+    );
+}
+
+#[test]
+fn offsets_point_at_each_serialized_instruction() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE_JSON).unwrap();
+    for vector in &fixture.vectors {
+        let data = &vector.data;
+        assert_eq!(
+            u16::from_le_bytes([data[0], data[1]]) as usize,
+            vector.instructions.len()
+        );
+        let mut expected_offset = 2 + 2 * vector.instructions.len();
+        for instruction in &vector.instructions {
+            assert_eq!(
+                instruction.offset as usize, expected_offset,
+                "{}",
+                vector.name
+            );
+            let mut bytes = &data[expected_offset..];
+            assert_eq!(
+                u16::from_le_bytes([bytes[0], bytes[1]]) as usize,
+                instruction.accounts.len()
+            );
+            bytes = &bytes[2..];
+            for meta in &instruction.accounts {
+                let flags = u8::from(meta.is_signer) | (u8::from(meta.is_writable) << 1);
+                assert_eq!(bytes[0], flags);
+                assert_eq!(bytes[1..33], meta.pubkey);
+                bytes = &bytes[33..];
+            }
+            assert_eq!(bytes[..32], instruction.program_id);
+            assert_eq!(
+                u16::from_le_bytes([bytes[32], bytes[33]]) as usize,
+                instruction.data.len()
+            );
+            assert_eq!(bytes[34..34 + instruction.data.len()], instruction.data);
+            expected_offset +=
+                2 + 33 * instruction.accounts.len() + 32 + 2 + instruction.data.len();
+        }
+        assert_eq!(data.len(), expected_offset + 2);
+        assert_eq!(data[expected_offset..], vector.current_index.to_le_bytes());
+    }
+}
+
+#[test]
+#[ignore = "prints the regenerated fixture"]
+fn print_official_instructions_sysvar_vectors() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&official_fixture()).unwrap()
+    );
+}
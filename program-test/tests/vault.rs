@@ -0,0 +1,155 @@
+//! Integration test for examples/vault.zig.
+//!
+//! Runs the full lamport round-trip as one `ScenarioRunner` scenario:
+//! Initialize (PDA created rent-exempt via System CPI) → Deposit
+//! (System transfer into the vault) → Withdraw (direct lamport move to a
+//! recipient), checking the stored `balance`, rent exemption, and total
+//! lamport conservation after every step.
+
+mod common;
+
+use {
+    common::ScenarioRunner,
+    mollusk_svm::{program::keyed_account_for_system_program, Mollusk},
+    solana_account::Account,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+    solana_sdk_ids::{bpf_loader_upgradeable, system_program},
+    std::path::Path,
+};
+
+// Must match `examples/vault.zig`'s `PROGRAM_ID` constant.
+mod program {
+    solana_pubkey::declare_id!("Zigc1Hc97L8Pebma74jDzYiyoUvdxxcj7Gxppg9VRxK");
+}
+
+/// Account layout from `examples/vault.zig::VaultState`.
+///   - discriminator: [8]u8
+///   - authority:     [32]u8
+///   - balance:       u64
+///   - bump:          u8
+///   - _pad:          [7]u8
+const VAULT_STATE_SIZE: usize = 8 + 32 + 8 + 1 + 7;
+const BALANCE_OFFSET: usize = 8 + 32;
+
+fn setup() -> (Mollusk, Pubkey, Pubkey, u8) {
+    let mut mollusk = Mollusk::default();
+    mollusk.add_program(
+        &program::id(),
+        "zig-out/lib/example_vault",
+        &bpf_loader_upgradeable::id(),
+    );
+    let authority = Pubkey::new_from_array([0x11; 32]);
+    let (vault, bump) =
+        Pubkey::find_program_address(&[b"vault", authority.as_ref()], &program::id());
+    (mollusk, authority, vault, bump)
+}
+
+fn system_account(lamports: u64) -> Account {
+    Account {
+        lamports,
+        data: vec![],
+        owner: system_program::id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn build_initialize_ix(authority: Pubkey, vault: Pubkey, bump: u8) -> Instruction {
+    Instruction {
+        program_id: program::id(),
+        accounts: vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: vec![0u8, bump],
+    }
+}
+
+fn build_deposit_ix(payer: Pubkey, vault: Pubkey, amount: u64) -> Instruction {
+    let mut data = vec![1u8];
+    data.extend_from_slice(&amount.to_le_bytes());
+    Instruction {
+        program_id: program::id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+fn build_withdraw_ix(
+    authority: Pubkey,
+    vault: Pubkey,
+    recipient: Pubkey,
+    amount: u64,
+) -> Instruction {
+    let mut data = vec![2u8];
+    data.extend_from_slice(&amount.to_le_bytes());
+    Instruction {
+        program_id: program::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(vault, false),
+            AccountMeta::new(recipient, false),
+        ],
+        data,
+    }
+}
+
+#[test]
+fn test_vault_deposit_withdraw_scenario() {
+    let (mollusk, authority, vault, bump) = setup();
+    let recipient = Pubkey::new_from_array([0x33; 32]);
+    let (system_pid, system_program_account) = keyed_account_for_system_program();
+    let balance = |runner: &ScenarioRunner| -> u64 {
+        let data = &runner.account(&vault).data;
+        u64::from_le_bytes(data[BALANCE_OFFSET..BALANCE_OFFSET + 8].try_into().unwrap())
+    };
+
+    let mut runner = ScenarioRunner::new(
+        mollusk,
+        vec![
+            (authority, system_account(100_000_000)),
+            (vault, system_account(0)),
+            (recipient, system_account(5_000_000)),
+            (system_pid, system_program_account),
+        ],
+    );
+
+    runner.process(&[build_initialize_ix(authority, vault, bump)]);
+    runner.checkpoint("initialized");
+    assert_eq!(runner.account(&vault).owner, program::id());
+    assert_eq!(runner.account(&vault).data.len(), VAULT_STATE_SIZE);
+    assert_eq!(balance(&runner), 0);
+    runner.assert_rent_exempt(&vault);
+    let rent_reserve = runner.account(&vault).lamports;
+
+    runner.process(&[build_deposit_ix(authority, vault, 1_000_000)]);
+    runner.checkpoint("deposited");
+    assert_eq!(balance(&runner), 1_000_000);
+    assert_eq!(runner.account(&vault).lamports, rent_reserve + 1_000_000);
+    runner.assert_rent_exempt(&vault);
+
+    runner.process(&[build_withdraw_ix(authority, vault, recipient, 400_000)]);
+    runner.checkpoint("withdrawn");
+    assert_eq!(balance(&runner), 600_000);
+    assert_eq!(runner.account(&vault).lamports, rent_reserve + 600_000);
+    assert_eq!(runner.account(&recipient).lamports, 5_400_000);
+    runner.assert_rent_exempt(&vault);
+    runner.assert_rent_exempt(&recipient);
+
+    runner.assert_lamports_conserved();
+    let names: Vec<&str> = runner
+        .checkpoints()
+        .iter()
+        .map(|c| c.name.as_str())
+        .collect();
+    assert_eq!(names, ["initial", "initialized", "deposited", "withdrawn"]);
+    runner.dump_checkpoints(
+        Path::new(env!("CARGO_TARGET_TMPDIR")).join("vault_deposit_withdraw_checkpoints.json"),
+    );
+}
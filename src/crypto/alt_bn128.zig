@@ -35,10 +35,17 @@
 //! native ark-bn254 code uses little-endian. Pick the variant
 //! matching your serialization. The LE variants save a few CU per
 //! call at the syscall boundary.
+//!
+//! ### Host
+//!
+//! Off-chain, G1 addition, G1 multiplication and pairing run through a
+//! pure-Zig fallback (`alt_bn128/host.zig`) that returns the bytes the
+//! syscall would. G1 subtraction returns `error.Unexpected` on host.
 
 const std = @import("std");
 const builtin = @import("builtin");
 const program_error = @import("../program_error/root.zig");
+const host = @import("alt_bn128/host.zig");
 
 const ProgramError = program_error.ProgramError;
 
@@ -101,7 +108,7 @@ extern fn sol_alt_bn128_group_op(
 ) callconv(.c) u64;
 
 fn syscall(op: u64, input: []const u8, out: []u8) Error!void {
-    if (comptime !is_solana) return error.Unexpected;
+    if (comptime !is_solana) return hostGroupOp(op, input, out);
     const rc = sol_alt_bn128_group_op(op, input.ptr, input.len, out.ptr);
     return switch (rc) {
         0 => {},
@@ -112,6 +119,28 @@ fn syscall(op: u64, input: []const u8, out: []u8) Error!void {
     };
 }
 
+// Host fallback, following the non-Solana path of `solana_bn254`. Short
+// big-endian input is zero-padded the way the runtime pads it.
+// Subtraction has no official host implementation to follow, so it stays
+// `Unexpected`.
+fn hostGroupOp(op: u64, input: []const u8, out: []u8) Error!void {
+    const endian: std.builtin.Endian = if (op & LE_FLAG != 0) .little else .big;
+    switch (op & ~LE_FLAG) {
+        G1_ADD_BE => {
+            var padded: [G1_ADDITION_INPUT_SIZE]u8 = .{0} ** G1_ADDITION_INPUT_SIZE;
+            @memcpy(padded[0..input.len], input);
+            try host.g1Addition(&padded, endian, out[0..G1_POINT_SIZE]);
+        },
+        G1_MUL_BE => {
+            var padded: [G1_MULTIPLICATION_INPUT_SIZE]u8 = .{0} ** G1_MULTIPLICATION_INPUT_SIZE;
+            @memcpy(padded[0..input.len], input);
+            try host.g1Multiplication(&padded, endian, out[0..G1_POINT_SIZE]);
+        },
+        PAIRING_BE => try host.pairing(input, endian, out[0..PAIRING_OUTPUT_SIZE]),
+        else => return error.Unexpected,
+    }
+}
+
 // =============================================================================
 // G1 addition
 // =============================================================================
@@ -218,6 +247,10 @@ pub fn errorToProgramError(err: Error) ProgramError {
 
 const testing = std.testing;
 
+test {
+    _ = host;
+}
+
 test "alt_bn128: size constants" {
     try testing.expectEqual(@as(usize, 64), G1_POINT_SIZE);
     try testing.expectEqual(@as(usize, 128), G2_POINT_SIZE);
@@ -239,10 +272,20 @@ test "alt_bn128: pairing rejects unaligned input" {
     try testing.expectError(error.InvalidInputData, pairingBE(&input, &out));
 }
 
-test "alt_bn128: host stub returns Unexpected for valid sizes" {
+test "alt_bn128: host fallback pads short big-endian input with the identity" {
+    // The generator (1, 2) with the second point cut off entirely.
+    var input: [G1_POINT_SIZE]u8 = .{0} ** G1_POINT_SIZE;
+    input[FIELD_SIZE - 1] = 1;
+    input[G1_POINT_SIZE - 1] = 2;
+    var out: [G1_POINT_SIZE]u8 = undefined;
+    try g1AdditionBE(&input, &out);
+    try testing.expectEqualSlices(u8, &input, &out);
+}
+
+test "alt_bn128: host stub returns Unexpected for subtraction" {
     const input: [G1_ADDITION_INPUT_SIZE]u8 = .{0} ** G1_ADDITION_INPUT_SIZE;
     var out: [G1_POINT_SIZE]u8 = undefined;
-    try testing.expectError(error.Unexpected, g1AdditionBE(&input, &out));
+    try testing.expectError(error.Unexpected, g1SubtractionBE(&input, &out));
 }
 
 test "alt_bn128: errorToCode matches Rust mapping" {
@@ -258,3 +301,160 @@ test "alt_bn128: errorToProgramError split between malformed-input and on-curve
     try testing.expectEqual(ProgramError.InvalidArgument, errorToProgramError(error.GroupError));
     try testing.expectEqual(ProgramError.InvalidArgument, errorToProgramError(error.Unexpected));
 }
+
+/// A G1 group-operation vector. `output_*` is null exactly when the
+/// official implementation rejects the input with `expected_error`.
+const GroupOpVector = struct {
+    name: []const u8,
+    input_be: []const u8,
+    input_le: []const u8,
+    output_be: ?[]const u8,
+    output_le: ?[]const u8,
+    expected_error: ?u32,
+};
+
+/// `be` and `le` hold the same `chunk`-byte elements in opposite byte
+/// order: 32 for field elements, 64 for the `Fq2` coordinates of G2.
+fn expectChunksReversed(comptime chunk: usize, be: []const u8, le: []const u8) !void {
+    try testing.expectEqual(be.len, le.len);
+    try testing.expectEqual(@as(usize, 0), be.len % chunk);
+    var i: usize = 0;
+    while (i < be.len) : (i += chunk) {
+        var reversed: [chunk]u8 = be[i..][0..chunk].*;
+        std.mem.reverse(u8, &reversed);
+        try testing.expectEqualSlices(u8, &reversed, le[i..][0..chunk]);
+    }
+}
+
+fn errorFromCode(code: u32) Error {
+    return switch (code) {
+        1 => error.InvalidInputData,
+        2 => error.GroupError,
+        3 => error.SliceOutOfBounds,
+        else => error.Unexpected,
+    };
+}
+
+fn expectGroupOpVector(vector: GroupOpVector) !void {
+    try expectChunksReversed(FIELD_SIZE, vector.input_be, vector.input_le);
+    if (vector.expected_error) |code| {
+        try testing.expect(vector.output_be == null and vector.output_le == null);
+        try testing.expectEqual(code, errorToCode(errorFromCode(code)));
+    } else {
+        try testing.expectEqual(G1_POINT_SIZE, vector.output_be.?.len);
+        try expectChunksReversed(FIELD_SIZE, vector.output_be.?, vector.output_le.?);
+    }
+}
+
+/// `result` and the bytes in `out` are the official outcome of `vector`
+/// in `endian`: its output, or the error its code names.
+fn expectOfficialOutcome(vector: GroupOpVector, endian: std.builtin.Endian, result: Error!void, out: []const u8) !void {
+    if (vector.expected_error) |code| {
+        try testing.expectError(errorFromCode(code), result);
+    } else {
+        try result;
+        try testing.expectEqualSlices(u8, if (endian == .big) vector.output_be.? else vector.output_le.?, out);
+    }
+}
+
+// Generated by `program-test/tests/alt_bn128_addition.rs` from the official
+// `solana_bn254::prelude::alt_bn128_g1_addition_{be,le}`. The host fallback
+// must reproduce every output and error in both encodings.
+test "alt_bn128: g1 addition vectors match official outputs" {
+    const parsed = try std.json.parseFromSlice(
+        struct { field_modulus: [FIELD_SIZE]u8, vectors: []const GroupOpVector },
+        testing.allocator,
+        @embedFile("alt_bn128_addition_vectors.json"),
+        .{},
+    );
+    defer parsed.deinit();
+
+    for (parsed.value.vectors) |vector| {
+        try testing.expectEqual(G1_ADDITION_INPUT_SIZE, vector.input_be.len);
+        try expectGroupOpVector(vector);
+
+        var out: [G1_POINT_SIZE]u8 = undefined;
+        try expectOfficialOutcome(vector, .big, g1AdditionBE(vector.input_be, &out), &out);
+        try expectOfficialOutcome(vector, .little, g1AdditionLE(vector.input_le[0..G1_ADDITION_INPUT_SIZE], &out), &out);
+    }
+}
+
+// Generated by `program-test/tests/alt_bn128_multiplication.rs` from the
+// official `solana_bn254::prelude::alt_bn128_g1_multiplication_{be,le}`.
+// The syscall does not reduce the scalar, so `r + 2` yields the same point
+// as `2`. The host fallback must reproduce every output and error in both
+// encodings.
+test "alt_bn128: g1 multiplication vectors match official outputs" {
+    const parsed = try std.json.parseFromSlice(
+        struct {
+            field_modulus: [FIELD_SIZE]u8,
+            group_order: [FIELD_SIZE]u8,
+            vectors: []const GroupOpVector,
+        },
+        testing.allocator,
+        @embedFile("alt_bn128_multiplication_vectors.json"),
+        .{},
+    );
+    defer parsed.deinit();
+
+    var doubled: ?[]const u8 = null;
+    for (parsed.value.vectors) |vector| {
+        try testing.expectEqual(G1_MULTIPLICATION_INPUT_SIZE, vector.input_be.len);
+        try expectGroupOpVector(vector);
+        if (std.mem.eql(u8, vector.name, "scalar_two")) doubled = vector.output_be.?;
+        if (std.mem.eql(u8, vector.name, "scalar_group_order_plus_two")) {
+            try testing.expectEqualSlices(u8, doubled.?, vector.output_be.?);
+        }
+
+        var out: [G1_POINT_SIZE]u8 = undefined;
+        try expectOfficialOutcome(vector, .big, g1MultiplicationBE(vector.input_be, &out), &out);
+        try expectOfficialOutcome(vector, .little, g1MultiplicationLE(vector.input_le[0..G1_MULTIPLICATION_INPUT_SIZE], &out), &out);
+    }
+}
+
+// Generated by `program-test/tests/alt_bn128_pairing.rs` from the official
+// `solana_bn254::prelude::alt_bn128_pairing_{be,le}`. The LE input reverses
+// each G1 field element and each 64-byte G2 coordinate. The host fallback
+// must reproduce every 32-byte result in both encodings.
+test "alt_bn128: pairing vectors match official outputs" {
+    const parsed = try std.json.parseFromSlice(
+        struct {
+            scalar: u64,
+            g1_generator: [G1_POINT_SIZE]u8,
+            g2_generator: [G2_POINT_SIZE]u8,
+            vectors: []const GroupOpVector,
+        },
+        testing.allocator,
+        @embedFile("alt_bn128_pairing_vectors.json"),
+        .{},
+    );
+    defer parsed.deinit();
+
+    var one_be: [PAIRING_OUTPUT_SIZE]u8 = .{0} ** PAIRING_OUTPUT_SIZE;
+    one_be[PAIRING_OUTPUT_SIZE - 1] = 1;
+    const zero: [PAIRING_OUTPUT_SIZE]u8 = .{0} ** PAIRING_OUTPUT_SIZE;
+
+    for (parsed.value.vectors) |vector| {
+        try testing.expectEqual(vector.input_be.len, vector.input_le.len);
+        try testing.expectEqual(@as(usize, 0), vector.input_be.len % PAIRING_ELEMENT_SIZE);
+        var i: usize = 0;
+        while (i < vector.input_be.len) : (i += PAIRING_ELEMENT_SIZE) {
+            const be = vector.input_be[i..][0..PAIRING_ELEMENT_SIZE];
+            const le = vector.input_le[i..][0..PAIRING_ELEMENT_SIZE];
+            try expectChunksReversed(FIELD_SIZE, be[0..G1_POINT_SIZE], le[0..G1_POINT_SIZE]);
+            try expectChunksReversed(FQ2_SIZE, be[G1_POINT_SIZE..], le[G1_POINT_SIZE..]);
+        }
+
+        if (vector.output_be) |output| {
+            try testing.expect(vector.expected_error == null);
+            try testing.expect(std.mem.eql(u8, output, &one_be) or std.mem.eql(u8, output, &zero));
+            try expectChunksReversed(FIELD_SIZE, output, vector.output_le.?);
+        } else {
+            try testing.expect(vector.output_le == null and vector.expected_error != null);
+        }
+
+        var out: [PAIRING_OUTPUT_SIZE]u8 = undefined;
+        try expectOfficialOutcome(vector, .big, pairingBE(vector.input_be, &out), &out);
+        try expectOfficialOutcome(vector, .little, pairingLE(vector.input_le, &out), &out);
+    }
+}
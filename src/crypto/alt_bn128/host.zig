@@ -0,0 +1,484 @@
+//! Host fallback for `sol_alt_bn128_group_op`.
+//!
+//! Follows the non-Solana path of `solana_bn254` so host tests see the
+//! bytes the syscall writes on-chain: inputs must be canonical field
+//! elements of points on the curve (the all-zero encoding is the
+//! identity), and the identity encodes back to all zeros.
+//!
+//! Points are affine, every inversion goes through Fermat's little
+//! theorem, and `Fp12` multiplies schoolbook. That is slow, but this
+//! code only runs off-chain.
+
+const std = @import("std");
+
+const Endian = std.builtin.Endian;
+
+pub const Error = error{InvalidInputData};
+
+/// The BN254 base field modulus.
+const P = 0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47;
+/// The Montgomery radix.
+const R = 1 << 256;
+
+fn limbsOf(comptime x: comptime_int) [4]u64 {
+    var limbs: [4]u64 = undefined;
+    inline for (&limbs, 0..) |*limb, i| limb.* = (x >> (64 * i)) & std.math.maxInt(u64);
+    return limbs;
+}
+
+/// An element of the base field, in Montgomery form over little-endian
+/// 64-bit limbs.
+pub const Fp = struct {
+    limbs: [4]u64,
+
+    pub const zero: Fp = .{ .limbs = .{ 0, 0, 0, 0 } };
+    pub const one: Fp = constant(1);
+
+    const modulus = limbsOf(P);
+    const r2 = limbsOf(R * R % P);
+    /// `-p^-1 mod 2^64`, by Newton iteration.
+    const m_prime: u64 = blk: {
+        var x: u64 = 1;
+        for (0..6) |_| x *%= 2 -% modulus[0] *% x;
+        break :blk 0 -% x;
+    };
+
+    pub fn constant(comptime x: comptime_int) Fp {
+        return .{ .limbs = comptime limbsOf(x * R % P) };
+    }
+
+    fn atLeast(a: [4]u64, b: [4]u64) bool {
+        var i: usize = 4;
+        while (i > 0) {
+            i -= 1;
+            if (a[i] != b[i]) return a[i] > b[i];
+        }
+        return true;
+    }
+
+    /// `a + b`, wrapping modulo `2^256`.
+    fn addLimbs(a: [4]u64, b: [4]u64) [4]u64 {
+        var out: [4]u64 = undefined;
+        var carry: u1 = 0;
+        for (0..4) |i| {
+            const s1 = @addWithOverflow(a[i], b[i]);
+            const s2 = @addWithOverflow(s1[0], carry);
+            out[i] = s2[0];
+            carry = s1[1] | s2[1];
+        }
+        return out;
+    }
+
+    /// `a - b`, wrapping modulo `2^256`, and the borrow out.
+    fn subLimbs(a: [4]u64, b: [4]u64) struct { [4]u64, u1 } {
+        var out: [4]u64 = undefined;
+        var borrow: u1 = 0;
+        for (0..4) |i| {
+            const d1 = @subWithOverflow(a[i], b[i]);
+            const d2 = @subWithOverflow(d1[0], borrow);
+            out[i] = d2[0];
+            borrow = d1[1] | d2[1];
+        }
+        return .{ out, borrow };
+    }
+
+    /// Bring a value below `2p` into `[0, p)`.
+    fn reduce(limbs: [4]u64) Fp {
+        return .{ .limbs = if (atLeast(limbs, modulus)) subLimbs(limbs, modulus)[0] else limbs };
+    }
+
+    pub fn add(a: Fp, b: Fp) Fp {
+        // p < 2^254, so the sum never carries out of the top limb.
+        return reduce(addLimbs(a.limbs, b.limbs));
+    }
+
+    pub fn sub(a: Fp, b: Fp) Fp {
+        const difference, const borrow = subLimbs(a.limbs, b.limbs);
+        return .{ .limbs = if (borrow == 1) addLimbs(difference, modulus) else difference };
+    }
+
+    pub fn neg(a: Fp) Fp {
+        return zero.sub(a);
+    }
+
+    /// Montgomery product `a * b / R mod p`, coarsely integrated
+    /// operand scanning.
+    pub fn mul(a: Fp, b: Fp) Fp {
+        var t: [6]u64 = .{0} ** 6;
+        for (0..4) |i| {
+            var carry: u64 = 0;
+            for (0..4) |j| {
+                const s = @as(u128, t[j]) + @as(u128, a.limbs[j]) * b.limbs[i] + carry;
+                t[j] = @truncate(s);
+                carry = @intCast(s >> 64);
+            }
+            const s4 = @as(u128, t[4]) + carry;
+            t[4] = @truncate(s4);
+            t[5] = @intCast(s4 >> 64);
+
+            const m = t[0] *% m_prime;
+            carry = @intCast((@as(u128, t[0]) + @as(u128, m) * modulus[0]) >> 64);
+            for (1..4) |j| {
+                const s = @as(u128, t[j]) + @as(u128, m) * modulus[j] + carry;
+                t[j - 1] = @truncate(s);
+                carry = @intCast(s >> 64);
+            }
+            const s3 = @as(u128, t[4]) + carry;
+            t[3] = @truncate(s3);
+            t[4] = t[5] + @as(u64, @intCast(s3 >> 64));
+        }
+        return reduce(t[0..4].*);
+    }
+
+    /// `a^(p - 2)`; zero maps to zero.
+    pub fn inverse(a: Fp) Fp {
+        const exponent = comptime limbsOf(P - 2);
+        var acc = one;
+        var bit: usize = 256;
+        while (bit > 0) {
+            bit -= 1;
+            acc = acc.mul(acc);
+            if ((exponent[bit / 64] >> @intCast(bit % 64)) & 1 == 1) acc = acc.mul(a);
+        }
+        return acc;
+    }
+
+    pub fn eql(a: Fp, b: Fp) bool {
+        return std.mem.eql(u64, &a.limbs, &b.limbs);
+    }
+
+    pub fn isZero(a: Fp) bool {
+        return a.eql(zero);
+    }
+
+    /// Null unless `bytes` holds an integer below `p`.
+    pub fn fromBytes(bytes: *const [32]u8, endian: Endian) ?Fp {
+        var raw: [4]u64 = .{0} ** 4;
+        for (0..32) |i| {
+            const byte: u64 = bytes[if (endian == .little) i else 31 - i];
+            raw[i / 8] |= byte << @intCast(8 * (i % 8));
+        }
+        if (atLeast(raw, modulus)) return null;
+        return (Fp{ .limbs = raw }).mul(.{ .limbs = r2 });
+    }
+
+    pub fn toBytes(a: Fp, endian: Endian, out: *[32]u8) void {
+        const raw = a.mul(.{ .limbs = .{ 1, 0, 0, 0 } }).limbs;
+        for (0..32) |i| {
+            out[if (endian == .little) i else 31 - i] = @truncate(raw[i / 8] >> @intCast(8 * (i % 8)));
+        }
+    }
+};
+
+/// `Fp[u] / (u^2 + 1)`, the field G2 coordinates live in.
+pub const Fp2 = struct {
+    c0: Fp,
+    c1: Fp,
+
+    pub const zero: Fp2 = .{ .c0 = Fp.zero, .c1 = Fp.zero };
+    pub const one: Fp2 = .{ .c0 = Fp.one, .c1 = Fp.zero };
+
+    pub fn add(a: Fp2, b: Fp2) Fp2 {
+        return .{ .c0 = a.c0.add(b.c0), .c1 = a.c1.add(b.c1) };
+    }
+
+    pub fn sub(a: Fp2, b: Fp2) Fp2 {
+        return .{ .c0 = a.c0.sub(b.c0), .c1 = a.c1.sub(b.c1) };
+    }
+
+    pub fn neg(a: Fp2) Fp2 {
+        return zero.sub(a);
+    }
+
+    pub fn mul(a: Fp2, b: Fp2) Fp2 {
+        return .{
+            .c0 = a.c0.mul(b.c0).sub(a.c1.mul(b.c1)),
+            .c1 = a.c0.mul(b.c1).add(a.c1.mul(b.c0)),
+        };
+    }
+
+    pub fn scale(a: Fp2, k: Fp) Fp2 {
+        return .{ .c0 = a.c0.mul(k), .c1 = a.c1.mul(k) };
+    }
+
+    /// The conjugate over the norm; zero maps to zero.
+    pub fn inverse(a: Fp2) Fp2 {
+        const norm = a.c0.mul(a.c0).add(a.c1.mul(a.c1));
+        const scaled = a.scale(norm.inverse());
+        return .{ .c0 = scaled.c0, .c1 = scaled.c1.neg() };
+    }
+
+    pub fn eql(a: Fp2, b: Fp2) bool {
+        return a.c0.eql(b.c0) and a.c1.eql(b.c1);
+    }
+
+    pub fn isZero(a: Fp2) bool {
+        return a.eql(zero);
+    }
+
+    /// Null unless both coefficients are below `p`. Big-endian puts `c1`
+    /// first, the EIP-197 order; little-endian puts `c0` first.
+    fn fromBytes(bytes: *const [64]u8, endian: Endian) ?Fp2 {
+        const first = Fp.fromBytes(bytes[0..32], endian) orelse return null;
+        const second = Fp.fromBytes(bytes[32..64], endian) orelse return null;
+        return if (endian == .little) .{ .c0 = first, .c1 = second } else .{ .c0 = second, .c1 = first };
+    }
+};
+
+/// `9 + u`, the non-residue the sextic twist and `Fp12` are built on.
+const xi: Fp2 = .{ .c0 = Fp.constant(9), .c1 = Fp.one };
+
+/// `Fp2[w] / (w^6 - ξ)`, the field pairings land in.
+const Fp12 = struct {
+    c: [6]Fp2,
+
+    const one: Fp12 = .{ .c = [_]Fp2{Fp2.one} ++ [_]Fp2{Fp2.zero} ** 5 };
+
+    fn mul(a: Fp12, b: Fp12) Fp12 {
+        var c: [6]Fp2 = .{Fp2.zero} ** 6;
+        for (a.c, 0..) |ai, i| {
+            for (b.c, 0..) |bj, j| {
+                const t = ai.mul(bj);
+                if (i + j < 6) {
+                    c[i + j] = c[i + j].add(t);
+                } else {
+                    c[i + j - 6] = c[i + j - 6].add(t.mul(xi));
+                }
+            }
+        }
+        return .{ .c = c };
+    }
+
+    /// `a^exponent` for a big-endian `exponent`.
+    fn pow(a: Fp12, exponent: []const u8) Fp12 {
+        var acc = one;
+        for (exponent) |byte| {
+            var bit: u4 = 8;
+            while (bit > 0) {
+                bit -= 1;
+                acc = acc.mul(acc);
+                if ((byte >> @intCast(bit)) & 1 == 1) acc = acc.mul(a);
+            }
+        }
+        return acc;
+    }
+
+    fn eql(a: Fp12, b: Fp12) bool {
+        for (a.c, b.c) |x, y| {
+            if (!x.eql(y)) return false;
+        }
+        return true;
+    }
+};
+
+/// An affine point on `y^2 = x^3 + b` over `F`.
+fn Affine(comptime F: type) type {
+    return struct {
+        x: F,
+        y: F,
+        infinity: bool,
+
+        const Self = @This();
+
+        pub const identity: Self = .{ .x = F.zero, .y = F.zero, .infinity = true };
+
+        pub fn isOnCurve(self: Self, b: F) bool {
+            return self.infinity or self.y.mul(self.y).eql(self.x.mul(self.x).mul(self.x).add(b));
+        }
+
+        /// The slope of the line through `a` and `b`, the tangent when they
+        /// are equal; null when that line is vertical.
+        pub fn slope(a: Self, b: Self) ?F {
+            if (a.x.eql(b.x)) {
+                if (!a.y.eql(b.y) or a.y.isZero()) return null;
+                const xx = a.x.mul(a.x);
+                return xx.add(xx).add(xx).mul(a.y.add(a.y).inverse());
+            }
+            return b.y.sub(a.y).mul(b.x.sub(a.x).inverse());
+        }
+
+        /// `a + b` for finite points, given the `slope` between them.
+        fn addAlong(a: Self, b: Self, s: F) Self {
+            const x = s.mul(s).sub(a.x).sub(b.x);
+            return .{ .x = x, .y = s.mul(a.x.sub(x)).sub(a.y), .infinity = false };
+        }
+
+        pub fn add(a: Self, b: Self) Self {
+            if (a.infinity) return b;
+            if (b.infinity) return a;
+            return a.addAlong(b, a.slope(b) orelse return identity);
+        }
+
+        /// `scalar * self`, reading `scalar` as a plain 256-bit integer
+        /// in little-endian limbs.
+        pub fn mul(self: Self, scalar: [4]u64) Self {
+            var acc = identity;
+            var bit: usize = 256;
+            while (bit > 0) {
+                bit -= 1;
+                acc = acc.add(acc);
+                if ((scalar[bit / 64] >> @intCast(bit % 64)) & 1 == 1) acc = acc.add(self);
+            }
+            return acc;
+        }
+    };
+}
+
+const G1 = Affine(Fp);
+const g1_b = Fp.constant(3);
+
+fn decodeG1(bytes: *const [64]u8, endian: Endian) Error!G1 {
+    if (std.mem.allEqual(u8, bytes, 0)) return G1.identity;
+    const point: G1 = .{
+        .x = Fp.fromBytes(bytes[0..32], endian) orelse return error.InvalidInputData,
+        .y = Fp.fromBytes(bytes[32..64], endian) orelse return error.InvalidInputData,
+        .infinity = false,
+    };
+    if (!point.isOnCurve(g1_b)) return error.InvalidInputData;
+    return point;
+}
+
+fn encodeG1(point: G1, endian: Endian, out: *[64]u8) void {
+    if (point.infinity) {
+        @memset(out, 0);
+        return;
+    }
+    point.x.toBytes(endian, out[0..32]);
+    point.y.toBytes(endian, out[32..64]);
+}
+
+/// `a || b` to `a + b`.
+pub fn g1Addition(input: *const [128]u8, endian: Endian, out: *[64]u8) Error!void {
+    const a = try decodeG1(input[0..64], endian);
+    const b = try decodeG1(input[64..128], endian);
+    encodeG1(a.add(b), endian, out);
+}
+
+/// `point || scalar` to `scalar * point`. The scalar is not reduced
+/// modulo the group order, matching the syscall.
+pub fn g1Multiplication(input: *const [96]u8, endian: Endian, out: *[64]u8) Error!void {
+    const point = try decodeG1(input[0..64], endian);
+    var scalar: [4]u64 = .{0} ** 4;
+    for (0..32) |i| {
+        const byte: u64 = input[64 + if (endian == .little) i else 31 - i];
+        scalar[i / 8] |= byte << @intCast(8 * (i % 8));
+    }
+    encodeG1(point.mul(scalar), endian, out);
+}
+
+const G2 = Affine(Fp2);
+/// `3 / ξ`, the coefficient of the sextic twist.
+const g2_b: Fp2 = .{
+    .c0 = Fp.constant(19485874751759354771024239261021720505790618469301721065564631296452457478373),
+    .c1 = Fp.constant(266929791119991161246907387137283842545076965332900288569378510910307636690),
+};
+
+/// The order of G1 and of the G2 subgroup.
+const ORDER = 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001;
+
+fn decodeG2(bytes: *const [128]u8, endian: Endian) Error!G2 {
+    if (std.mem.allEqual(u8, bytes, 0)) return G2.identity;
+    const point: G2 = .{
+        .x = Fp2.fromBytes(bytes[0..64], endian) orelse return error.InvalidInputData,
+        .y = Fp2.fromBytes(bytes[64..128], endian) orelse return error.InvalidInputData,
+        .infinity = false,
+    };
+    if (!point.isOnCurve(g2_b)) return error.InvalidInputData;
+    // The twist has points outside the order-r subgroup.
+    if (!point.mul(comptime limbsOf(ORDER)).infinity) return error.InvalidInputData;
+    return point;
+}
+
+/// `x` as `len` big-endian bytes.
+fn bytesOf(comptime len: usize, comptime x: comptime_int) [len]u8 {
+    if (x >> (8 * len) != 0) @compileError("value does not fit");
+    @setEvalBranchQuota(10 * len);
+    var out: [len]u8 = undefined;
+    inline for (0..len) |i| out[len - 1 - i] = (x >> (8 * i)) & 0xff;
+    return out;
+}
+
+/// The optimal ate loop count `6x^2` for the BN parameter
+/// `x = 4965661367192848881`.
+const ate_loop_count: u128 = 6 * 4965661367192848881 * 4965661367192848881;
+
+/// `(p^12 - 1) / r`, 2790 bits.
+const final_exponent = blk: {
+    const p6 = P * P * P * P * P * P;
+    break :blk bytesOf(349, (p6 * p6 - 1) / ORDER);
+};
+
+/// The line through `t` with `slope`, evaluated at `p` and placed in
+/// `Fp12` by the twist. The vertical parts are dropped; the final
+/// exponentiation sends them to one.
+fn line(t: G2, slope: Fp2, p: G1) Fp12 {
+    var l: [6]Fp2 = .{Fp2.zero} ** 6;
+    l[0] = .{ .c0 = p.y, .c1 = Fp.zero };
+    l[1] = slope.scale(p.x).neg();
+    l[3] = slope.mul(t.x).sub(t.y);
+    return .{ .c = l };
+}
+
+fn millerLoop(p: G1, q: G2) Fp12 {
+    var f = Fp12.one;
+    var t = q;
+    var bit: u7 = 127 - @clz(ate_loop_count);
+    while (bit > 0) {
+        bit -= 1;
+        // `t` stays a multiple of `q` below the group order, so neither
+        // line below is vertical.
+        const tangent = t.slope(t).?;
+        f = f.mul(f).mul(line(t, tangent, p));
+        t = t.addAlong(t, tangent);
+        if ((ate_loop_count >> bit) & 1 == 1) {
+            const chord = t.slope(q).?;
+            f = f.mul(line(t, chord, p));
+            t = t.addAlong(q, chord);
+        }
+    }
+    return f;
+}
+
+/// `(g1 || g2)*` to one when the product of the pairings is the identity,
+/// zero otherwise. Pairs with an identity point contribute nothing, and
+/// empty input yields one.
+pub fn pairing(input: []const u8, endian: Endian, out: *[32]u8) Error!void {
+    std.debug.assert(input.len % 192 == 0);
+    var f = Fp12.one;
+    var i: usize = 0;
+    while (i < input.len) : (i += 192) {
+        const p = try decodeG1(input[i..][0..64], endian);
+        const q = try decodeG2(input[i + 64 ..][0..128], endian);
+        if (p.infinity or q.infinity) continue;
+        f = f.mul(millerLoop(p, q));
+    }
+    @memset(out, 0);
+    out[if (endian == .little) @as(usize, 0) else 31] = @intFromBool(f.pow(&final_exponent).eql(Fp12.one));
+}
+
+const testing = std.testing;
+
+test "alt_bn128 host: Montgomery arithmetic round-trips" {
+    const two = Fp.constant(2);
+    try testing.expect(two.mul(two.inverse()).eql(Fp.one));
+    try testing.expect(Fp.one.add(Fp.one).eql(two));
+    try testing.expect(Fp.one.sub(two).add(two).eql(Fp.one));
+    try testing.expect(Fp.one.neg().add(Fp.one).isZero());
+
+    var bytes: [32]u8 = undefined;
+    Fp.constant(P - 1).toBytes(.little, &bytes);
+    try testing.expect(Fp.fromBytes(&bytes, .little).?.eql(Fp.one.neg()));
+}
+
+test "alt_bn128 host: the twist coefficient is 3 / xi" {
+    try testing.expect(g2_b.mul(xi).eql(.{ .c0 = Fp.constant(3), .c1 = Fp.zero }));
+    try testing.expect(xi.mul(xi.inverse()).eql(Fp2.one));
+}
+
+test "alt_bn128 host: field elements at or above p are rejected" {
+    var bytes: [32]u8 = undefined;
+    for (0..32) |i| bytes[31 - i] = @truncate(Fp.modulus[i / 8] >> @intCast(8 * (i % 8)));
+    try testing.expectEqual(@as(?Fp, null), Fp.fromBytes(&bytes, .big));
+    bytes[31] -= 1;
+    try testing.expect(Fp.fromBytes(&bytes, .big).?.eql(Fp.one.neg()));
+}
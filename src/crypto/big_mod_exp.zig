@@ -54,10 +54,146 @@ extern fn sol_big_mod_exp(params: *const u8, result: *u8) callconv(.c) u64;
 pub const Error = error{
     /// `out.len < modulus.len`.
     OutputTooSmall,
-    /// Any non-zero return code from the syscall.
+    /// Any non-zero return code from the syscall, or an input over
+    /// 512 bytes on host.
     Unexpected,
 };
 
+// Host fallback, following `solana_big_mod_exp::big_mod_exp`:
+// square-and-multiply over little-endian u32 limbs with Barrett
+// reduction, so even moduli work as well as odd ones.
+const host = struct {
+    /// The syscall rejects any input longer than this.
+    const MAX_LEN = 512;
+    const LIMBS = MAX_LEN / 4;
+
+    fn significant(a: []const u32) usize {
+        var n = a.len;
+        while (n > 0 and a[n - 1] == 0) n -= 1;
+        return n;
+    }
+
+    /// `a >= b`, reading missing high limbs as zero.
+    fn atLeast(a: []const u32, b: []const u32) bool {
+        var i = @max(a.len, b.len);
+        while (i > 0) {
+            i -= 1;
+            const x: u32 = if (i < a.len) a[i] else 0;
+            const y: u32 = if (i < b.len) b[i] else 0;
+            if (x != y) return x > y;
+        }
+        return true;
+    }
+
+    /// `a -= b`, wrapping modulo `2^(32 * a.len)`.
+    fn sub(a: []u32, b: []const u32) void {
+        var borrow: u64 = 0;
+        for (a, 0..) |*limb, i| {
+            const y: u64 = if (i < b.len) b[i] else 0;
+            const d = @as(u64, limb.*) -% y -% borrow;
+            limb.* = @truncate(d);
+            borrow = d >> 63;
+        }
+    }
+
+    /// `out = a * b`, truncated to `out.len` limbs.
+    fn mul(out: []u32, a: []const u32, b: []const u32) void {
+        @memset(out, 0);
+        for (a, 0..) |x, i| {
+            var carry: u64 = 0;
+            var j: usize = 0;
+            while (j < b.len and i + j < out.len) : (j += 1) {
+                const t = @as(u64, x) * b[j] + out[i + j] + carry;
+                out[i + j] = @truncate(t);
+                carry = t >> 32;
+            }
+            if (i + j < out.len) out[i + j] = @truncate(carry);
+        }
+    }
+
+    /// `r = (r << 1) | bit`.
+    fn shiftInBit(r: []u32, bit: u1) void {
+        var carry: u32 = bit;
+        for (r) |*limb| {
+            const next = limb.* >> 31;
+            limb.* = (limb.* << 1) | carry;
+            carry = next;
+        }
+    }
+
+    fn bigModExp(base: []const u8, exponent: []const u8, modulus: []const u8, out: []u8) Error!void {
+        if (base.len > MAX_LEN or exponent.len > MAX_LEN or modulus.len > MAX_LEN) return error.Unexpected;
+        @memset(out, 0);
+
+        var m_limbs = [_]u32{0} ** LIMBS;
+        for (0..modulus.len) |i| {
+            const byte: u32 = modulus[modulus.len - 1 - i];
+            m_limbs[i / 4] |= byte << @intCast(8 * (i % 4));
+        }
+        const k = significant(&m_limbs);
+        if (k == 0 or (k == 1 and m_limbs[0] == 1)) return;
+        const m = m_limbs[0..k];
+
+        // Barrett constant floor(2^(64k) / m). It takes k + 2 limbs
+        // when m is exactly 2^(32(k - 1)).
+        var mu = [_]u32{0} ** (LIMBS + 2);
+        var rem = [_]u32{0} ** (LIMBS + 1);
+        var bit: usize = 64 * k + 1;
+        while (bit > 0) {
+            bit -= 1;
+            shiftInBit(rem[0 .. k + 1], @intFromBool(bit == 64 * k));
+            if (atLeast(rem[0 .. k + 1], m)) {
+                sub(rem[0 .. k + 1], m);
+                mu[bit / 32] |= @as(u32, 1) << @intCast(bit % 32);
+            }
+        }
+
+        // The base may be longer than the modulus, so reduce it bit by bit.
+        var b = [_]u32{0} ** (LIMBS + 1);
+        for (base) |byte| {
+            var shift: u4 = 8;
+            while (shift > 0) {
+                shift -= 1;
+                shiftInBit(b[0 .. k + 1], @truncate(byte >> @intCast(shift)));
+                if (atLeast(b[0 .. k + 1], m)) sub(b[0 .. k + 1], m);
+            }
+        }
+
+        var acc = [_]u32{0} ** LIMBS;
+        acc[0] = 1;
+        for (exponent) |byte| {
+            var shift: u4 = 8;
+            while (shift > 0) {
+                shift -= 1;
+                mulMod(acc[0..k], b[0..k], m, mu[0 .. k + 2], false);
+                if ((byte >> @intCast(shift)) & 1 == 1) mulMod(acc[0..k], b[0..k], m, mu[0 .. k + 2], true);
+            }
+        }
+
+        for (0..out.len) |i| {
+            if (i / 4 < k) out[out.len - 1 - i] = @truncate(acc[i / 4] >> @intCast(8 * (i % 4)));
+        }
+    }
+
+    /// `acc = acc * (by_base ? b : acc) mod m`, with `acc` and `b` below `m`.
+    fn mulMod(acc: []u32, b: []const u32, m: []const u32, mu: []const u32, by_base: bool) void {
+        const k = m.len;
+        var wide = [_]u32{0} ** (2 * LIMBS);
+        var q2 = [_]u32{0} ** (2 * LIMBS + 3);
+        var r2 = [_]u32{0} ** (LIMBS + 1);
+        var r = [_]u32{0} ** (LIMBS + 1);
+
+        const x = wide[0 .. 2 * k];
+        mul(x, acc, if (by_base) b else acc);
+        mul(q2[0 .. 2 * k + 3], x[k - 1 ..], mu);
+        mul(r2[0 .. k + 1], q2[k + 1 .. 2 * k + 3], m);
+        @memcpy(r[0 .. k + 1], x[0 .. k + 1]);
+        sub(r[0 .. k + 1], r2[0 .. k + 1]);
+        while (atLeast(r[0 .. k + 1], m)) sub(r[0 .. k + 1], m);
+        @memcpy(acc, r[0..k]);
+    }
+};
+
 /// Compute `base^exponent mod modulus`. Writes exactly `modulus.len`
 /// bytes (big-endian, left-padded with zeros) into the prefix of
 /// `out`. Returns the slice that was actually written.
@@ -88,13 +224,7 @@ pub fn bigModExp(
         );
         if (rc != 0) return error.Unexpected;
     } else {
-        // Host fallback: zero the output. The syscall on real clusters
-        // does the modpow; on host we can't bring in num-bigint as a
-        // dependency, so callers shouldn't rely on real values from
-        // host stubs. This keeps the wrapper testable without changing
-        // its API.
-        @memset(result_slice, 0);
-        return error.Unexpected;
+        try host.bigModExp(base, exponent, modulus, result_slice);
     }
 
     return result_slice;
@@ -119,10 +249,48 @@ test "big_mod_exp: OutputTooSmall when out shorter than modulus" {
     try testing.expectError(error.OutputTooSmall, bigModExp(&base, &exp, &modulus, &out));
 }
 
-test "big_mod_exp: host stub returns Unexpected" {
-    const base = [_]u8{2};
-    const exp = [_]u8{3};
-    const modulus = [_]u8{5};
+test "big_mod_exp: host fallback computes small powers" {
+    var out: [2]u8 = undefined;
+    try testing.expectEqualSlices(u8, &.{3}, try bigModExp(&.{2}, &.{3}, &.{5}, &out));
+    // An empty exponent is zero; the result keeps the modulus width.
+    try testing.expectEqualSlices(u8, &.{ 0, 1 }, try bigModExp(&.{7}, &.{}, &.{ 0, 10 }, &out));
+    try testing.expectEqualSlices(u8, &.{ 0, 0 }, try bigModExp(&.{7}, &.{2}, &.{ 0, 1 }, &out));
+}
+
+test "big_mod_exp: host fallback rejects inputs over the syscall limit" {
+    const long = [_]u8{1} ** 513;
     var out: [1]u8 = undefined;
-    try testing.expectError(error.Unexpected, bigModExp(&base, &exp, &modulus, &out));
+    try testing.expectError(error.Unexpected, bigModExp(&long, &.{1}, &.{7}, &out));
+}
+
+// Generated by `program-test/tests/big_mod_exp.rs` from the official
+// `solana_big_mod_exp::big_mod_exp`.
+test "big_mod_exp: 32- and 256-byte vectors match the official results" {
+    const parsed = try std.json.parseFromSlice(
+        struct {
+            seed: u64,
+            vectors: []const struct {
+                name: []const u8,
+                base: []const u8,
+                exponent: []const u8,
+                modulus: []const u8,
+                result: []const u8,
+            },
+        },
+        testing.allocator,
+        @embedFile("big_mod_exp_vectors.json"),
+        .{},
+    );
+    defer parsed.deinit();
+
+    var out: [256]u8 = undefined;
+    for (parsed.value.vectors) |vector| {
+        try testing.expectError(
+            error.OutputTooSmall,
+            bigModExp(vector.base, vector.exponent, vector.modulus, out[0 .. vector.modulus.len - 1]),
+        );
+        out = .{0xaa} ** out.len;
+        const result = try bigModExp(vector.base, vector.exponent, vector.modulus, &out);
+        try testing.expectEqualSlices(u8, vector.result, result);
+    }
 }
@@ -0,0 +1,276 @@
+//! Curve25519 group operations via the `sol_curve_*` syscalls.
+//!
+//! Wraps the three syscalls the Rust SDK exposes through
+//! `solana-curve25519`, for both point representations:
+//!
+//!   - **Validate** — `sol_curve_validate_point`
+//!   - **Add / sub / mul** — `sol_curve_group_op`
+//!   - **MSM** — `sol_curve_multiscalar_mul`
+//!
+//! Points are the 32-byte compressed encodings (`CompressedEdwardsY`
+//! for edwards, `CompressedRistretto` for ristretto) and scalars are
+//! 32-byte little-endian, canonical (reduced mod the group order `ℓ`).
+//! These are the building blocks of the ZK ElGamal proof program and
+//! the confidential-transfer extensions.
+//!
+//! Off-chain the same operations run in software on
+//! `std.crypto.ecc.Edwards25519` / `Ristretto255`, so host code and
+//! tests get the values the syscalls would return.
+
+const std = @import("std");
+const builtin = @import("builtin");
+
+/// 32 bytes — compressed point encoding for either curve.
+pub const POINT_SIZE: usize = 32;
+/// 32 bytes — little-endian scalar.
+pub const SCALAR_SIZE: usize = 32;
+
+/// Maximum number of points the runtime accepts in one MSM call.
+pub const MAX_MULTISCALAR_POINTS: usize = 512;
+
+pub const Point = [POINT_SIZE]u8;
+pub const Scalar = [SCALAR_SIZE]u8;
+
+/// `curve_id` argument — `CURVE25519_EDWARDS` / `CURVE25519_RISTRETTO`.
+pub const Curve = enum(u64) {
+    edwards = 0,
+    ristretto = 1,
+};
+
+/// `group_op` argument of `sol_curve_group_op` — `ADD` / `SUB` / `MUL`.
+pub const GroupOp = enum(u64) {
+    add = 0,
+    subtract = 1,
+    multiply = 2,
+};
+
+/// Errors returned by the curve25519 wrappers. The syscalls only
+/// report success (`0`) or failure (`1`): an input that does not
+/// decode to a point or canonical scalar maps to `InvalidInput`, on
+/// chain and on host alike.
+pub const Error = error{
+    InvalidInput,
+    Unexpected,
+};
+
+const is_solana = builtin.os.tag == .freestanding and builtin.cpu.arch == .bpfel;
+
+const Edwards25519 = std.crypto.ecc.Edwards25519;
+const Ristretto255 = std.crypto.ecc.Ristretto255;
+
+extern fn sol_curve_validate_point(
+    curve_id: u64,
+    point: [*]const u8,
+    result: [*]u8,
+) callconv(.c) u64;
+
+extern fn sol_curve_group_op(
+    curve_id: u64,
+    group_op: u64,
+    left_input: [*]const u8,
+    right_input: [*]const u8,
+    result_point: [*]u8,
+) callconv(.c) u64;
+
+extern fn sol_curve_multiscalar_mul(
+    curve_id: u64,
+    scalars: [*]const u8,
+    points: [*]const u8,
+    points_len: u64,
+    result_point: [*]u8,
+) callconv(.c) u64;
+
+fn mapResult(rc: u64) Error!void {
+    return switch (rc) {
+        0 => {},
+        1 => error.InvalidInput,
+        else => error.Unexpected,
+    };
+}
+
+// Host fallback, following `solana_curve25519`: a ristretto point is
+// carried as its edwards representative between decode and encode.
+const host = struct {
+    fn decode(curve: Curve, bytes: *const Point) Error!Edwards25519 {
+        return switch (curve) {
+            .edwards => Edwards25519.fromBytes(bytes.*) catch error.InvalidInput,
+            .ristretto => (Ristretto255.fromBytes(bytes.*) catch return error.InvalidInput).p,
+        };
+    }
+
+    fn encode(curve: Curve, point: Edwards25519) Point {
+        return switch (curve) {
+            .edwards => point.toBytes(),
+            .ristretto => (Ristretto255{ .p = point }).toBytes(),
+        };
+    }
+
+    // Plain double-and-add: `Edwards25519.mul` rejects an identity
+    // result, which the syscall returns like any other point.
+    fn mul(scalar: *const Scalar, point: Edwards25519) Error!Edwards25519 {
+        Edwards25519.scalar.rejectNonCanonical(scalar.*) catch return error.InvalidInput;
+        var acc = Edwards25519.identityElement;
+        var bit: usize = SCALAR_SIZE * 8;
+        while (bit > 0) {
+            bit -= 1;
+            acc = acc.dbl();
+            if ((scalar[bit / 8] >> @intCast(bit % 8)) & 1 == 1) acc = acc.add(point);
+        }
+        return acc;
+    }
+
+    fn groupOp(curve: Curve, op: GroupOp, left: *const [32]u8, right: *const Point) Error!Point {
+        const rhs = try decode(curve, right);
+        const result = switch (op) {
+            .add => (try decode(curve, left)).add(rhs),
+            .subtract => (try decode(curve, left)).sub(rhs),
+            .multiply => try mul(left, rhs),
+        };
+        return encode(curve, result);
+    }
+
+    fn multiscalarMultiply(curve: Curve, scalars: []const Scalar, points: []const Point) Error!Point {
+        var acc = Edwards25519.identityElement;
+        for (scalars, points) |*scalar, *point| {
+            acc = acc.add(try mul(scalar, try decode(curve, point)));
+        }
+        return encode(curve, acc);
+    }
+};
+
+/// Whether `point` decodes to a valid point on `curve`.
+pub fn validatePoint(curve: Curve, point: *const Point) Error!bool {
+    if (comptime !is_solana) {
+        _ = host.decode(curve, point) catch return false;
+        return true;
+    }
+    var unused: u8 = 0;
+    return sol_curve_validate_point(@intFromEnum(curve), point, @ptrCast(&unused)) == 0;
+}
+
+fn groupOp(curve: Curve, op: GroupOp, left: *const [32]u8, right: *const Point, out: *Point) Error!void {
+    if (comptime !is_solana) {
+        out.* = try host.groupOp(curve, op, left, right);
+        return;
+    }
+    return mapResult(sol_curve_group_op(@intFromEnum(curve), @intFromEnum(op), left, right, out));
+}
+
+/// `left + right`.
+pub fn add(curve: Curve, left: *const Point, right: *const Point, out: *Point) Error!void {
+    return groupOp(curve, .add, left, right, out);
+}
+
+/// `left - right`.
+pub fn subtract(curve: Curve, left: *const Point, right: *const Point, out: *Point) Error!void {
+    return groupOp(curve, .subtract, left, right, out);
+}
+
+/// `scalar * point`. The scalar is the left operand, as in the syscall.
+pub fn multiply(curve: Curve, scalar: *const Scalar, point: *const Point, out: *Point) Error!void {
+    return groupOp(curve, .multiply, scalar, point, out);
+}
+
+/// `Σ scalars[i] * points[i]`. Both slices must have the same length,
+/// at most `MAX_MULTISCALAR_POINTS`.
+pub fn multiscalarMultiply(
+    curve: Curve,
+    scalars: []const Scalar,
+    points: []const Point,
+    out: *Point,
+) Error!void {
+    if (scalars.len != points.len or points.len > MAX_MULTISCALAR_POINTS) return error.InvalidInput;
+    if (comptime !is_solana) {
+        out.* = try host.multiscalarMultiply(curve, scalars, points);
+        return;
+    }
+    return mapResult(sol_curve_multiscalar_mul(
+        @intFromEnum(curve),
+        @ptrCast(scalars.ptr),
+        @ptrCast(points.ptr),
+        points.len,
+        out,
+    ));
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+const testing = std.testing;
+
+test "curve25519: ids match the Rust syscall constants" {
+    try testing.expectEqual(@as(u64, 0), @intFromEnum(Curve.edwards));
+    try testing.expectEqual(@as(u64, 1), @intFromEnum(Curve.ristretto));
+    try testing.expectEqual(@as(u64, 0), @intFromEnum(GroupOp.add));
+    try testing.expectEqual(@as(u64, 1), @intFromEnum(GroupOp.subtract));
+    try testing.expectEqual(@as(u64, 2), @intFromEnum(GroupOp.multiply));
+}
+
+test "curve25519: multiscalarMultiply rejects mismatched lengths" {
+    const scalars: [2]Scalar = .{.{0} ** SCALAR_SIZE} ** 2;
+    const points: [1]Point = .{.{0} ** POINT_SIZE};
+    var out: Point = undefined;
+    try testing.expectError(error.InvalidInput, multiscalarMultiply(.edwards, &scalars, &points, &out));
+}
+
+// Generated by `program-test/tests/curve25519.rs` from the official
+// `solana_curve25519` edwards / ristretto wrappers.
+test "curve25519: host operations match the official vectors" {
+    const parsed = try std.json.parseFromSlice(
+        struct {
+            vectors: []const struct {
+                name: []const u8,
+                curve_id: u64,
+                /// `"validate_point"`, `"group_op"` or `"multiscalar_mul"`.
+                syscall: []const u8,
+                group_op: ?u64,
+                scalars: []const Scalar,
+                points: []const Point,
+                success: bool,
+                result: ?Point,
+            },
+        },
+        testing.allocator,
+        @embedFile("curve25519_vectors.json"),
+        .{},
+    );
+    defer parsed.deinit();
+
+    for (parsed.value.vectors) |vector| {
+        const curve = try std.meta.intToEnum(Curve, vector.curve_id);
+
+        if (std.mem.eql(u8, vector.syscall, "validate_point")) {
+            try testing.expectEqual(@as(usize, 1), vector.points.len);
+            try testing.expectEqual(vector.success, try validatePoint(curve, &vector.points[0]));
+            continue;
+        }
+
+        var out: Point = undefined;
+        const result = if (std.mem.eql(u8, vector.syscall, "group_op"))
+            switch (try std.meta.intToEnum(GroupOp, vector.group_op.?)) {
+                .add => add(curve, &vector.points[0], &vector.points[1], &out),
+                .subtract => subtract(curve, &vector.points[0], &vector.points[1], &out),
+                .multiply => multiply(curve, &vector.scalars[0], &vector.points[0], &out),
+            }
+        else blk: {
+            try testing.expectEqualStrings("multiscalar_mul", vector.syscall);
+            break :blk multiscalarMultiply(curve, vector.scalars, vector.points, &out);
+        };
+
+        if (vector.success) {
+            try result;
+            try testing.expectEqualSlices(u8, &vector.result.?, &out);
+        } else {
+            try testing.expectError(error.InvalidInput, result);
+            try testing.expect(vector.result == null);
+        }
+    }
+}
+
+test "curve25519: multiplying by zero yields the identity" {
+    const zero: Scalar = .{0} ** SCALAR_SIZE;
+    var out: Point = undefined;
+    try multiply(.edwards, &zero, &Edwards25519.basePoint.toBytes(), &out);
+    try testing.expectEqualSlices(u8, &Edwards25519.identityElement.toBytes(), &out);
+}
@@ -1,4 +1,5 @@
-//! Hash utilities — SHA-256, Keccak-256, Blake3 syscall wrappers.
+//! Hash utilities — SHA-256, Keccak-256, Blake3 syscall wrappers, plus
+//! software Blake3 keyed and derive-key modes.
 //!
 //! Mirrors `solana-program`'s `hash` / `keccak` / `blake3` modules.
 //! Each hash family exposes a single function:
@@ -134,6 +135,26 @@ pub fn blake3(vals: []const []const u8) ProgramError!Hash {
     return hash;
 }
 
+/// Blake3 in keyed-hash mode. The syscall only does default hashing,
+/// so this always runs `std.crypto.hash.Blake3` in software.
+pub fn blake3Keyed(key: *const [HASH_BYTES]u8, vals: []const []const u8) Hash {
+    var hash: Hash = undefined;
+    var hasher = std.crypto.hash.Blake3.init(.{ .key = key.* });
+    for (vals) |v| hasher.update(v);
+    hasher.final(&hash.bytes);
+    return hash;
+}
+
+/// Blake3 in derive-key mode with a hardcoded, globally unique `context`.
+/// Software only, like `blake3Keyed`.
+pub fn blake3DeriveKey(context: []const u8, vals: []const []const u8) Hash {
+    var hash: Hash = undefined;
+    var hasher = std.crypto.hash.Blake3.initKdf(context, .{});
+    for (vals) |v| hasher.update(v);
+    hasher.final(&hash.bytes);
+    return hash;
+}
+
 /// Legacy name — same as `sha256`. Matches Rust SDK's
 /// `solana_program::hash::hashv` which has been the canonical
 /// "hash these byte slices" helper for years.
@@ -190,3 +211,88 @@ test "hash: hashv alias points to sha256" {
     const b = try sha256(&.{"factory"});
     try std.testing.expectEqualSlices(u8, &a.bytes, &b.bytes);
 }
+
+const HashvVector = struct {
+    name: []const u8,
+    inputs: []const []const u8,
+    hash: [32]u8,
+};
+
+// Generated by `program-test/tests/sha256_hashv.rs` from the official
+// `solana_sha256_hasher::hashv`.
+test "hash: sha256 over multiple slices matches official hashv" {
+    const parsed = try std.json.parseFromSlice(
+        struct { vectors: []const HashvVector },
+        std.testing.allocator,
+        @embedFile("sha256_hashv_vectors.json"),
+        .{},
+    );
+    defer parsed.deinit();
+
+    for (parsed.value.vectors) |vector| {
+        const h = try sha256(vector.inputs);
+        try std.testing.expectEqualSlices(u8, &vector.hash, &h.bytes);
+
+        const joined = try std.mem.concat(std.testing.allocator, u8, vector.inputs);
+        defer std.testing.allocator.free(joined);
+        const one_shot = try sha256(&.{joined});
+        try std.testing.expectEqualSlices(u8, &vector.hash, &one_shot.bytes);
+    }
+}
+
+// Generated by `program-test/tests/keccak_hashv.rs` from the official
+// `solana_keccak_hasher::hashv`.
+test "hash: keccak256 over multiple slices matches official hashv" {
+    const parsed = try std.json.parseFromSlice(
+        struct { rate: usize, vectors: []const HashvVector },
+        std.testing.allocator,
+        @embedFile("keccak_hashv_vectors.json"),
+        .{},
+    );
+    defer parsed.deinit();
+    try std.testing.expectEqual(std.crypto.hash.sha3.Keccak256.block_length, parsed.value.rate);
+
+    for (parsed.value.vectors) |vector| {
+        const h = try keccak256(vector.inputs);
+        try std.testing.expectEqualSlices(u8, &vector.hash, &h.bytes);
+
+        const joined = try std.mem.concat(std.testing.allocator, u8, vector.inputs);
+        defer std.testing.allocator.free(joined);
+        const one_shot = try keccak256(&.{joined});
+        try std.testing.expectEqualSlices(u8, &vector.hash, &one_shot.bytes);
+    }
+}
+
+// Generated by `program-test/tests/blake3_modes.rs` from the official
+// `blake3` crate.
+test "hash: blake3 default, keyed and derive-key modes match official blake3" {
+    const parsed = try std.json.parseFromSlice(
+        struct {
+            seed: u64,
+            key: [HASH_BYTES]u8,
+            context: []const u8,
+            vectors: []const struct {
+                name: []const u8,
+                mode: []const u8,
+                input: []const u8,
+                hash: [HASH_BYTES]u8,
+            },
+        },
+        std.testing.allocator,
+        @embedFile("blake3_modes_vectors.json"),
+        .{},
+    );
+    defer parsed.deinit();
+
+    for (parsed.value.vectors) |vector| {
+        const h = if (std.mem.eql(u8, vector.mode, "hash"))
+            try blake3(&.{vector.input})
+        else if (std.mem.eql(u8, vector.mode, "keyed_hash"))
+            blake3Keyed(&parsed.value.key, &.{vector.input})
+        else if (std.mem.eql(u8, vector.mode, "derive_key"))
+            blake3DeriveKey(parsed.value.context, &.{vector.input})
+        else
+            return error.UnknownBlake3Mode;
+        try std.testing.expectEqualSlices(u8, &vector.hash, &h.bytes);
+    }
+}
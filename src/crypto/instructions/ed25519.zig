@@ -322,6 +322,42 @@ test "ed25519_instruction: parseSignature rejects non-self-contained layout" {
     try std.testing.expectError(error.InvalidInstructionData, parseSignature(fake_ix, 0));
 }
 
+const Ed25519InstructionVector = struct {
+    name: []const u8,
+    secret_key: [32]u8,
+    public_key: Pubkey,
+    message: []const u8,
+    signature: [SIGNATURE_SERIALIZED_SIZE]u8,
+    offsets: SignatureOffsets,
+    data: []const u8,
+};
+
+// Generated by `program-test/tests/ed25519_instruction.rs` from the official
+// `new_ed25519_instruction_with_signature`.
+test "ed25519_instruction: verify matches official signed instructions" {
+    const parsed = try std.json.parseFromSlice(
+        struct { vectors: []const Ed25519InstructionVector },
+        std.testing.allocator,
+        @embedFile("ed25519_vectors.json"),
+        .{},
+    );
+    defer parsed.deinit();
+
+    for (parsed.value.vectors) |vector| {
+        var scratch: [512]u8 = undefined;
+        const ix = try verify(vector.message, &vector.public_key, &vector.signature, &scratch);
+        try std.testing.expectEqualSlices(u8, vector.data, ix.data);
+
+        const bytes = try buildInstructionBytes(ix.data);
+        defer std.testing.allocator.free(bytes);
+        const view = try parseSignature(.{ .bytes = bytes }, 0);
+        try std.testing.expectEqual(vector.offsets, view.offsets);
+        try std.testing.expectEqual(vector.public_key, view.public_key.*);
+        try std.testing.expectEqualSlices(u8, &vector.signature, view.signature);
+        try std.testing.expectEqualSlices(u8, vector.message, view.message);
+    }
+}
+
 fn buildInstructionBytes(data: []const u8) ![]u8 {
     const allocator = std.testing.allocator;
     const bytes = try allocator.alloc(u8, 2 + 32 + 2 + data.len);
@@ -366,6 +366,67 @@ test "secp256k1_instruction: constructEthAddress validates length" {
     try std.testing.expectError(error.InvalidArgument, constructEthAddress(&[_]u8{0} ** 63));
 }
 
+const Secp256k1Signer = struct {
+    secret_key: [32]u8,
+    public_key: []const u8,
+    eth_address: [HASHED_PUBKEY_SERIALIZED_SIZE]u8,
+    message: []const u8,
+    signature: []const u8,
+    recovery_id: u8,
+};
+
+const Secp256k1InstructionVector = struct {
+    name: []const u8,
+    signers: []const Secp256k1Signer,
+    offsets: []const SignatureOffsets,
+    data: []const u8,
+};
+
+// Generated by `program-test/tests/secp256k1_instruction.rs` from the
+// official `new_secp256k1_instruction_with_signature` and `sign_message`.
+test "secp256k1_instruction: builders match official signed instructions" {
+    const parsed = try std.json.parseFromSlice(
+        struct { vectors: []const Secp256k1InstructionVector },
+        std.testing.allocator,
+        @embedFile("secp256k1_vectors.json"),
+        .{},
+    );
+    defer parsed.deinit();
+
+    for (parsed.value.vectors) |vector| {
+        var scratch: [512]u8 = undefined;
+        const ix = if (vector.signers.len == 1) blk: {
+            const signer = vector.signers[0];
+            break :blk try verifyFirst(
+                signer.message,
+                &signer.eth_address,
+                signer.signature[0..SIGNATURE_SERIALIZED_SIZE],
+                signer.recovery_id,
+                &scratch,
+            );
+        } else try buildInstruction(
+            vector.offsets,
+            vector.data[1 + vector.offsets.len * SIGNATURE_OFFSETS_SERIALIZED_SIZE ..],
+            &scratch,
+        );
+        try std.testing.expectEqualSlices(u8, vector.data, ix.data);
+
+        const bytes = try buildInstructionBytes(ix.data);
+        defer std.testing.allocator.free(bytes);
+        const fake_ix = IntrospectedInstruction{ .bytes = bytes };
+        try std.testing.expectEqual(vector.signers.len, try signatureCount(fake_ix));
+        for (vector.signers, vector.offsets, 0..) |signer, offsets, i| {
+            const view = try parseSignatureSelfContained(fake_ix, i, 0);
+            try std.testing.expectEqual(offsets, view.offsets);
+            try std.testing.expectEqual(signer.eth_address, view.eth_address.*);
+            try std.testing.expectEqual(signer.eth_address, try constructEthAddress(signer.public_key));
+            try std.testing.expectEqualSlices(u8, signer.signature, view.signature);
+            try std.testing.expectEqual(signer.recovery_id, view.recovery_id);
+            try std.testing.expectEqualSlices(u8, signer.message, view.message);
+        }
+    }
+}
+
 fn buildInstructionBytes(data: []const u8) ![]u8 {
     const allocator = std.testing.allocator;
     const bytes = try allocator.alloc(u8, 2 + 32 + 2 + data.len);
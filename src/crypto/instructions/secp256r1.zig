@@ -34,6 +34,19 @@ pub const SIGNATURE_OFFSETS_START: usize = 2;
 pub const DATA_START: usize = SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SERIALIZED_SIZE;
 pub const CURRENT_INSTRUCTION_INDEX: u16 = std.math.maxInt(u16);
 pub const MAX_SIGNATURES: usize = 8;
+pub const FIELD_SIZE: usize = 32;
+
+/// Group order `n` of P-256, big-endian.
+pub const SECP256R1_ORDER: [FIELD_SIZE]u8 = .{
+    0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xBC, 0xE6, 0xFA, 0xAD, 0xA7, 0x17, 0x9E, 0x84, 0xF3, 0xB9, 0xCA, 0xC2, 0xFC, 0x63, 0x25, 0x51,
+};
+
+/// `n / 2`, big-endian. The precompile rejects signatures whose `s` exceeds it.
+pub const SECP256R1_HALF_ORDER: [FIELD_SIZE]u8 = .{
+    0x7F, 0xFF, 0xFF, 0xFF, 0x80, 0x00, 0x00, 0x00, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xDE, 0x73, 0x7D, 0x56, 0xD3, 0x8B, 0xCF, 0x42, 0x79, 0xDC, 0xE5, 0x61, 0x7E, 0x31, 0x92, 0xA8,
+};
 
 /// One serialized offset entry inside the secp256r1 instruction data.
 pub const SignatureOffsets = extern struct {
@@ -131,6 +144,22 @@ pub fn verify(
     };
 }
 
+/// Whether the `s` half of an `r || s` signature is at most `n / 2`.
+pub fn isLowS(signature: *const [SIGNATURE_SERIALIZED_SIZE]u8) bool {
+    const s = std.mem.readInt(u256, signature[FIELD_SIZE..], .big);
+    return s <= std.mem.readInt(u256, &SECP256R1_HALF_ORDER, .big);
+}
+
+/// Replace a high `s` with `n - s` in place, as the official signer does
+/// before building the instruction. Returns whether `s` changed.
+pub fn normalizeLowS(signature: *[SIGNATURE_SERIALIZED_SIZE]u8) bool {
+    if (isLowS(signature)) return false;
+    const s = std.mem.readInt(u256, signature[FIELD_SIZE..], .big);
+    const order = std.mem.readInt(u256, &SECP256R1_ORDER, .big);
+    std.mem.writeInt(u256, signature[FIELD_SIZE..], order - s, .big);
+    return true;
+}
+
 pub fn signatureCount(ix: IntrospectedInstruction) !u8 {
     const data = ix.data();
     if (data.len < SIGNATURE_OFFSETS_START) return error.InvalidInstructionData;
@@ -344,6 +373,53 @@ test "secp256r1_instruction: parseSignature rejects non-self-contained layout" {
     try std.testing.expectError(error.InvalidInstructionData, parseSignature(fake_ix, 0));
 }
 
+const Secp256r1InstructionVector = struct {
+    name: []const u8,
+    secret_key: [32]u8,
+    public_key: [COMPRESSED_PUBKEY_SERIALIZED_SIZE]u8,
+    message: []const u8,
+    raw_signature: [SIGNATURE_SERIALIZED_SIZE]u8,
+    signature: [SIGNATURE_SERIALIZED_SIZE]u8,
+    high_s_normalized: bool,
+    offsets: SignatureOffsets,
+    data: []const u8,
+    /// First solana-sdk release with the secp256r1 program.
+    since_sdk: ?[]const u8 = null,
+};
+
+// Generated by `program-test/tests/secp256r1_instruction.rs` from the
+// official `new_secp256r1_instruction_with_signature`.
+test "secp256r1_instruction: verify and low-S match official signed instructions" {
+    const parsed = try std.json.parseFromSlice(
+        struct { half_order: [FIELD_SIZE]u8, vectors: []const Secp256r1InstructionVector },
+        std.testing.allocator,
+        @embedFile("secp256r1_vectors.json"),
+        .{},
+    );
+    defer parsed.deinit();
+    try std.testing.expectEqual(SECP256R1_HALF_ORDER, parsed.value.half_order);
+
+    for (parsed.value.vectors) |vector| {
+        try std.testing.expectEqualStrings("2.1.1", vector.since_sdk.?);
+        var signature = vector.raw_signature;
+        try std.testing.expectEqual(vector.high_s_normalized, normalizeLowS(&signature));
+        try std.testing.expectEqual(vector.signature, signature);
+        try std.testing.expect(isLowS(&signature));
+
+        var scratch: [512]u8 = undefined;
+        const ix = try verify(vector.message, &vector.public_key, &signature, &scratch);
+        try std.testing.expectEqualSlices(u8, vector.data, ix.data);
+
+        const bytes = try buildInstructionBytes(ix.data);
+        defer std.testing.allocator.free(bytes);
+        const view = try parseSignature(.{ .bytes = bytes }, 0);
+        try std.testing.expectEqual(vector.offsets, view.offsets);
+        try std.testing.expectEqual(vector.public_key, view.public_key.*);
+        try std.testing.expectEqualSlices(u8, &vector.signature, view.signature);
+        try std.testing.expectEqualSlices(u8, vector.message, view.message);
+    }
+}
+
 fn buildInstructionBytes(data: []const u8) ![]u8 {
     const allocator = std.testing.allocator;
     const bytes = try allocator.alloc(u8, 2 + 32 + 2 + data.len);
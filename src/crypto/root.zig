@@ -4,11 +4,14 @@
 //! native signature-verification instruction builders/parsers so related
 //! code lives together physically as well as logically.
 
+const std = @import("std");
+
 pub const hash = @import("hash.zig");
 pub const secp256k1_recover = @import("secp256k1_recover.zig");
 pub const alt_bn128 = @import("alt_bn128.zig");
 pub const poseidon = @import("poseidon.zig");
 pub const big_mod_exp = @import("big_mod_exp.zig");
+pub const curve25519 = @import("curve25519.zig");
 
 pub const instructions = struct {
     pub const ed25519 = @import("instructions/ed25519.zig");
@@ -28,3 +31,7 @@ pub const keccak256 = hash.keccak256;
 pub const blake3 = hash.blake3;
 pub const hashv = hash.hashv;
 pub const bigModExp = big_mod_exp.bigModExp;
+
+test {
+    std.testing.refAllDecls(@This());
+}
@@ -692,6 +692,132 @@ test "entrypoint: skipAccounts is dup-aware" {
     try std.testing.expectEqual(@as(u64, 333), last.lamports());
 }
 
+const DuplicateAliasingVectors = struct {
+    const AliasedAccount = struct {
+        key: Pubkey,
+        lamports: u64,
+        program_owned: bool,
+    };
+    const Operation = struct {
+        kind: []const u8,
+        position: usize,
+    };
+
+    lamports_delta: u64,
+    accounts: []const AliasedAccount,
+    positions: []const u8,
+    operations: []const Operation,
+    observed_position: usize,
+    expected_final_lamports: []const u64,
+};
+
+test "entrypoint: duplicate slots alias the original account record" {
+    const parsed = try std.json.parseFromSlice(
+        DuplicateAliasingVectors,
+        std.testing.allocator,
+        @embedFile("duplicate_aliasing_vectors.json"),
+        .{},
+    );
+    defer parsed.deinit();
+    const vectors = parsed.value;
+
+    // Serialize the first occurrence of each account in full and later
+    // occurrences as a duplicate marker pointing at that position.
+    var input: [32768]u8 align(8) = undefined;
+    @memset(&input, 0);
+    var ptr: [*]u8 = &input;
+    std.mem.writeInt(u64, ptr[0..8], vectors.positions.len, .little);
+    ptr += 8;
+    for (vectors.positions, 0..) |index, position| {
+        if (std.mem.indexOfScalar(u8, vectors.positions[0..position], index)) |first| {
+            ptr[0] = @intCast(first);
+            ptr += 8;
+            continue;
+        }
+        const record: Account = .{
+            .borrow_state = account.NON_DUP_MARKER,
+            .is_signer = 0,
+            .is_writable = 1,
+            .is_executable = 0,
+            ._padding = .{0} ** 4,
+            .key = vectors.accounts[index].key,
+            .owner = makePubkey(if (vectors.accounts[index].program_owned) 0x01 else 0x00),
+            .lamports = vectors.accounts[index].lamports,
+            .data_len = 0,
+        };
+        @memcpy(ptr[0..@sizeOf(Account)], std.mem.asBytes(&record));
+        ptr += @sizeOf(Account) + MAX_PERMITTED_DATA_INCREASE + 8;
+    }
+    std.mem.writeInt(u64, ptr[0..8], 0, .little);
+
+    var ctx = InstructionContext.init(&input);
+    var infos: [8]AccountInfo = undefined;
+    for (infos[0..vectors.positions.len]) |*info| {
+        info.* = switch (try ctx.nextAccountMaybe()) {
+            .account => |a| a,
+            .duplicated => |idx| infos[idx],
+        };
+    }
+
+    const observed = infos[vectors.observed_position];
+    const before = observed.lamports();
+    for (vectors.operations) |op| {
+        const info = infos[op.position];
+        if (std.mem.eql(u8, op.kind, "debit")) {
+            info.subLamports(vectors.lamports_delta);
+        } else if (std.mem.eql(u8, op.kind, "credit")) {
+            info.addLamports(vectors.lamports_delta);
+        } else return error.UnknownOperation;
+    }
+    try std.testing.expectEqual(before + vectors.lamports_delta, observed.lamports());
+
+    for (vectors.expected_final_lamports, 0..) |expected, index| {
+        const position = std.mem.indexOfScalar(u8, vectors.positions, @intCast(index)).?;
+        try std.testing.expectEqual(expected, infos[position].lamports());
+    }
+    for (vectors.positions, 0..) |index, position| {
+        try std.testing.expectEqualSlices(u8, &vectors.accounts[index].key, infos[position].key());
+    }
+}
+
+const EntrypointReturnVector = struct {
+    name: []const u8,
+    returned_u64: u64,
+    interpreted_as: []const u8,
+    is_custom: bool,
+    custom_code: ?u32,
+};
+
+test "entrypoint: returned u64 decodes like the runtime's ProgramError::from" {
+    const parsed = try std.json.parseFromSlice(
+        struct { vectors: []const EntrypointReturnVector },
+        std.testing.allocator,
+        @embedFile("entrypoint_return_vectors.json"),
+        .{},
+    );
+    defer parsed.deinit();
+
+    for (parsed.value.vectors) |vector| {
+        if (vector.returned_u64 == SUCCESS) {
+            try std.testing.expectEqualStrings("Success", vector.interpreted_as);
+            continue;
+        }
+        const err = program_error.u64ToError(vector.returned_u64);
+        try std.testing.expectEqual(vector.is_custom, err == error.Custom);
+        if (vector.custom_code) |code| {
+            // Only the low half survives; `customError` round-trips it
+            // unless the high half was also set.
+            try std.testing.expectEqual(code, @as(u32, @truncate(vector.returned_u64)));
+            if (vector.returned_u64 >> program_error.BUILTIN_BIT_SHIFT == 0 or code == 0) {
+                try std.testing.expectEqual(vector.returned_u64, program_error.customError(code));
+            }
+        } else {
+            try std.testing.expectEqualStrings(vector.interpreted_as, @errorName(err));
+            try std.testing.expectEqual(vector.returned_u64, program_error.errorToU64(err));
+        }
+    }
+}
+
 // =========================================================================
 // AccountCursor tests
 // =========================================================================
@@ -151,3 +151,95 @@ test "pda: verifyPdaCanonical returns canonical bump" {
     const bump = try verifyPdaCanonical(&found.address, &.{"vault"}, &program_id);
     try std.testing.expectEqual(found.bump_seed, bump);
 }
+
+const BumpAttempt = struct {
+    bump: u8,
+    succeeds: bool,
+    address: ?Pubkey,
+};
+
+const CreateProgramAddressVector = struct {
+    name: []const u8,
+    program_id: Pubkey,
+    seeds: []const []const u8,
+    canonical_bump: u8,
+    canonical_address: Pubkey,
+    attempts: []const BumpAttempt,
+};
+
+// Generated by `program-test/tests/pda.rs` from the official
+// `Pubkey::create_program_address` / `find_program_address`.
+test "pda: explicit bumps match official createProgramAddress" {
+    const parsed = try std.json.parseFromSlice(
+        struct { vectors: []const CreateProgramAddressVector },
+        std.testing.allocator,
+        @embedFile("create_program_address_vectors.json"),
+        .{},
+    );
+    defer parsed.deinit();
+
+    for (parsed.value.vectors) |vector| {
+        const found = try findProgramAddress(vector.seeds, &vector.program_id);
+        try std.testing.expectEqual(vector.canonical_bump, found.bump_seed);
+        try std.testing.expectEqualSlices(u8, &vector.canonical_address, &found.address);
+
+        var seeds: [MAX_SEEDS][]const u8 = undefined;
+        @memcpy(seeds[0..vector.seeds.len], vector.seeds);
+        for (vector.attempts) |attempt| {
+            const bump_seed: []const u8 = &.{attempt.bump};
+            seeds[vector.seeds.len] = bump_seed;
+            const result = createProgramAddress(seeds[0 .. vector.seeds.len + 1], &vector.program_id);
+            if (attempt.succeeds) {
+                try std.testing.expectEqualSlices(u8, &attempt.address.?, &(try result));
+            } else {
+                try std.testing.expectError(ProgramError.InvalidSeeds, result);
+            }
+        }
+    }
+}
+
+const PdaErrorVector = struct {
+    name: []const u8,
+    function: []const u8,
+    program_id: Pubkey,
+    seeds: []const []const u8,
+    expected_error: ?[]const u8,
+    address: ?Pubkey,
+    bump: ?u8,
+};
+
+// Generated by `program-test/tests/pda.rs`. Where the official
+// `try_find_program_address` returns `None`, `findProgramAddress` reports
+// `MaxSeedLengthExceeded`.
+test "pda: seed limits match official create and find" {
+    const parsed = try std.json.parseFromSlice(
+        struct { max_seeds: usize, max_seed_len: usize, vectors: []const PdaErrorVector },
+        std.testing.allocator,
+        @embedFile("pda_error_vectors.json"),
+        .{},
+    );
+    defer parsed.deinit();
+    try std.testing.expectEqual(MAX_SEEDS, parsed.value.max_seeds);
+    try std.testing.expectEqual(MAX_SEED_LEN, parsed.value.max_seed_len);
+
+    for (parsed.value.vectors) |vector| {
+        if (std.mem.eql(u8, vector.function, "create_program_address")) {
+            const result = createProgramAddress(vector.seeds, &vector.program_id);
+            if (vector.address) |address| {
+                try std.testing.expectEqualSlices(u8, &address, &(try result));
+            } else {
+                try std.testing.expectError(error.MaxSeedLengthExceeded, result);
+                try std.testing.expectEqualStrings("MaxSeedLengthExceeded", vector.expected_error.?);
+            }
+        } else {
+            const result = findProgramAddress(vector.seeds, &vector.program_id);
+            if (vector.address) |address| {
+                const found = try result;
+                try std.testing.expectEqualSlices(u8, &address, &found.address);
+                try std.testing.expectEqual(vector.bump.?, found.bump_seed);
+            } else {
+                try std.testing.expectError(error.MaxSeedLengthExceeded, result);
+            }
+        }
+    }
+}
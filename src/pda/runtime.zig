@@ -101,12 +101,22 @@ pub fn findProgramAddress(
         return pda;
     }
 
-    // Host implementation
+    // Host implementation. The bump seed takes one of the `MAX_SEEDS`
+    // slots; the official `try_find_program_address` finds nothing here.
+    if (seeds.len >= MAX_SEEDS) {
+        return ProgramError.MaxSeedLengthExceeded;
+    }
+    for (seeds) |seed| {
+        if (seed.len > MAX_SEED_LEN) {
+            return ProgramError.MaxSeedLengthExceeded;
+        }
+    }
+
     var bump_seed: u8 = 255;
     while (true) : (bump_seed -= 1) {
         const bump_seed_slice: []const u8 = &.{bump_seed};
 
-        var seeds_with_bump: [16][]const u8 = undefined;
+        var seeds_with_bump: [MAX_SEEDS][]const u8 = undefined;
         for (seeds, 0..) |seed, i| {
             seeds_with_bump[i] = seed;
         }
@@ -10,8 +10,8 @@ const encodeBase58 = @import("base58.zig").encodeBase58;
 ///
 /// Implemented via `std.crypto.ecc.Edwards25519.fromBytes`, which
 /// performs full point decompression and rejects encodings that don't
-/// decompress to a valid curve point (including the all-zero pubkey
-/// used by the System Program — not on curve).
+/// decompress to a valid curve point. Like the runtime, it reduces a
+/// non-canonical y, and the all-zero pubkey (y = 0) is on the curve.
 pub fn isPointOnCurve(pk: *const Pubkey) bool {
     const point = std.crypto.ecc.Edwards25519.fromBytes(pk.*) catch return false;
     point.rejectIdentity() catch {};
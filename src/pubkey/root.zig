@@ -118,3 +118,25 @@ test "pubkey: isPointOnCurve" {
     not_on_curve[0] = 2; // y=2 is not on Edwards25519 (no x exists).
     try std.testing.expect(!isPointOnCurve(&not_on_curve));
 }
+
+const IsOnCurveVector = struct {
+    name: []const u8,
+    bytes: Pubkey,
+    is_on_curve: bool,
+};
+
+// Generated by `program-test/tests/is_on_curve.rs` from the official
+// `Pubkey::is_on_curve`.
+test "pubkey: isPointOnCurve matches official is_on_curve" {
+    const parsed = try std.json.parseFromSlice(
+        struct { seed: u64, vectors: []const IsOnCurveVector },
+        std.testing.allocator,
+        @embedFile("is_on_curve_vectors.json"),
+        .{},
+    );
+    defer parsed.deinit();
+
+    for (parsed.value.vectors) |vector| {
+        try std.testing.expectEqual(vector.is_on_curve, isPointOnCurve(&vector.bytes));
+    }
+}
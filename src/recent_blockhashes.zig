@@ -0,0 +1,47 @@
+const std = @import("std");
+const pubkey_mod = @import("pubkey/root.zig");
+
+/// Deprecated upstream, but the runtime still fills it and the nonce
+/// instructions still take it. There is no syscall for it, so programs
+/// read it from the account passed in.
+pub const RecentBlockhashes = struct {
+    pub const id = pubkey_mod.comptimeFromBase58("SysvarRecentB1ockHashes11111111111111111111");
+
+    pub const max_entries = 150;
+};
+
+/// One entry, newest first: a blockhash and the fee it was issued with.
+pub const Entry = extern struct {
+    blockhash: [32]u8,
+    lamports_per_signature: u64,
+
+    /// View raw sysvar account data as a slice of entries. `data` is
+    /// expected to start with a little-endian u64 entry count followed
+    /// by `len` packed `Entry` records; the account is allocated for
+    /// `max_entries`, so trailing bytes past `len` are ignored.
+    pub fn from(data: []const u8) []align(1) const Entry {
+        std.debug.assert(data.len >= @sizeOf(u64));
+        const len = std.mem.readInt(u64, data[0..@sizeOf(u64)], .little);
+        const body = data.ptr + @sizeOf(u64);
+        return @as([*]align(1) const Entry, @ptrCast(body))[0..len];
+    }
+};
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+test "recent_blockhashes: Entry.from parses entry count and ignores padding" {
+    var buf: [@sizeOf(u64) + 3 * @sizeOf(Entry)]u8 align(8) = .{0} ** (@sizeOf(u64) + 3 * @sizeOf(Entry));
+    std.mem.writeInt(u64, buf[0..8], 2, .little);
+    const newest = Entry{ .blockhash = .{7} ** 32, .lamports_per_signature = 5_000 };
+    const older = Entry{ .blockhash = .{6} ** 32, .lamports_per_signature = 10_000 };
+    @memcpy(buf[8..][0..@sizeOf(Entry)], std.mem.asBytes(&newest));
+    @memcpy(buf[8 + @sizeOf(Entry) ..][0..@sizeOf(Entry)], std.mem.asBytes(&older));
+
+    const entries = Entry.from(&buf);
+    try std.testing.expectEqual(@as(usize, 40), @sizeOf(Entry));
+    try std.testing.expectEqual(@as(usize, 2), entries.len);
+    try std.testing.expectEqualSlices(u8, &newest.blockhash, &entries[0].blockhash);
+    try std.testing.expectEqual(@as(u64, 10_000), entries[1].lamports_per_signature);
+}
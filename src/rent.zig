@@ -22,6 +22,23 @@ pub const Rent = struct {
     /// Account storage overhead for calculation of base rent.
     pub const account_storage_overhead: u64 = 128;
 
+    /// Seconds in a (tropical) year, as used by the cluster's slots-per-year
+    /// conversion.
+    pub const seconds_per_year: f64 = 365.242_199 * 24.0 * 60.0 * 60.0;
+
+    /// Slots in one year for a cluster ticking `ticks_per_second` with
+    /// `ticks_per_slot` ticks per slot. Mirrors `GenesisConfig::slots_per_year`,
+    /// including its tick duration truncated to whole microseconds.
+    pub fn slotsPerYear(ticks_per_slot: u64, ticks_per_second: u64) f64 {
+        const tick_ns: f64 = @floatFromInt((1_000_000 / ticks_per_second) * 1_000);
+        return 1.0 * seconds_per_year * (1_000_000_000.0 / tick_ns) / @as(f64, @floatFromInt(ticks_per_slot));
+    }
+
+    /// `years_elapsed` argument for `getAmountDue` after `slots_elapsed` slots.
+    pub fn yearsElapsed(slots_elapsed: u64, slots_per_year: f64) f64 {
+        return @as(f64, @floatFromInt(slots_elapsed)) / slots_per_year;
+    }
+
     pub const Data = packed struct {
         lamports_per_byte_year: u64 = Rent.default_lamports_per_byte_year,
         exemption_threshold: f64 = Rent.default_exemption_threshold,
@@ -92,3 +109,76 @@ pub const Rent = struct {
 test "rent: id matches sysvar root export" {
     try @import("std").testing.expectEqual(sysvar.RENT_ID, Rent.id);
 }
+
+test "rent: default minimum balances for standard account sizes" {
+    const testing = @import("std").testing;
+    var parsed = try @import("rent_vectors").loadStandardAccountMinimums(testing.allocator);
+    defer parsed.deinit();
+    const fixture = parsed.value;
+    const rent: Rent.Data = .{};
+    try testing.expectEqual(rent.lamports_per_byte_year, fixture.lamports_per_byte_year);
+    try testing.expectEqual(rent.exemption_threshold, fixture.exemption_threshold);
+
+    try testing.expectEqual(@as(usize, 6), fixture.vectors.len);
+    for (fixture.vectors) |vector| {
+        try testing.expectEqual(vector.minimum_balance, rent.getMinimumBalance(vector.data_len));
+    }
+    try testing.expectEqual(@import("system/root.zig").NONCE_STATE_SIZE, fixture.get("nonce").?.data_len);
+}
+
+// Generated by `program-test/tests/rent.rs` from
+// `GenesisConfig::slots_per_year`'s computation and the official `Rent::due`.
+test "rent: slots per year, years elapsed and rent due match the official vectors" {
+    const testing = @import("std").testing;
+    var parsed = try @import("rent_vectors").loadSlotsPerYear(testing.allocator);
+    defer parsed.deinit();
+
+    // `epsilon` is a relative tolerance recorded next to each f64.
+    for (parsed.value.slots_per_year) |case| {
+        const slots_per_year = Rent.slotsPerYear(case.ticks_per_slot, case.ticks_per_second);
+        try testing.expectApproxEqRel(case.expected_slots_per_year, slots_per_year, case.epsilon);
+        const years_elapsed = Rent.yearsElapsed(case.epochs_elapsed * case.slots_per_epoch, slots_per_year);
+        try testing.expectApproxEqRel(case.expected_years_elapsed, years_elapsed, case.epsilon);
+    }
+
+    // The first case is the default 400ms slot: one epoch is two days.
+    const defaults = parsed.value.slots_per_year[0];
+    try testing.expectEqual(@as(u64, 64), defaults.ticks_per_slot);
+    try testing.expectEqual(@as(u64, 160), defaults.ticks_per_second);
+    const slots_per_year = Rent.slotsPerYear(defaults.ticks_per_slot, defaults.ticks_per_second);
+    try testing.expectEqual(@as(u64, 78_892_314), @as(u64, @intFromFloat(slots_per_year)));
+    try testing.expectApproxEqRel(@as(f64, 2.0), Rent.yearsElapsed(432_000, slots_per_year) * 365.242_199, 1e-9);
+
+    const rent: Rent.Data = .{};
+    try testing.expectEqual(parsed.value.lamports_per_byte_year, rent.lamports_per_byte_year);
+    for (parsed.value.due_amount) |example| {
+        const case = parsed.value.slots_per_year[example.case];
+        try testing.expectEqual(case.expected_years_elapsed, example.years_elapsed);
+        try testing.expectEqual(example.minimum_balance, rent.getMinimumBalance(example.data_len));
+        try testing.expectEqual(
+            @as(?u64, example.due_amount),
+            rent.getAmountDue(example.balance, example.data_len, example.years_elapsed),
+        );
+        // Funded to the minimum, the same account owes nothing.
+        try testing.expectEqual(
+            @as(?u64, null),
+            rent.getAmountDue(example.minimum_balance, example.data_len, example.years_elapsed),
+        );
+    }
+}
+
+// Generated by `program-test/tests/rent.rs` from the official `Rent::due`.
+test "rent: getAmountDue matches official Rent::due" {
+    const std = @import("std");
+    var parsed = try @import("rent_vectors").loadDue(std.testing.allocator);
+    defer parsed.deinit();
+    const rent: Rent.Data = .{};
+    try std.testing.expectEqual(parsed.value.lamports_per_byte_year, rent.lamports_per_byte_year);
+    try std.testing.expectEqual(parsed.value.exemption_threshold, rent.exemption_threshold);
+
+    for (parsed.value.vectors) |vector| {
+        const due = rent.getAmountDue(vector.balance, vector.data_len, vector.years_elapsed);
+        try std.testing.expectEqual(std.mem.eql(u8, vector.variant, "exempt"), due == null);
+        try std.testing.expectEqual(vector.amount, due);
+    }
+}
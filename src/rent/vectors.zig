@@ -0,0 +1,89 @@
+//! Official `Rent` vectors, generated by `program-test/tests/rent.rs`.
+//!
+//! Exported from `build.zig` as the `rent_vectors` module so the packages
+//! that size or charge accounts check them against the same files as
+//! `src/rent.zig`. Test-only: nothing in the SDK imports it.
+
+const std = @import("std");
+
+pub const DueVector = struct {
+    name: []const u8,
+    balance: u64,
+    data_len: usize,
+    years_elapsed: f64,
+    /// `"exempt"` or `"paying"`.
+    variant: []const u8,
+    /// The `RentDue::Paying` amount.
+    amount: ?u64,
+};
+
+pub const Due = struct {
+    lamports_per_byte_year: u64,
+    exemption_threshold: f64,
+    vectors: []const DueVector,
+};
+
+pub fn loadDue(allocator: std.mem.Allocator) !std.json.Parsed(Due) {
+    return std.json.parseFromSlice(Due, allocator, @embedFile("due_vectors.json"), .{});
+}
+
+pub const SlotsPerYearVector = struct {
+    ticks_per_slot: u64,
+    ticks_per_second: u64,
+    slots_per_epoch: u64,
+    expected_slots_per_year: f64,
+    epochs_elapsed: u64,
+    expected_years_elapsed: f64,
+    /// Relative tolerance for both `f64` fields.
+    epsilon: f64,
+};
+
+/// `Rent::due` for an account short of its minimum after
+/// `slots_per_year[case]`'s elapsed epochs.
+pub const DueAmountExample = struct {
+    case: usize,
+    data_len: u64,
+    balance: u64,
+    minimum_balance: u64,
+    years_elapsed: f64,
+    due_amount: u64,
+};
+
+pub const SlotsPerYear = struct {
+    lamports_per_byte_year: u64,
+    slots_per_year: []const SlotsPerYearVector,
+    due_amount: []const DueAmountExample,
+};
+
+pub fn loadSlotsPerYear(allocator: std.mem.Allocator) !std.json.Parsed(SlotsPerYear) {
+    return std.json.parseFromSlice(SlotsPerYear, allocator, @embedFile("slots_per_year_vectors.json"), .{});
+}
+
+pub const StandardAccountVector = struct {
+    name: []const u8,
+    data_len: usize,
+    minimum_balance: u64,
+};
+
+pub const StandardAccountMinimums = struct {
+    lamports_per_byte_year: u64,
+    exemption_threshold: f64,
+    vectors: []const StandardAccountVector,
+
+    /// The entry for the layout called `name`, e.g. `"nonce"`.
+    pub fn get(self: StandardAccountMinimums, name: []const u8) ?StandardAccountVector {
+        for (self.vectors) |vector| {
+            if (std.mem.eql(u8, vector.name, name)) return vector;
+        }
+        return null;
+    }
+};
+
+pub fn loadStandardAccountMinimums(allocator: std.mem.Allocator) !std.json.Parsed(StandardAccountMinimums) {
+    return std.json.parseFromSlice(
+        StandardAccountMinimums,
+        allocator,
+        @embedFile("standard_account_minimums_vectors.json"),
+        .{},
+    );
+}
@@ -65,6 +65,7 @@ pub const pda = @import("pda/root.zig");
 pub const clock = @import("clock.zig");
 pub const rent = @import("rent.zig");
 pub const slot_hashes = @import("slot_hashes.zig");
+pub const recent_blockhashes = @import("recent_blockhashes.zig");
 pub const stake_history = @import("stake_history/root.zig");
 pub const stake = @import("stake.zig");
 
@@ -194,6 +195,7 @@ pub const clock_id = sysvar.CLOCK_ID;
 pub const rent_id = sysvar.RENT_ID;
 pub const epoch_schedule_id = sysvar.EPOCH_SCHEDULE_ID;
 pub const slot_hashes_id = sysvar.SLOT_HASHES_ID;
+pub const recent_blockhashes_id = sysvar.RECENT_BLOCKHASHES_ID;
 pub const stake_history_id = sysvar.STAKE_HISTORY_ID;
 pub const instructions_sysvar_id = sysvar.INSTRUCTIONS_ID;
 
@@ -244,17 +246,25 @@ const mock_only_source_paths = [_][]const u8{
     "examples/counter.zig",
     "examples/mock_router.zig",
     "examples/mock_adapter.zig",
+    "examples/program_id_check.zig",
+    "examples/duplicate_aliasing.zig",
+    "examples/slot_hashes.zig",
     "program-test/build.zig",
+    "program-test/tests/common/mod.rs",
     "program-test/tests/hello.rs",
     "program-test/tests/token_2022.rs",
     "program-test/tests/escrow.rs",
     "program-test/tests/counter.rs",
+    "program-test/tests/vault.rs",
     "program-test/tests/spl_token.rs",
     "program-test/tests/spl_ata.rs",
     "program-test/tests/pubkey.rs",
     "program-test/tests/cpi.rs",
     "program-test/tests/spl_memo.rs",
     "program-test/tests/mock_router.rs",
+    "program-test/tests/program_id_check.rs",
+    "program-test/tests/duplicate_aliasing.rs",
+    "program-test/tests/slot_progression.rs",
 };
 
 const banned_offchain_terms = [_][]const u8{
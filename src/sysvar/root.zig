@@ -42,6 +42,7 @@ pub const CLOCK_ID = shared.CLOCK_ID;
 pub const RENT_ID = shared.RENT_ID;
 pub const EPOCH_SCHEDULE_ID = shared.EPOCH_SCHEDULE_ID;
 pub const SLOT_HASHES_ID = shared.SLOT_HASHES_ID;
+pub const RECENT_BLOCKHASHES_ID = shared.RECENT_BLOCKHASHES_ID;
 pub const STAKE_HISTORY_ID = shared.STAKE_HISTORY_ID;
 pub const INSTRUCTIONS_ID = shared.INSTRUCTIONS_ID;
 
@@ -76,6 +77,99 @@ test "sysvar: EpochSchedule layout is 33 bytes" {
     try std.testing.expect(@sizeOf(EpochSchedule) >= 33);
 }
 
+const EpochScheduleVector = struct {
+    name: []const u8,
+    slots_per_epoch: u64,
+    leader_schedule_slot_offset: u64,
+    warmup: bool,
+    first_normal_epoch: u64,
+    first_normal_slot: u64,
+    slots: []const struct { slot: u64, epoch: u64, slot_index: u64 },
+    epochs: []const struct { epoch: u64, first_slot: u64, slots_in_epoch: u64 },
+};
+
+// Generated by `program-test/tests/epoch_schedule.rs` from
+// `solana_epoch_schedule::EpochSchedule`.
+test "sysvar: EpochSchedule derives epoch and slot index from absolute slots" {
+    const parsed = try std.json.parseFromSlice(
+        struct { vectors: []const EpochScheduleVector },
+        std.testing.allocator,
+        @embedFile("epoch_schedule_vectors.json"),
+        .{},
+    );
+    defer parsed.deinit();
+
+    for (parsed.value.vectors) |vector| {
+        const schedule: EpochSchedule = .{
+            .slots_per_epoch = vector.slots_per_epoch,
+            .leader_schedule_slot_offset = vector.leader_schedule_slot_offset,
+            .warmup = vector.warmup,
+            .first_normal_epoch = vector.first_normal_epoch,
+            .first_normal_slot = vector.first_normal_slot,
+        };
+        for (vector.slots) |row| {
+            const derived = schedule.getEpochAndSlotIndex(row.slot);
+            try std.testing.expectEqual(row.epoch, derived.epoch);
+            try std.testing.expectEqual(row.slot_index, derived.slot_index);
+            try std.testing.expectEqual(row.epoch, schedule.getEpoch(row.slot));
+            try std.testing.expect(row.slot <= schedule.getLastSlotInEpoch(row.epoch));
+        }
+        for (vector.epochs) |row| {
+            try std.testing.expectEqual(row.first_slot, schedule.getFirstSlotInEpoch(row.epoch));
+            try std.testing.expectEqual(row.slots_in_epoch, schedule.getSlotsInEpoch(row.epoch));
+        }
+    }
+}
+
+test "sysvar: EpochSchedule saturates warmup slots near u64 max" {
+    // No official schedule keeps `u64::MAX` in warmup, so this only pins
+    // that the derivation saturates instead of overflowing.
+    const schedule: EpochSchedule = .{
+        .slots_per_epoch = 8192,
+        .leader_schedule_slot_offset = 8192,
+        .warmup = true,
+        .first_normal_epoch = 8,
+        .first_normal_slot = std.math.maxInt(u64),
+    };
+    const derived = schedule.getEpochAndSlotIndex(std.math.maxInt(u64) - 1);
+    try std.testing.expectEqual(@as(u64, 58), derived.epoch);
+    try std.testing.expectEqual(std.math.maxInt(u64) - 1 - ((@as(u64, 1) << 63) - 32), derived.slot_index);
+}
+
+const ClockVector = struct {
+    name: []const u8,
+    slot: u64,
+    epoch_start_timestamp: i64,
+    epoch: u64,
+    leader_schedule_epoch: u64,
+    unix_timestamp: i64,
+    serialized: []const u8,
+};
+
+// Generated by `program-test/tests/clock.rs` from bincode `Clock`.
+test "sysvar: Clock layout matches official bincode serialization" {
+    const parsed = try std.json.parseFromSlice(
+        struct { size: usize, vectors: []const ClockVector },
+        std.testing.allocator,
+        @embedFile("clock_vectors.json"),
+        .{},
+    );
+    defer parsed.deinit();
+    try std.testing.expectEqual(parsed.value.size, @sizeOf(Clock));
+
+    for (parsed.value.vectors) |vector| {
+        const clock: Clock = .{
+            .slot = vector.slot,
+            .epoch_start_timestamp = vector.epoch_start_timestamp,
+            .epoch = vector.epoch,
+            .leader_schedule_epoch = vector.leader_schedule_epoch,
+            .unix_timestamp = vector.unix_timestamp,
+        };
+        try std.testing.expectEqualSlices(u8, vector.serialized, std.mem.asBytes(&clock));
+        try std.testing.expectEqual(clock, std.mem.bytesToValue(Clock, vector.serialized));
+    }
+}
+
 test "sysvar: LastRestartSlot is a single u64" {
     try std.testing.expectEqual(@as(usize, 8), @sizeOf(LastRestartSlot));
 }
@@ -29,6 +29,9 @@ pub const EPOCH_SCHEDULE_ID: Pubkey = pubkey.comptimeFromBase58("SysvarEpochSche
 /// Slot hashes sysvar ID
 pub const SLOT_HASHES_ID: Pubkey = pubkey.comptimeFromBase58("SysvarS1otHashes111111111111111111111111111");
 
+/// Recent blockhashes sysvar ID (deprecated upstream, still populated)
+pub const RECENT_BLOCKHASHES_ID: Pubkey = pubkey.comptimeFromBase58("SysvarRecentB1ockHashes11111111111111111111");
+
 /// Stake history sysvar ID
 pub const STAKE_HISTORY_ID: Pubkey = pubkey.comptimeFromBase58("SysvarStakeHistory1111111111111111111111111");
 
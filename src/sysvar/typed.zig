@@ -1,3 +1,4 @@
+const std = @import("std");
 const shared = @import("shared.zig");
 const log = shared.log;
 const ProgramError = shared.ProgramError;
@@ -20,6 +21,67 @@ pub const EpochSchedule = extern struct {
     /// The first slot after the warmup period
     first_normal_slot: u64,
 
+    /// Length of the first warmup epoch; each warmup epoch doubles it.
+    pub const MINIMUM_SLOTS_PER_EPOCH: u64 = 32;
+
+    /// Epoch and offset into that epoch for an absolute slot. During
+    /// warmup epochs are `32 << epoch` slots long, so the split is not
+    /// `slot / slots_per_epoch`.
+    pub const EpochAndSlotIndex = struct {
+        epoch: u64,
+        slot_index: u64,
+    };
+
+    /// Number of slots in `epoch`.
+    pub fn getSlotsInEpoch(self: EpochSchedule, epoch: u64) u64 {
+        if (epoch < self.first_normal_epoch) {
+            return saturatingPow2(epoch +| @ctz(MINIMUM_SLOTS_PER_EPOCH));
+        }
+        return self.slots_per_epoch;
+    }
+
+    pub fn getEpoch(self: EpochSchedule, slot: u64) u64 {
+        return self.getEpochAndSlotIndex(slot).epoch;
+    }
+
+    pub fn getEpochAndSlotIndex(self: EpochSchedule, slot: u64) EpochAndSlotIndex {
+        if (slot < self.first_normal_slot) {
+            // Like Rust's `next_power_of_two` in release builds, a slot
+            // too large to round up wraps to 0, whose `@ctz` is 64.
+            const next_pow = std.math.ceilPowerOfTwo(u64, slot +| MINIMUM_SLOTS_PER_EPOCH +| 1) catch 0;
+            const epoch = @as(u64, @ctz(next_pow)) -| @ctz(MINIMUM_SLOTS_PER_EPOCH) -| 1;
+            const epoch_len = saturatingPow2(epoch + @ctz(MINIMUM_SLOTS_PER_EPOCH));
+            return .{
+                .epoch = epoch,
+                .slot_index = slot -| (epoch_len -| MINIMUM_SLOTS_PER_EPOCH),
+            };
+        }
+        if (self.slots_per_epoch == 0) {
+            return .{ .epoch = self.first_normal_epoch, .slot_index = 0 };
+        }
+        const normal_slot_index = slot - self.first_normal_slot;
+        return .{
+            .epoch = self.first_normal_epoch +| normal_slot_index / self.slots_per_epoch,
+            .slot_index = normal_slot_index % self.slots_per_epoch,
+        };
+    }
+
+    pub fn getFirstSlotInEpoch(self: EpochSchedule, epoch: u64) u64 {
+        if (epoch <= self.first_normal_epoch) {
+            return (saturatingPow2(epoch) -| 1) *| MINIMUM_SLOTS_PER_EPOCH;
+        }
+        return ((epoch - self.first_normal_epoch) *| self.slots_per_epoch) +| self.first_normal_slot;
+    }
+
+    pub fn getLastSlotInEpoch(self: EpochSchedule, epoch: u64) u64 {
+        return (self.getFirstSlotInEpoch(epoch) +| self.getSlotsInEpoch(epoch)) -| 1;
+    }
+
+    fn saturatingPow2(exponent: u64) u64 {
+        if (exponent >= 64) return std.math.maxInt(u64);
+        return @as(u64, 1) << @intCast(exponent);
+    }
+
     /// Read the epoch schedule via syscall. Returns
     /// `error.UnsupportedSysvar` if the runtime rejects the call or
     /// when running off-chain without the syscall surface.
@@ -213,3 +213,57 @@ test "sysvar_instructions: current index trailer" {
     const idx = readU16LE(buf, buf.len - 2);
     try testing.expectEqual(@as(u16, 2), idx);
 }
+
+const SysvarAccountMetaVector = struct {
+    pubkey: Pubkey,
+    is_signer: bool,
+    is_writable: bool,
+};
+
+const SysvarInstructionVector = struct {
+    offset: u16,
+    program_id: Pubkey,
+    accounts: []const SysvarAccountMetaVector,
+    data: []const u8,
+};
+
+const InstructionsSysvarVector = struct {
+    name: []const u8,
+    current_index: u16,
+    instructions: []const SysvarInstructionVector,
+    data: []const u8,
+};
+
+test "sysvar_instructions: deserialize matches construct_instructions_data vectors" {
+    const parsed = try std.json.parseFromSlice(
+        struct { vectors: []const InstructionsSysvarVector },
+        testing.allocator,
+        @embedFile("instructions_sysvar_vectors.json"),
+        .{},
+    );
+    defer parsed.deinit();
+
+    for (parsed.value.vectors) |vector| {
+        const data = vector.data;
+        try testing.expectEqual(@as(u16, @intCast(vector.instructions.len)), readU16LE(data, 0));
+        try testing.expectEqual(vector.current_index, readU16LE(data, data.len - 2));
+
+        for (vector.instructions, 0..) |expected, i| {
+            const ix = try deserialize(@intCast(i), data);
+            try testing.expectEqual(@as(usize, expected.offset), @intFromPtr(ix.bytes.ptr) - @intFromPtr(data.ptr));
+            try testing.expectEqualSlices(u8, &expected.program_id, ix.programId());
+            try testing.expectEqualSlices(u8, expected.data, ix.data());
+
+            try testing.expectEqual(@as(u16, @intCast(expected.accounts.len)), ix.numAccounts());
+            var it = ix.accounts();
+            for (expected.accounts) |expected_meta| {
+                const meta = it.next().?;
+                try testing.expectEqualSlices(u8, &expected_meta.pubkey, meta.pubkey);
+                try testing.expectEqual(expected_meta.is_signer, meta.isSigner());
+                try testing.expectEqual(expected_meta.is_writable, meta.isWritable());
+            }
+            try testing.expect(it.next() == null);
+        }
+        try testing.expectError(error.InvalidArgument, deserialize(@intCast(vector.instructions.len), data));
+    }
+}
@@ -0,0 +1,178 @@
+//! Filters for the official test vectors the packages load.
+//!
+//! Exported from `build.zig` as the `vector_filter` module so every package
+//! fixture loader filters by SDK version and by tag the same way.
+//! Test-only: nothing in the SDK imports it.
+//!
+//! A vector opts in through optional fields on its fixture struct:
+//!
+//!   * `since_sdk: ?[]const u8 = null` — first solana-sdk release that
+//!     knows the vector's variant; null when every supported SDK does.
+//!   * `tags: []const Tag = &.{}` — what kind of case the vector is.
+
+const std = @import("std");
+
+/// The fixed tag vocabulary. Fixtures store tags as JSON strings, which
+/// `std.json` parses into this enum, so a typo'd tag fails to load.
+pub const Tag = enum {
+    /// The official implementation rejects the input.
+    @"error",
+    /// The input sits exactly on, or one past, a documented limit.
+    boundary,
+    /// The input is near the largest value its type holds.
+    large,
+    /// The input is derived from a seeded generator.
+    random,
+};
+
+/// Whether a vector first known to `since_sdk` decodes on a consumer
+/// pinned to `sdk`.
+pub fn availableIn(since_sdk: ?[]const u8, sdk: std.SemanticVersion) !bool {
+    const since = since_sdk orelse return true;
+    return (try std.SemanticVersion.parse(since)).order(sdk) != .gt;
+}
+
+pub fn hasTag(tags: []const Tag, tag: Tag) bool {
+    return std.mem.indexOfScalar(Tag, tags, tag) != null;
+}
+
+pub const TagFilter = struct {
+    /// Keep only vectors with at least one of these tags; empty keeps all.
+    include: []const Tag = &.{},
+    /// Drop vectors with any of these tags.
+    exclude: []const Tag = &.{},
+
+    pub fn matches(self: TagFilter, tags: []const Tag) bool {
+        for (self.exclude) |tag| {
+            if (hasTag(tags, tag)) return false;
+        }
+        if (self.include.len == 0) return true;
+        for (self.include) |tag| {
+            if (hasTag(tags, tag)) return true;
+        }
+        return false;
+    }
+};
+
+/// The `items` a consumer pinned to `sdk` can decode, in order. The caller
+/// owns the returned slice; the items still borrow from the parsed fixture.
+pub fn upTo(
+    comptime T: type,
+    allocator: std.mem.Allocator,
+    items: []const T,
+    sdk: std.SemanticVersion,
+) ![]T {
+    var count: usize = 0;
+    for (items) |item| {
+        if (try availableIn(item.since_sdk, sdk)) count += 1;
+    }
+    const kept = try allocator.alloc(T, count);
+    var i: usize = 0;
+    for (items) |item| {
+        if (!(try availableIn(item.since_sdk, sdk))) continue;
+        kept[i] = item;
+        i += 1;
+    }
+    return kept;
+}
+
+/// The `items` whose tags pass `filter`, in order. The caller owns the
+/// returned slice; the items still borrow from the parsed fixture.
+pub fn withTags(
+    comptime T: type,
+    allocator: std.mem.Allocator,
+    items: []const T,
+    filter: TagFilter,
+) ![]T {
+    var count: usize = 0;
+    for (items) |item| {
+        if (filter.matches(item.tags)) count += 1;
+    }
+    const kept = try allocator.alloc(T, count);
+    var i: usize = 0;
+    for (items) |item| {
+        if (!filter.matches(item.tags)) continue;
+        kept[i] = item;
+        i += 1;
+    }
+    return kept;
+}
+
+const TestVector = struct {
+    name: []const u8,
+    since_sdk: ?[]const u8 = null,
+    tags: []const Tag = &.{},
+};
+
+const test_json =
+    \\[
+    \\  {"name": "legacy"},
+    \\  {"name": "in_2_1", "since_sdk": "2.1.0", "tags": ["boundary"]},
+    \\  {"name": "in_2_2", "since_sdk": "2.2.0", "tags": ["error", "large"]},
+    \\  {"name": "rejected", "tags": ["error"]}
+    \\]
+;
+
+fn expectNames(expected: []const []const u8, vectors: []const TestVector) !void {
+    try std.testing.expectEqual(expected.len, vectors.len);
+    for (expected, vectors) |name, vector| {
+        try std.testing.expectEqualStrings(name, vector.name);
+    }
+}
+
+test "an older sdk excludes exactly the newer vectors" {
+    const allocator = std.testing.allocator;
+    var parsed = try std.json.parseFromSlice([]const TestVector, allocator, test_json, .{});
+    defer parsed.deinit();
+
+    const Case = struct { sdk: std.SemanticVersion, kept: []const []const u8 };
+    const cases = [_]Case{
+        .{ .sdk = .{ .major = 2, .minor = 0, .patch = 26 }, .kept = &.{ "legacy", "rejected" } },
+        .{ .sdk = .{ .major = 2, .minor = 1, .patch = 0 }, .kept = &.{ "legacy", "in_2_1", "rejected" } },
+        .{ .sdk = .{ .major = 2, .minor = 1, .patch = 21 }, .kept = &.{ "legacy", "in_2_1", "rejected" } },
+        .{ .sdk = .{ .major = 3, .minor = 0, .patch = 0 }, .kept = &.{ "legacy", "in_2_1", "in_2_2", "rejected" } },
+    };
+    for (cases) |case| {
+        const kept = try upTo(TestVector, allocator, parsed.value, case.sdk);
+        defer allocator.free(kept);
+        try expectNames(case.kept, kept);
+    }
+}
+
+test "a malformed since_sdk is reported instead of kept" {
+    const vectors = [_]TestVector{.{ .name = "bad", .since_sdk = "2.1" }};
+    try std.testing.expectError(
+        error.InvalidVersion,
+        upTo(TestVector, std.testing.allocator, &vectors, .{ .major = 3, .minor = 0, .patch = 0 }),
+    );
+}
+
+test "tag filters include and exclude" {
+    const allocator = std.testing.allocator;
+    var parsed = try std.json.parseFromSlice([]const TestVector, allocator, test_json, .{});
+    defer parsed.deinit();
+
+    const Case = struct { filter: TagFilter, kept: []const []const u8 };
+    const cases = [_]Case{
+        .{ .filter = .{}, .kept = &.{ "legacy", "in_2_1", "in_2_2", "rejected" } },
+        .{ .filter = .{ .include = &.{.@"error"} }, .kept = &.{ "in_2_2", "rejected" } },
+        .{ .filter = .{ .exclude = &.{.@"error"} }, .kept = &.{ "legacy", "in_2_1" } },
+        .{ .filter = .{ .include = &.{ .boundary, .large } }, .kept = &.{ "in_2_1", "in_2_2" } },
+        .{ .filter = .{ .include = &.{.@"error"}, .exclude = &.{.large} }, .kept = &.{"rejected"} },
+        .{ .filter = .{ .include = &.{.random} }, .kept = &.{} },
+    };
+    for (cases) |case| {
+        const kept = try withTags(TestVector, allocator, parsed.value, case.filter);
+        defer allocator.free(kept);
+        try expectNames(case.kept, kept);
+    }
+}
+
+test "tags outside the vocabulary fail to parse" {
+    try std.testing.expectError(
+        error.InvalidEnumTag,
+        std.json.parseFromSlice([]const TestVector, std.testing.allocator,
+            \\[{"name": "typo", "tags": ["erorr"]}]
+        , .{}),
+    );
+}